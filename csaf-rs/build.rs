@@ -13,11 +13,27 @@ pub enum BuildError {
     SyntaxError(#[from] syn::Error),
     #[error("JSON parsing error")]
     JsonError(#[from] serde_json::Error),
+    #[error("CWE catalog error: {0}")]
+    CweError(String),
+    #[error("Language subtag registry error: {0}")]
+    LanguageRegistryError(String),
+    #[error("SPDX license list error: {0}")]
+    LicenseListError(String),
     #[error("other error")]
     Other,
 }
 
 fn main() -> Result<(), BuildError> {
+    // Tests which need the vendored CWE catalog or language subtag registry are only run if
+    // the data is available
+    println!("cargo::rustc-check-cfg=cfg(cwe_catalog, language_registry)");
+    if has_cwe_catalog("./src/csaf/cwe/data")? {
+        println!("cargo::rustc-cfg=cwe_catalog");
+    }
+    if Path::new("./src/csaf/language/data/language-subtag-registry.txt").exists() {
+        println!("cargo::rustc-cfg=language_registry");
+    }
+
     // We only need to generate these files as part of our cargo build process,
     // not if we are publishing or getting built by cargo from a crates.io
     // package. This is because the files are generated from the JSON schema
@@ -53,6 +69,12 @@ fn main() -> Result<(), BuildError> {
         "csaf/csaf2_1/ssvc_dp_schema.rs",
         false,
     )?;
+    build_cwe_catalog("./src/csaf/cwe/data", "csaf/cwe/catalog.rs")?;
+    build_language_registry(
+        "./src/csaf/language/data/language-subtag-registry.txt",
+        "csaf/language/subtags.rs",
+    )?;
+    build_license_list("./src/csaf/license/data", "csaf/license/list.rs")?;
 
     Ok(())
 }
@@ -100,3 +122,234 @@ fn remove_datetime_formats(value: &mut serde_json::Value) {
         }
     }
 }
+
+/// Checks whether at least one CWE CSV export is vendored in the given directory.
+fn has_cwe_catalog(input_dir: &str) -> Result<bool, BuildError> {
+    for entry in fs::read_dir(input_dir)? {
+        let file_name = entry?.file_name().to_string_lossy().to_string();
+        if file_name.starts_with("cwec_v") && file_name.ends_with(".csv") {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Generates the embedded CWE catalog from the vendored MITRE CSV exports. Each file
+/// `cwec_v<version>.csv` contains the weaknesses of one CWE version, of which only the
+/// columns `CWE-ID` and `Name` are used.
+fn build_cwe_catalog(input_dir: &str, output: &str) -> Result<(), BuildError> {
+    let mut catalogs = Vec::new();
+    for entry in fs::read_dir(input_dir)? {
+        let path = entry?.path();
+        let file_name = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+        let Some(version) = file_name.strip_prefix("cwec_v").and_then(|f| f.strip_suffix(".csv")) else {
+            continue;
+        };
+        let sort_key = version
+            .split('.')
+            .map(|part| part.parse::<u32>())
+            .collect::<Result<Vec<u32>, _>>()
+            .map_err(|_| BuildError::CweError(format!("invalid CWE version in {}", file_name)))?;
+
+        let rows = parse_csv(&fs::read_to_string(&path)?);
+        let header = rows.first().ok_or_else(|| BuildError::CweError(format!("{} is empty", file_name)))?;
+        let column = |name: &str| {
+            header
+                .iter()
+                .position(|h| h == name)
+                .ok_or_else(|| BuildError::CweError(format!("{} has no column {}", file_name, name)))
+        };
+        let (id_column, name_column) = (column("CWE-ID")?, column("Name")?);
+
+        let mut weaknesses = Vec::new();
+        for row in rows.iter().skip(1).filter(|row| row.len() > id_column.max(name_column)) {
+            let id = row[id_column]
+                .parse::<u32>()
+                .map_err(|_| BuildError::CweError(format!("invalid CWE ID {} in {}", row[id_column], file_name)))?;
+            weaknesses.push((id, row[name_column].clone()));
+        }
+        weaknesses.sort();
+        weaknesses.dedup_by_key(|(id, _)| *id);
+        catalogs.push((sort_key, version.to_string(), weaknesses));
+    }
+    catalogs.sort();
+
+    let mut content = String::from(
+        "// This file is generated by build.rs from the CWE CSV files in src/csaf/cwe/data.\n\
+         // Do not edit it manually.\n",
+    );
+    if catalogs.is_empty() {
+        content.push_str("use super::CweCatalog;\n\npub(super) static CATALOGS: &[CweCatalog] = &[];\n");
+    } else {
+        content.push_str("use super::{CweCatalog, Weakness};\n\npub(super) static CATALOGS: &[CweCatalog] = &[\n");
+        for (_, version, weaknesses) in catalogs {
+            content.push_str(&format!("    CweCatalog::new(\n        {:?},\n        &[\n", version));
+            for (id, name) in weaknesses {
+                content.push_str(&format!("            Weakness {{ id: {}, name: {:?} }},\n", id, name));
+            }
+            content.push_str("        ],\n    ),\n");
+        }
+        content.push_str("];\n");
+    }
+
+    let mut out_file = Path::new("src").to_path_buf();
+    out_file.push(output);
+    Ok(fs::write(out_file, content)?)
+}
+
+/// Generates the embedded language subtag registry from the vendored IANA Language Subtag
+/// Registry (in record-jar format). If it is not vendored, the registry is generated as `None`.
+fn build_language_registry(input: &str, output: &str) -> Result<(), BuildError> {
+    const KINDS: [&str; 5] = ["language", "extlang", "script", "region", "variant"];
+
+    let mut content = String::from(
+        "// This file is generated by build.rs from the IANA Language Subtag Registry in src/csaf/language/data.\n\
+         // Do not edit it manually.\n",
+    );
+    let mut out_file = Path::new("src").to_path_buf();
+    out_file.push(output);
+    let registry = match fs::read_to_string(input) {
+        Ok(registry) => registry,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            content.push_str("use super::SubtagRegistry;\n\npub(super) static REGISTRY: Option<SubtagRegistry> = None;\n");
+            return Ok(fs::write(out_file, content)?);
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    let mut file_date = None;
+    let mut ranges: Vec<Vec<(String, String)>> = vec![Vec::new(); KINDS.len()];
+    let mut grandfathered = Vec::new();
+    for record in registry.split("\n%%") {
+        let field = |name: &str| {
+            record
+                .lines()
+                .find_map(|line| line.strip_prefix(name).and_then(|l| l.strip_prefix(": ")))
+                .map(|value| value.trim().to_ascii_lowercase())
+        };
+        if let Some(date) = field("File-Date") {
+            file_date = Some(date);
+            continue;
+        }
+        let kind = field("Type").ok_or_else(|| BuildError::LanguageRegistryError(format!("record without type: {}", record)))?;
+        if kind == "grandfathered" {
+            grandfathered.extend(field("Tag"));
+        } else if let Some(index) = KINDS.iter().position(|k| *k == kind) {
+            let subtag = field("Subtag").ok_or_else(|| BuildError::LanguageRegistryError(format!("record without subtag: {}", record)))?;
+            let (start, end) = subtag.split_once("..").unwrap_or((&subtag, &subtag));
+            ranges[index].push((start.to_string(), end.to_string()));
+        }
+    }
+    let file_date = file_date.ok_or_else(|| BuildError::LanguageRegistryError("missing File-Date".to_string()))?;
+
+    content.push_str("use super::{SubtagRange, SubtagRegistry};\n\n");
+    content.push_str("pub(super) static REGISTRY: Option<SubtagRegistry> = Some(SubtagRegistry {\n");
+    content.push_str(&format!("    file_date: {:?},\n", file_date));
+    for (kind, mut ranges) in KINDS.iter().zip(ranges) {
+        ranges.sort_by(|a, b| (a.0.len(), &a.0).cmp(&(b.0.len(), &b.0)));
+        content.push_str(&format!("    {}s: &[\n", kind));
+        for (start, end) in ranges {
+            content.push_str(&format!("        SubtagRange({:?}, {:?}),\n", start, end));
+        }
+        content.push_str("    ],\n");
+    }
+    content.push_str("    grandfathered: &[\n");
+    for tag in grandfathered {
+        content.push_str(&format!("        {:?},\n", tag));
+    }
+    content.push_str("    ],\n});\n");
+
+    Ok(fs::write(out_file, content)?)
+}
+
+/// Generates the embedded SPDX license list from the vendored `licenses.json` and
+/// `exceptions.json` of the SPDX license list data. If they are not vendored, the list is
+/// generated as `None`.
+fn build_license_list(input_dir: &str, output: &str) -> Result<(), BuildError> {
+    let mut content = String::from(
+        "// This file is generated by build.rs from the SPDX license list in src/csaf/license/data.\n\
+         // Do not edit it manually.\n",
+    );
+    let mut out_file = Path::new("src").to_path_buf();
+    out_file.push(output);
+    let read_list = |file_name: &str| -> Result<Option<serde_json::Value>, BuildError> {
+        match fs::read_to_string(Path::new(input_dir).join(file_name)) {
+            Ok(list) => Ok(Some(serde_json::from_str(&list)?)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    };
+    let (Some(licenses), Some(exceptions)) = (read_list("licenses.json")?, read_list("exceptions.json")?) else {
+        content.push_str("use super::LicenseList;\n\npub(super) static LICENSE_LIST: Option<LicenseList> = None;\n");
+        return Ok(fs::write(out_file, content)?);
+    };
+
+    let version = licenses["licenseListVersion"]
+        .as_str()
+        .ok_or_else(|| BuildError::LicenseListError("missing licenseListVersion".to_string()))?;
+    let entries = |list: &serde_json::Value, key: &str, id_field: &str| {
+        let mut entries = list[key]
+            .as_array()
+            .ok_or_else(|| BuildError::LicenseListError(format!("missing {}", key)))?
+            .iter()
+            .map(|entry| {
+                let id = entry[id_field]
+                    .as_str()
+                    .ok_or_else(|| BuildError::LicenseListError(format!("{} without {}", key, id_field)))?;
+                let deprecated = entry["isDeprecatedLicenseId"].as_bool().unwrap_or(false);
+                Ok((id.to_ascii_lowercase(), id.to_string(), deprecated))
+            })
+            .collect::<Result<Vec<_>, BuildError>>()?;
+        // Sorted case-insensitively, as identifiers are matched case-insensitively
+        entries.sort();
+        Ok::<_, BuildError>(entries)
+    };
+
+    content.push_str("use super::{LicenseList, ListedIdentifier};\n\n");
+    content.push_str("pub(super) static LICENSE_LIST: Option<LicenseList> = Some(LicenseList {\n");
+    content.push_str(&format!("    version: {:?},\n", version));
+    for (field, list, key, id_field) in [
+        ("licenses", &licenses, "licenses", "licenseId"),
+        ("exceptions", &exceptions, "exceptions", "licenseExceptionId"),
+    ] {
+        content.push_str(&format!("    {}: &[\n", field));
+        for (_, id, deprecated) in entries(list, key, id_field)? {
+            content.push_str(&format!("        ListedIdentifier {{ id: {:?}, deprecated: {} }},\n", id, deprecated));
+        }
+        content.push_str("    ],\n");
+    }
+    content.push_str("});\n");
+
+    Ok(fs::write(out_file, content)?)
+}
+
+/// Splits CSV content into rows of fields. Fields may be quoted, in which case they may
+/// contain separators, line breaks and escaped (doubled) quotes.
+fn parse_csv(content: &str) -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = content.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => row.push(std::mem::take(&mut field)),
+            '\r' if !quoted => {}
+            '\n' if !quoted => {
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+            }
+            _ => field.push(c),
+        }
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+    rows
+}
@@ -1,11 +1,11 @@
-use crate::csaf::csaf2_0::schema::{Branch, CategoryOfTheRemediation, CommonSecurityAdvisoryFramework, DocumentGenerator, DocumentLevelMetaData, DocumentStatus, Flag, FullProductNameT, HelperToIdentifyTheProduct, Id, Involvement, LabelOfTlp, Note, ProductGroup, ProductStatus, ProductTree, Relationship, Remediation, Revision, RulesForSharingDocument, Score, Threat, Tracking, TrafficLightProtocolTlp, Vulnerability};
-use crate::csaf::csaf2_1::schema::{CategoryOfTheRemediation as Remediation21, DocumentStatus as Status21, Epss, LabelOfTlp as Tlp21};
-use crate::csaf::getter_traits::{BranchTrait, CsafTrait, DistributionTrait, DocumentTrait, FlagTrait, ProductTrait, GeneratorTrait, InvolvementTrait, MetricTrait, ProductGroupTrait, ProductIdentificationHelperTrait, ProductStatusTrait, ProductTreeTrait, RelationshipTrait, RemediationTrait, RevisionTrait, SharingGroupTrait, ThreatTrait, TlpTrait, TrackingTrait, VulnerabilityTrait, ContentTrait, VulnerabilityIdTrait, NoteTrait, WithGroupIds};
+use crate::csaf::csaf2_0::schema::{Branch, CategoryOfPublisher, CategoryOfReference, CategoryOfTheBranch, CategoryOfTheRemediation, CategoryOfTheThreat, CommonSecurityAdvisoryFramework, CryptographicHashes, Cwe, DocumentGenerator, DocumentLevelMetaData, DocumentStatus, FileHash, Flag, FullProductNameT, HelperToIdentifyTheProduct, Id, Involvement, LabelOfTheFlag, LabelOfTlp, Note, NoteCategory, PartyCategory, ProductGroup, ProductStatus, ProductTree, Publisher, Reference, Relationship, Remediation, Revision, RulesForSharingDocument, Score, Threat, Tracking, TrafficLightProtocolTlp, Vulnerability};
+use crate::csaf::csaf2_1::schema::{CategoryOfPublisher as Publisher21, CategoryOfReference as Reference21, CategoryOfTheBranch as Branch21, CategoryOfTheRemediation as Remediation21, CategoryOfTheThreat as Threat21, DocumentStatus as Status21, Epss, LabelOfTheFlag as Flag21, LabelOfTlp as Tlp21, NoteCategory as NoteCategory21, PartyCategory as Party21};
+use crate::csaf::getter_traits::{BranchTrait, CsafTrait, DistributionTrait, DocumentTrait, FileHashTrait, ReferenceTrait, FlagTrait, ProductTrait, GeneratorTrait, HashTrait, InvolvementTrait, MetricTrait, ProductGroupTrait, ProductIdentificationHelperTrait, ProductStatusTrait, ProductTreeTrait, PublisherTrait, RelationshipTrait, RemediationTrait, RevisionTrait, SharingGroupTrait, ThreatTrait, TlpTrait, TrackingTrait, VulnerabilityTrait, ContentTrait, VulnerabilityIdTrait, NoteTrait, WithGroupIds, CweTrait, FirstKnownExploitationDateTrait};
 use std::ops::Deref;
 use serde::de::Error;
 use serde_json::{Map, Value};
 use crate::csaf::csaf2_1::ssvc_schema::SsvcV1;
-use crate::csaf::validation::ValidationError;
+use crate::csaf::validation::{ValidationError, ValidationSeverity};
 
 impl WithGroupIds for Remediation {
     fn get_group_ids(&self) -> Option<impl Iterator<Item=&String> + '_> {
@@ -96,6 +96,10 @@ impl MetricTrait for Score {
     fn get_source(&self) -> &Option<String> {
         &None
     }
+
+    fn get_metric_json_path(&self, vulnerability_idx: usize, metric_idx: usize) -> String {
+        format!("/vulnerabilities/{}/scores/{}", vulnerability_idx, metric_idx)
+    }
 }
 
 impl ContentTrait for Score {
@@ -153,6 +157,14 @@ impl ThreatTrait for Threat {
     fn get_date(&self) -> &Option<String> {
         &self.date
     }
+    /// Normalizes the threat category to CSAF 2.1, which uses the same categories.
+    fn get_category(&self) -> Threat21 {
+        match self.category {
+            CategoryOfTheThreat::ExploitStatus => Threat21::ExploitStatus,
+            CategoryOfTheThreat::Impact => Threat21::Impact,
+            CategoryOfTheThreat::TargetSet => Threat21::TargetSet,
+        }
+    }
 }
 
 impl VulnerabilityTrait for Vulnerability {
@@ -165,6 +177,8 @@ impl VulnerabilityTrait for Vulnerability {
     type InvolvementType = Involvement;
     type VulnerabilityIdType = Id;
     type NoteType = Note;
+    type CweType = Cwe;
+    type FirstKnownExploitationDateType = FirstKnownExploitationDate;
 
     fn get_remediations(&self) -> &Vec<Self::RemediationType> {
         &self.remediations
@@ -190,6 +204,10 @@ impl VulnerabilityTrait for Vulnerability {
         &self.discovery_date
     }
 
+    fn get_first_known_exploitation_dates(&self) -> impl Iterator<Item = &Self::FirstKnownExploitationDateType> + '_ {
+        std::iter::empty()
+    }
+
     fn get_flags(&self) -> &Option<Vec<Self::FlagType>> {
         &self.flags
     }
@@ -209,6 +227,29 @@ impl VulnerabilityTrait for Vulnerability {
     fn get_notes(&self) -> Option<&Vec<Self::NoteType>> {
         self.notes.as_ref().map(|x| x.deref())
     }
+
+    fn get_cwes(&self) -> Vec<&Self::CweType> {
+        self.cwe.iter().collect()
+    }
+}
+
+impl CweTrait for Cwe {
+    fn get_id(&self) -> &String {
+        self.id.deref()
+    }
+
+    fn get_name(&self) -> &String {
+        self.name.deref()
+    }
+
+    /// CSAF 2.0 does not specify the version of the CWE catalog
+    fn get_version(&self) -> Option<&String> {
+        None
+    }
+
+    fn get_cwe_json_path(&self, vulnerability_idx: usize, _cwe_idx: usize) -> String {
+        format!("/vulnerabilities/{}/cwe", vulnerability_idx)
+    }
 }
 
 impl VulnerabilityIdTrait for Id {
@@ -231,12 +272,66 @@ impl FlagTrait for Flag {
     fn get_date(&self) -> &Option<String> {
         &self.date
     }
+
+    /// Normalizes the flag label to CSAF 2.1, which uses the same labels.
+    fn get_label(&self) -> Flag21 {
+        match self.label {
+            LabelOfTheFlag::ComponentNotPresent => Flag21::ComponentNotPresent,
+            LabelOfTheFlag::InlineMitigationsAlreadyExist => Flag21::InlineMitigationsAlreadyExist,
+            LabelOfTheFlag::VulnerableCodeCannotBeControlledByAdversary => Flag21::VulnerableCodeCannotBeControlledByAdversary,
+            LabelOfTheFlag::VulnerableCodeNotInExecutePath => Flag21::VulnerableCodeNotInExecutePath,
+            LabelOfTheFlag::VulnerableCodeNotPresent => Flag21::VulnerableCodeNotPresent,
+        }
+    }
+
+    fn get_product_ids(&self) -> Option<impl Iterator<Item = &String> + '_> {
+        self.product_ids.as_ref().map(|p| (*p).iter().map(|x| x.deref()))
+    }
+}
+
+/// First known exploitation dates do not exist in CSAF 2.0, so this type has no values.
+#[derive(Debug)]
+pub enum FirstKnownExploitationDate {}
+
+impl WithGroupIds for FirstKnownExploitationDate {
+    fn get_group_ids(&self) -> Option<impl Iterator<Item=&String> + '_> {
+        None::<std::iter::Empty<&String>>
+    }
+}
+
+impl FirstKnownExploitationDateTrait for FirstKnownExploitationDate {
+    fn get_date(&self) -> &String {
+        match *self {}
+    }
+
+    fn get_exploitation_date(&self) -> &String {
+        match *self {}
+    }
+
+    fn get_product_ids(&self) -> Option<impl Iterator<Item = &String> + '_> {
+        None::<std::iter::Empty<&String>>
+    }
 }
 
 impl InvolvementTrait for Involvement {
     fn get_date(&self) -> &Option<String> {
         &self.date
     }
+
+    /// Normalizes the party category to CSAF 2.1, which uses the same categories.
+    fn get_party(&self) -> Party21 {
+        match self.party {
+            PartyCategory::Coordinator => Party21::Coordinator,
+            PartyCategory::Discoverer => Party21::Discoverer,
+            PartyCategory::Other => Party21::Other,
+            PartyCategory::User => Party21::User,
+            PartyCategory::Vendor => Party21::Vendor,
+        }
+    }
+
+    fn get_product_ids(&self) -> Option<impl Iterator<Item = &String> + '_> {
+        None::<std::iter::Empty<&String>>
+    }
 }
 
 impl CsafTrait for CommonSecurityAdvisoryFramework {
@@ -261,11 +356,21 @@ impl DocumentTrait for DocumentLevelMetaData {
     type TrackingType = Tracking;
     type DistributionType = RulesForSharingDocument;
     type NoteType = Note;
+    type ReferenceType = Reference;
+    type PublisherType = Publisher;
+
+    fn get_category(&self) -> &String {
+        self.category.deref()
+    }
 
     fn get_tracking(&self) -> &Self::TrackingType {
         &self.tracking
     }
 
+    fn get_publisher(&self) -> &Self::PublisherType {
+        &self.publisher
+    }
+
     /// Return distribution as ref Option, it is optional anyways
     fn get_distribution_20(&self) -> Option<&Self::DistributionType> {
         self.distribution.as_ref()
@@ -276,7 +381,8 @@ impl DocumentTrait for DocumentLevelMetaData {
         match self.distribution.as_ref() {
             None => Err(ValidationError {
                 message: "CSAF 2.1 requires the distribution property, but it is not set.".to_string(),
-                instance_path: "/document/distribution".to_string()
+                instance_path: "/document/distribution".to_string(),
+                severity: ValidationSeverity::Error,
             }),
             Some(distribution) => Ok(distribution)
         }
@@ -285,6 +391,40 @@ impl DocumentTrait for DocumentLevelMetaData {
     fn get_notes(&self) -> Option<&Vec<Self::NoteType>> {
         self.notes.as_ref().map(|x| x.deref())
     }
+
+    fn get_references(&self) -> Option<&Vec<Self::ReferenceType>> {
+        self.references.as_deref()
+    }
+
+    fn get_lang(&self) -> Option<&String> {
+        self.lang.as_deref()
+    }
+
+    fn get_source_lang(&self) -> Option<&String> {
+        self.source_lang.as_deref()
+    }
+
+    fn get_license_expression(&self) -> Option<&String> {
+        None
+    }
+}
+
+impl PublisherTrait for Publisher {
+    /// Normalizes the publisher category to CSAF 2.1, which added the category `multiplier`.
+    fn get_category(&self) -> Publisher21 {
+        match self.category {
+            CategoryOfPublisher::Coordinator => Publisher21::Coordinator,
+            CategoryOfPublisher::Discoverer => Publisher21::Discoverer,
+            CategoryOfPublisher::Other => Publisher21::Other,
+            CategoryOfPublisher::Translator => Publisher21::Translator,
+            CategoryOfPublisher::User => Publisher21::User,
+            CategoryOfPublisher::Vendor => Publisher21::Vendor,
+        }
+    }
+
+    fn get_name(&self) -> &String {
+        self.name.deref()
+    }
 }
 
 impl DistributionTrait for RulesForSharingDocument {
@@ -305,7 +445,8 @@ impl DistributionTrait for RulesForSharingDocument {
         match self.tlp.as_ref() {
             None => Err(ValidationError {
                 message: "CSAF 2.1 requires the TLP property, but it is not set.".to_string(),
-                instance_path: "/document/distribution/sharing_group/tlp".to_string()
+                instance_path: "/document/distribution/sharing_group/tlp".to_string(),
+                severity: ValidationSeverity::Error,
             }),
             Some(tlp) => Ok(tlp)
         }
@@ -318,7 +459,38 @@ impl WithGroupIds for Note {
     }
 }
 
-impl NoteTrait for Note {}
+impl NoteTrait for Note {
+    /// Normalizes the note category to CSAF 2.1, which uses the same categories.
+    fn get_category(&self) -> NoteCategory21 {
+        match self.category {
+            NoteCategory::Description => NoteCategory21::Description,
+            NoteCategory::Details => NoteCategory21::Details,
+            NoteCategory::Faq => NoteCategory21::Faq,
+            NoteCategory::General => NoteCategory21::General,
+            NoteCategory::LegalDisclaimer => NoteCategory21::LegalDisclaimer,
+            NoteCategory::Other => NoteCategory21::Other,
+            NoteCategory::Summary => NoteCategory21::Summary,
+        }
+    }
+
+    fn get_title(&self) -> Option<&String> {
+        self.title.as_deref()
+    }
+}
+
+impl ReferenceTrait for Reference {
+    /// Normalizes the reference category to CSAF 2.1, which uses the same categories.
+    fn get_category(&self) -> Reference21 {
+        match self.category {
+            CategoryOfReference::External => Reference21::External,
+            CategoryOfReference::Self_ => Reference21::Self_,
+        }
+    }
+
+    fn get_summary(&self) -> &String {
+        self.summary.deref()
+    }
+}
 
 impl SharingGroupTrait for () {
     fn get_id(&self) -> &String {
@@ -381,6 +553,10 @@ impl TrackingTrait for Tracking {
     fn get_id(&self) -> &String {
         self.id.deref()
     }
+
+    fn get_version(&self) -> &String {
+        self.version.deref()
+    }
 }
 
 impl GeneratorTrait for DocumentGenerator {
@@ -436,6 +612,27 @@ impl BranchTrait<FullProductNameT> for Branch {
     fn get_product(&self) -> &Option<FullProductNameT> {
         &self.product
     }
+
+    fn get_category(&self) -> Branch21 {
+        match self.category {
+            CategoryOfTheBranch::Architecture => Branch21::Architecture,
+            CategoryOfTheBranch::HostName => Branch21::HostName,
+            CategoryOfTheBranch::Language => Branch21::Language,
+            CategoryOfTheBranch::Legacy => Branch21::Legacy,
+            CategoryOfTheBranch::PatchLevel => Branch21::PatchLevel,
+            CategoryOfTheBranch::ProductFamily => Branch21::ProductFamily,
+            CategoryOfTheBranch::ProductName => Branch21::ProductName,
+            CategoryOfTheBranch::ProductVersion => Branch21::ProductVersion,
+            CategoryOfTheBranch::ProductVersionRange => Branch21::ProductVersionRange,
+            CategoryOfTheBranch::ServicePack => Branch21::ServicePack,
+            CategoryOfTheBranch::Specification => Branch21::Specification,
+            CategoryOfTheBranch::Vendor => Branch21::Vendor,
+        }
+    }
+
+    fn get_name(&self) -> &String {
+        self.name.deref()
+    }
 }
 
 impl ProductGroupTrait for ProductGroup {
@@ -475,6 +672,8 @@ impl ProductTrait for FullProductNameT {
 }
 
 impl ProductIdentificationHelperTrait for HelperToIdentifyTheProduct {
+    type HashType = CryptographicHashes;
+
     fn get_purls(&self) -> Option<&[String]> {
         self.purl.as_ref().map(|purl| std::slice::from_ref(purl))
     }
@@ -486,4 +685,30 @@ impl ProductIdentificationHelperTrait for HelperToIdentifyTheProduct {
     fn get_serial_numbers(&self) -> Option<impl Iterator<Item = &String> + '_> {
         self.serial_numbers.as_ref().map(|v| v.iter().map(|x| x.deref()))
     }
+
+    fn get_hashes(&self) -> &Vec<Self::HashType> {
+        &self.hashes
+    }
+}
+
+impl HashTrait for CryptographicHashes {
+    type FileHashType = FileHash;
+
+    fn get_filename(&self) -> &String {
+        self.filename.deref()
+    }
+
+    fn get_file_hashes(&self) -> &Vec<Self::FileHashType> {
+        &self.file_hashes
+    }
+}
+
+impl FileHashTrait for FileHash {
+    fn get_algorithm(&self) -> &String {
+        self.algorithm.deref()
+    }
+
+    fn get_value(&self) -> &String {
+        self.value.deref()
+    }
 }
\ No newline at end of file
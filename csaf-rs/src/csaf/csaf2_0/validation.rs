@@ -1,34 +1,33 @@
 use super::schema::CommonSecurityAdvisoryFramework;
-use std::collections::HashMap;
+use crate::csaf::document::{CsafDocument, CsafVersion, CsafVersioned};
+use crate::csaf::registry::{presets_for_version, registered_tests, tests_for_version};
 use crate::csaf::validation::{Test, Validatable, ValidationPreset};
-use crate::csaf::validations::test_6_1_01::test_6_1_01_missing_definition_of_product_id;
-use crate::csaf::validations::test_6_1_02::test_6_1_02_multiple_definition_of_product_id;
+use std::collections::HashMap;
+
+impl CsafVersioned for CommonSecurityAdvisoryFramework {
+    const VERSION: CsafVersion = CsafVersion::V2_0;
+}
 
-impl Validatable<CommonSecurityAdvisoryFramework> for CommonSecurityAdvisoryFramework {
+impl Validatable<CsafDocument<CommonSecurityAdvisoryFramework>> for CsafDocument<CommonSecurityAdvisoryFramework> {
     fn presets(&self) -> HashMap<ValidationPreset, Vec<&str>> {
-        let basic_tests = Vec::from(["6.1.1", "6.1.2"]);
-        // More tests may be added in extend() here later
-        let extended_tests: Vec<&str> = basic_tests.clone();
-        // extended_tests.extend(["foo"].iter());
-        let full_tests: Vec<&str> = extended_tests.clone();
-        // full_tests.extend(["bar"].iter());
-        HashMap::from([
-            (ValidationPreset::Basic, basic_tests),
-            (ValidationPreset::Extended, extended_tests),
-            (ValidationPreset::Full, full_tests),
-        ])
+        presets_for_version::<CommonSecurityAdvisoryFramework>()
     }
 
-    fn tests(&self) -> HashMap<&str, Test<CommonSecurityAdvisoryFramework>> {
-        type CsafTest = Test<CommonSecurityAdvisoryFramework>;
-        HashMap::from([
-            ("6.1.1", test_6_1_01_missing_definition_of_product_id as CsafTest),
-            ("6.1.2", test_6_1_02_multiple_definition_of_product_id as CsafTest),
-        ])
+    fn tests(&self) -> HashMap<&str, Test<CsafDocument<CommonSecurityAdvisoryFramework>>> {
+        tests_for_version::<CommonSecurityAdvisoryFramework>()
+            .into_iter()
+            .map(|t| (t.info.id, t.test))
+            .collect()
     }
 
-    fn doc(&self) -> &CommonSecurityAdvisoryFramework {
+    fn known_test_ids(&self) -> Vec<&str> {
+        registered_tests::<CommonSecurityAdvisoryFramework>()
+            .into_iter()
+            .map(|t| t.info.id)
+            .collect()
+    }
+
+    fn doc(&self) -> &CsafDocument<CommonSecurityAdvisoryFramework> {
         self
     }
 }
-
@@ -1,5 +1,5 @@
-use crate::csaf::csaf2_1::schema::{Branch, CategoryOfTheRemediation, CommonSecurityAdvisoryFramework, Content, DocumentGenerator, DocumentLevelMetaData, DocumentStatus, Epss, Flag, FullProductNameT, HelperToIdentifyTheProduct, Id, Involvement, LabelOfTlp, Metric, Note, ProductGroup, ProductStatus, ProductTree, Relationship, Remediation, Revision, RulesForSharingDocument, SharingGroup, Threat, Tracking, TrafficLightProtocolTlp, Vulnerability};
-use crate::csaf::getter_traits::{BranchTrait, CsafTrait, DistributionTrait, DocumentTrait, FlagTrait, ProductTrait, GeneratorTrait, InvolvementTrait, MetricTrait, ProductGroupTrait, ProductIdentificationHelperTrait, ProductStatusTrait, ProductTreeTrait, RelationshipTrait, RemediationTrait, RevisionTrait, SharingGroupTrait, ThreatTrait, TlpTrait, TrackingTrait, VulnerabilityTrait, ContentTrait, VulnerabilityIdTrait, NoteTrait, WithGroupIds};
+use crate::csaf::csaf2_1::schema::{Branch, CategoryOfPublisher, CategoryOfReference, CategoryOfTheBranch, CategoryOfTheRemediation, CategoryOfTheThreat, CommonSecurityAdvisoryFramework, CryptographicHashes, Content, Cwe, DocumentGenerator, DocumentLevelMetaData, DocumentStatus, Epss, FileHash, FirstKnownExploitationDate, Flag, FullProductNameT, HelperToIdentifyTheProduct, Id, Involvement, LabelOfTheFlag, LabelOfTlp, Metric, Note, NoteCategory, PartyCategory, ProductGroup, ProductStatus, ProductTree, Publisher, Reference, Relationship, Remediation, Revision, RulesForSharingDocument, SharingGroup, Threat, Tracking, TrafficLightProtocolTlp, Vulnerability};
+use crate::csaf::getter_traits::{BranchTrait, CsafTrait, DistributionTrait, DocumentTrait, FileHashTrait, FirstKnownExploitationDateTrait, ReferenceTrait, FlagTrait, ProductTrait, GeneratorTrait, HashTrait, InvolvementTrait, MetricTrait, ProductGroupTrait, ProductIdentificationHelperTrait, ProductStatusTrait, ProductTreeTrait, PublisherTrait, RelationshipTrait, RemediationTrait, RevisionTrait, SharingGroupTrait, ThreatTrait, TlpTrait, TrackingTrait, VulnerabilityTrait, ContentTrait, VulnerabilityIdTrait, NoteTrait, WithGroupIds, CweTrait};
 use std::ops::Deref;
 use serde_json::{Map, Value};
 use crate::csaf::csaf2_1::ssvc_schema::SsvcV1;
@@ -77,6 +77,10 @@ impl MetricTrait for Metric {
     fn get_source(&self) -> &Option<String> {
         &self.source
     }
+
+    fn get_metric_json_path(&self, vulnerability_idx: usize, metric_idx: usize) -> String {
+        format!("/vulnerabilities/{}/metrics/{}", vulnerability_idx, metric_idx)
+    }
 }
 
 impl ContentTrait for Content {
@@ -140,6 +144,9 @@ impl ThreatTrait for Threat {
     fn get_date(&self) -> &Option<String> {
         &self.date
     }
+    fn get_category(&self) -> CategoryOfTheThreat {
+        self.category
+    }
 }
 
 impl VulnerabilityTrait for Vulnerability {
@@ -151,6 +158,8 @@ impl VulnerabilityTrait for Vulnerability {
     type InvolvementType = Involvement;
     type VulnerabilityIdType = Id;
     type NoteType = Note;
+    type CweType = Cwe;
+    type FirstKnownExploitationDateType = FirstKnownExploitationDate;
 
     fn get_remediations(&self) -> &Vec<Self::RemediationType> {
         &self.remediations
@@ -176,6 +185,10 @@ impl VulnerabilityTrait for Vulnerability {
         &self.discovery_date
     }
 
+    fn get_first_known_exploitation_dates(&self) -> impl Iterator<Item = &Self::FirstKnownExploitationDateType> + '_ {
+        self.first_known_exploitation_dates.iter().flatten()
+    }
+
     fn get_flags(&self) -> &Option<Vec<Self::FlagType>> {
         &self.flags
     }
@@ -195,6 +208,28 @@ impl VulnerabilityTrait for Vulnerability {
     fn get_notes(&self) -> Option<&Vec<Self::NoteType>> {
         self.notes.as_ref().map(|x| x.deref())
     }
+
+    fn get_cwes(&self) -> Vec<&Self::CweType> {
+        self.cwes.iter().flatten().collect()
+    }
+}
+
+impl CweTrait for Cwe {
+    fn get_id(&self) -> &String {
+        self.id.deref()
+    }
+
+    fn get_name(&self) -> &String {
+        self.name.deref()
+    }
+
+    fn get_version(&self) -> Option<&String> {
+        Some(self.version.deref())
+    }
+
+    fn get_cwe_json_path(&self, vulnerability_idx: usize, cwe_idx: usize) -> String {
+        format!("/vulnerabilities/{}/cwes/{}", vulnerability_idx, cwe_idx)
+    }
 }
 
 impl VulnerabilityIdTrait for Id {
@@ -217,12 +252,48 @@ impl FlagTrait for Flag {
     fn get_date(&self) -> &Option<String> {
         &self.date
     }
+
+    fn get_label(&self) -> LabelOfTheFlag {
+        self.label
+    }
+
+    fn get_product_ids(&self) -> Option<impl Iterator<Item = &String> + '_> {
+        self.product_ids.as_ref().map(|p| (*p).iter().map(|x| x.deref()))
+    }
+}
+
+impl WithGroupIds for FirstKnownExploitationDate {
+    fn get_group_ids(&self) -> Option<impl Iterator<Item=&String> + '_> {
+        self.group_ids.as_ref().map(|g| (*g).iter().map(|x| x.deref()))
+    }
+}
+
+impl FirstKnownExploitationDateTrait for FirstKnownExploitationDate {
+    fn get_date(&self) -> &String {
+        &self.date
+    }
+
+    fn get_exploitation_date(&self) -> &String {
+        &self.exploitation_date
+    }
+
+    fn get_product_ids(&self) -> Option<impl Iterator<Item = &String> + '_> {
+        self.product_ids.as_ref().map(|p| (*p).iter().map(|x| x.deref()))
+    }
 }
 
 impl InvolvementTrait for Involvement {
     fn get_date(&self) -> &Option<String> {
         &self.date
     }
+
+    fn get_party(&self) -> PartyCategory {
+        self.party
+    }
+
+    fn get_product_ids(&self) -> Option<impl Iterator<Item = &String> + '_> {
+        self.product_ids.as_ref().map(|p| (*p).iter().map(|x| x.deref()))
+    }
 }
 
 impl CsafTrait for CommonSecurityAdvisoryFramework {
@@ -247,11 +318,21 @@ impl DocumentTrait for DocumentLevelMetaData {
     type TrackingType = Tracking;
     type DistributionType = RulesForSharingDocument;
     type NoteType = Note;
+    type ReferenceType = Reference;
+    type PublisherType = Publisher;
+
+    fn get_category(&self) -> &String {
+        self.category.deref()
+    }
 
     fn get_tracking(&self) -> &Self::TrackingType {
         &self.tracking
     }
 
+    fn get_publisher(&self) -> &Self::PublisherType {
+        &self.publisher
+    }
+
     /// We normalize to Option here because property was optional in CSAF 2.0
     fn get_distribution_21(&self) -> Result<&Self::DistributionType, ValidationError> {
         Ok(&self.distribution)
@@ -265,6 +346,32 @@ impl DocumentTrait for DocumentLevelMetaData {
     fn get_notes(&self) -> Option<&Vec<Self::NoteType>> {
         self.notes.as_ref().map(|x| x.deref())
     }
+
+    fn get_references(&self) -> Option<&Vec<Self::ReferenceType>> {
+        self.references.as_deref()
+    }
+
+    fn get_lang(&self) -> Option<&String> {
+        self.lang.as_deref()
+    }
+
+    fn get_source_lang(&self) -> Option<&String> {
+        self.source_lang.as_deref()
+    }
+
+    fn get_license_expression(&self) -> Option<&String> {
+        self.license_expression.as_deref()
+    }
+}
+
+impl PublisherTrait for Publisher {
+    fn get_category(&self) -> CategoryOfPublisher {
+        self.category
+    }
+
+    fn get_name(&self) -> &String {
+        self.name.deref()
+    }
 }
 
 impl DistributionTrait for RulesForSharingDocument {
@@ -292,7 +399,25 @@ impl WithGroupIds for Note {
     }
 }
 
-impl NoteTrait for Note {}
+impl NoteTrait for Note {
+    fn get_category(&self) -> NoteCategory {
+        self.category
+    }
+
+    fn get_title(&self) -> Option<&String> {
+        self.title.as_deref()
+    }
+}
+
+impl ReferenceTrait for Reference {
+    fn get_category(&self) -> CategoryOfReference {
+        self.category
+    }
+
+    fn get_summary(&self) -> &String {
+        self.summary.deref()
+    }
+}
 
 impl SharingGroupTrait for SharingGroup {
     fn get_id(&self) -> &String {
@@ -337,6 +462,10 @@ impl TrackingTrait for Tracking {
     fn get_id(&self) -> &String {
         self.id.deref()
     }
+
+    fn get_version(&self) -> &String {
+        self.version.deref()
+    }
 }
 
 impl GeneratorTrait for DocumentGenerator {
@@ -392,6 +521,14 @@ impl BranchTrait<FullProductNameT> for Branch {
     fn get_product(&self) -> &Option<FullProductNameT> {
         &self.product
     }
+
+    fn get_category(&self) -> CategoryOfTheBranch {
+        self.category
+    }
+
+    fn get_name(&self) -> &String {
+        self.name.deref()
+    }
 }
 
 impl ProductGroupTrait for ProductGroup {
@@ -431,6 +568,8 @@ impl ProductTrait for FullProductNameT {
 }
 
 impl ProductIdentificationHelperTrait for HelperToIdentifyTheProduct {
+    type HashType = CryptographicHashes;
+
     fn get_purls(&self) -> Option<&[String]> {
         self.purls.as_ref().map(|v| v.as_slice())
     }
@@ -442,4 +581,30 @@ impl ProductIdentificationHelperTrait for HelperToIdentifyTheProduct {
     fn get_serial_numbers(&self) -> Option<impl Iterator<Item = &String> + '_> {
         self.serial_numbers.as_ref().map(|v| v.iter().map(|x| x.deref()))
     }
+
+    fn get_hashes(&self) -> &Vec<Self::HashType> {
+        &self.hashes
+    }
+}
+
+impl HashTrait for CryptographicHashes {
+    type FileHashType = FileHash;
+
+    fn get_filename(&self) -> &String {
+        self.filename.deref()
+    }
+
+    fn get_file_hashes(&self) -> &Vec<Self::FileHashType> {
+        &self.file_hashes
+    }
+}
+
+impl FileHashTrait for FileHash {
+    fn get_algorithm(&self) -> &String {
+        self.algorithm.deref()
+    }
+
+    fn get_value(&self) -> &String {
+        self.value.deref()
+    }
 }
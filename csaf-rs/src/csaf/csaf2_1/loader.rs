@@ -1,18 +1,14 @@
-use std::{fs::File, io::BufReader};
 use super::schema::CommonSecurityAdvisoryFramework;
+use crate::csaf::document::CsafDocument;
+use serde_json::Value;
+use std::{fs::File, io::BufReader};
 
-pub fn load_document(path: &str) -> std::io::Result<CommonSecurityAdvisoryFramework> {
-    println!("Trying to load document {}", path);
-
+/// Loads the document at the given path, keeping its raw JSON for the schema validation.
+pub fn load_document(path: &str) -> std::io::Result<CsafDocument<CommonSecurityAdvisoryFramework>> {
     let f = File::open(path)?;
     let reader = BufReader::new(f);
-    let doc: CommonSecurityAdvisoryFramework = serde_json::from_reader(reader)?;
-    println!(
-        "Successfully parsed document '{}'",
-        doc.document.title.to_string()
-    );
-
-    Ok(doc)
+    let json: Value = serde_json::from_reader(reader)?;
+    Ok(CsafDocument::<CommonSecurityAdvisoryFramework>::from_json(json)?)
 }
 
 #[cfg(test)]
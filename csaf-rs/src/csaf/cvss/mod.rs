@@ -0,0 +1,260 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+/// Defines an enum for the values of a CVSS metric. Each value is mapped to its abbreviation
+/// in the vector string and to its name in the JSON representation of the CVSS object.
+macro_rules! cvss_metric {
+    ($(#[$meta:meta])* $name:ident { $($variant:ident => $abbreviation:literal, $json:literal;)+ }) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize)]
+        pub enum $name {
+            $(#[serde(rename = $json)] $variant,)+
+        }
+
+        impl $crate::csaf::cvss::CvssMetric for $name {
+            const VALUES: &'static [Self] = &[$(Self::$variant),+];
+
+            fn abbreviation(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $abbreviation,)+
+                }
+            }
+
+            fn json_value(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $json,)+
+                }
+            }
+        }
+    };
+}
+
+pub mod v2;
+pub mod v3;
+pub mod v4;
+
+/// Values of a CVSS metric, see [cvss_metric].
+pub trait CvssMetric: Sized + Copy + PartialEq + 'static {
+    /// All values of the metric.
+    const VALUES: &'static [Self];
+
+    /// Returns the abbreviation of the value used in vector strings, e.g. "N".
+    fn abbreviation(&self) -> &'static str;
+
+    /// Returns the name of the value used in CVSS JSON objects, e.g. "NETWORK".
+    fn json_value(&self) -> &'static str;
+
+    /// Returns the value with the given abbreviation, if there is one.
+    fn from_abbreviation(abbreviation: &str) -> Option<Self> {
+        Self::VALUES.iter().copied().find(|v| v.abbreviation() == abbreviation)
+    }
+}
+
+/// Errors while reading CVSS vector strings or CVSS JSON objects.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum CvssError {
+    /// The vector string does not conform to the CVSS specification.
+    InvalidVector(String),
+    /// The JSON object does not conform to the CVSS JSON schema.
+    InvalidObject(String),
+}
+
+impl Display for CvssError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidVector(message) => write!(f, "invalid vector string: {}", message),
+            Self::InvalidObject(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for CvssError {}
+
+/// Qualitative severity rating of a CVSS score (CVSS v3 and later).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CvssSeverity {
+    None,
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+impl CvssSeverity {
+    /// Returns the severity rating of the given score according to the CVSS specification.
+    pub fn from_score(score: f64) -> CvssSeverity {
+        match score {
+            s if s < 0.1 => CvssSeverity::None,
+            s if s < 4.0 => CvssSeverity::Low,
+            s if s < 7.0 => CvssSeverity::Medium,
+            s if s < 9.0 => CvssSeverity::High,
+            _ => CvssSeverity::Critical,
+        }
+    }
+}
+
+impl Display for CvssSeverity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => write!(f, "NONE"),
+            Self::Low => write!(f, "LOW"),
+            Self::Medium => write!(f, "MEDIUM"),
+            Self::High => write!(f, "HIGH"),
+            Self::Critical => write!(f, "CRITICAL"),
+        }
+    }
+}
+
+/// A property of a CVSS object whose value differs from the value expected
+/// according to the vector string of the object.
+#[derive(Debug, PartialEq, Clone)]
+pub struct CvssMismatch {
+    /// Name of the property in the CVSS object, e.g. "baseScore".
+    pub property: &'static str,
+    /// The value given in the CVSS object.
+    pub actual: String,
+    /// The value expected according to the vector string.
+    pub expected: String,
+}
+
+/// Splits a vector string (without prefix) into its metrics, indexed by their abbreviation.
+/// Fails on unknown metrics, duplicate metrics and malformed metric definitions. If `ordered` is
+/// set, it also fails on metrics which are not in the order of `known_metrics`. Only CVSS v4.0
+/// mandates this order, earlier versions merely recommend it.
+fn parse_metrics<'a>(
+    vector: &'a str,
+    known_metrics: &[&str],
+    ordered: bool,
+) -> Result<HashMap<&'a str, &'a str>, CvssError> {
+    let mut metrics = HashMap::new();
+    let mut previous = None;
+    for part in vector.split('/') {
+        let (key, value) = part
+            .split_once(':')
+            .ok_or_else(|| CvssError::InvalidVector(format!("malformed metric \"{}\"", part)))?;
+        let position = known_metrics
+            .iter()
+            .position(|known| *known == key)
+            .ok_or_else(|| CvssError::InvalidVector(format!("unknown metric \"{}\"", key)))?;
+        if metrics.insert(key, value).is_some() {
+            return Err(CvssError::InvalidVector(format!("metric \"{}\" is defined multiple times", key)));
+        }
+        if let Some((previous_key, previous_position)) = previous {
+            if ordered && position < previous_position {
+                return Err(CvssError::InvalidVector(format!(
+                    "metric \"{}\" must precede metric \"{}\"",
+                    key, previous_key
+                )));
+            }
+        }
+        previous = Some((key, position));
+    }
+    Ok(metrics)
+}
+
+/// Reads the value of a metric, which is `None` if the metric is not part of the vector.
+fn optional_metric<T: CvssMetric>(metrics: &HashMap<&str, &str>, key: &str) -> Result<Option<T>, CvssError> {
+    match metrics.get(key) {
+        None => Ok(None),
+        Some(value) => T::from_abbreviation(value).map(Some).ok_or_else(|| {
+            CvssError::InvalidVector(format!("invalid value \"{}\" for metric \"{}\"", value, key))
+        }),
+    }
+}
+
+/// Reads the value of a metric, which must be part of the vector.
+fn required_metric<T: CvssMetric>(metrics: &HashMap<&str, &str>, key: &str) -> Result<T, CvssError> {
+    optional_metric(metrics, key)?
+        .ok_or_else(|| CvssError::InvalidVector(format!("missing mandatory metric \"{}\"", key)))
+}
+
+/// Serde helper to deserialize vector strings into their parsed representation.
+fn deserialize_vector<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: std::str::FromStr<Err = CvssError>,
+{
+    let vector = <String as serde::Deserialize>::deserialize(deserializer)?;
+    vector.parse().map_err(serde::de::Error::custom)
+}
+
+/// Checks that all given scores are within the range of valid CVSS scores.
+fn check_score_ranges(scores: &[(&str, Option<f64>)]) -> Result<(), CvssError> {
+    for (property, score) in scores {
+        if let Some(score) = score {
+            if !(0.0..=10.0).contains(score) {
+                return Err(CvssError::InvalidObject(format!(
+                    "{} must be between 0 and 10, but is {}",
+                    property, score
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Adds a mismatch if a given metric property differs from the value in the vector string.
+fn compare_metric<T: CvssMetric>(
+    mismatches: &mut Vec<CvssMismatch>,
+    property: &'static str,
+    actual: Option<T>,
+    expected: T,
+) {
+    if let Some(actual) = actual {
+        if actual != expected {
+            mismatches.push(CvssMismatch {
+                property,
+                actual: actual.json_value().to_string(),
+                expected: expected.json_value().to_string(),
+            });
+        }
+    }
+}
+
+/// Adds a mismatch if a given score differs from the computed score.
+fn compare_score(mismatches: &mut Vec<CvssMismatch>, property: &'static str, actual: Option<f64>, expected: f64) {
+    if let Some(actual) = actual {
+        if (actual - expected).abs() > 1e-6 {
+            mismatches.push(CvssMismatch {
+                property,
+                actual: actual.to_string(),
+                expected: format!("{:.1}", expected),
+            });
+        }
+    }
+}
+
+/// Adds a mismatch if a given severity differs from the computed severity.
+fn compare_severity(
+    mismatches: &mut Vec<CvssMismatch>,
+    property: &'static str,
+    actual: Option<CvssSeverity>,
+    expected: CvssSeverity,
+) {
+    if let Some(actual) = actual {
+        if actual != expected {
+            mismatches.push(CvssMismatch {
+                property,
+                actual: actual.to_string(),
+                expected: expected.to_string(),
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::cvss::CvssSeverity;
+
+    #[test]
+    fn test_severity_from_score() {
+        assert_eq!(CvssSeverity::None, CvssSeverity::from_score(0.0));
+        assert_eq!(CvssSeverity::Low, CvssSeverity::from_score(0.1));
+        assert_eq!(CvssSeverity::Low, CvssSeverity::from_score(3.9));
+        assert_eq!(CvssSeverity::Medium, CvssSeverity::from_score(4.0));
+        assert_eq!(CvssSeverity::High, CvssSeverity::from_score(8.9));
+        assert_eq!(CvssSeverity::Critical, CvssSeverity::from_score(9.0));
+        assert_eq!(CvssSeverity::Critical, CvssSeverity::from_score(10.0));
+    }
+}
@@ -0,0 +1,466 @@
+//! CVSS v2.0 vectors and JSON objects, see <https://www.first.org/cvss/v2/guide>.
+
+use crate::csaf::cvss::{
+    check_score_ranges, compare_metric, compare_score, deserialize_vector, optional_metric, parse_metrics,
+    required_metric, CvssError, CvssMismatch,
+};
+use serde_json::{Map, Value};
+use std::str::FromStr;
+
+cvss_metric! {
+    /// Access Vector (AV)
+    AccessVector {
+        Local => "L", "LOCAL";
+        AdjacentNetwork => "A", "ADJACENT_NETWORK";
+        Network => "N", "NETWORK";
+    }
+}
+
+cvss_metric! {
+    /// Access Complexity (AC)
+    AccessComplexity {
+        High => "H", "HIGH";
+        Medium => "M", "MEDIUM";
+        Low => "L", "LOW";
+    }
+}
+
+cvss_metric! {
+    /// Authentication (Au)
+    Authentication {
+        Multiple => "M", "MULTIPLE";
+        Single => "S", "SINGLE";
+        None => "N", "NONE";
+    }
+}
+
+cvss_metric! {
+    /// Confidentiality (C), Integrity (I) and Availability (A) Impact
+    Impact {
+        None => "N", "NONE";
+        Partial => "P", "PARTIAL";
+        Complete => "C", "COMPLETE";
+    }
+}
+
+cvss_metric! {
+    /// Exploitability (E)
+    Exploitability {
+        Unproven => "U", "UNPROVEN";
+        ProofOfConcept => "POC", "PROOF_OF_CONCEPT";
+        Functional => "F", "FUNCTIONAL";
+        High => "H", "HIGH";
+        NotDefined => "ND", "NOT_DEFINED";
+    }
+}
+
+cvss_metric! {
+    /// Remediation Level (RL)
+    RemediationLevel {
+        OfficialFix => "OF", "OFFICIAL_FIX";
+        TemporaryFix => "TF", "TEMPORARY_FIX";
+        Workaround => "W", "WORKAROUND";
+        Unavailable => "U", "UNAVAILABLE";
+        NotDefined => "ND", "NOT_DEFINED";
+    }
+}
+
+cvss_metric! {
+    /// Report Confidence (RC)
+    ReportConfidence {
+        Unconfirmed => "UC", "UNCONFIRMED";
+        Uncorroborated => "UR", "UNCORROBORATED";
+        Confirmed => "C", "CONFIRMED";
+        NotDefined => "ND", "NOT_DEFINED";
+    }
+}
+
+cvss_metric! {
+    /// Collateral Damage Potential (CDP)
+    CollateralDamagePotential {
+        None => "N", "NONE";
+        Low => "L", "LOW";
+        LowMedium => "LM", "LOW_MEDIUM";
+        MediumHigh => "MH", "MEDIUM_HIGH";
+        High => "H", "HIGH";
+        NotDefined => "ND", "NOT_DEFINED";
+    }
+}
+
+cvss_metric! {
+    /// Target Distribution (TD)
+    TargetDistribution {
+        None => "N", "NONE";
+        Low => "L", "LOW";
+        Medium => "M", "MEDIUM";
+        High => "H", "HIGH";
+        NotDefined => "ND", "NOT_DEFINED";
+    }
+}
+
+cvss_metric! {
+    /// Confidentiality (CR), Integrity (IR) and Availability (AR) Requirement
+    Requirement {
+        Low => "L", "LOW";
+        Medium => "M", "MEDIUM";
+        High => "H", "HIGH";
+        NotDefined => "ND", "NOT_DEFINED";
+    }
+}
+
+const KNOWN_METRICS: &[&str] = &[
+    "AV", "AC", "Au", "C", "I", "A", "E", "RL", "RC", "CDP", "TD", "CR", "IR", "AR",
+];
+
+/// A parsed CVSS v2.0 vector string. Metrics not contained in the vector string are "not defined".
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CvssV2Vector {
+    pub access_vector: AccessVector,
+    pub access_complexity: AccessComplexity,
+    pub authentication: Authentication,
+    pub confidentiality_impact: Impact,
+    pub integrity_impact: Impact,
+    pub availability_impact: Impact,
+    pub exploitability: Exploitability,
+    pub remediation_level: RemediationLevel,
+    pub report_confidence: ReportConfidence,
+    pub collateral_damage_potential: CollateralDamagePotential,
+    pub target_distribution: TargetDistribution,
+    pub confidentiality_requirement: Requirement,
+    pub integrity_requirement: Requirement,
+    pub availability_requirement: Requirement,
+}
+
+impl FromStr for CvssV2Vector {
+    type Err = CvssError;
+
+    fn from_str(vector: &str) -> Result<Self, Self::Err> {
+        let m = parse_metrics(vector, KNOWN_METRICS, false)?;
+
+        Ok(CvssV2Vector {
+            access_vector: required_metric(&m, "AV")?,
+            access_complexity: required_metric(&m, "AC")?,
+            authentication: required_metric(&m, "Au")?,
+            confidentiality_impact: required_metric(&m, "C")?,
+            integrity_impact: required_metric(&m, "I")?,
+            availability_impact: required_metric(&m, "A")?,
+            exploitability: optional_metric(&m, "E")?.unwrap_or(Exploitability::NotDefined),
+            remediation_level: optional_metric(&m, "RL")?.unwrap_or(RemediationLevel::NotDefined),
+            report_confidence: optional_metric(&m, "RC")?.unwrap_or(ReportConfidence::NotDefined),
+            collateral_damage_potential: optional_metric(&m, "CDP")?
+                .unwrap_or(CollateralDamagePotential::NotDefined),
+            target_distribution: optional_metric(&m, "TD")?.unwrap_or(TargetDistribution::NotDefined),
+            confidentiality_requirement: optional_metric(&m, "CR")?.unwrap_or(Requirement::NotDefined),
+            integrity_requirement: optional_metric(&m, "IR")?.unwrap_or(Requirement::NotDefined),
+            availability_requirement: optional_metric(&m, "AR")?.unwrap_or(Requirement::NotDefined),
+        })
+    }
+}
+
+fn access_vector_weight(value: AccessVector) -> f64 {
+    match value {
+        AccessVector::Local => 0.395,
+        AccessVector::AdjacentNetwork => 0.646,
+        AccessVector::Network => 1.0,
+    }
+}
+
+fn access_complexity_weight(value: AccessComplexity) -> f64 {
+    match value {
+        AccessComplexity::High => 0.35,
+        AccessComplexity::Medium => 0.61,
+        AccessComplexity::Low => 0.71,
+    }
+}
+
+fn authentication_weight(value: Authentication) -> f64 {
+    match value {
+        Authentication::Multiple => 0.45,
+        Authentication::Single => 0.56,
+        Authentication::None => 0.704,
+    }
+}
+
+fn impact_weight(value: Impact) -> f64 {
+    match value {
+        Impact::None => 0.0,
+        Impact::Partial => 0.275,
+        Impact::Complete => 0.66,
+    }
+}
+
+fn exploitability_weight(value: Exploitability) -> f64 {
+    match value {
+        Exploitability::Unproven => 0.85,
+        Exploitability::ProofOfConcept => 0.9,
+        Exploitability::Functional => 0.95,
+        Exploitability::High | Exploitability::NotDefined => 1.0,
+    }
+}
+
+fn remediation_level_weight(value: RemediationLevel) -> f64 {
+    match value {
+        RemediationLevel::OfficialFix => 0.87,
+        RemediationLevel::TemporaryFix => 0.9,
+        RemediationLevel::Workaround => 0.95,
+        RemediationLevel::Unavailable | RemediationLevel::NotDefined => 1.0,
+    }
+}
+
+fn report_confidence_weight(value: ReportConfidence) -> f64 {
+    match value {
+        ReportConfidence::Unconfirmed => 0.9,
+        ReportConfidence::Uncorroborated => 0.95,
+        ReportConfidence::Confirmed | ReportConfidence::NotDefined => 1.0,
+    }
+}
+
+fn collateral_damage_potential_weight(value: CollateralDamagePotential) -> f64 {
+    match value {
+        CollateralDamagePotential::None | CollateralDamagePotential::NotDefined => 0.0,
+        CollateralDamagePotential::Low => 0.1,
+        CollateralDamagePotential::LowMedium => 0.3,
+        CollateralDamagePotential::MediumHigh => 0.4,
+        CollateralDamagePotential::High => 0.5,
+    }
+}
+
+fn target_distribution_weight(value: TargetDistribution) -> f64 {
+    match value {
+        TargetDistribution::None => 0.0,
+        TargetDistribution::Low => 0.25,
+        TargetDistribution::Medium => 0.75,
+        TargetDistribution::High | TargetDistribution::NotDefined => 1.0,
+    }
+}
+
+fn requirement_weight(value: Requirement) -> f64 {
+    match value {
+        Requirement::Low => 0.5,
+        Requirement::Medium | Requirement::NotDefined => 1.0,
+        Requirement::High => 1.51,
+    }
+}
+
+/// Rounds to one decimal, ignoring floating point noise below the precision of the formulas.
+fn round_to_one_decimal(value: f64) -> f64 {
+    ((value * 10.0 * 100_000.0).round() / 100_000.0).round() / 10.0
+}
+
+/// Computed scores of a CVSS v2.0 vector.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CvssV2Scores {
+    pub base_score: f64,
+    pub temporal_score: f64,
+    pub environmental_score: f64,
+}
+
+impl CvssV2Vector {
+    /// Computes the base score from the given impact weights.
+    fn score_with_impact(&self, impact: f64) -> f64 {
+        let exploitability = 20.0
+            * access_vector_weight(self.access_vector)
+            * access_complexity_weight(self.access_complexity)
+            * authentication_weight(self.authentication);
+        let f_impact = if impact == 0.0 { 0.0 } else { 1.176 };
+        round_to_one_decimal((0.6 * impact + 0.4 * exploitability - 1.5) * f_impact)
+    }
+
+    fn temporal_weight(&self) -> f64 {
+        exploitability_weight(self.exploitability)
+            * remediation_level_weight(self.remediation_level)
+            * report_confidence_weight(self.report_confidence)
+    }
+
+    /// Computes the base score.
+    pub fn base_score(&self) -> f64 {
+        let impact = 10.41
+            * (1.0
+                - (1.0 - impact_weight(self.confidentiality_impact))
+                    * (1.0 - impact_weight(self.integrity_impact))
+                    * (1.0 - impact_weight(self.availability_impact)));
+        self.score_with_impact(impact)
+    }
+
+    /// Computes the temporal score, which equals the base score if no temporal metrics are defined.
+    pub fn temporal_score(&self) -> f64 {
+        round_to_one_decimal(self.base_score() * self.temporal_weight())
+    }
+
+    /// Computes the environmental score.
+    pub fn environmental_score(&self) -> f64 {
+        let adjusted_impact = (10.41
+            * (1.0
+                - (1.0
+                    - impact_weight(self.confidentiality_impact)
+                        * requirement_weight(self.confidentiality_requirement))
+                    * (1.0
+                        - impact_weight(self.integrity_impact) * requirement_weight(self.integrity_requirement))
+                    * (1.0
+                        - impact_weight(self.availability_impact)
+                            * requirement_weight(self.availability_requirement))))
+        .min(10.0);
+        let adjusted_temporal = round_to_one_decimal(self.score_with_impact(adjusted_impact) * self.temporal_weight());
+        round_to_one_decimal(
+            (adjusted_temporal
+                + (10.0 - adjusted_temporal) * collateral_damage_potential_weight(self.collateral_damage_potential))
+                * target_distribution_weight(self.target_distribution),
+        )
+    }
+
+    /// Computes all scores.
+    pub fn scores(&self) -> CvssV2Scores {
+        CvssV2Scores {
+            base_score: self.base_score(),
+            temporal_score: self.temporal_score(),
+            environmental_score: self.environmental_score(),
+        }
+    }
+}
+
+/// Version of a CVSS v2 JSON object, which is always "2.0".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize)]
+pub enum CvssV2Version {
+    #[serde(rename = "2.0")]
+    V2_0,
+}
+
+/// A CVSS v2.0 JSON object, as contained in the `cvss_v2` property of CSAF documents.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CvssV2 {
+    pub version: CvssV2Version,
+    #[serde(deserialize_with = "deserialize_vector")]
+    pub vector_string: CvssV2Vector,
+    pub access_vector: Option<AccessVector>,
+    pub access_complexity: Option<AccessComplexity>,
+    pub authentication: Option<Authentication>,
+    pub confidentiality_impact: Option<Impact>,
+    pub integrity_impact: Option<Impact>,
+    pub availability_impact: Option<Impact>,
+    pub base_score: f64,
+    pub exploitability: Option<Exploitability>,
+    pub remediation_level: Option<RemediationLevel>,
+    pub report_confidence: Option<ReportConfidence>,
+    pub temporal_score: Option<f64>,
+    pub collateral_damage_potential: Option<CollateralDamagePotential>,
+    pub target_distribution: Option<TargetDistribution>,
+    pub confidentiality_requirement: Option<Requirement>,
+    pub integrity_requirement: Option<Requirement>,
+    pub availability_requirement: Option<Requirement>,
+    pub environmental_score: Option<f64>,
+}
+
+impl CvssV2 {
+    /// Reads a CVSS v2.0 JSON object, checking it against the constraints of the CVSS v2.0
+    /// JSON schema and checking that its vector string is valid.
+    pub fn from_json(json: &Map<String, Value>) -> Result<CvssV2, CvssError> {
+        let cvss: CvssV2 = serde_json::from_value(Value::Object(json.clone()))
+            .map_err(|e| CvssError::InvalidObject(e.to_string()))?;
+        check_score_ranges(&[
+            ("baseScore", Some(cvss.base_score)),
+            ("temporalScore", cvss.temporal_score),
+            ("environmentalScore", cvss.environmental_score),
+        ])?;
+        Ok(cvss)
+    }
+
+    /// Returns all scores which differ from the values computed from the vector string.
+    pub fn computation_mismatches(&self) -> Vec<CvssMismatch> {
+        let scores = self.vector_string.scores();
+        let mut mismatches = Vec::new();
+        compare_score(&mut mismatches, "baseScore", Some(self.base_score), scores.base_score);
+        compare_score(&mut mismatches, "temporalScore", self.temporal_score, scores.temporal_score);
+        compare_score(&mut mismatches, "environmentalScore", self.environmental_score, scores.environmental_score);
+        mismatches
+    }
+
+    /// Returns all metric properties which contradict the vector string.
+    pub fn property_mismatches(&self) -> Vec<CvssMismatch> {
+        let v = &self.vector_string;
+        let mut m = Vec::new();
+        compare_metric(&mut m, "accessVector", self.access_vector, v.access_vector);
+        compare_metric(&mut m, "accessComplexity", self.access_complexity, v.access_complexity);
+        compare_metric(&mut m, "authentication", self.authentication, v.authentication);
+        compare_metric(&mut m, "confidentialityImpact", self.confidentiality_impact, v.confidentiality_impact);
+        compare_metric(&mut m, "integrityImpact", self.integrity_impact, v.integrity_impact);
+        compare_metric(&mut m, "availabilityImpact", self.availability_impact, v.availability_impact);
+        compare_metric(&mut m, "exploitability", self.exploitability, v.exploitability);
+        compare_metric(&mut m, "remediationLevel", self.remediation_level, v.remediation_level);
+        compare_metric(&mut m, "reportConfidence", self.report_confidence, v.report_confidence);
+        compare_metric(&mut m, "collateralDamagePotential", self.collateral_damage_potential, v.collateral_damage_potential);
+        compare_metric(&mut m, "targetDistribution", self.target_distribution, v.target_distribution);
+        compare_metric(&mut m, "confidentialityRequirement", self.confidentiality_requirement, v.confidentiality_requirement);
+        compare_metric(&mut m, "integrityRequirement", self.integrity_requirement, v.integrity_requirement);
+        compare_metric(&mut m, "availabilityRequirement", self.availability_requirement, v.availability_requirement);
+        m
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::cvss::v2::{AccessVector, CvssV2, CvssV2Vector, Exploitability};
+    use crate::csaf::cvss::CvssError;
+    use serde_json::json;
+
+    fn scores(vector: &str) -> (f64, f64, f64) {
+        let vector: CvssV2Vector = vector.parse().unwrap();
+        (vector.base_score(), vector.temporal_score(), vector.environmental_score())
+    }
+
+    #[test]
+    fn test_parse_vector() {
+        let vector: CvssV2Vector = "AV:N/AC:L/Au:N/C:N/I:N/A:C/E:POC".parse().unwrap();
+        assert_eq!(AccessVector::Network, vector.access_vector);
+        assert_eq!(Exploitability::ProofOfConcept, vector.exploitability);
+
+        // Unlike CVSS v4.0, earlier versions do not mandate the order of the metrics
+        for valid in ["AC:L/AV:N/Au:N/C:N/I:N/A:C", "AV:N/AC:L/Au:N/C:N/I:N/A:C/RL:OF/E:POC"] {
+            assert!(valid.parse::<CvssV2Vector>().is_ok(), "{}", valid);
+        }
+
+        for invalid in [
+            "CVSS:2.0/AV:N/AC:L/Au:N/C:N/I:N/A:C",
+            "AV:N/AC:L/Au:N/C:N/I:N",
+            "AV:N/AC:L/Au:N/C:N/I:N/A:C/A:C",
+            "AV:N/AC:L/AU:N/C:N/I:N/A:C",
+            "AV:N/AC:L/Au:N/C:N/I:N/A:X",
+        ] {
+            assert!(matches!(invalid.parse::<CvssV2Vector>(), Err(CvssError::InvalidVector(_))), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_scores() {
+        assert_eq!((7.8, 7.8, 7.8), scores("AV:N/AC:L/Au:N/C:N/I:N/A:C"));
+        assert_eq!((10.0, 10.0, 10.0), scores("AV:N/AC:L/Au:N/C:C/I:C/A:C"));
+        assert_eq!((0.0, 0.0, 0.0), scores("AV:N/AC:L/Au:N/C:N/I:N/A:N"));
+        assert_eq!(
+            (7.8, 6.4, 9.2),
+            scores("AV:N/AC:L/Au:N/C:N/I:N/A:C/E:F/RL:OF/RC:C/CDP:H/TD:H/CR:M/IR:M/AR:H")
+        );
+    }
+
+    #[test]
+    fn test_cvss_object() {
+        let json = json!({
+            "version": "2.0",
+            "vectorString": "AV:N/AC:L/Au:N/C:N/I:N/A:C",
+            "accessVector": "NETWORK",
+            "availabilityImpact": "PARTIAL",
+            "baseScore": 7.8,
+            "temporalScore": 7.0
+        });
+        let cvss = CvssV2::from_json(json.as_object().unwrap()).unwrap();
+        let computation = cvss.computation_mismatches();
+        assert_eq!(1, computation.len());
+        assert_eq!(("temporalScore", "7.8"), (computation[0].property, computation[0].expected.as_str()));
+        let properties = cvss.property_mismatches();
+        assert_eq!(1, properties.len());
+        assert_eq!("availabilityImpact", properties[0].property);
+
+        let mut invalid = json;
+        invalid["version"] = json!("3.1");
+        assert!(matches!(CvssV2::from_json(invalid.as_object().unwrap()), Err(CvssError::InvalidObject(_))));
+    }
+}
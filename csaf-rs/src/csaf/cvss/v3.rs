@@ -0,0 +1,634 @@
+//! CVSS v3.0 and v3.1 vectors and JSON objects, see <https://www.first.org/cvss/v3.1/specification-document>.
+
+use crate::csaf::cvss::{
+    check_score_ranges, compare_metric, compare_score, compare_severity, deserialize_vector, optional_metric,
+    parse_metrics, required_metric, CvssError, CvssMetric, CvssMismatch, CvssSeverity,
+};
+use serde_json::{Map, Value};
+use std::str::FromStr;
+
+cvss_metric! {
+    /// Attack Vector (AV)
+    AttackVector {
+        Network => "N", "NETWORK";
+        AdjacentNetwork => "A", "ADJACENT_NETWORK";
+        Local => "L", "LOCAL";
+        Physical => "P", "PHYSICAL";
+    }
+}
+
+cvss_metric! {
+    /// Modified Attack Vector (MAV)
+    ModifiedAttackVector {
+        NotDefined => "X", "NOT_DEFINED";
+        Network => "N", "NETWORK";
+        AdjacentNetwork => "A", "ADJACENT_NETWORK";
+        Local => "L", "LOCAL";
+        Physical => "P", "PHYSICAL";
+    }
+}
+
+cvss_metric! {
+    /// Attack Complexity (AC)
+    AttackComplexity {
+        Low => "L", "LOW";
+        High => "H", "HIGH";
+    }
+}
+
+cvss_metric! {
+    /// Modified Attack Complexity (MAC)
+    ModifiedAttackComplexity {
+        NotDefined => "X", "NOT_DEFINED";
+        Low => "L", "LOW";
+        High => "H", "HIGH";
+    }
+}
+
+cvss_metric! {
+    /// Privileges Required (PR)
+    PrivilegesRequired {
+        None => "N", "NONE";
+        Low => "L", "LOW";
+        High => "H", "HIGH";
+    }
+}
+
+cvss_metric! {
+    /// Modified Privileges Required (MPR)
+    ModifiedPrivilegesRequired {
+        NotDefined => "X", "NOT_DEFINED";
+        None => "N", "NONE";
+        Low => "L", "LOW";
+        High => "H", "HIGH";
+    }
+}
+
+cvss_metric! {
+    /// User Interaction (UI)
+    UserInteraction {
+        None => "N", "NONE";
+        Required => "R", "REQUIRED";
+    }
+}
+
+cvss_metric! {
+    /// Modified User Interaction (MUI)
+    ModifiedUserInteraction {
+        NotDefined => "X", "NOT_DEFINED";
+        None => "N", "NONE";
+        Required => "R", "REQUIRED";
+    }
+}
+
+cvss_metric! {
+    /// Scope (S)
+    Scope {
+        Unchanged => "U", "UNCHANGED";
+        Changed => "C", "CHANGED";
+    }
+}
+
+cvss_metric! {
+    /// Modified Scope (MS)
+    ModifiedScope {
+        NotDefined => "X", "NOT_DEFINED";
+        Unchanged => "U", "UNCHANGED";
+        Changed => "C", "CHANGED";
+    }
+}
+
+cvss_metric! {
+    /// Confidentiality (C), Integrity (I) and Availability (A) Impact
+    Impact {
+        None => "N", "NONE";
+        Low => "L", "LOW";
+        High => "H", "HIGH";
+    }
+}
+
+cvss_metric! {
+    /// Modified Confidentiality (MC), Integrity (MI) and Availability (MA) Impact
+    ModifiedImpact {
+        NotDefined => "X", "NOT_DEFINED";
+        None => "N", "NONE";
+        Low => "L", "LOW";
+        High => "H", "HIGH";
+    }
+}
+
+cvss_metric! {
+    /// Exploit Code Maturity (E)
+    ExploitCodeMaturity {
+        NotDefined => "X", "NOT_DEFINED";
+        Unproven => "U", "UNPROVEN";
+        ProofOfConcept => "P", "PROOF_OF_CONCEPT";
+        Functional => "F", "FUNCTIONAL";
+        High => "H", "HIGH";
+    }
+}
+
+cvss_metric! {
+    /// Remediation Level (RL)
+    RemediationLevel {
+        NotDefined => "X", "NOT_DEFINED";
+        OfficialFix => "O", "OFFICIAL_FIX";
+        TemporaryFix => "T", "TEMPORARY_FIX";
+        Workaround => "W", "WORKAROUND";
+        Unavailable => "U", "UNAVAILABLE";
+    }
+}
+
+cvss_metric! {
+    /// Report Confidence (RC)
+    ReportConfidence {
+        NotDefined => "X", "NOT_DEFINED";
+        Unknown => "U", "UNKNOWN";
+        Reasonable => "R", "REASONABLE";
+        Confirmed => "C", "CONFIRMED";
+    }
+}
+
+cvss_metric! {
+    /// Confidentiality (CR), Integrity (IR) and Availability (AR) Requirement
+    Requirement {
+        NotDefined => "X", "NOT_DEFINED";
+        Low => "L", "LOW";
+        Medium => "M", "MEDIUM";
+        High => "H", "HIGH";
+    }
+}
+
+/// Minor version of CVSS v3.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize)]
+pub enum CvssV3Version {
+    #[serde(rename = "3.0")]
+    V3_0,
+    #[serde(rename = "3.1")]
+    V3_1,
+}
+
+const KNOWN_METRICS: &[&str] = &[
+    "AV", "AC", "PR", "UI", "S", "C", "I", "A", "E", "RL", "RC", "CR", "IR", "AR", "MAV", "MAC", "MPR", "MUI",
+    "MS", "MC", "MI", "MA",
+];
+
+/// A parsed CVSS v3.x vector string. Metrics not contained in the vector string are "not defined".
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CvssV3Vector {
+    pub version: CvssV3Version,
+    pub attack_vector: AttackVector,
+    pub attack_complexity: AttackComplexity,
+    pub privileges_required: PrivilegesRequired,
+    pub user_interaction: UserInteraction,
+    pub scope: Scope,
+    pub confidentiality_impact: Impact,
+    pub integrity_impact: Impact,
+    pub availability_impact: Impact,
+    pub exploit_code_maturity: ExploitCodeMaturity,
+    pub remediation_level: RemediationLevel,
+    pub report_confidence: ReportConfidence,
+    pub confidentiality_requirement: Requirement,
+    pub integrity_requirement: Requirement,
+    pub availability_requirement: Requirement,
+    pub modified_attack_vector: ModifiedAttackVector,
+    pub modified_attack_complexity: ModifiedAttackComplexity,
+    pub modified_privileges_required: ModifiedPrivilegesRequired,
+    pub modified_user_interaction: ModifiedUserInteraction,
+    pub modified_scope: ModifiedScope,
+    pub modified_confidentiality_impact: ModifiedImpact,
+    pub modified_integrity_impact: ModifiedImpact,
+    pub modified_availability_impact: ModifiedImpact,
+}
+
+impl FromStr for CvssV3Vector {
+    type Err = CvssError;
+
+    fn from_str(vector: &str) -> Result<Self, Self::Err> {
+        let (version, metrics) = if let Some(metrics) = vector.strip_prefix("CVSS:3.0/") {
+            (CvssV3Version::V3_0, metrics)
+        } else if let Some(metrics) = vector.strip_prefix("CVSS:3.1/") {
+            (CvssV3Version::V3_1, metrics)
+        } else {
+            return Err(CvssError::InvalidVector(format!(
+                "\"{}\" does not start with \"CVSS:3.0/\" or \"CVSS:3.1/\"",
+                vector
+            )));
+        };
+        let m = parse_metrics(metrics, KNOWN_METRICS, false)?;
+
+        Ok(CvssV3Vector {
+            version,
+            attack_vector: required_metric(&m, "AV")?,
+            attack_complexity: required_metric(&m, "AC")?,
+            privileges_required: required_metric(&m, "PR")?,
+            user_interaction: required_metric(&m, "UI")?,
+            scope: required_metric(&m, "S")?,
+            confidentiality_impact: required_metric(&m, "C")?,
+            integrity_impact: required_metric(&m, "I")?,
+            availability_impact: required_metric(&m, "A")?,
+            exploit_code_maturity: optional_metric(&m, "E")?.unwrap_or(ExploitCodeMaturity::NotDefined),
+            remediation_level: optional_metric(&m, "RL")?.unwrap_or(RemediationLevel::NotDefined),
+            report_confidence: optional_metric(&m, "RC")?.unwrap_or(ReportConfidence::NotDefined),
+            confidentiality_requirement: optional_metric(&m, "CR")?.unwrap_or(Requirement::NotDefined),
+            integrity_requirement: optional_metric(&m, "IR")?.unwrap_or(Requirement::NotDefined),
+            availability_requirement: optional_metric(&m, "AR")?.unwrap_or(Requirement::NotDefined),
+            modified_attack_vector: optional_metric(&m, "MAV")?.unwrap_or(ModifiedAttackVector::NotDefined),
+            modified_attack_complexity: optional_metric(&m, "MAC")?.unwrap_or(ModifiedAttackComplexity::NotDefined),
+            modified_privileges_required: optional_metric(&m, "MPR")?
+                .unwrap_or(ModifiedPrivilegesRequired::NotDefined),
+            modified_user_interaction: optional_metric(&m, "MUI")?.unwrap_or(ModifiedUserInteraction::NotDefined),
+            modified_scope: optional_metric(&m, "MS")?.unwrap_or(ModifiedScope::NotDefined),
+            modified_confidentiality_impact: optional_metric(&m, "MC")?.unwrap_or(ModifiedImpact::NotDefined),
+            modified_integrity_impact: optional_metric(&m, "MI")?.unwrap_or(ModifiedImpact::NotDefined),
+            modified_availability_impact: optional_metric(&m, "MA")?.unwrap_or(ModifiedImpact::NotDefined),
+        })
+    }
+}
+
+/// Returns the value of a modified metric, falling back to the base metric if it is not defined.
+fn modified<M: CvssMetric, T: CvssMetric>(modified: M, base: T) -> T {
+    T::from_abbreviation(modified.abbreviation()).unwrap_or(base)
+}
+
+fn attack_vector_weight(value: AttackVector) -> f64 {
+    match value {
+        AttackVector::Network => 0.85,
+        AttackVector::AdjacentNetwork => 0.62,
+        AttackVector::Local => 0.55,
+        AttackVector::Physical => 0.2,
+    }
+}
+
+fn attack_complexity_weight(value: AttackComplexity) -> f64 {
+    match value {
+        AttackComplexity::Low => 0.77,
+        AttackComplexity::High => 0.44,
+    }
+}
+
+fn privileges_required_weight(value: PrivilegesRequired, scope: Scope) -> f64 {
+    match (value, scope) {
+        (PrivilegesRequired::None, _) => 0.85,
+        (PrivilegesRequired::Low, Scope::Unchanged) => 0.62,
+        (PrivilegesRequired::Low, Scope::Changed) => 0.68,
+        (PrivilegesRequired::High, Scope::Unchanged) => 0.27,
+        (PrivilegesRequired::High, Scope::Changed) => 0.5,
+    }
+}
+
+fn user_interaction_weight(value: UserInteraction) -> f64 {
+    match value {
+        UserInteraction::None => 0.85,
+        UserInteraction::Required => 0.62,
+    }
+}
+
+fn impact_weight(value: Impact) -> f64 {
+    match value {
+        Impact::High => 0.56,
+        Impact::Low => 0.22,
+        Impact::None => 0.0,
+    }
+}
+
+fn exploit_code_maturity_weight(value: ExploitCodeMaturity) -> f64 {
+    match value {
+        ExploitCodeMaturity::NotDefined | ExploitCodeMaturity::High => 1.0,
+        ExploitCodeMaturity::Functional => 0.97,
+        ExploitCodeMaturity::ProofOfConcept => 0.94,
+        ExploitCodeMaturity::Unproven => 0.91,
+    }
+}
+
+fn remediation_level_weight(value: RemediationLevel) -> f64 {
+    match value {
+        RemediationLevel::NotDefined | RemediationLevel::Unavailable => 1.0,
+        RemediationLevel::Workaround => 0.97,
+        RemediationLevel::TemporaryFix => 0.96,
+        RemediationLevel::OfficialFix => 0.95,
+    }
+}
+
+fn report_confidence_weight(value: ReportConfidence) -> f64 {
+    match value {
+        ReportConfidence::NotDefined | ReportConfidence::Confirmed => 1.0,
+        ReportConfidence::Reasonable => 0.96,
+        ReportConfidence::Unknown => 0.92,
+    }
+}
+
+fn requirement_weight(value: Requirement) -> f64 {
+    match value {
+        Requirement::NotDefined | Requirement::Medium => 1.0,
+        Requirement::High => 1.5,
+        Requirement::Low => 0.5,
+    }
+}
+
+/// Rounds up to one decimal, avoiding floating point artifacts (see appendix A of CVSS v3.1).
+/// This yields the same results as the definition of CVSS v3.0 for exactly representable numbers.
+fn round_up(value: f64) -> f64 {
+    let int_input = (value * 100_000.0).round() as i64;
+    if int_input % 10_000 == 0 {
+        int_input as f64 / 100_000.0
+    } else {
+        ((int_input / 10_000) + 1) as f64 / 10.0
+    }
+}
+
+/// Computed scores and severities of a CVSS v3.x vector.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CvssV3Scores {
+    pub base_score: f64,
+    pub base_severity: CvssSeverity,
+    pub temporal_score: f64,
+    pub temporal_severity: CvssSeverity,
+    pub environmental_score: f64,
+    pub environmental_severity: CvssSeverity,
+}
+
+impl CvssV3Vector {
+    /// Computes the base score.
+    pub fn base_score(&self) -> f64 {
+        let iss = 1.0
+            - (1.0 - impact_weight(self.confidentiality_impact))
+                * (1.0 - impact_weight(self.integrity_impact))
+                * (1.0 - impact_weight(self.availability_impact));
+        let impact = match self.scope {
+            Scope::Unchanged => 6.42 * iss,
+            Scope::Changed => 7.52 * (iss - 0.029) - 3.25 * (iss - 0.02).powi(15),
+        };
+        let exploitability = 8.22
+            * attack_vector_weight(self.attack_vector)
+            * attack_complexity_weight(self.attack_complexity)
+            * privileges_required_weight(self.privileges_required, self.scope)
+            * user_interaction_weight(self.user_interaction);
+
+        if impact <= 0.0 {
+            0.0
+        } else {
+            match self.scope {
+                Scope::Unchanged => round_up((impact + exploitability).min(10.0)),
+                Scope::Changed => round_up((1.08 * (impact + exploitability)).min(10.0)),
+            }
+        }
+    }
+
+    /// Computes the temporal score, which equals the base score if no temporal metrics are defined.
+    pub fn temporal_score(&self) -> f64 {
+        round_up(self.base_score() * self.temporal_weight())
+    }
+
+    fn temporal_weight(&self) -> f64 {
+        exploit_code_maturity_weight(self.exploit_code_maturity)
+            * remediation_level_weight(self.remediation_level)
+            * report_confidence_weight(self.report_confidence)
+    }
+
+    /// Computes the environmental score, taking the base metrics for all modified metrics
+    /// which are not defined.
+    pub fn environmental_score(&self) -> f64 {
+        let scope = modified(self.modified_scope, self.scope);
+        let miss = (1.0
+            - (1.0
+                - requirement_weight(self.confidentiality_requirement)
+                    * impact_weight(modified(self.modified_confidentiality_impact, self.confidentiality_impact)))
+                * (1.0
+                    - requirement_weight(self.integrity_requirement)
+                        * impact_weight(modified(self.modified_integrity_impact, self.integrity_impact)))
+                * (1.0
+                    - requirement_weight(self.availability_requirement)
+                        * impact_weight(modified(self.modified_availability_impact, self.availability_impact))))
+        .min(0.915);
+        let modified_impact = match (scope, self.version) {
+            (Scope::Unchanged, _) => 6.42 * miss,
+            (Scope::Changed, CvssV3Version::V3_0) => 7.52 * (miss - 0.029) - 3.25 * (miss - 0.02).powi(15),
+            (Scope::Changed, CvssV3Version::V3_1) => {
+                7.52 * (miss - 0.029) - 3.25 * (miss * 0.9731 - 0.02).powi(13)
+            }
+        };
+        let modified_exploitability = 8.22
+            * attack_vector_weight(modified(self.modified_attack_vector, self.attack_vector))
+            * attack_complexity_weight(modified(self.modified_attack_complexity, self.attack_complexity))
+            * privileges_required_weight(
+                modified(self.modified_privileges_required, self.privileges_required),
+                scope,
+            )
+            * user_interaction_weight(modified(self.modified_user_interaction, self.user_interaction));
+
+        if modified_impact <= 0.0 {
+            0.0
+        } else {
+            let score = match scope {
+                Scope::Unchanged => round_up((modified_impact + modified_exploitability).min(10.0)),
+                Scope::Changed => round_up((1.08 * (modified_impact + modified_exploitability)).min(10.0)),
+            };
+            round_up(score * self.temporal_weight())
+        }
+    }
+
+    /// Computes all scores and their severities.
+    pub fn scores(&self) -> CvssV3Scores {
+        let base_score = self.base_score();
+        let temporal_score = self.temporal_score();
+        let environmental_score = self.environmental_score();
+        CvssV3Scores {
+            base_score,
+            base_severity: CvssSeverity::from_score(base_score),
+            temporal_score,
+            temporal_severity: CvssSeverity::from_score(temporal_score),
+            environmental_score,
+            environmental_severity: CvssSeverity::from_score(environmental_score),
+        }
+    }
+}
+
+/// A CVSS v3.0 or v3.1 JSON object, as contained in the `cvss_v3` property of CSAF documents.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CvssV3 {
+    pub version: CvssV3Version,
+    #[serde(deserialize_with = "deserialize_vector")]
+    pub vector_string: CvssV3Vector,
+    pub attack_vector: Option<AttackVector>,
+    pub attack_complexity: Option<AttackComplexity>,
+    pub privileges_required: Option<PrivilegesRequired>,
+    pub user_interaction: Option<UserInteraction>,
+    pub scope: Option<Scope>,
+    pub confidentiality_impact: Option<Impact>,
+    pub integrity_impact: Option<Impact>,
+    pub availability_impact: Option<Impact>,
+    pub base_score: f64,
+    pub base_severity: CvssSeverity,
+    pub exploit_code_maturity: Option<ExploitCodeMaturity>,
+    pub remediation_level: Option<RemediationLevel>,
+    pub report_confidence: Option<ReportConfidence>,
+    pub temporal_score: Option<f64>,
+    pub temporal_severity: Option<CvssSeverity>,
+    pub confidentiality_requirement: Option<Requirement>,
+    pub integrity_requirement: Option<Requirement>,
+    pub availability_requirement: Option<Requirement>,
+    pub modified_attack_vector: Option<ModifiedAttackVector>,
+    pub modified_attack_complexity: Option<ModifiedAttackComplexity>,
+    pub modified_privileges_required: Option<ModifiedPrivilegesRequired>,
+    pub modified_user_interaction: Option<ModifiedUserInteraction>,
+    pub modified_scope: Option<ModifiedScope>,
+    pub modified_confidentiality_impact: Option<ModifiedImpact>,
+    pub modified_integrity_impact: Option<ModifiedImpact>,
+    pub modified_availability_impact: Option<ModifiedImpact>,
+    pub environmental_score: Option<f64>,
+    pub environmental_severity: Option<CvssSeverity>,
+}
+
+impl CvssV3 {
+    /// Reads a CVSS v3.x JSON object, checking it against the constraints of the CVSS v3.x
+    /// JSON schemas and checking that its vector string is valid.
+    pub fn from_json(json: &Map<String, Value>) -> Result<CvssV3, CvssError> {
+        let cvss: CvssV3 = serde_json::from_value(Value::Object(json.clone()))
+            .map_err(|e| CvssError::InvalidObject(e.to_string()))?;
+        if cvss.version != cvss.vector_string.version {
+            return Err(CvssError::InvalidObject(
+                "the version does not match the version of the vector string".to_string(),
+            ));
+        }
+        check_score_ranges(&[
+            ("baseScore", Some(cvss.base_score)),
+            ("temporalScore", cvss.temporal_score),
+            ("environmentalScore", cvss.environmental_score),
+        ])?;
+        Ok(cvss)
+    }
+
+    /// Returns all scores and severities which differ from the values computed from the vector string.
+    pub fn computation_mismatches(&self) -> Vec<CvssMismatch> {
+        let scores = self.vector_string.scores();
+        let mut mismatches = Vec::new();
+        compare_score(&mut mismatches, "baseScore", Some(self.base_score), scores.base_score);
+        compare_severity(&mut mismatches, "baseSeverity", Some(self.base_severity), scores.base_severity);
+        compare_score(&mut mismatches, "temporalScore", self.temporal_score, scores.temporal_score);
+        compare_severity(&mut mismatches, "temporalSeverity", self.temporal_severity, scores.temporal_severity);
+        compare_score(&mut mismatches, "environmentalScore", self.environmental_score, scores.environmental_score);
+        compare_severity(
+            &mut mismatches,
+            "environmentalSeverity",
+            self.environmental_severity,
+            scores.environmental_severity,
+        );
+        mismatches
+    }
+
+    /// Returns all metric properties which contradict the vector string.
+    pub fn property_mismatches(&self) -> Vec<CvssMismatch> {
+        let v = &self.vector_string;
+        let mut m = Vec::new();
+        compare_metric(&mut m, "attackVector", self.attack_vector, v.attack_vector);
+        compare_metric(&mut m, "attackComplexity", self.attack_complexity, v.attack_complexity);
+        compare_metric(&mut m, "privilegesRequired", self.privileges_required, v.privileges_required);
+        compare_metric(&mut m, "userInteraction", self.user_interaction, v.user_interaction);
+        compare_metric(&mut m, "scope", self.scope, v.scope);
+        compare_metric(&mut m, "confidentialityImpact", self.confidentiality_impact, v.confidentiality_impact);
+        compare_metric(&mut m, "integrityImpact", self.integrity_impact, v.integrity_impact);
+        compare_metric(&mut m, "availabilityImpact", self.availability_impact, v.availability_impact);
+        compare_metric(&mut m, "exploitCodeMaturity", self.exploit_code_maturity, v.exploit_code_maturity);
+        compare_metric(&mut m, "remediationLevel", self.remediation_level, v.remediation_level);
+        compare_metric(&mut m, "reportConfidence", self.report_confidence, v.report_confidence);
+        compare_metric(&mut m, "confidentialityRequirement", self.confidentiality_requirement, v.confidentiality_requirement);
+        compare_metric(&mut m, "integrityRequirement", self.integrity_requirement, v.integrity_requirement);
+        compare_metric(&mut m, "availabilityRequirement", self.availability_requirement, v.availability_requirement);
+        compare_metric(&mut m, "modifiedAttackVector", self.modified_attack_vector, v.modified_attack_vector);
+        compare_metric(&mut m, "modifiedAttackComplexity", self.modified_attack_complexity, v.modified_attack_complexity);
+        compare_metric(&mut m, "modifiedPrivilegesRequired", self.modified_privileges_required, v.modified_privileges_required);
+        compare_metric(&mut m, "modifiedUserInteraction", self.modified_user_interaction, v.modified_user_interaction);
+        compare_metric(&mut m, "modifiedScope", self.modified_scope, v.modified_scope);
+        compare_metric(&mut m, "modifiedConfidentialityImpact", self.modified_confidentiality_impact, v.modified_confidentiality_impact);
+        compare_metric(&mut m, "modifiedIntegrityImpact", self.modified_integrity_impact, v.modified_integrity_impact);
+        compare_metric(&mut m, "modifiedAvailabilityImpact", self.modified_availability_impact, v.modified_availability_impact);
+        m
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::cvss::v3::{AttackVector, CvssV3, CvssV3Vector, CvssV3Version, ModifiedAttackVector};
+    use crate::csaf::cvss::{CvssError, CvssSeverity};
+    use serde_json::json;
+
+    fn scores(vector: &str) -> (f64, f64, f64) {
+        let vector: CvssV3Vector = vector.parse().unwrap();
+        (vector.base_score(), vector.temporal_score(), vector.environmental_score())
+    }
+
+    #[test]
+    fn test_parse_vector() {
+        let vector: CvssV3Vector = "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/MAV:L".parse().unwrap();
+        assert_eq!(CvssV3Version::V3_1, vector.version);
+        assert_eq!(AttackVector::Network, vector.attack_vector);
+        assert_eq!(ModifiedAttackVector::Local, vector.modified_attack_vector);
+
+        // Unlike CVSS v4.0, earlier versions do not mandate the order of the metrics
+        for valid in [
+            "CVSS:3.1/AC:L/AV:N/PR:N/UI:N/S:U/C:H/I:H/A:H",
+            "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/MAV:L/E:P",
+        ] {
+            assert!(valid.parse::<CvssV3Vector>().is_ok(), "{}", valid);
+        }
+
+        for invalid in [
+            "CVSS:3.2/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+            "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H",
+            "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/AV:N",
+            "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:X",
+            "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/FOO:X",
+            "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/",
+        ] {
+            assert!(matches!(invalid.parse::<CvssV3Vector>(), Err(CvssError::InvalidVector(_))), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_scores() {
+        assert_eq!((9.8, 9.8, 9.8), scores("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H"));
+        // The modified impact formula of CVSS v3.1 differs from the base impact formula
+        assert_eq!((9.9, 9.9, 10.0), scores("CVSS:3.1/AV:N/AC:L/PR:L/UI:N/S:C/C:H/I:H/A:H"));
+        assert_eq!((9.9, 9.9, 9.9), scores("CVSS:3.0/AV:N/AC:L/PR:L/UI:N/S:C/C:H/I:H/A:H"));
+        assert_eq!((6.1, 6.1, 6.1), scores("CVSS:3.0/AV:N/AC:L/PR:N/UI:R/S:C/C:L/I:L/A:N"));
+        assert_eq!((7.8, 7.8, 7.8), scores("CVSS:3.1/AV:L/AC:L/PR:L/UI:N/S:U/C:H/I:H/A:H"));
+        assert_eq!((0.0, 0.0, 0.0), scores("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:N/I:N/A:N"));
+        assert_eq!((9.8, 8.8, 8.8), scores("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E:P/RL:O/RC:C"));
+        assert_eq!((9.8, 9.8, 8.4), scores("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/MAV:L"));
+    }
+
+    #[test]
+    fn test_cvss_object() {
+        let json = json!({
+            "version": "3.1",
+            "vectorString": "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+            "attackVector": "LOCAL",
+            "baseScore": 9.0,
+            "baseSeverity": "CRITICAL"
+        });
+        let cvss = CvssV3::from_json(json.as_object().unwrap()).unwrap();
+        assert_eq!(CvssSeverity::Critical, cvss.base_severity);
+        let computation = cvss.computation_mismatches();
+        assert_eq!(1, computation.len());
+        assert_eq!(("baseScore", "9.8"), (computation[0].property, computation[0].expected.as_str()));
+        let properties = cvss.property_mismatches();
+        assert_eq!(1, properties.len());
+        assert_eq!(("attackVector", "LOCAL", "NETWORK"), (
+            properties[0].property,
+            properties[0].actual.as_str(),
+            properties[0].expected.as_str(),
+        ));
+
+        let mut invalid = json.clone();
+        invalid["version"] = json!("3.0");
+        assert!(matches!(CvssV3::from_json(invalid.as_object().unwrap()), Err(CvssError::InvalidObject(_))));
+        let mut invalid = json.clone();
+        invalid["baseScore"] = json!(10.5);
+        assert!(matches!(CvssV3::from_json(invalid.as_object().unwrap()), Err(CvssError::InvalidObject(_))));
+        let mut invalid = json;
+        invalid.as_object_mut().unwrap().remove("baseSeverity");
+        assert!(matches!(CvssV3::from_json(invalid.as_object().unwrap()), Err(CvssError::InvalidObject(_))));
+    }
+}
@@ -0,0 +1,902 @@
+//! CVSS v4.0 vectors and JSON objects, see <https://www.first.org/cvss/v4.0/specification-document>.
+//!
+//! Scores are computed like the FIRST reference calculator: the vector is assigned to a
+//! MacroVector, whose score is looked up and lowered by the mean severity distance of the
+//! vector to the highest severity vectors of the MacroVector.
+
+use crate::csaf::cvss::{
+    check_score_ranges, compare_metric, compare_score, compare_severity, deserialize_vector, optional_metric,
+    parse_metrics, required_metric, CvssError, CvssMetric, CvssMismatch, CvssSeverity,
+};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::LazyLock;
+
+cvss_metric! {
+    /// Attack Vector (AV)
+    AttackVector {
+        Network => "N", "NETWORK";
+        Adjacent => "A", "ADJACENT";
+        Local => "L", "LOCAL";
+        Physical => "P", "PHYSICAL";
+    }
+}
+
+cvss_metric! {
+    /// Modified Attack Vector (MAV)
+    ModifiedAttackVector {
+        NotDefined => "X", "NOT_DEFINED";
+        Network => "N", "NETWORK";
+        Adjacent => "A", "ADJACENT";
+        Local => "L", "LOCAL";
+        Physical => "P", "PHYSICAL";
+    }
+}
+
+cvss_metric! {
+    /// Attack Complexity (AC)
+    AttackComplexity {
+        Low => "L", "LOW";
+        High => "H", "HIGH";
+    }
+}
+
+cvss_metric! {
+    /// Modified Attack Complexity (MAC)
+    ModifiedAttackComplexity {
+        NotDefined => "X", "NOT_DEFINED";
+        Low => "L", "LOW";
+        High => "H", "HIGH";
+    }
+}
+
+cvss_metric! {
+    /// Attack Requirements (AT)
+    AttackRequirements {
+        None => "N", "NONE";
+        Present => "P", "PRESENT";
+    }
+}
+
+cvss_metric! {
+    /// Modified Attack Requirements (MAT)
+    ModifiedAttackRequirements {
+        NotDefined => "X", "NOT_DEFINED";
+        None => "N", "NONE";
+        Present => "P", "PRESENT";
+    }
+}
+
+cvss_metric! {
+    /// Privileges Required (PR)
+    PrivilegesRequired {
+        None => "N", "NONE";
+        Low => "L", "LOW";
+        High => "H", "HIGH";
+    }
+}
+
+cvss_metric! {
+    /// Modified Privileges Required (MPR)
+    ModifiedPrivilegesRequired {
+        NotDefined => "X", "NOT_DEFINED";
+        None => "N", "NONE";
+        Low => "L", "LOW";
+        High => "H", "HIGH";
+    }
+}
+
+cvss_metric! {
+    /// User Interaction (UI)
+    UserInteraction {
+        None => "N", "NONE";
+        Passive => "P", "PASSIVE";
+        Active => "A", "ACTIVE";
+    }
+}
+
+cvss_metric! {
+    /// Modified User Interaction (MUI)
+    ModifiedUserInteraction {
+        NotDefined => "X", "NOT_DEFINED";
+        None => "N", "NONE";
+        Passive => "P", "PASSIVE";
+        Active => "A", "ACTIVE";
+    }
+}
+
+cvss_metric! {
+    /// Impact on the vulnerable system (VC, VI, VA) and the subsequent systems (SC, SI, SA)
+    Impact {
+        High => "H", "HIGH";
+        Low => "L", "LOW";
+        None => "N", "NONE";
+    }
+}
+
+cvss_metric! {
+    /// Modified impact on the vulnerable system (MVC, MVI, MVA) and the subsequent system's
+    /// confidentiality (MSC)
+    ModifiedImpact {
+        NotDefined => "X", "NOT_DEFINED";
+        High => "H", "HIGH";
+        Low => "L", "LOW";
+        None => "N", "NONE";
+    }
+}
+
+cvss_metric! {
+    /// Modified impact on the subsequent system's integrity (MSI) and availability (MSA),
+    /// which additionally allows to express an impact on human safety
+    ModifiedSubsequentImpact {
+        NotDefined => "X", "NOT_DEFINED";
+        Safety => "S", "SAFETY";
+        High => "H", "HIGH";
+        Low => "L", "LOW";
+        None => "N", "NONE";
+    }
+}
+
+cvss_metric! {
+    /// Exploit Maturity (E)
+    ExploitMaturity {
+        NotDefined => "X", "NOT_DEFINED";
+        Attacked => "A", "ATTACKED";
+        ProofOfConcept => "P", "PROOF_OF_CONCEPT";
+        Unreported => "U", "UNREPORTED";
+    }
+}
+
+cvss_metric! {
+    /// Confidentiality (CR), Integrity (IR) and Availability (AR) Requirement
+    Requirement {
+        NotDefined => "X", "NOT_DEFINED";
+        High => "H", "HIGH";
+        Medium => "M", "MEDIUM";
+        Low => "L", "LOW";
+    }
+}
+
+cvss_metric! {
+    /// Safety (S)
+    Safety {
+        NotDefined => "X", "NOT_DEFINED";
+        Negligible => "N", "NEGLIGIBLE";
+        Present => "P", "PRESENT";
+    }
+}
+
+cvss_metric! {
+    /// Automatable (AU)
+    Automatable {
+        NotDefined => "X", "NOT_DEFINED";
+        No => "N", "NO";
+        Yes => "Y", "YES";
+    }
+}
+
+cvss_metric! {
+    /// Recovery (R)
+    Recovery {
+        NotDefined => "X", "NOT_DEFINED";
+        Automatic => "A", "AUTOMATIC";
+        User => "U", "USER";
+        Irrecoverable => "I", "IRRECOVERABLE";
+    }
+}
+
+cvss_metric! {
+    /// Value Density (V)
+    ValueDensity {
+        NotDefined => "X", "NOT_DEFINED";
+        Diffuse => "D", "DIFFUSE";
+        Concentrated => "C", "CONCENTRATED";
+    }
+}
+
+cvss_metric! {
+    /// Vulnerability Response Effort (RE)
+    VulnerabilityResponseEffort {
+        NotDefined => "X", "NOT_DEFINED";
+        Low => "L", "LOW";
+        Moderate => "M", "MODERATE";
+        High => "H", "HIGH";
+    }
+}
+
+cvss_metric! {
+    /// Provider Urgency (U)
+    ProviderUrgency {
+        NotDefined => "X", "NOT_DEFINED";
+        Clear => "Clear", "CLEAR";
+        Green => "Green", "GREEN";
+        Amber => "Amber", "AMBER";
+        Red => "Red", "RED";
+    }
+}
+
+/// Version of a CVSS v4 JSON object, which is always "4.0".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize)]
+pub enum CvssV4Version {
+    #[serde(rename = "4.0")]
+    V4_0,
+}
+
+/// All metrics in the order mandated for vector strings.
+const KNOWN_METRICS: &[&str] = &[
+    "AV", "AC", "AT", "PR", "UI", "VC", "VI", "VA", "SC", "SI", "SA", "E", "CR", "IR", "AR", "MAV", "MAC", "MAT",
+    "MPR", "MUI", "MVC", "MVI", "MVA", "MSC", "MSI", "MSA", "S", "AU", "R", "V", "RE", "U",
+];
+
+/// A parsed CVSS v4.0 vector string. Metrics not contained in the vector string are "not defined".
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CvssV4Vector {
+    pub attack_vector: AttackVector,
+    pub attack_complexity: AttackComplexity,
+    pub attack_requirements: AttackRequirements,
+    pub privileges_required: PrivilegesRequired,
+    pub user_interaction: UserInteraction,
+    pub vuln_confidentiality_impact: Impact,
+    pub vuln_integrity_impact: Impact,
+    pub vuln_availability_impact: Impact,
+    pub sub_confidentiality_impact: Impact,
+    pub sub_integrity_impact: Impact,
+    pub sub_availability_impact: Impact,
+    pub exploit_maturity: ExploitMaturity,
+    pub confidentiality_requirement: Requirement,
+    pub integrity_requirement: Requirement,
+    pub availability_requirement: Requirement,
+    pub modified_attack_vector: ModifiedAttackVector,
+    pub modified_attack_complexity: ModifiedAttackComplexity,
+    pub modified_attack_requirements: ModifiedAttackRequirements,
+    pub modified_privileges_required: ModifiedPrivilegesRequired,
+    pub modified_user_interaction: ModifiedUserInteraction,
+    pub modified_vuln_confidentiality_impact: ModifiedImpact,
+    pub modified_vuln_integrity_impact: ModifiedImpact,
+    pub modified_vuln_availability_impact: ModifiedImpact,
+    pub modified_sub_confidentiality_impact: ModifiedImpact,
+    pub modified_sub_integrity_impact: ModifiedSubsequentImpact,
+    pub modified_sub_availability_impact: ModifiedSubsequentImpact,
+    pub safety: Safety,
+    pub automatable: Automatable,
+    pub recovery: Recovery,
+    pub value_density: ValueDensity,
+    pub vulnerability_response_effort: VulnerabilityResponseEffort,
+    pub provider_urgency: ProviderUrgency,
+}
+
+impl FromStr for CvssV4Vector {
+    type Err = CvssError;
+
+    fn from_str(vector: &str) -> Result<Self, Self::Err> {
+        let metrics = vector.strip_prefix("CVSS:4.0/").ok_or_else(|| {
+            CvssError::InvalidVector(format!("\"{}\" does not start with \"CVSS:4.0/\"", vector))
+        })?;
+        let m = parse_metrics(metrics, KNOWN_METRICS, true)?;
+
+        Ok(CvssV4Vector {
+            attack_vector: required_metric(&m, "AV")?,
+            attack_complexity: required_metric(&m, "AC")?,
+            attack_requirements: required_metric(&m, "AT")?,
+            privileges_required: required_metric(&m, "PR")?,
+            user_interaction: required_metric(&m, "UI")?,
+            vuln_confidentiality_impact: required_metric(&m, "VC")?,
+            vuln_integrity_impact: required_metric(&m, "VI")?,
+            vuln_availability_impact: required_metric(&m, "VA")?,
+            sub_confidentiality_impact: required_metric(&m, "SC")?,
+            sub_integrity_impact: required_metric(&m, "SI")?,
+            sub_availability_impact: required_metric(&m, "SA")?,
+            exploit_maturity: optional_metric(&m, "E")?.unwrap_or(ExploitMaturity::NotDefined),
+            confidentiality_requirement: optional_metric(&m, "CR")?.unwrap_or(Requirement::NotDefined),
+            integrity_requirement: optional_metric(&m, "IR")?.unwrap_or(Requirement::NotDefined),
+            availability_requirement: optional_metric(&m, "AR")?.unwrap_or(Requirement::NotDefined),
+            modified_attack_vector: optional_metric(&m, "MAV")?.unwrap_or(ModifiedAttackVector::NotDefined),
+            modified_attack_complexity: optional_metric(&m, "MAC")?.unwrap_or(ModifiedAttackComplexity::NotDefined),
+            modified_attack_requirements: optional_metric(&m, "MAT")?
+                .unwrap_or(ModifiedAttackRequirements::NotDefined),
+            modified_privileges_required: optional_metric(&m, "MPR")?
+                .unwrap_or(ModifiedPrivilegesRequired::NotDefined),
+            modified_user_interaction: optional_metric(&m, "MUI")?.unwrap_or(ModifiedUserInteraction::NotDefined),
+            modified_vuln_confidentiality_impact: optional_metric(&m, "MVC")?.unwrap_or(ModifiedImpact::NotDefined),
+            modified_vuln_integrity_impact: optional_metric(&m, "MVI")?.unwrap_or(ModifiedImpact::NotDefined),
+            modified_vuln_availability_impact: optional_metric(&m, "MVA")?.unwrap_or(ModifiedImpact::NotDefined),
+            modified_sub_confidentiality_impact: optional_metric(&m, "MSC")?.unwrap_or(ModifiedImpact::NotDefined),
+            modified_sub_integrity_impact: optional_metric(&m, "MSI")?
+                .unwrap_or(ModifiedSubsequentImpact::NotDefined),
+            modified_sub_availability_impact: optional_metric(&m, "MSA")?
+                .unwrap_or(ModifiedSubsequentImpact::NotDefined),
+            safety: optional_metric(&m, "S")?.unwrap_or(Safety::NotDefined),
+            automatable: optional_metric(&m, "AU")?.unwrap_or(Automatable::NotDefined),
+            recovery: optional_metric(&m, "R")?.unwrap_or(Recovery::NotDefined),
+            value_density: optional_metric(&m, "V")?.unwrap_or(ValueDensity::NotDefined),
+            vulnerability_response_effort: optional_metric(&m, "RE")?
+                .unwrap_or(VulnerabilityResponseEffort::NotDefined),
+            provider_urgency: optional_metric(&m, "U")?.unwrap_or(ProviderUrgency::NotDefined),
+        })
+    }
+}
+
+/// Scores of the MacroVectors, indexed by their EQ1 to EQ6 levels.
+static MACRO_VECTOR_SCORES: LazyLock<HashMap<&'static str, f64>> = LazyLock::new(|| {
+    HashMap::from([
+        ("000000", 10.0), ("000001", 9.9), ("000010", 9.8), ("000011", 9.5), ("000020", 9.5), ("000021", 9.2),
+        ("000100", 10.0), ("000101", 9.6), ("000110", 9.3), ("000111", 8.7), ("000120", 9.1), ("000121", 8.1),
+        ("000200", 9.3), ("000201", 9.0), ("000210", 8.9), ("000211", 8.0), ("000220", 8.1), ("000221", 6.8),
+        ("001000", 9.8), ("001001", 9.5), ("001010", 9.5), ("001011", 9.2), ("001020", 9.0), ("001021", 8.4),
+        ("001100", 9.3), ("001101", 9.2), ("001110", 8.9), ("001111", 8.1), ("001120", 8.1), ("001121", 6.5),
+        ("001200", 8.8), ("001201", 8.0), ("001210", 7.8), ("001211", 7.0), ("001220", 6.9), ("001221", 4.8),
+        ("002001", 9.2), ("002011", 8.2), ("002021", 7.2), ("002101", 7.9), ("002111", 6.9), ("002121", 5.0),
+        ("002201", 6.9), ("002211", 5.5), ("002221", 2.7),
+        ("010000", 9.9), ("010001", 9.7), ("010010", 9.5), ("010011", 9.2), ("010020", 9.2), ("010021", 8.5),
+        ("010100", 9.5), ("010101", 9.1), ("010110", 9.0), ("010111", 8.3), ("010120", 8.4), ("010121", 7.1),
+        ("010200", 9.2), ("010201", 8.1), ("010210", 8.2), ("010211", 7.1), ("010220", 7.2), ("010221", 5.3),
+        ("011000", 9.5), ("011001", 9.3), ("011010", 9.2), ("011011", 8.5), ("011020", 8.5), ("011021", 7.3),
+        ("011100", 9.2), ("011101", 8.2), ("011110", 8.0), ("011111", 7.2), ("011120", 7.0), ("011121", 5.9),
+        ("011200", 8.4), ("011201", 7.0), ("011210", 7.1), ("011211", 5.2), ("011220", 5.0), ("011221", 3.0),
+        ("012001", 8.6), ("012011", 7.5), ("012021", 5.2), ("012101", 7.1), ("012111", 5.2), ("012121", 2.9),
+        ("012201", 6.3), ("012211", 2.9), ("012221", 1.7),
+        ("100000", 9.8), ("100001", 9.5), ("100010", 9.4), ("100011", 8.7), ("100020", 9.1), ("100021", 8.1),
+        ("100100", 9.4), ("100101", 8.9), ("100110", 8.6), ("100111", 7.4), ("100120", 7.7), ("100121", 6.4),
+        ("100200", 8.7), ("100201", 7.5), ("100210", 7.4), ("100211", 6.3), ("100220", 6.3), ("100221", 4.9),
+        ("101000", 9.4), ("101001", 8.9), ("101010", 8.8), ("101011", 7.7), ("101020", 7.6), ("101021", 6.7),
+        ("101100", 8.6), ("101101", 7.6), ("101110", 7.4), ("101111", 5.8), ("101120", 5.9), ("101121", 5.0),
+        ("101200", 7.2), ("101201", 5.7), ("101210", 5.7), ("101211", 5.2), ("101220", 5.2), ("101221", 2.5),
+        ("102001", 8.3), ("102011", 7.0), ("102021", 5.4), ("102101", 6.5), ("102111", 5.8), ("102121", 2.6),
+        ("102201", 5.3), ("102211", 2.1), ("102221", 1.3),
+        ("110000", 9.5), ("110001", 9.0), ("110010", 8.8), ("110011", 7.6), ("110020", 7.6), ("110021", 7.0),
+        ("110100", 9.0), ("110101", 7.7), ("110110", 7.5), ("110111", 6.2), ("110120", 6.1), ("110121", 5.3),
+        ("110200", 7.7), ("110201", 6.6), ("110210", 6.8), ("110211", 5.9), ("110220", 5.2), ("110221", 3.0),
+        ("111000", 8.9), ("111001", 7.8), ("111010", 7.6), ("111011", 6.7), ("111020", 6.2), ("111021", 5.8),
+        ("111100", 7.4), ("111101", 5.9), ("111110", 5.7), ("111111", 5.7), ("111120", 4.7), ("111121", 2.3),
+        ("111200", 6.1), ("111201", 5.2), ("111210", 5.7), ("111211", 2.9), ("111220", 2.4), ("111221", 1.6),
+        ("112001", 7.1), ("112011", 5.9), ("112021", 3.0), ("112101", 5.8), ("112111", 2.6), ("112121", 1.5),
+        ("112201", 2.3), ("112211", 1.3), ("112221", 0.6),
+        ("200000", 9.3), ("200001", 8.7), ("200010", 8.6), ("200011", 7.2), ("200020", 7.5), ("200021", 5.8),
+        ("200100", 8.6), ("200101", 7.4), ("200110", 7.4), ("200111", 6.1), ("200120", 5.6), ("200121", 3.4),
+        ("200200", 7.0), ("200201", 5.4), ("200210", 5.2), ("200211", 4.0), ("200220", 4.0), ("200221", 2.2),
+        ("201000", 8.5), ("201001", 7.5), ("201010", 7.4), ("201011", 5.5), ("201020", 6.2), ("201021", 5.1),
+        ("201100", 7.2), ("201101", 5.7), ("201110", 5.5), ("201111", 4.1), ("201120", 4.6), ("201121", 1.9),
+        ("201200", 5.3), ("201201", 3.6), ("201210", 3.4), ("201211", 1.9), ("201220", 1.9), ("201221", 0.8),
+        ("202001", 6.4), ("202011", 5.1), ("202021", 2.0), ("202101", 4.7), ("202111", 2.1), ("202121", 1.1),
+        ("202201", 2.4), ("202211", 0.9), ("202221", 0.4),
+        ("210000", 8.8), ("210001", 7.5), ("210010", 7.3), ("210011", 5.3), ("210020", 6.0), ("210021", 5.0),
+        ("210100", 7.3), ("210101", 5.5), ("210110", 5.9), ("210111", 4.0), ("210120", 4.1), ("210121", 2.0),
+        ("210200", 5.4), ("210201", 4.3), ("210210", 4.5), ("210211", 2.2), ("210220", 2.0), ("210221", 1.1),
+        ("211000", 7.5), ("211001", 5.5), ("211010", 5.8), ("211011", 4.5), ("211020", 4.0), ("211021", 2.1),
+        ("211100", 6.1), ("211101", 5.1), ("211110", 4.8), ("211111", 1.8), ("211120", 2.0), ("211121", 0.9),
+        ("211200", 4.6), ("211201", 1.8), ("211210", 1.7), ("211211", 0.7), ("211220", 0.8), ("211221", 0.2),
+        ("212001", 5.3), ("212011", 2.4), ("212021", 1.4), ("212101", 2.4), ("212111", 1.2), ("212121", 0.5),
+        ("212201", 1.0), ("212211", 0.3), ("212221", 0.1),
+    ])
+});
+
+/// Returns the score of the MacroVector with the given EQ levels, if the MacroVector exists.
+fn macro_vector_score(eq: [u8; 6]) -> Option<f64> {
+    let key: String = eq.iter().map(|level| char::from(b'0' + level)).collect();
+    MACRO_VECTOR_SCORES.get(key.as_str()).copied()
+}
+
+/// Highest severity vectors (as partial vector strings) of each EQ level.
+fn eq_max_vectors(eq: usize, level: u8, eq6_level: u8) -> &'static [&'static str] {
+    match (eq, level, eq6_level) {
+        (1, 0, _) => &["AV:N/PR:N/UI:N"],
+        (1, 1, _) => &["AV:A/PR:N/UI:N", "AV:N/PR:L/UI:N", "AV:N/PR:N/UI:P"],
+        (1, 2, _) => &["AV:P/PR:N/UI:N", "AV:A/PR:L/UI:P"],
+        (2, 0, _) => &["AC:L/AT:N"],
+        (2, 1, _) => &["AC:H/AT:N", "AC:L/AT:P"],
+        // EQ3 and EQ6 are combined, as both depend on the impact on the vulnerable system
+        (3, 0, 0) => &["VC:H/VI:H/VA:H/CR:H/IR:H/AR:H"],
+        (3, 0, 1) => &["VC:H/VI:H/VA:L/CR:M/IR:M/AR:H", "VC:H/VI:H/VA:H/CR:M/IR:M/AR:M"],
+        (3, 1, 0) => &["VC:L/VI:H/VA:H/CR:H/IR:H/AR:H", "VC:H/VI:L/VA:H/CR:H/IR:H/AR:H"],
+        (3, 1, 1) => &[
+            "VC:L/VI:H/VA:L/CR:H/IR:M/AR:H",
+            "VC:L/VI:H/VA:H/CR:H/IR:M/AR:M",
+            "VC:H/VI:L/VA:H/CR:M/IR:H/AR:M",
+            "VC:H/VI:L/VA:L/CR:M/IR:H/AR:H",
+            "VC:L/VI:L/VA:H/CR:H/IR:H/AR:M",
+        ],
+        (3, 2, 1) => &["VC:L/VI:L/VA:L/CR:H/IR:H/AR:H"],
+        (4, 0, _) => &["SC:H/SI:S/SA:S"],
+        (4, 1, _) => &["SC:H/SI:H/SA:H"],
+        (4, 2, _) => &["SC:L/SI:L/SA:L"],
+        (5, 0, _) => &["E:A"],
+        (5, 1, _) => &["E:P"],
+        (5, 2, _) => &["E:U"],
+        _ => &[],
+    }
+}
+
+/// Number of severity steps between the highest and the lowest severity vectors of each EQ level.
+fn eq_depth(eq: usize, level: u8, eq6_level: u8) -> f64 {
+    match (eq, level, eq6_level) {
+        (1, 0, _) => 1.0,
+        (1, 1, _) => 4.0,
+        (1, 2, _) => 5.0,
+        (2, 0, _) => 1.0,
+        (2, 1, _) => 2.0,
+        (3, 0, 0) => 7.0,
+        (3, 0, 1) => 6.0,
+        (3, 1, _) => 8.0,
+        (3, 2, _) => 10.0,
+        (4, 0, _) => 6.0,
+        (4, 1, _) => 5.0,
+        (4, 2, _) => 4.0,
+        _ => 1.0,
+    }
+}
+
+/// Severity level of a metric value, higher values are less severe.
+fn severity_level(metric: &str, value: &str) -> f64 {
+    match (metric, value) {
+        ("AV", "N") | ("PR", "N") | ("UI", "N") | ("AC", "L") | ("AT", "N") => 0.0,
+        ("AV", "A") | ("PR", "L") | ("UI", "P") | ("AC", "H") | ("AT", "P") => 0.1,
+        ("AV", "L") | ("PR", "H") | ("UI", "A") => 0.2,
+        ("AV", "P") => 0.3,
+        ("VC" | "VI" | "VA", "H") => 0.0,
+        ("VC" | "VI" | "VA", "L") => 0.1,
+        ("VC" | "VI" | "VA", "N") => 0.2,
+        ("SI" | "SA", "S") => 0.0,
+        ("SC" | "SI" | "SA", "H") => 0.1,
+        ("SC" | "SI" | "SA", "L") => 0.2,
+        ("SC" | "SI" | "SA", "N") => 0.3,
+        ("CR" | "IR" | "AR", "H") => 0.0,
+        ("CR" | "IR" | "AR", "M") => 0.1,
+        ("CR" | "IR" | "AR", "L") => 0.2,
+        _ => 0.0,
+    }
+}
+
+/// Returns the abbreviation of the value which takes effect for the given metric, i.e. the value
+/// of the modified metric if it is defined, or else the value of the base metric.
+fn effective<M: CvssMetric, T: CvssMetric>(modified: M, base: T) -> &'static str {
+    match modified.abbreviation() {
+        "X" => base.abbreviation(),
+        value => value,
+    }
+}
+
+/// Rounds to one decimal, ignoring floating point noise like the reference calculator.
+fn round_to_one_decimal(value: f64) -> f64 {
+    ((value + 1e-6) * 10.0).round() / 10.0
+}
+
+/// Computed scores and severities of a CVSS v4.0 vector.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CvssV4Scores {
+    pub base_score: f64,
+    pub base_severity: CvssSeverity,
+    pub threat_score: f64,
+    pub threat_severity: CvssSeverity,
+    pub environmental_score: f64,
+    pub environmental_severity: CvssSeverity,
+}
+
+impl CvssV4Vector {
+    /// Returns the abbreviation of the value which takes effect for the given metric when scoring.
+    /// Undefined threat metrics and security requirements default to their worst case.
+    fn effective_value(&self, metric: &str) -> &'static str {
+        match metric {
+            "AV" => effective(self.modified_attack_vector, self.attack_vector),
+            "AC" => effective(self.modified_attack_complexity, self.attack_complexity),
+            "AT" => effective(self.modified_attack_requirements, self.attack_requirements),
+            "PR" => effective(self.modified_privileges_required, self.privileges_required),
+            "UI" => effective(self.modified_user_interaction, self.user_interaction),
+            "VC" => effective(self.modified_vuln_confidentiality_impact, self.vuln_confidentiality_impact),
+            "VI" => effective(self.modified_vuln_integrity_impact, self.vuln_integrity_impact),
+            "VA" => effective(self.modified_vuln_availability_impact, self.vuln_availability_impact),
+            "SC" => effective(self.modified_sub_confidentiality_impact, self.sub_confidentiality_impact),
+            "SI" => effective(self.modified_sub_integrity_impact, self.sub_integrity_impact),
+            "SA" => effective(self.modified_sub_availability_impact, self.sub_availability_impact),
+            "E" => effective(self.exploit_maturity, ExploitMaturity::Attacked),
+            "CR" => effective(self.confidentiality_requirement, Requirement::High),
+            "IR" => effective(self.integrity_requirement, Requirement::High),
+            "AR" => effective(self.availability_requirement, Requirement::High),
+            _ => "X",
+        }
+    }
+
+    /// Computes the EQ levels of the MacroVector the vector belongs to.
+    fn macro_vector(&self) -> [u8; 6] {
+        let v = |metric| self.effective_value(metric);
+
+        let eq1 = if v("AV") == "N" && v("PR") == "N" && v("UI") == "N" {
+            0
+        } else if (v("AV") == "N" || v("PR") == "N" || v("UI") == "N") && v("AV") != "P" {
+            1
+        } else {
+            2
+        };
+        let eq2 = if v("AC") == "L" && v("AT") == "N" { 0 } else { 1 };
+        let eq3 = if v("VC") == "H" && v("VI") == "H" {
+            0
+        } else if v("VC") == "H" || v("VI") == "H" || v("VA") == "H" {
+            1
+        } else {
+            2
+        };
+        let eq4 = if v("SI") == "S" || v("SA") == "S" {
+            0
+        } else if v("SC") == "H" || v("SI") == "H" || v("SA") == "H" {
+            1
+        } else {
+            2
+        };
+        let eq5 = match v("E") {
+            "A" => 0,
+            "P" => 1,
+            _ => 2,
+        };
+        let eq6 = if (v("CR") == "H" && v("VC") == "H")
+            || (v("IR") == "H" && v("VI") == "H")
+            || (v("AR") == "H" && v("VA") == "H")
+        {
+            0
+        } else {
+            1
+        };
+        [eq1, eq2, eq3, eq4, eq5, eq6]
+    }
+
+    /// Computes the score of all metrics contained in the vector, i.e. the CVSS-B, CVSS-BT,
+    /// CVSS-BE or CVSS-BTE score, depending on the metric groups used.
+    pub fn score(&self) -> f64 {
+        if ["VC", "VI", "VA", "SC", "SI", "SA"].iter().all(|metric| self.effective_value(metric) == "N") {
+            return 0.0;
+        }
+
+        let eq = self.macro_vector();
+        let Some(value) = macro_vector_score(eq) else {
+            return 0.0;
+        };
+        let lower = |index: usize| {
+            let mut next = eq;
+            next[index] += 1;
+            macro_vector_score(next)
+        };
+
+        // Scores of the next lower MacroVectors of each EQ, if they exist
+        let eq3_eq6_lower = match (eq[2], eq[5]) {
+            // 00 has two lower MacroVectors (01 and 10), the higher one is used
+            (0, 0) => match (lower(5), lower(2)) {
+                (Some(left), Some(right)) => Some(left.max(right)),
+                (left, right) => left.or(right),
+            },
+            (1, 0) => lower(5),
+            (2, 1) => None,
+            _ => lower(2),
+        };
+        let lower_scores = [lower(0), lower(1), eq3_eq6_lower, lower(3), lower(4)];
+
+        // Find the first highest severity vector of the MacroVector the vector does not exceed
+        let mut max_vectors = Vec::new();
+        for eq1_max in eq_max_vectors(1, eq[0], eq[5]) {
+            for eq2_max in eq_max_vectors(2, eq[1], eq[5]) {
+                for eq3_max in eq_max_vectors(3, eq[2], eq[5]) {
+                    for eq4_max in eq_max_vectors(4, eq[3], eq[5]) {
+                        for eq5_max in eq_max_vectors(5, eq[4], eq[5]) {
+                            max_vectors.push(format!("{}/{}/{}/{}/{}", eq1_max, eq2_max, eq3_max, eq4_max, eq5_max));
+                        }
+                    }
+                }
+            }
+        }
+        let candidates: Vec<HashMap<&str, f64>> = max_vectors
+            .iter()
+            .map(|max_vector| {
+                max_vector
+                    .split('/')
+                    .filter_map(|part| part.split_once(':'))
+                    .map(|(metric, max_value)| {
+                        (
+                            metric,
+                            severity_level(metric, self.effective_value(metric)) - severity_level(metric, max_value),
+                        )
+                    })
+                    .collect::<HashMap<&str, f64>>()
+            })
+            .collect();
+        // Like the reference calculator, fall back to the last one if the vector exceeds all of them
+        let distances = candidates
+            .iter()
+            .find(|distances| distances.values().all(|distance| *distance >= 0.0))
+            .or(candidates.last())
+            .cloned()
+            .unwrap_or_default();
+        let distance = |metrics: &[&str]| metrics.iter().map(|m| distances.get(m).unwrap_or(&0.0)).sum::<f64>();
+        // The severity distance of EQ5 is always 0
+        let current_distances = [
+            distance(&["AV", "PR", "UI"]),
+            distance(&["AC", "AT"]),
+            distance(&["VC", "VI", "VA", "CR", "IR", "AR"]),
+            distance(&["SC", "SI", "SA"]),
+            0.0,
+        ];
+        let depths = [
+            eq_depth(1, eq[0], eq[5]),
+            eq_depth(2, eq[1], eq[5]),
+            eq_depth(3, eq[2], eq[5]),
+            eq_depth(4, eq[3], eq[5]),
+            eq_depth(5, eq[4], eq[5]),
+        ];
+
+        // Mean of the proportional distances to the next lower MacroVectors
+        let mut existing_lower = 0;
+        let mut normalized_sum = 0.0;
+        for i in 0..5 {
+            if let Some(lower_score) = lower_scores[i] {
+                existing_lower += 1;
+                normalized_sum += (value - lower_score) * current_distances[i] / (depths[i] * 0.1);
+            }
+        }
+        let mean_distance = if existing_lower == 0 { 0.0 } else { normalized_sum / existing_lower as f64 };
+
+        round_to_one_decimal((value - mean_distance).clamp(0.0, 10.0))
+    }
+
+    /// Returns a copy of the vector without threat and environmental metrics.
+    fn without_threat_and_environmental_metrics(&self) -> CvssV4Vector {
+        CvssV4Vector {
+            exploit_maturity: ExploitMaturity::NotDefined,
+            ..self.without_environmental_metrics()
+        }
+    }
+
+    /// Returns a copy of the vector without environmental metrics.
+    fn without_environmental_metrics(&self) -> CvssV4Vector {
+        CvssV4Vector {
+            confidentiality_requirement: Requirement::NotDefined,
+            integrity_requirement: Requirement::NotDefined,
+            availability_requirement: Requirement::NotDefined,
+            modified_attack_vector: ModifiedAttackVector::NotDefined,
+            modified_attack_complexity: ModifiedAttackComplexity::NotDefined,
+            modified_attack_requirements: ModifiedAttackRequirements::NotDefined,
+            modified_privileges_required: ModifiedPrivilegesRequired::NotDefined,
+            modified_user_interaction: ModifiedUserInteraction::NotDefined,
+            modified_vuln_confidentiality_impact: ModifiedImpact::NotDefined,
+            modified_vuln_integrity_impact: ModifiedImpact::NotDefined,
+            modified_vuln_availability_impact: ModifiedImpact::NotDefined,
+            modified_sub_confidentiality_impact: ModifiedImpact::NotDefined,
+            modified_sub_integrity_impact: ModifiedSubsequentImpact::NotDefined,
+            modified_sub_availability_impact: ModifiedSubsequentImpact::NotDefined,
+            ..self.clone()
+        }
+    }
+
+    /// Computes the score of the base metrics (CVSS-B).
+    pub fn base_score(&self) -> f64 {
+        self.without_threat_and_environmental_metrics().score()
+    }
+
+    /// Computes the score of the base and threat metrics (CVSS-BT).
+    pub fn threat_score(&self) -> f64 {
+        self.without_environmental_metrics().score()
+    }
+
+    /// Computes the score of the base, threat and environmental metrics (CVSS-BTE).
+    pub fn environmental_score(&self) -> f64 {
+        self.score()
+    }
+
+    /// Computes all scores and their severities.
+    pub fn scores(&self) -> CvssV4Scores {
+        let base_score = self.base_score();
+        let threat_score = self.threat_score();
+        let environmental_score = self.environmental_score();
+        CvssV4Scores {
+            base_score,
+            base_severity: CvssSeverity::from_score(base_score),
+            threat_score,
+            threat_severity: CvssSeverity::from_score(threat_score),
+            environmental_score,
+            environmental_severity: CvssSeverity::from_score(environmental_score),
+        }
+    }
+}
+
+/// A CVSS v4.0 JSON object, as contained in the `cvss_v4` property of CSAF 2.1 documents.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CvssV4 {
+    pub version: CvssV4Version,
+    #[serde(deserialize_with = "deserialize_vector")]
+    pub vector_string: CvssV4Vector,
+    pub attack_vector: Option<AttackVector>,
+    pub attack_complexity: Option<AttackComplexity>,
+    pub attack_requirements: Option<AttackRequirements>,
+    pub privileges_required: Option<PrivilegesRequired>,
+    pub user_interaction: Option<UserInteraction>,
+    pub vuln_confidentiality_impact: Option<Impact>,
+    pub vuln_integrity_impact: Option<Impact>,
+    pub vuln_availability_impact: Option<Impact>,
+    pub sub_confidentiality_impact: Option<Impact>,
+    pub sub_integrity_impact: Option<Impact>,
+    pub sub_availability_impact: Option<Impact>,
+    pub base_score: f64,
+    pub base_severity: CvssSeverity,
+    pub exploit_maturity: Option<ExploitMaturity>,
+    pub threat_score: Option<f64>,
+    pub threat_severity: Option<CvssSeverity>,
+    pub confidentiality_requirement: Option<Requirement>,
+    pub integrity_requirement: Option<Requirement>,
+    pub availability_requirement: Option<Requirement>,
+    pub modified_attack_vector: Option<ModifiedAttackVector>,
+    pub modified_attack_complexity: Option<ModifiedAttackComplexity>,
+    pub modified_attack_requirements: Option<ModifiedAttackRequirements>,
+    pub modified_privileges_required: Option<ModifiedPrivilegesRequired>,
+    pub modified_user_interaction: Option<ModifiedUserInteraction>,
+    pub modified_vuln_confidentiality_impact: Option<ModifiedImpact>,
+    pub modified_vuln_integrity_impact: Option<ModifiedImpact>,
+    pub modified_vuln_availability_impact: Option<ModifiedImpact>,
+    pub modified_sub_confidentiality_impact: Option<ModifiedImpact>,
+    pub modified_sub_integrity_impact: Option<ModifiedSubsequentImpact>,
+    pub modified_sub_availability_impact: Option<ModifiedSubsequentImpact>,
+    pub environmental_score: Option<f64>,
+    pub environmental_severity: Option<CvssSeverity>,
+    #[serde(rename = "Safety")]
+    pub safety: Option<Safety>,
+    #[serde(rename = "Automatable")]
+    pub automatable: Option<Automatable>,
+    #[serde(rename = "Recovery")]
+    pub recovery: Option<Recovery>,
+    pub value_density: Option<ValueDensity>,
+    pub vulnerability_response_effort: Option<VulnerabilityResponseEffort>,
+    pub provider_urgency: Option<ProviderUrgency>,
+}
+
+impl CvssV4 {
+    /// Reads a CVSS v4.0 JSON object, checking it against the constraints of the CVSS v4.0
+    /// JSON schema and checking that its vector string is valid.
+    pub fn from_json(json: &Map<String, Value>) -> Result<CvssV4, CvssError> {
+        let cvss: CvssV4 = serde_json::from_value(Value::Object(json.clone()))
+            .map_err(|e| CvssError::InvalidObject(e.to_string()))?;
+        check_score_ranges(&[
+            ("baseScore", Some(cvss.base_score)),
+            ("threatScore", cvss.threat_score),
+            ("environmentalScore", cvss.environmental_score),
+        ])?;
+        Ok(cvss)
+    }
+
+    /// Returns all scores and severities which differ from the values computed from the vector string.
+    pub fn computation_mismatches(&self) -> Vec<CvssMismatch> {
+        let scores = self.vector_string.scores();
+        let mut mismatches = Vec::new();
+        compare_score(&mut mismatches, "baseScore", Some(self.base_score), scores.base_score);
+        compare_severity(&mut mismatches, "baseSeverity", Some(self.base_severity), scores.base_severity);
+        compare_score(&mut mismatches, "threatScore", self.threat_score, scores.threat_score);
+        compare_severity(&mut mismatches, "threatSeverity", self.threat_severity, scores.threat_severity);
+        compare_score(&mut mismatches, "environmentalScore", self.environmental_score, scores.environmental_score);
+        compare_severity(
+            &mut mismatches,
+            "environmentalSeverity",
+            self.environmental_severity,
+            scores.environmental_severity,
+        );
+        mismatches
+    }
+
+    /// Returns all metric properties which contradict the vector string.
+    pub fn property_mismatches(&self) -> Vec<CvssMismatch> {
+        let v = &self.vector_string;
+        let mut m = Vec::new();
+        compare_metric(&mut m, "attackVector", self.attack_vector, v.attack_vector);
+        compare_metric(&mut m, "attackComplexity", self.attack_complexity, v.attack_complexity);
+        compare_metric(&mut m, "attackRequirements", self.attack_requirements, v.attack_requirements);
+        compare_metric(&mut m, "privilegesRequired", self.privileges_required, v.privileges_required);
+        compare_metric(&mut m, "userInteraction", self.user_interaction, v.user_interaction);
+        compare_metric(&mut m, "vulnConfidentialityImpact", self.vuln_confidentiality_impact, v.vuln_confidentiality_impact);
+        compare_metric(&mut m, "vulnIntegrityImpact", self.vuln_integrity_impact, v.vuln_integrity_impact);
+        compare_metric(&mut m, "vulnAvailabilityImpact", self.vuln_availability_impact, v.vuln_availability_impact);
+        compare_metric(&mut m, "subConfidentialityImpact", self.sub_confidentiality_impact, v.sub_confidentiality_impact);
+        compare_metric(&mut m, "subIntegrityImpact", self.sub_integrity_impact, v.sub_integrity_impact);
+        compare_metric(&mut m, "subAvailabilityImpact", self.sub_availability_impact, v.sub_availability_impact);
+        compare_metric(&mut m, "exploitMaturity", self.exploit_maturity, v.exploit_maturity);
+        compare_metric(&mut m, "confidentialityRequirement", self.confidentiality_requirement, v.confidentiality_requirement);
+        compare_metric(&mut m, "integrityRequirement", self.integrity_requirement, v.integrity_requirement);
+        compare_metric(&mut m, "availabilityRequirement", self.availability_requirement, v.availability_requirement);
+        compare_metric(&mut m, "modifiedAttackVector", self.modified_attack_vector, v.modified_attack_vector);
+        compare_metric(&mut m, "modifiedAttackComplexity", self.modified_attack_complexity, v.modified_attack_complexity);
+        compare_metric(&mut m, "modifiedAttackRequirements", self.modified_attack_requirements, v.modified_attack_requirements);
+        compare_metric(&mut m, "modifiedPrivilegesRequired", self.modified_privileges_required, v.modified_privileges_required);
+        compare_metric(&mut m, "modifiedUserInteraction", self.modified_user_interaction, v.modified_user_interaction);
+        compare_metric(&mut m, "modifiedVulnConfidentialityImpact", self.modified_vuln_confidentiality_impact, v.modified_vuln_confidentiality_impact);
+        compare_metric(&mut m, "modifiedVulnIntegrityImpact", self.modified_vuln_integrity_impact, v.modified_vuln_integrity_impact);
+        compare_metric(&mut m, "modifiedVulnAvailabilityImpact", self.modified_vuln_availability_impact, v.modified_vuln_availability_impact);
+        compare_metric(&mut m, "modifiedSubConfidentialityImpact", self.modified_sub_confidentiality_impact, v.modified_sub_confidentiality_impact);
+        compare_metric(&mut m, "modifiedSubIntegrityImpact", self.modified_sub_integrity_impact, v.modified_sub_integrity_impact);
+        compare_metric(&mut m, "modifiedSubAvailabilityImpact", self.modified_sub_availability_impact, v.modified_sub_availability_impact);
+        compare_metric(&mut m, "Safety", self.safety, v.safety);
+        compare_metric(&mut m, "Automatable", self.automatable, v.automatable);
+        compare_metric(&mut m, "Recovery", self.recovery, v.recovery);
+        compare_metric(&mut m, "valueDensity", self.value_density, v.value_density);
+        compare_metric(&mut m, "vulnerabilityResponseEffort", self.vulnerability_response_effort, v.vulnerability_response_effort);
+        compare_metric(&mut m, "providerUrgency", self.provider_urgency, v.provider_urgency);
+        m
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::cvss::v4::{CvssV4, CvssV4Vector, ModifiedSubsequentImpact, ProviderUrgency, MACRO_VECTOR_SCORES};
+    use crate::csaf::cvss::{CvssError, CvssSeverity};
+    use serde_json::json;
+
+    fn score(vector: &str) -> f64 {
+        vector.parse::<CvssV4Vector>().unwrap().score()
+    }
+
+    #[test]
+    fn test_macro_vector_scores() {
+        // 3 (EQ1) * 2 (EQ2) * 5 (EQ3 and EQ6) * 3 (EQ4) * 3 (EQ5)
+        assert_eq!(270, MACRO_VECTOR_SCORES.len());
+    }
+
+    #[test]
+    fn test_parse_vector() {
+        let vector: CvssV4Vector = "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N/MSI:S/U:Amber"
+            .parse()
+            .unwrap();
+        assert_eq!(ModifiedSubsequentImpact::Safety, vector.modified_sub_integrity_impact);
+        assert_eq!(ProviderUrgency::Amber, vector.provider_urgency);
+
+        for invalid in [
+            "CVSS:3.1/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N",
+            "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N",
+            "CVSS:4.0/AC:L/AV:N/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N",
+            "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:S/SA:N",
+            "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N/U:AMBER",
+        ] {
+            assert!(matches!(invalid.parse::<CvssV4Vector>(), Err(CvssError::InvalidVector(_))), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_scores() {
+        assert_eq!(10.0, score("CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:H/SI:H/SA:H"));
+        assert_eq!(9.3, score("CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N"));
+        assert_eq!(8.5, score("CVSS:4.0/AV:L/AC:L/AT:N/PR:L/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N"));
+        assert_eq!(0.0, score("CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:N/VI:N/VA:N/SC:N/SI:N/SA:N"));
+        // Modified metrics and supplemental metrics
+        assert_eq!(
+            10.0,
+            score("CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N/MSI:S/MSA:S")
+        );
+        assert_eq!(
+            9.3,
+            score("CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N/S:P/AU:Y/R:I/V:C/RE:H/U:Red")
+        );
+
+        let vector: CvssV4Vector = "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N/E:U/MAV:L"
+            .parse()
+            .unwrap();
+        let scores = vector.scores();
+        assert_eq!(9.3, scores.base_score);
+        assert!(scores.threat_score < scores.base_score);
+        assert!(scores.environmental_score < scores.threat_score);
+        assert_eq!(CvssSeverity::Critical, scores.base_severity);
+    }
+
+    #[test]
+    fn test_cvss_object() {
+        let json = json!({
+            "version": "4.0",
+            "vectorString": "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N/AU:Y",
+            "attackVector": "LOCAL",
+            "Automatable": "YES",
+            "baseScore": 9.3,
+            "baseSeverity": "HIGH"
+        });
+        let cvss = CvssV4::from_json(json.as_object().unwrap()).unwrap();
+        let computation = cvss.computation_mismatches();
+        assert_eq!(1, computation.len());
+        assert_eq!(("baseSeverity", "CRITICAL"), (computation[0].property, computation[0].expected.as_str()));
+        let properties = cvss.property_mismatches();
+        assert_eq!(1, properties.len());
+        assert_eq!("attackVector", properties[0].property);
+
+        let mut invalid = json;
+        invalid["version"] = json!("3.1");
+        assert!(matches!(CvssV4::from_json(invalid.as_object().unwrap()), Err(CvssError::InvalidObject(_))));
+    }
+}
@@ -0,0 +1,5 @@
+// This file is generated by build.rs from the CWE CSV files in src/csaf/cwe/data.
+// Do not edit it manually.
+use super::CweCatalog;
+
+pub(super) static CATALOGS: &[CweCatalog] = &[];
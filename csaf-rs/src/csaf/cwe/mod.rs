@@ -0,0 +1,122 @@
+//! Offline catalog of the MITRE Common Weakness Enumeration (CWE).
+//!
+//! The catalog is generated by `build.rs` from the CSV exports of the MITRE CWE list, which
+//! are vendored in `src/csaf/cwe/data` as `cwec_v<version>.csv` (one file per CWE version).
+//! See `src/csaf/cwe/data/README.md` for how to add a version.
+
+mod catalog;
+
+use std::fmt::{Display, Formatter};
+
+/// A weakness of the CWE catalog.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Weakness {
+    /// The numeric ID, e.g. `79` for `CWE-79`
+    pub id: u32,
+    /// The name of the weakness
+    pub name: &'static str,
+}
+
+impl Display for Weakness {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CWE-{}: {}", self.id, self.name)
+    }
+}
+
+/// All weaknesses of a single version of the CWE catalog.
+#[derive(Debug)]
+pub struct CweCatalog {
+    version: &'static str,
+    /// Sorted by ID, so that they can be looked up by binary search
+    weaknesses: &'static [Weakness],
+}
+
+impl CweCatalog {
+    /// Creates a catalog from weaknesses, which need to be sorted by their ID.
+    pub const fn new(version: &'static str, weaknesses: &'static [Weakness]) -> Self {
+        CweCatalog { version, weaknesses }
+    }
+
+    /// Returns all embedded catalogs, ordered from the oldest to the newest version.
+    pub fn all() -> &'static [CweCatalog] {
+        catalog::CATALOGS
+    }
+
+    /// Returns the embedded catalog of the given CWE version (e.g. `4.16`), if available.
+    pub fn by_version(version: &str) -> Option<&'static CweCatalog> {
+        find_version(Self::all(), version)
+    }
+
+    /// Returns the embedded catalog of the newest CWE version, if any catalog is available.
+    pub fn latest() -> Option<&'static CweCatalog> {
+        Self::all().last()
+    }
+
+    /// Returns the CWE version of this catalog.
+    pub fn version(&self) -> &'static str {
+        self.version
+    }
+
+    /// Returns all weaknesses of this catalog, sorted by their ID.
+    pub fn weaknesses(&self) -> &'static [Weakness] {
+        self.weaknesses
+    }
+
+    /// Looks up a weakness by its CWE ID in the form `CWE-<number>`.
+    pub fn get(&self, cwe_id: &str) -> Option<&'static Weakness> {
+        self.get_by_number(parse_cwe_id(cwe_id)?)
+    }
+
+    /// Looks up a weakness by its numeric ID.
+    pub fn get_by_number(&self, id: u32) -> Option<&'static Weakness> {
+        self.weaknesses
+            .binary_search_by_key(&id, |w| w.id)
+            .ok()
+            .map(|i| &self.weaknesses[i])
+    }
+}
+
+/// Finds the catalog with the given version in a list of catalogs.
+pub(crate) fn find_version<'a>(catalogs: &'a [CweCatalog], version: &str) -> Option<&'a CweCatalog> {
+    catalogs.iter().find(|c| c.version == version)
+}
+
+/// Parses a CWE ID in the form `CWE-<number>` into its numeric part.
+pub fn parse_cwe_id(cwe_id: &str) -> Option<u32> {
+    let number = cwe_id.strip_prefix("CWE-")?;
+    if number.starts_with('0') || !number.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    number.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::cwe::{find_version, parse_cwe_id, CweCatalog, Weakness};
+
+    static WEAKNESSES: [Weakness; 2] = [
+        Weakness { id: 79, name: "Improper Neutralization of Input During Web Page Generation ('Cross-site Scripting')" },
+        Weakness { id: 787, name: "Out-of-bounds Write" },
+    ];
+
+    #[test]
+    fn test_lookup() {
+        let catalogs = [CweCatalog::new("4.15", &WEAKNESSES)];
+        let catalog = find_version(&catalogs, "4.15").unwrap();
+        assert_eq!("4.15", catalog.version());
+        assert_eq!(Some(&WEAKNESSES[1]), catalog.get("CWE-787"));
+        assert_eq!("CWE-787: Out-of-bounds Write", catalog.get("CWE-787").unwrap().to_string());
+        assert_eq!(None, catalog.get("CWE-80"));
+        assert_eq!(None, catalog.get("787"));
+        assert!(find_version(&catalogs, "4.16").is_none());
+    }
+
+    #[test]
+    fn test_parse_cwe_id() {
+        assert_eq!(Some(79), parse_cwe_id("CWE-79"));
+        assert_eq!(None, parse_cwe_id("CWE-079"));
+        assert_eq!(None, parse_cwe_id("CWE-7a"));
+        assert_eq!(None, parse_cwe_id("CWE-"));
+        assert_eq!(None, parse_cwe_id("cwe-79"));
+    }
+}
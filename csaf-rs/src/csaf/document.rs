@@ -0,0 +1,238 @@
+use crate::csaf::csaf2_0::schema::CommonSecurityAdvisoryFramework as Csaf20;
+use crate::csaf::csaf2_1::schema::CommonSecurityAdvisoryFramework as Csaf21;
+use crate::csaf::getter_traits::CsafTrait;
+use crate::csaf::validation::{validate_by_preset, validate_by_test, TestResult, ValidationPreset, ValidationReport};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Version of the CSAF standard.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord, serde::Serialize)]
+pub enum CsafVersion {
+    #[serde(rename = "2.0")]
+    V2_0,
+    #[serde(rename = "2.1")]
+    V2_1,
+}
+
+impl FromStr for CsafVersion {
+    type Err = ();
+
+    fn from_str(input: &str) -> Result<CsafVersion, Self::Err> {
+        match input {
+            "2.0" => Ok(CsafVersion::V2_0),
+            "2.1" => Ok(CsafVersion::V2_1),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Display for CsafVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::V2_0 => write!(f, "2.0"),
+            Self::V2_1 => write!(f, "2.1"),
+        }
+    }
+}
+
+impl CsafVersion {
+    /// Detects the CSAF version of a raw JSON document from `document.csaf_version` and the
+    /// version referenced by `$schema`. Either is sufficient, but if both declare a supported
+    /// version, they must match.
+    pub fn detect(json: &Value) -> Result<CsafVersion, VersionDetectionError> {
+        let csaf_version = json
+            .pointer("/document/csaf_version")
+            .and_then(Value::as_str)
+            .and_then(|v| CsafVersion::from_str(v).ok());
+        let schema = match json.get("$schema").and_then(Value::as_str) {
+            Some("https://docs.oasis-open.org/csaf/csaf/v2.0/csaf_json_schema.json") => Some(CsafVersion::V2_0),
+            Some("https://docs.oasis-open.org/csaf/csaf/v2.1/schema/csaf.json") => Some(CsafVersion::V2_1),
+            _ => None,
+        };
+
+        match (csaf_version, schema) {
+            (Some(csaf_version), Some(schema)) if csaf_version != schema => {
+                Err(VersionDetectionError::Conflict { csaf_version, schema })
+            }
+            (Some(version), _) | (None, Some(version)) => Ok(version),
+            (None, None) => Err(VersionDetectionError::Unknown),
+        }
+    }
+}
+
+/// Error detecting the CSAF version of a raw JSON document.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum VersionDetectionError {
+    /// Neither `document.csaf_version` nor `$schema` declare a supported version.
+    Unknown,
+    /// `document.csaf_version` and `$schema` declare different versions.
+    Conflict { csaf_version: CsafVersion, schema: CsafVersion },
+}
+
+impl Display for VersionDetectionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unknown => write!(f, "Could not detect the CSAF version from document/csaf_version or $schema"),
+            Self::Conflict { csaf_version, schema } => write!(
+                f,
+                "document/csaf_version declares CSAF {}, but $schema references CSAF {}",
+                csaf_version, schema
+            ),
+        }
+    }
+}
+
+impl std::error::Error for VersionDetectionError {}
+
+/// Implemented by the typed representation of a CSAF document of a specific version.
+pub trait CsafVersioned {
+    /// The CSAF version of the document type.
+    const VERSION: CsafVersion;
+}
+
+/// A CSAF document of a specific version, together with the raw JSON it was parsed from.
+///
+/// The raw JSON is kept for checks which cannot be performed on the typed representation,
+/// such as the JSON schema validation (the generated structs drop some of the schema's
+/// constraints and empty optional arrays are not distinguishable from missing ones).
+#[derive(Debug, Clone)]
+pub struct CsafDocument<T> {
+    json: Value,
+    document: T,
+}
+
+impl<T: DeserializeOwned> CsafDocument<T> {
+    /// Deserializes the typed document from the given JSON value and keeps the value.
+    pub fn from_json(json: Value) -> Result<Self, serde_json::Error> {
+        let document = T::deserialize(&json)?;
+        Ok(CsafDocument { json, document })
+    }
+}
+
+impl<T> CsafDocument<T> {
+    /// Returns the raw JSON value of the document.
+    pub fn json(&self) -> &Value {
+        &self.json
+    }
+
+    /// Returns the typed document.
+    pub fn document(&self) -> &T {
+        &self.document
+    }
+}
+
+impl<T: CsafTrait> CsafTrait for CsafDocument<T> {
+    type VulnerabilityType = T::VulnerabilityType;
+    type ProductTreeType = T::ProductTreeType;
+    type DocumentType = T::DocumentType;
+
+    fn get_product_tree(&self) -> &Option<Self::ProductTreeType> {
+        self.document.get_product_tree()
+    }
+
+    fn get_vulnerabilities(&self) -> &Vec<Self::VulnerabilityType> {
+        self.document.get_vulnerabilities()
+    }
+
+    fn get_document(&self) -> &Self::DocumentType {
+        self.document.get_document()
+    }
+}
+
+/// A CSAF document of any supported version, tagged with its version.
+///
+/// Validation is dispatched to the tests and presets of the respective version.
+#[derive(Debug, Clone)]
+pub enum VersionedCsafDocument {
+    Csaf2_0(CsafDocument<Csaf20>),
+    Csaf2_1(CsafDocument<Csaf21>),
+}
+
+impl VersionedCsafDocument {
+    /// Deserializes the given JSON value as a document of the given CSAF version.
+    pub fn from_json(json: Value, version: CsafVersion) -> Result<Self, serde_json::Error> {
+        Ok(match version {
+            CsafVersion::V2_0 => VersionedCsafDocument::Csaf2_0(CsafDocument::from_json(json)?),
+            CsafVersion::V2_1 => VersionedCsafDocument::Csaf2_1(CsafDocument::from_json(json)?),
+        })
+    }
+
+    /// Returns the CSAF version of the document.
+    pub fn version(&self) -> CsafVersion {
+        match self {
+            VersionedCsafDocument::Csaf2_0(_) => CsafVersion::V2_0,
+            VersionedCsafDocument::Csaf2_1(_) => CsafVersion::V2_1,
+        }
+    }
+
+    /// Returns the raw JSON value of the document.
+    pub fn json(&self) -> &Value {
+        match self {
+            VersionedCsafDocument::Csaf2_0(doc) => doc.json(),
+            VersionedCsafDocument::Csaf2_1(doc) => doc.json(),
+        }
+    }
+
+    /// Executes all tests of the given preset for the version of the document.
+    pub fn validate_by_preset(&self, preset: ValidationPreset) -> ValidationReport {
+        match self {
+            VersionedCsafDocument::Csaf2_0(doc) => validate_by_preset(doc, preset),
+            VersionedCsafDocument::Csaf2_1(doc) => validate_by_preset(doc, preset),
+        }
+    }
+
+    /// Executes the test with the given ID for the version of the document.
+    pub fn validate_by_test(&self, test_id: &str) -> TestResult {
+        match self {
+            VersionedCsafDocument::Csaf2_0(doc) => validate_by_test(doc, test_id),
+            VersionedCsafDocument::Csaf2_1(doc) => validate_by_test(doc, test_id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::document::{CsafVersion, VersionDetectionError, VersionedCsafDocument};
+    use crate::csaf::test_helper::minimal_document;
+    use crate::csaf::validation::TestResultStatus;
+    use serde_json::json;
+
+    #[test]
+    fn test_detect_version() {
+        assert_eq!(Ok(CsafVersion::V2_0), CsafVersion::detect(&minimal_document("2.0")));
+        assert_eq!(Ok(CsafVersion::V2_1), CsafVersion::detect(&minimal_document("2.1")));
+
+        let mut doc = minimal_document("2.1");
+        doc["document"]["csaf_version"] = json!("3.0");
+        assert_eq!(Ok(CsafVersion::V2_1), CsafVersion::detect(&doc));
+        doc["$schema"] = json!("https://example.com/schema.json");
+        assert_eq!(Err(VersionDetectionError::Unknown), CsafVersion::detect(&doc));
+        assert_eq!(Err(VersionDetectionError::Unknown), CsafVersion::detect(&json!([])));
+
+        let mut doc = minimal_document("2.1");
+        doc["document"]["csaf_version"] = json!("2.0");
+        assert_eq!(
+            Err(VersionDetectionError::Conflict { csaf_version: CsafVersion::V2_0, schema: CsafVersion::V2_1 }),
+            CsafVersion::detect(&doc)
+        );
+    }
+
+    #[test]
+    fn test_versioned_document() {
+        let doc = VersionedCsafDocument::from_json(minimal_document("2.1"), CsafVersion::V2_1).unwrap();
+        assert_eq!(CsafVersion::V2_1, doc.version());
+        assert!(doc.validate_by_test("6.1.34").errors().is_empty());
+        assert!(VersionedCsafDocument::from_json(minimal_document("2.0"), CsafVersion::V2_1).is_err());
+    }
+
+    #[test]
+    fn test_validate_by_test_status() {
+        let doc = VersionedCsafDocument::from_json(minimal_document("2.0"), CsafVersion::V2_0).unwrap();
+        assert_eq!(TestResultStatus::Success, doc.validate_by_test("6.1.1").status);
+        // Test 6.1.34 was introduced with CSAF 2.1
+        assert_eq!(TestResultStatus::NotApplicable, doc.validate_by_test("6.1.34").status);
+        assert_eq!(TestResultStatus::NotImplemented, doc.validate_by_test("6.1.99").status);
+    }
+}
@@ -0,0 +1,365 @@
+//! Semantics of document versions (`/$defs/version_t`) and of the revision history.
+//!
+//! CSAF documents use either integer versioning (`1`, `2`, ...) or semantic versioning
+//! (`1.0.0-rc.1+build.5`) for `/document/tracking/version` and the numbers of the
+//! revision history. [DocumentVersion] parses and compares both, [RevisionHistory] relates
+//! the revision history to the document version.
+
+use crate::csaf::getter_traits::{RevisionTrait, TrackingTrait};
+use chrono::{DateTime, FixedOffset};
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Error for a string which is neither an integer nor a semantic version.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct DocumentVersionError(pub String);
+
+impl Display for DocumentVersionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "'{}' is neither an integer nor a semantic version", self.0)
+    }
+}
+
+impl std::error::Error for DocumentVersionError {}
+
+/// The versioning scheme of a document version.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum VersioningScheme {
+    Integer,
+    Semantic,
+}
+
+impl Display for VersioningScheme {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Integer => write!(f, "integer"),
+            Self::Semantic => write!(f, "semantic"),
+        }
+    }
+}
+
+/// An identifier of the pre-release part of a semantic version.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum PreReleaseIdentifier {
+    Numeric(u64),
+    AlphaNumeric(String),
+}
+
+impl Ord for PreReleaseIdentifier {
+    /// Numeric identifiers have lower precedence than alphanumeric ones (SemVer 2.0.0, item 11).
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Numeric(a), Self::Numeric(b)) => a.cmp(b),
+            (Self::Numeric(_), Self::AlphaNumeric(_)) => Ordering::Less,
+            (Self::AlphaNumeric(_), Self::Numeric(_)) => Ordering::Greater,
+            (Self::AlphaNumeric(a), Self::AlphaNumeric(b)) => a.cmp(b),
+        }
+    }
+}
+
+impl PartialOrd for PreReleaseIdentifier {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for PreReleaseIdentifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Numeric(n) => write!(f, "{}", n),
+            Self::AlphaNumeric(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+/// A semantic version according to SemVer 2.0.0.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SemanticVersion {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+    pub pre_release: Vec<PreReleaseIdentifier>,
+    pub build: Vec<String>,
+}
+
+impl SemanticVersion {
+    /// Compares the precedence of two versions, which ignores the build metadata.
+    pub fn precedence(&self, other: &Self) -> Ordering {
+        (self.major, self.minor, self.patch)
+            .cmp(&(other.major, other.minor, other.patch))
+            .then_with(|| match (self.pre_release.is_empty(), other.pre_release.is_empty()) {
+                // A pre-release version has a lower precedence than the associated normal version
+                (true, true) => Ordering::Equal,
+                (true, false) => Ordering::Greater,
+                (false, true) => Ordering::Less,
+                (false, false) => self.pre_release.cmp(&other.pre_release),
+            })
+    }
+}
+
+/// Parses a numeric identifier, which must not have leading zeros.
+fn parse_numeric(value: &str) -> Option<u64> {
+    if value.is_empty() || !value.bytes().all(|b| b.is_ascii_digit()) || (value.len() > 1 && value.starts_with('0')) {
+        return None;
+    }
+    value.parse().ok()
+}
+
+fn is_identifier(value: &str) -> bool {
+    !value.is_empty() && value.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-')
+}
+
+impl FromStr for SemanticVersion {
+    type Err = DocumentVersionError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let error = || DocumentVersionError(value.to_string());
+        let (rest, build) = match value.split_once('+') {
+            Some((rest, build)) => (rest, build.split('.').map(|s| s.to_string()).collect()),
+            None => (value, Vec::new()),
+        };
+        if !build.iter().all(|b: &String| is_identifier(b)) {
+            return Err(error());
+        }
+        let (core, pre_release) = match rest.split_once('-') {
+            Some((core, pre_release)) => (core, Some(pre_release)),
+            None => (rest, None),
+        };
+        let pre_release = match pre_release {
+            None => Vec::new(),
+            Some(pre_release) => pre_release
+                .split('.')
+                .map(|identifier| {
+                    if identifier.bytes().all(|b| b.is_ascii_digit()) {
+                        parse_numeric(identifier).map(PreReleaseIdentifier::Numeric)
+                    } else if is_identifier(identifier) {
+                        Some(PreReleaseIdentifier::AlphaNumeric(identifier.to_string()))
+                    } else {
+                        None
+                    }
+                })
+                .collect::<Option<Vec<_>>>()
+                .ok_or_else(error)?,
+        };
+        let core = core.split('.').map(parse_numeric).collect::<Option<Vec<u64>>>().ok_or_else(error)?;
+        match core[..] {
+            [major, minor, patch] => Ok(SemanticVersion { major, minor, patch, pre_release, build }),
+            _ => Err(error()),
+        }
+    }
+}
+
+impl Display for SemanticVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)?;
+        if !self.pre_release.is_empty() {
+            let identifiers: Vec<String> = self.pre_release.iter().map(|i| i.to_string()).collect();
+            write!(f, "-{}", identifiers.join("."))?;
+        }
+        if !self.build.is_empty() {
+            write!(f, "+{}", self.build.join("."))?;
+        }
+        Ok(())
+    }
+}
+
+/// A document version, following either integer or semantic versioning.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum DocumentVersion {
+    Integer(u64),
+    Semantic(SemanticVersion),
+}
+
+impl FromStr for DocumentVersion {
+    type Err = DocumentVersionError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if value.contains('.') {
+            value.parse().map(DocumentVersion::Semantic)
+        } else {
+            parse_numeric(value)
+                .map(DocumentVersion::Integer)
+                .ok_or_else(|| DocumentVersionError(value.to_string()))
+        }
+    }
+}
+
+impl Display for DocumentVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Integer(n) => write!(f, "{}", n),
+            Self::Semantic(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+impl DocumentVersion {
+    /// Returns the versioning scheme of this version.
+    pub fn scheme(&self) -> VersioningScheme {
+        match self {
+            Self::Integer(_) => VersioningScheme::Integer,
+            Self::Semantic(_) => VersioningScheme::Semantic,
+        }
+    }
+
+    /// Returns the integer version or the major version of a semantic version.
+    pub fn major(&self) -> u64 {
+        match self {
+            Self::Integer(n) => *n,
+            Self::Semantic(v) => v.major,
+        }
+    }
+
+    /// Checks whether this version is `0` or `0.y.z`, which denotes a document in development.
+    pub fn is_initial_development(&self) -> bool {
+        self.major() == 0
+    }
+
+    /// Checks whether this is a semantic version with a pre-release part.
+    pub fn is_pre_release(&self) -> bool {
+        matches!(self, Self::Semantic(v) if !v.pre_release.is_empty())
+    }
+
+    /// Returns this version without its pre-release part.
+    pub fn without_pre_release(&self) -> DocumentVersion {
+        match self {
+            Self::Integer(n) => Self::Integer(*n),
+            Self::Semantic(v) => Self::Semantic(SemanticVersion { pre_release: Vec::new(), ..v.clone() }),
+        }
+    }
+
+    /// Compares the precedence of two versions, ignoring build metadata. Versions of
+    /// different schemes cannot be compared.
+    pub fn precedence(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Self::Integer(a), Self::Integer(b)) => Some(a.cmp(b)),
+            (Self::Semantic(a), Self::Semantic(b)) => Some(a.precedence(b)),
+            _ => None,
+        }
+    }
+}
+
+/// An item of the revision history with its parsed number and date.
+#[derive(Debug, Clone)]
+pub struct RevisionItem<'a> {
+    /// The index of the item in the revision history, for JSON paths
+    pub index: usize,
+    pub number: &'a String,
+    /// The parsed number, if it is a valid version
+    pub version: Option<DocumentVersion>,
+    /// The parsed date, if it is a valid timestamp
+    pub date: Option<DateTime<FixedOffset>>,
+}
+
+impl RevisionItem<'_> {
+    /// Returns the JSON path of the number of this item.
+    pub fn number_json_path(&self) -> String {
+        format!("/document/tracking/revision_history/{}/number", self.index)
+    }
+}
+
+/// The document version together with the revision history of a document.
+#[derive(Debug, Clone)]
+pub struct RevisionHistory<'a> {
+    pub version_number: &'a String,
+    /// The parsed document version, if it is a valid version
+    pub version: Option<DocumentVersion>,
+    /// The items in the order of the document
+    pub items: Vec<RevisionItem<'a>>,
+}
+
+/// JSON path of the document version.
+pub const VERSION_JSON_PATH: &str = "/document/tracking/version";
+
+impl<'a> RevisionHistory<'a> {
+    /// Parses the document version and the revision history of the given tracking information.
+    pub fn from_tracking(tracking: &'a impl TrackingTrait) -> Self {
+        RevisionHistory {
+            version_number: tracking.get_version(),
+            version: tracking.get_version().parse().ok(),
+            items: tracking
+                .get_revision_history()
+                .iter()
+                .enumerate()
+                .map(|(index, revision)| RevisionItem {
+                    index,
+                    number: revision.get_number(),
+                    version: revision.get_number().parse().ok(),
+                    date: DateTime::parse_from_rfc3339(revision.get_date()).ok(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns the items sorted ascending by date. The sort is stable, so items with the same
+    /// date keep their order. Items with an invalid date come first.
+    pub fn sorted_by_date(&self) -> Vec<&RevisionItem<'a>> {
+        let mut items: Vec<&RevisionItem> = self.items.iter().collect();
+        items.sort_by_key(|item| item.date);
+        items
+    }
+
+    /// Returns the latest item, i.e. the last one when sorted ascending by date.
+    pub fn latest(&self) -> Option<&RevisionItem<'a>> {
+        self.sorted_by_date().last().copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::document_version::{DocumentVersion, PreReleaseIdentifier, SemanticVersion, VersioningScheme};
+    use std::cmp::Ordering;
+
+    fn version(value: &str) -> DocumentVersion {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(DocumentVersion::Integer(12), version("12"));
+        assert_eq!(
+            DocumentVersion::Semantic(SemanticVersion {
+                major: 1,
+                minor: 0,
+                patch: 2,
+                pre_release: vec![
+                    PreReleaseIdentifier::AlphaNumeric("rc".to_string()),
+                    PreReleaseIdentifier::Numeric(1),
+                ],
+                build: vec!["build".to_string(), "05".to_string()],
+            }),
+            version("1.0.2-rc.1+build.05")
+        );
+        assert_eq!("1.0.2-rc.1+build.05", version("1.0.2-rc.1+build.05").to_string());
+        for invalid in ["", "01", "1.0", "1.0.0.0", "1.01.0", "1.0.0-", "1.0.0-01", "1.0.0+", "1.0.0-a..b", "a"] {
+            assert!(invalid.parse::<DocumentVersion>().is_err(), "{} should be invalid", invalid);
+        }
+    }
+
+    #[test]
+    fn test_semantics() {
+        assert_eq!(VersioningScheme::Integer, version("0").scheme());
+        assert!(version("0").is_initial_development());
+        assert!(version("0.9.0").is_initial_development());
+        assert!(!version("1.0.0-alpha").is_initial_development());
+        assert!(version("1.0.0-alpha").is_pre_release());
+        assert!(!version("1.0.0+build").is_pre_release());
+        assert_eq!(version("1.0.0"), version("1.0.0-alpha").without_pre_release());
+    }
+
+    #[test]
+    fn test_precedence() {
+        // Example of SemVer 2.0.0, item 11
+        let ordered = [
+            "1.0.0-alpha", "1.0.0-alpha.1", "1.0.0-alpha.beta", "1.0.0-beta", "1.0.0-beta.2", "1.0.0-beta.11",
+            "1.0.0-rc.1", "1.0.0", "2.0.0", "2.1.0", "2.1.1",
+        ];
+        for pair in ordered.windows(2) {
+            assert_eq!(Some(Ordering::Less), version(pair[0]).precedence(&version(pair[1])), "{:?}", pair);
+        }
+        assert_eq!(Some(Ordering::Equal), version("1.0.0+a").precedence(&version("1.0.0+b")));
+        assert_eq!(Some(Ordering::Greater), version("10").precedence(&version("9")));
+        assert_eq!(None, version("1").precedence(&version("1.0.0")));
+    }
+}
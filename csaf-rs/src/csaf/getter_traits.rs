@@ -1,8 +1,13 @@
 use std::collections::{BTreeSet, HashSet};
-use crate::csaf::csaf2_1::schema::{CategoryOfTheRemediation, DocumentStatus, Epss, LabelOfTlp};
+use crate::csaf::csaf2_1::schema::{CategoryOfPublisher, CategoryOfReference, CategoryOfTheBranch, CategoryOfTheRemediation, CategoryOfTheThreat, DocumentStatus, Epss, LabelOfTheFlag, LabelOfTlp, NoteCategory, PartyCategory};
 use crate::csaf::csaf2_1::ssvc_schema::SsvcV1;
+use crate::csaf::cvss::CvssError;
+use crate::csaf::cvss::v2::CvssV2;
+use crate::csaf::cvss::v3::CvssV3;
+use crate::csaf::cvss::v4::CvssV4;
 use crate::csaf::helpers::resolve_product_groups;
 use crate::csaf::validation::ValidationError;
+use crate::csaf::vers::{Vers, VersError, VERS_PREFIX};
 
 /// Trait representing an abstract Common Security Advisory Framework (CSAF) document.
 ///
@@ -40,9 +45,21 @@ pub trait DocumentTrait {
     /// Type representing document notes
     type NoteType: NoteTrait;
 
+    /// Type representing document references
+    type ReferenceType: ReferenceTrait;
+
+    /// Type representing the publisher of the document
+    type PublisherType: PublisherTrait;
+
+    /// Returns the category of this document, e.g. `csaf_vex`
+    fn get_category(&self) -> &String;
+
     /// Returns the tracking information for this document
     fn get_tracking(&self) -> &Self::TrackingType;
 
+    /// Returns the publisher of this document
+    fn get_publisher(&self) -> &Self::PublisherType;
+
     /// Returns the distribution information for this document with CSAF 2.1 semantics
     fn get_distribution_21(&self) -> Result<&Self::DistributionType, ValidationError>;
 
@@ -51,6 +68,27 @@ pub trait DocumentTrait {
 
     /// Returns the notes associtated with this document
     fn get_notes(&self) -> Option<&Vec<Self::NoteType>>;
+
+    /// Returns the references associated with this document
+    fn get_references(&self) -> Option<&Vec<Self::ReferenceType>>;
+
+    /// Returns the language tag of this document
+    fn get_lang(&self) -> Option<&String>;
+
+    /// Returns the language tag of the document this document was translated from
+    fn get_source_lang(&self) -> Option<&String>;
+
+    /// Returns the SPDX license expression of the document (CSAF 2.1 only)
+    fn get_license_expression(&self) -> Option<&String>;
+}
+
+/// Trait representing the publisher of a document
+pub trait PublisherTrait {
+    /// Returns the category of the publisher, normalized to CSAF 2.1
+    fn get_category(&self) -> CategoryOfPublisher;
+
+    /// Returns the name of the publisher
+    fn get_name(&self) -> &String;
 }
 
 /// Trait representing distribution information for a document
@@ -71,7 +109,22 @@ pub trait DistributionTrait {
     fn get_tlp_21(&self) -> Result<&Self::TlpType, ValidationError>;
 }
 
-pub trait NoteTrait: WithGroupIds {}
+pub trait NoteTrait: WithGroupIds {
+    /// Returns the category of the note
+    fn get_category(&self) -> NoteCategory;
+
+    /// Returns the title of the note, if any
+    fn get_title(&self) -> Option<&String>;
+}
+
+/// Trait representing a reference to a resource related to the document or a vulnerability
+pub trait ReferenceTrait {
+    /// Returns whether the reference points to the document itself or to an external resource
+    fn get_category(&self) -> CategoryOfReference;
+
+    /// Returns the summary of the reference
+    fn get_summary(&self) -> &String;
+}
 
 /// Trait representing sharing group information
 pub trait SharingGroupTrait {
@@ -112,6 +165,9 @@ pub trait TrackingTrait {
 
     /// Returns the tracking ID of this document
     fn get_id(&self) -> &String;
+
+    /// Returns the version of this document
+    fn get_version(&self) -> &String;
 }
 
 /// Trait for accessing document generator information
@@ -162,6 +218,12 @@ pub trait VulnerabilityTrait {
     /// The associated type representing vulnerability notes.
     type NoteType: NoteTrait;
 
+    /// The associated type representing a CWE of the vulnerability.
+    type CweType: CweTrait;
+
+    /// The associated type representing a first known exploitation date.
+    type FirstKnownExploitationDateType: FirstKnownExploitationDateTrait;
+
     /// Retrieves a list of remediations associated with the vulnerability.
     fn get_remediations(&self) -> &Vec<Self::RemediationType>;
 
@@ -180,6 +242,10 @@ pub trait VulnerabilityTrait {
     /// Returns the date when this vulnerability was initially discovered.
     fn get_discovery_date(&self) -> &Option<String>;
 
+    /// Returns the dates when this vulnerability was first known to be exploited, which are
+    /// always empty for CSAF 2.0.
+    fn get_first_known_exploitation_dates(&self) -> impl Iterator<Item = &Self::FirstKnownExploitationDateType> + '_;
+
     /// Returns all flags associated with this vulnerability.
     fn get_flags(&self) -> &Option<Vec<Self::FlagType>>;
 
@@ -194,6 +260,26 @@ pub trait VulnerabilityTrait {
 
     /// Returns the notes associated with this vulnerability.
     fn get_notes(&self) -> Option<&Vec<Self::NoteType>>;
+
+    /// Returns the CWEs associated with this vulnerability.
+    ///
+    /// CSAF 2.0 allows a single CWE only, which is normalized to a list here.
+    fn get_cwes(&self) -> Vec<&Self::CweType>;
+}
+
+/// Trait for accessing the CWE of a vulnerability
+pub trait CweTrait {
+    /// Returns the CWE ID, e.g. `CWE-79`
+    fn get_id(&self) -> &String;
+
+    /// Returns the name of the CWE
+    fn get_name(&self) -> &String;
+
+    /// Returns the version of the CWE catalog the CWE refers to (only available since CSAF 2.1)
+    fn get_version(&self) -> Option<&String>;
+
+    /// Returns the JSON path of the CWE, which differs between CSAF versions.
+    fn get_cwe_json_path(&self, vulnerability_idx: usize, cwe_idx: usize) -> String;
 }
 
 pub trait VulnerabilityIdTrait {
@@ -206,12 +292,36 @@ pub trait VulnerabilityIdTrait {
 pub trait FlagTrait: WithGroupIds {
     /// Returns the date associated with this vulnerability flag
     fn get_date(&self) -> &Option<String>;
+
+    /// Returns the label (VEX justification) of this vulnerability flag
+    fn get_label(&self) -> LabelOfTheFlag;
+
+    /// Returns the product IDs associated with this vulnerability flag
+    fn get_product_ids(&self) -> Option<impl Iterator<Item = &String> + '_>;
+}
+
+/// Trait for accessing the first known exploitation of a vulnerability
+pub trait FirstKnownExploitationDateTrait: WithGroupIds {
+    /// Returns the date when this information was last updated
+    fn get_date(&self) -> &String;
+
+    /// Returns the date when the exploitation happened
+    fn get_exploitation_date(&self) -> &String;
+
+    /// Returns the product IDs the exploitation applies to
+    fn get_product_ids(&self) -> Option<impl Iterator<Item = &String> + '_>;
 }
 
 /// Trait for accessing vulnerability involvement information
 pub trait InvolvementTrait {
     /// Returns the date associated with this vulnerability involvement
     fn get_date(&self) -> &Option<String>;
+
+    /// Returns the category of the party involved
+    fn get_party(&self) -> PartyCategory;
+
+    /// Returns the product IDs this involvement applies to (CSAF 2.1 only)
+    fn get_product_ids(&self) -> Option<impl Iterator<Item = &String> + '_>;
 }
 
 /// Trait representing an abstract remediation in a CSAF document.
@@ -351,11 +461,17 @@ pub trait MetricTrait {
     fn get_content(&self) -> &Self::ContentType;
     
     fn get_source(&self) -> &Option<String>;
+
+    /// Returns the JSON path of this metric (`scores` in CSAF 2.0, `metrics` in CSAF 2.1).
+    fn get_metric_json_path(&self, vulnerability_idx: usize, metric_idx: usize) -> String;
 }
 
 pub trait ContentTrait {
+    /// Returns whether this content contains SSVC. The SSVC tests (6.1.46 to 6.1.49) skip
+    /// metrics without SSVC, e.g. metrics containing only CVSS.
     fn has_ssvc_v1(&self) -> bool;
-    
+
+    /// Parses the SSVC of this content, which fails if there is none (see [ContentTrait::has_ssvc_v1]).
     fn get_ssvc_v1(&self) -> Result<SsvcV1, serde_json::Error>;
 
     fn get_cvss_v2(&self) -> Option<&serde_json::Map<String, serde_json::Value>>;
@@ -364,6 +480,21 @@ pub trait ContentTrait {
 
     fn get_cvss_v4(&self) -> Option<&serde_json::Map<String, serde_json::Value>>;
 
+    /// Returns the typed CVSS v2 object, or the reason why it is invalid.
+    fn get_cvss_v2_parsed(&self) -> Option<Result<CvssV2, CvssError>> {
+        self.get_cvss_v2().map(CvssV2::from_json)
+    }
+
+    /// Returns the typed CVSS v3.x object, or the reason why it is invalid.
+    fn get_cvss_v3_parsed(&self) -> Option<Result<CvssV3, CvssError>> {
+        self.get_cvss_v3().map(CvssV3::from_json)
+    }
+
+    /// Returns the typed CVSS v4.0 object, or the reason why it is invalid.
+    fn get_cvss_v4_parsed(&self) -> Option<Result<CvssV4, CvssError>> {
+        self.get_cvss_v4().map(CvssV4::from_json)
+    }
+
     fn get_epss(&self) -> &Option<Epss>;
 
     fn get_content_json_path(&self, vulnerability_idx: usize, metric_idx: usize) -> String;
@@ -376,6 +507,9 @@ pub trait ThreatTrait: WithGroupIds {
 
     /// Returns the date associated with this threat
     fn get_date(&self) -> &Option<String>;
+
+    /// Returns the category of this threat
+    fn get_category(&self) -> CategoryOfTheThreat;
 }
 
 /// Trait representing an abstract product tree in a CSAF document.
@@ -481,6 +615,23 @@ pub trait ProductTreeTrait {
         &self,
         callback: &mut impl FnMut(&Self::FullProductNameType, &str) -> Result<(), ValidationError>
     ) -> Result<(), ValidationError>;
+
+    /// Visits all branches of the product tree depth-first, together with their JSON path.
+    ///
+    /// # Returns
+    /// * `Ok(())` if all branches were visited successfully
+    /// * `Err(ValidationError)` if the callback returned an error for any branch
+    fn visit_all_branches(
+        &self,
+        callback: &mut impl FnMut(&Self::BranchType, &str) -> Result<(), ValidationError>
+    ) -> Result<(), ValidationError> {
+        if let Some(branches) = self.get_branches() {
+            for (i, branch) in branches.iter().enumerate() {
+                branch.visit_branches_rec(&format!("/product_tree/branches/{}", i), callback)?;
+            }
+        }
+        Ok(())
+    }
 }
 
 /// Trait representing an abstract branch in a product tree.
@@ -491,6 +642,35 @@ pub trait BranchTrait<FPN: ProductTrait> : Sized {
     /// Retrieves the full product name associated with this branch, if available.
     fn get_product(&self) -> &Option<FPN>;
 
+    /// Returns the category of this branch.
+    fn get_category(&self) -> CategoryOfTheBranch;
+
+    /// Returns the name of this branch.
+    fn get_name(&self) -> &String;
+
+    /// Parses the name of a `product_version_range` branch as version range specifier.
+    ///
+    /// # Returns
+    /// * `None` if this is no `product_version_range` branch or its name does not use the vers syntax
+    /// * `Some(Err(VersError))` if the name starts with `vers:`, but is not well-formed
+    fn get_vers(&self) -> Option<Result<Vers, VersError>> {
+        if self.get_category() != CategoryOfTheBranch::ProductVersionRange
+            || !self.get_name().starts_with(VERS_PREFIX)
+        {
+            return None;
+        }
+        Some(self.get_name().parse())
+    }
+
+    /// Checks whether the given version is inside the version range of this branch.
+    ///
+    /// # Returns
+    /// * `None` if this branch has no version range in vers syntax, see [BranchTrait::get_vers]
+    /// * `Some(Err(VersError))` if the range or the version is not valid for the versioning scheme
+    fn contains_version(&self, version: &str) -> Option<Result<bool, VersError>> {
+        self.get_vers().map(|vers| vers?.contains(version))
+    }
+
     /// Recursively visits all branches in the tree structure,
     /// applying the provided callback function to each branch.
     ///
@@ -520,28 +700,30 @@ pub trait BranchTrait<FPN: ProductTrait> : Sized {
     ///
     /// This method recursively checks if the branch structure exceeds the specified depth limit.
     /// It traverses the branch hierarchy depth-first, decrementing the remaining depth parameter
-    /// at each level. If branches are found beyond the allowed depth, it returns the path to the
-    /// first excessive branch.
+    /// at each level. Every branch on the last allowed level which still has sub-branches is
+    /// reported with the path to its first excessive branch.
     ///
     /// # Parameters
     /// * `remaining_depth` - The maximum number of branch levels still allowed
     ///
     /// # Returns
-    /// * `Some(String)` containing the path to the first branch that exceeds the allowed depth
-    /// * `None` if no branches exceed the allowed depth
-    fn find_excessive_branch_depth(&self, remaining_depth: u32) -> Option<String> {
+    /// * A vector containing the paths to all branches that exceed the allowed depth, in
+    ///   document order (empty if no branches exceed the allowed depth)
+    fn find_excessive_branch_depths(&self, remaining_depth: u32) -> Vec<String> {
+        let mut paths = Vec::<String>::new();
         if let Some(branches) = self.get_branches() {
             // If we've reached depth limit and there are branches, we've found a violation
             if remaining_depth == 1 {
-                return Some("/branches/0".to_string());
+                paths.push("/branches/0".to_string());
+                return paths;
             }
             for (i, branch) in branches.iter().enumerate() {
-                if let Some(sub_path) = branch.find_excessive_branch_depth(remaining_depth - 1) {
-                    return Some(format!("/branches/{}{}", i, sub_path));
+                for sub_path in branch.find_excessive_branch_depths(remaining_depth - 1) {
+                    paths.push(format!("/branches/{}{}", i, sub_path));
                 }
             }
         }
-        None
+        paths
     }
 }
 
@@ -583,12 +765,39 @@ pub trait ProductTrait {
 
 /// Trait representing an abstract product identification helper of a full product name.
 pub trait ProductIdentificationHelperTrait {
+    /// The associated type representing the cryptographic hashes of a file.
+    type HashType: HashTrait;
+
     /// Returns the PURLs identifying the associated product.
     fn get_purls(&self) -> Option<&[String]>;
 
     fn get_model_numbers(&self) -> Option<impl Iterator<Item = &String> + '_>;
 
     fn get_serial_numbers(&self) -> Option<impl Iterator<Item = &String> + '_>;
+
+    /// Returns the cryptographic hashes of the files identifying the associated product.
+    fn get_hashes(&self) -> &Vec<Self::HashType>;
+}
+
+/// Trait representing the cryptographic hashes of a single file.
+pub trait HashTrait {
+    /// The associated type representing a single hash value.
+    type FileHashType: FileHashTrait;
+
+    /// Returns the name of the hashed file.
+    fn get_filename(&self) -> &String;
+
+    /// Returns the hash values of the file.
+    fn get_file_hashes(&self) -> &Vec<Self::FileHashType>;
+}
+
+/// Trait representing a hash value together with the algorithm used to calculate it.
+pub trait FileHashTrait {
+    /// Returns the name of the hash algorithm, e.g. `sha256`.
+    fn get_algorithm(&self) -> &String;
+
+    /// Returns the hash value.
+    fn get_value(&self) -> &String;
 }
 
 pub trait WithGroupIds {
@@ -1,5 +1,7 @@
-use crate::csaf::getter_traits::{CsafTrait, ProductGroupTrait, ProductTreeTrait};
-use std::collections::{BTreeSet, HashMap, HashSet};
+use crate::csaf::getter_traits::{CsafTrait, DocumentTrait, ProductGroupTrait, ProductTreeTrait, RevisionTrait, TrackingTrait};
+use crate::csaf::timeline::{Timeline, Timestamp};
+use crate::csaf::validation::{ValidationError, ValidationSeverity};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fs;
 use std::ops::Deref;
 use std::sync::LazyLock;
@@ -23,6 +25,65 @@ where
     })
 }
 
+/// Returns the product IDs referenced directly or via product groups, e.g. by a remediation.
+pub fn resolve_products<'a>(
+    doc: &impl CsafTrait,
+    product_ids: Option<impl Iterator<Item = &'a String>>,
+    group_ids: Option<impl Iterator<Item = &'a String>>,
+) -> BTreeSet<String> {
+    let mut products: BTreeSet<String> = product_ids.into_iter().flatten().cloned().collect();
+    if let Some(group_ids) = group_ids {
+        products.extend(resolve_product_groups(doc, group_ids).unwrap_or_default());
+    }
+    products
+}
+
+/// Returns all language tags of the document (`lang` and `source_lang`) together with their JSON path.
+pub fn get_language_tags(doc: &impl CsafTrait) -> Vec<(&'static str, &String)> {
+    let document = doc.get_document();
+    [("/document/lang", document.get_lang()), ("/document/source_lang", document.get_source_lang())]
+        .into_iter()
+        .filter_map(|(path, lang)| lang.map(|lang| (path, lang)))
+        .collect()
+}
+
+/// Returns the date of the newest item in the revision history of a document with status
+/// `final` or `interim`, which timestamps in the document must not be later than. The
+/// `timeline` must be the one of `doc`.
+///
+/// Returns `Ok(None)` for documents with another status, and errors for revision dates which
+/// are no valid timestamps or for an empty revision history.
+pub fn get_newest_revision_date(doc: &impl CsafTrait, timeline: &Timeline) -> Result<Option<Timestamp>, Vec<ValidationError>> {
+    if !timeline.is_released() {
+        return Ok(None);
+    }
+
+    let revisions = doc.get_document().get_tracking().get_revision_history();
+    let errors: Vec<ValidationError> = timeline
+        .revision_history
+        .items
+        .iter()
+        .filter(|item| item.date.is_none())
+        .map(|item| ValidationError {
+            message: format!("Invalid date format in revision history: {}", revisions[item.index].get_date()),
+            instance_path: format!("/document/tracking/revision_history/{}/date", item.index),
+            severity: ValidationSeverity::Error,
+        })
+        .collect();
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    match timeline.newest_revision_date() {
+        Some(date) => Ok(Some(date)),
+        None => Err(vec![ValidationError {
+            message: "Revision history must not be empty for status final or interim".to_string(),
+            instance_path: "/document/tracking/revision_history".to_string(),
+            severity: ValidationSeverity::Error,
+        }]),
+    }
+}
+
 /// Counts the number of unescaped '*' characters in a given string.
 /// An asterisk is considered "unescaped" if it is not preceded by a backslash ('\\').
 /// Consecutive backslashes alternate between escaping or not escaping characters.
@@ -47,6 +108,20 @@ pub fn count_unescaped_stars(s: &str) -> u32 {
     count
 }
 
+/// Returns the index of every item whose key also occurs at another index, in ascending order.
+/// Items without a key (`None`) are never considered duplicates.
+pub fn find_duplicates<K: Ord>(keys: impl IntoIterator<Item = Option<K>>) -> Vec<usize> {
+    let mut occurrences = BTreeMap::<K, Vec<usize>>::new();
+    for (index, key) in keys.into_iter().enumerate() {
+        if let Some(key) = key {
+            occurrences.entry(key).or_default().push(index);
+        }
+    }
+    let mut duplicates: Vec<usize> = occurrences.into_values().filter(|i| i.len() > 1).flatten().collect();
+    duplicates.sort();
+    duplicates
+}
+
 /// Recursively loads all decision point JSON descriptions from ../ssvc/data/json/decision_points.
 /// Entries are stored in a `HashMap` indexed by their respective (name, version) tuple for lookup.
 pub static SSVC_DECISION_POINTS: LazyLock<HashMap<(String, String, String), DecisionPoint>> = LazyLock::new(|| {
@@ -0,0 +1,319 @@
+//! Parser for BCP 47 language tags (RFC 5646), as used by `document/lang` and
+//! `document/source_lang`.
+//!
+//! Parsing checks that a tag is well-formed. Whether its subtags are registered is checked
+//! against the IANA Language Subtag Registry, see [SubtagRegistry].
+
+mod registry;
+mod subtags;
+
+pub use registry::{SubtagRange, SubtagRegistry};
+
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// The grandfathered tags of RFC 5646, which are only valid as a whole.
+const GRANDFATHERED: &[&str] = &[
+    // irregular
+    "en-gb-oed", "i-ami", "i-bnn", "i-default", "i-enochian", "i-hak", "i-klingon", "i-lux", "i-mingo",
+    "i-navajo", "i-pwn", "i-tao", "i-tay", "i-tsu", "sgn-be-fr", "sgn-be-nl", "sgn-ch-de",
+    // regular
+    "art-lojban", "cel-gaulish", "no-bok", "no-nyn", "zh-guoyu", "zh-hakka", "zh-min", "zh-min-nan",
+    "zh-xiang",
+];
+
+/// The tag identifying the default language of a context (RFC 2277).
+const DEFAULT_LANGUAGE: &str = "i-default";
+
+/// Language subtags reserved for private use (RFC 5646, section 2.2.1).
+const PRIVATE_USE_LANGUAGES: SubtagRange = SubtagRange("qaa", "qtz");
+/// Script subtags reserved for private use (RFC 5646, section 2.2.3).
+const PRIVATE_USE_SCRIPTS: SubtagRange = SubtagRange("qaaa", "qabx");
+/// Region subtags reserved for private use (RFC 5646, section 2.2.4).
+const PRIVATE_USE_REGIONS: &[SubtagRange] = &[
+    SubtagRange("aa", "aa"),
+    SubtagRange("qm", "qz"),
+    SubtagRange("xa", "xz"),
+    SubtagRange("zz", "zz"),
+];
+
+/// Error for a language tag which is not well-formed.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum LanguageTagError {
+    /// The tag is empty.
+    Empty,
+    /// The subtag is not allowed at its position.
+    InvalidSubtag(String),
+    /// The tag ends with an extension or private use singleton without subtags.
+    MissingSubtag(String),
+    /// The variant occurs more than once.
+    DuplicateVariant(String),
+    /// The extension singleton occurs more than once.
+    DuplicateExtension(char),
+}
+
+impl Display for LanguageTagError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "the language tag is empty"),
+            Self::InvalidSubtag(subtag) => write!(f, "the subtag '{}' is not allowed at its position", subtag),
+            Self::MissingSubtag(singleton) => write!(f, "the singleton '{}' is not followed by a subtag", singleton),
+            Self::DuplicateVariant(variant) => write!(f, "the variant '{}' occurs more than once", variant),
+            Self::DuplicateExtension(singleton) => {
+                write!(f, "the extension '{}' occurs more than once", singleton)
+            }
+        }
+    }
+}
+
+/// An extension of a language tag, e.g. `u-co-phonebk`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Extension {
+    pub singleton: char,
+    pub subtags: Vec<String>,
+}
+
+/// A well-formed BCP 47 language tag. All subtags are normalized to lower case.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct LanguageTag {
+    /// The primary language subtag, missing for private use tags (`x-...`) and grandfathered tags
+    pub language: Option<String>,
+    pub extlangs: Vec<String>,
+    pub script: Option<String>,
+    pub region: Option<String>,
+    pub variants: Vec<String>,
+    pub extensions: Vec<Extension>,
+    /// The subtags following the private use singleton `x`
+    pub private_use: Vec<String>,
+    /// The whole tag, if it is one of the grandfathered tags of RFC 5646
+    pub grandfathered: Option<String>,
+}
+
+fn is_alpha(subtag: &str, min: usize, max: usize) -> bool {
+    (min..=max).contains(&subtag.len()) && subtag.bytes().all(|b| b.is_ascii_alphabetic())
+}
+
+fn is_alphanum(subtag: &str, min: usize, max: usize) -> bool {
+    (min..=max).contains(&subtag.len()) && subtag.bytes().all(|b| b.is_ascii_alphanumeric())
+}
+
+fn is_region(subtag: &str) -> bool {
+    is_alpha(subtag, 2, 2) || (subtag.len() == 3 && subtag.bytes().all(|b| b.is_ascii_digit()))
+}
+
+fn is_variant(subtag: &str) -> bool {
+    is_alphanum(subtag, 5, 8)
+        || (is_alphanum(subtag, 4, 4) && subtag.as_bytes()[0].is_ascii_digit())
+}
+
+impl FromStr for LanguageTag {
+    type Err = LanguageTagError;
+
+    /// Parses a language tag according to the ABNF of RFC 5646, section 2.1. Besides
+    /// well-formedness, duplicate variants and extensions are rejected (section 2.2.5 and 2.2.6).
+    fn from_str(tag: &str) -> Result<Self, Self::Err> {
+        if tag.is_empty() {
+            return Err(LanguageTagError::Empty);
+        }
+        let lower = tag.to_ascii_lowercase();
+        if GRANDFATHERED.contains(&lower.as_str()) {
+            return Ok(LanguageTag {
+                grandfathered: Some(lower),
+                ..Default::default()
+            });
+        }
+
+        let subtags: Vec<&str> = lower.split('-').collect();
+        let mut result = LanguageTag::default();
+        let mut i = 0;
+        let invalid = |subtag: &str| LanguageTagError::InvalidSubtag(subtag.to_string());
+
+        if subtags[0] != "x" {
+            let language = subtags[0];
+            if !is_alpha(language, 2, 3) && !is_alpha(language, 4, 8) {
+                return Err(invalid(language));
+            }
+            result.language = Some(language.to_string());
+            i += 1;
+            // Extended language subtags only follow 2 or 3 letter primary languages
+            if language.len() <= 3 {
+                while i < subtags.len() && result.extlangs.len() < 3 && is_alpha(subtags[i], 3, 3) {
+                    result.extlangs.push(subtags[i].to_string());
+                    i += 1;
+                }
+            }
+            if i < subtags.len() && is_alpha(subtags[i], 4, 4) {
+                result.script = Some(subtags[i].to_string());
+                i += 1;
+            }
+            if i < subtags.len() && is_region(subtags[i]) {
+                result.region = Some(subtags[i].to_string());
+                i += 1;
+            }
+            while i < subtags.len() && is_variant(subtags[i]) {
+                if result.variants.iter().any(|v| v == subtags[i]) {
+                    return Err(LanguageTagError::DuplicateVariant(subtags[i].to_string()));
+                }
+                result.variants.push(subtags[i].to_string());
+                i += 1;
+            }
+            while i < subtags.len() && subtags[i].len() == 1 && subtags[i] != "x" {
+                let singleton = subtags[i];
+                let singleton_char = singleton.chars().next().unwrap_or_default();
+                if !singleton_char.is_ascii_alphanumeric() {
+                    return Err(invalid(singleton));
+                }
+                if result.extensions.iter().any(|e| e.singleton == singleton_char) {
+                    return Err(LanguageTagError::DuplicateExtension(singleton_char));
+                }
+                i += 1;
+                let mut extension = Extension { singleton: singleton_char, subtags: Vec::new() };
+                while i < subtags.len() && is_alphanum(subtags[i], 2, 8) {
+                    extension.subtags.push(subtags[i].to_string());
+                    i += 1;
+                }
+                if extension.subtags.is_empty() {
+                    return Err(match subtags.get(i) {
+                        Some(subtag) => invalid(subtag),
+                        None => LanguageTagError::MissingSubtag(singleton.to_string()),
+                    });
+                }
+                result.extensions.push(extension);
+            }
+        }
+
+        if i < subtags.len() && subtags[i] == "x" {
+            i += 1;
+            while i < subtags.len() && is_alphanum(subtags[i], 1, 8) {
+                result.private_use.push(subtags[i].to_string());
+                i += 1;
+            }
+            if result.private_use.is_empty() && i == subtags.len() {
+                return Err(LanguageTagError::MissingSubtag("x".to_string()));
+            }
+        }
+
+        match subtags.get(i) {
+            Some(subtag) => Err(invalid(subtag)),
+            None => Ok(result),
+        }
+    }
+}
+
+impl LanguageTag {
+    /// Checks whether this is the tag `i-default`, which denotes the default language of a context.
+    pub fn is_default(&self) -> bool {
+        self.grandfathered.as_deref() == Some(DEFAULT_LANGUAGE)
+    }
+
+    /// Returns the subtags of this tag which are reserved for private use, i.e. private use
+    /// languages, scripts and regions as well as all subtags following the singleton `x`.
+    pub fn private_use_subtags(&self) -> Vec<&str> {
+        let mut subtags = Vec::new();
+        if let Some(language) = self.language.as_deref().filter(|l| PRIVATE_USE_LANGUAGES.contains(l)) {
+            subtags.push(language);
+        }
+        if let Some(script) = self.script.as_deref().filter(|s| PRIVATE_USE_SCRIPTS.contains(s)) {
+            subtags.push(script);
+        }
+        if let Some(region) = self.region.as_deref().filter(|r| PRIVATE_USE_REGIONS.iter().any(|p| p.contains(r))) {
+            subtags.push(region);
+        }
+        subtags.extend(self.private_use.iter().map(|s| s.as_str()));
+        subtags
+    }
+
+    /// Returns the subtags of this tag which are not registered in the given registry.
+    /// Extensions and private use subtags are not part of the registry and not checked.
+    pub fn unregistered_subtags(&self, registry: &SubtagRegistry) -> Vec<&str> {
+        let mut subtags = Vec::new();
+        if let Some(tag) = &self.grandfathered {
+            if !registry.contains_grandfathered(tag) {
+                subtags.push(tag.as_str());
+            }
+            return subtags;
+        }
+        let candidates = self
+            .language
+            .iter()
+            .map(|s| (s, registry.languages))
+            .chain(self.extlangs.iter().map(|s| (s, registry.extlangs)))
+            .chain(self.script.iter().map(|s| (s, registry.scripts)))
+            .chain(self.region.iter().map(|s| (s, registry.regions)))
+            .chain(self.variants.iter().map(|s| (s, registry.variants)));
+        for (subtag, ranges) in candidates {
+            if !SubtagRegistry::contains(ranges, subtag) {
+                subtags.push(subtag.as_str());
+            }
+        }
+        subtags
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::language::{Extension, LanguageTag, LanguageTagError, SubtagRange, SubtagRegistry};
+
+    fn parse(tag: &str) -> Result<LanguageTag, LanguageTagError> {
+        tag.parse::<LanguageTag>()
+    }
+
+    #[test]
+    fn test_parse_well_formed() {
+        let tag = parse("zh-Hant-CN-x-private1").unwrap();
+        assert_eq!(Some("zh".to_string()), tag.language);
+        assert_eq!(Some("hant".to_string()), tag.script);
+        assert_eq!(Some("cn".to_string()), tag.region);
+        assert_eq!(vec!["private1".to_string()], tag.private_use);
+
+        let tag = parse("zh-yue-419-1994-rozaj-u-co-phonebk-t-de").unwrap();
+        assert_eq!(vec!["yue".to_string()], tag.extlangs);
+        assert_eq!(Some("419".to_string()), tag.region);
+        assert_eq!(vec!["1994".to_string(), "rozaj".to_string()], tag.variants);
+        assert_eq!(
+            vec![
+                Extension { singleton: 'u', subtags: vec!["co".to_string(), "phonebk".to_string()] },
+                Extension { singleton: 't', subtags: vec!["de".to_string()] },
+            ],
+            tag.extensions
+        );
+
+        assert_eq!(Some("en-gb-oed".to_string()), parse("en-GB-oed").unwrap().grandfathered);
+        assert!(parse("i-default").unwrap().is_default());
+        assert_eq!(vec!["whatever".to_string()], parse("x-whatever").unwrap().private_use);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert_eq!(Err(LanguageTagError::Empty), parse(""));
+        assert_eq!(Err(LanguageTagError::InvalidSubtag("a".to_string())), parse("a-DE"));
+        assert_eq!(Err(LanguageTagError::InvalidSubtag("".to_string())), parse("en-"));
+        assert_eq!(Err(LanguageTagError::InvalidSubtag("toolongtag".to_string())), parse("toolongtag"));
+        assert_eq!(Err(LanguageTagError::InvalidSubtag("de".to_string())), parse("en-US-de"));
+        assert_eq!(Err(LanguageTagError::MissingSubtag("u".to_string())), parse("en-u"));
+        assert_eq!(Err(LanguageTagError::MissingSubtag("x".to_string())), parse("en-x"));
+        assert_eq!(Err(LanguageTagError::DuplicateVariant("rozaj".to_string())), parse("sl-rozaj-rozaj"));
+        assert_eq!(Err(LanguageTagError::DuplicateExtension('u')), parse("en-u-aa-u-bb"));
+    }
+
+    #[test]
+    fn test_private_use_and_registry() {
+        assert_eq!(vec!["qtx", "qaaa", "xa", "a"], parse("qtx-Qaaa-XA-x-a").unwrap().private_use_subtags());
+        assert!(parse("de-DE").unwrap().private_use_subtags().is_empty());
+
+        let registry = SubtagRegistry {
+            file_date: "2024-01-01",
+            languages: &[SubtagRange("de", "de"), SubtagRange("en", "en"), SubtagRange("qaa", "qtz")],
+            extlangs: &[],
+            scripts: &[SubtagRange("latn", "latn")],
+            regions: &[SubtagRange("de", "de")],
+            variants: &[],
+            grandfathered: &["i-default"],
+        };
+        assert!(parse("de-Latn-DE").unwrap().unregistered_subtags(&registry).is_empty());
+        assert!(parse("qab-x-any").unwrap().unregistered_subtags(&registry).is_empty());
+        assert!(parse("i-default").unwrap().unregistered_subtags(&registry).is_empty());
+        assert_eq!(vec!["fr", "cyrl", "us"], parse("fr-Cyrl-US").unwrap().unregistered_subtags(&registry));
+        assert_eq!(vec!["i-klingon"], parse("i-klingon").unwrap().unregistered_subtags(&registry));
+    }
+}
@@ -0,0 +1,48 @@
+use crate::csaf::language::subtags;
+
+/// An inclusive range of subtags in lower case, e.g. `qaa..qtz`. Single subtags are
+/// represented as range with equal bounds.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct SubtagRange(pub &'static str, pub &'static str);
+
+impl SubtagRange {
+    /// Checks whether the (lower case) subtag is part of this range. Bounds and subtag
+    /// have the same length, so that the lexicographic order applies.
+    pub fn contains(&self, subtag: &str) -> bool {
+        subtag.len() == self.0.len() && self.0 <= subtag && subtag <= self.1
+    }
+}
+
+/// The subtags of the IANA Language Subtag Registry. The ranges of each kind are sorted
+/// by the length and value of their lower bound.
+#[derive(Debug)]
+pub struct SubtagRegistry {
+    /// The `File-Date` of the registry
+    pub file_date: &'static str,
+    pub languages: &'static [SubtagRange],
+    pub extlangs: &'static [SubtagRange],
+    pub scripts: &'static [SubtagRange],
+    pub regions: &'static [SubtagRange],
+    pub variants: &'static [SubtagRange],
+    /// The grandfathered tags registered as a whole
+    pub grandfathered: &'static [&'static str],
+}
+
+impl SubtagRegistry {
+    /// Returns the registry embedded at build time, if it was vendored.
+    pub fn embedded() -> Option<&'static SubtagRegistry> {
+        subtags::REGISTRY.as_ref()
+    }
+
+    /// Checks whether the (lower case) subtag is contained in one of the sorted ranges.
+    /// Ranges do not overlap, so only the last range starting before the subtag can contain it.
+    pub fn contains(ranges: &[SubtagRange], subtag: &str) -> bool {
+        let candidates = ranges.partition_point(|range| (range.0.len(), range.0) <= (subtag.len(), subtag));
+        candidates > 0 && ranges[candidates - 1].contains(subtag)
+    }
+
+    /// Checks whether the (lower case) grandfathered tag is registered.
+    pub fn contains_grandfathered(&self, tag: &str) -> bool {
+        self.grandfathered.contains(&tag)
+    }
+}
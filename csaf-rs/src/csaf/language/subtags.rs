@@ -0,0 +1,5 @@
+// This file is generated by build.rs from the IANA Language Subtag Registry in src/csaf/language/data.
+// Do not edit it manually.
+use super::SubtagRegistry;
+
+pub(super) static REGISTRY: Option<SubtagRegistry> = None;
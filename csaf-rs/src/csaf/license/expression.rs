@@ -0,0 +1,385 @@
+use crate::csaf::license::LicenseList;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Error for a license expression which is not well-formed.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum SpdxExpressionError {
+    /// The expression is empty.
+    Empty,
+    /// The expression ended where a license or a closing parenthesis was expected.
+    UnexpectedEnd,
+    /// The token is not allowed at its position.
+    UnexpectedToken(String),
+    /// The token is neither an operator nor a valid license, exception or reference identifier.
+    InvalidIdentifier(String),
+    /// The parentheses of the expression are nested too deeply.
+    TooDeep,
+}
+
+impl Display for SpdxExpressionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "the license expression is empty"),
+            Self::UnexpectedEnd => write!(f, "the license expression ends unexpectedly"),
+            Self::UnexpectedToken(token) => write!(f, "unexpected '{}'", token),
+            Self::InvalidIdentifier(token) => write!(f, "'{}' is no valid license identifier", token),
+            Self::TooDeep => write!(f, "the parentheses of the license expression are nested deeper than {} levels", MAX_DEPTH),
+        }
+    }
+}
+
+impl std::error::Error for SpdxExpressionError {}
+
+/// A parsed SPDX license expression. `WITH` binds stronger than `AND`, which binds stronger
+/// than `OR`. Chains of the same operator are kept flat, e.g. `A OR B OR C` is a single `Or`
+/// with three operands.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum SpdxExpression {
+    /// A license identifier of the SPDX license list, `+` denotes "or any later version".
+    License { id: String, or_later: bool },
+    /// A user defined license `[DocumentRef-<id>:]LicenseRef-<id>`, including the prefixes.
+    LicenseRef { id: String, or_later: bool },
+    /// A license with an exception identifier of the SPDX license list or an `AdditionRef-`.
+    With { license: Box<SpdxExpression>, exception: String },
+    And(Vec<SpdxExpression>),
+    Or(Vec<SpdxExpression>),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Token<'a> {
+    Open,
+    Close,
+    And,
+    Or,
+    With,
+    Identifier(&'a str),
+}
+
+fn tokenize(expression: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut start = None;
+    for (i, c) in expression.char_indices().chain([(expression.len(), ' ')]) {
+        if c.is_whitespace() || c == '(' || c == ')' {
+            if let Some(start) = start.take() {
+                // Operators are either all upper or all lower case
+                tokens.push(match &expression[start..i] {
+                    "AND" | "and" => Token::And,
+                    "OR" | "or" => Token::Or,
+                    "WITH" | "with" => Token::With,
+                    identifier => Token::Identifier(identifier),
+                });
+            }
+            match c {
+                '(' => tokens.push(Token::Open),
+                ')' => tokens.push(Token::Close),
+                _ => {}
+            }
+        } else if start.is_none() {
+            start = Some(i);
+        }
+    }
+    tokens
+}
+
+impl Display for Token<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::Open => write!(f, "("),
+            Token::Close => write!(f, ")"),
+            Token::And => write!(f, "AND"),
+            Token::Or => write!(f, "OR"),
+            Token::With => write!(f, "WITH"),
+            Token::Identifier(identifier) => write!(f, "{}", identifier),
+        }
+    }
+}
+
+/// Checks the `idstring` production: letters, digits, `-` and `.`.
+fn is_idstring(value: &str) -> bool {
+    !value.is_empty() && value.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'.')
+}
+
+/// Checks for `[DocumentRef-<idstring>:]<prefix><idstring>`.
+fn is_reference(value: &str, prefix: &str) -> bool {
+    let reference = match value.strip_prefix("DocumentRef-") {
+        Some(document_ref) => match document_ref.split_once(':') {
+            Some((document, reference)) if is_idstring(document) => reference,
+            _ => return false,
+        },
+        None => value,
+    };
+    reference.strip_prefix(prefix).is_some_and(is_idstring)
+}
+
+/// The maximum nesting depth of parentheses in an expression. Expressions are processed
+/// recursively, so deeper expressions are rejected instead of overflowing the stack. Operator
+/// chains are flat and do not add to the depth.
+const MAX_DEPTH: usize = 100;
+
+struct Parser<'a> {
+    tokens: Vec<Token<'a>>,
+    position: usize,
+    /// The number of currently open parentheses
+    parentheses: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<&Token<'a>> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Result<&Token<'a>, SpdxExpressionError> {
+        let token = self.tokens.get(self.position).ok_or(SpdxExpressionError::UnexpectedEnd)?;
+        self.position += 1;
+        Ok(token)
+    }
+
+    fn parse_or(&mut self) -> Result<SpdxExpression, SpdxExpressionError> {
+        let mut operands = vec![self.parse_and()?];
+        while self.peek() == Some(&Token::Or) {
+            self.position += 1;
+            operands.push(self.parse_and()?);
+        }
+        Ok(match operands.len() {
+            1 => operands.remove(0),
+            _ => SpdxExpression::Or(operands),
+        })
+    }
+
+    fn parse_and(&mut self) -> Result<SpdxExpression, SpdxExpressionError> {
+        let mut operands = vec![self.parse_with()?];
+        while self.peek() == Some(&Token::And) {
+            self.position += 1;
+            operands.push(self.parse_with()?);
+        }
+        Ok(match operands.len() {
+            1 => operands.remove(0),
+            _ => SpdxExpression::And(operands),
+        })
+    }
+
+    fn parse_with(&mut self) -> Result<SpdxExpression, SpdxExpressionError> {
+        let license = self.parse_primary()?;
+        if self.peek() != Some(&Token::With) {
+            return Ok(license);
+        }
+        self.position += 1;
+        let exception = match self.next()? {
+            Token::Identifier(exception) if is_idstring(exception) || is_reference(exception, "AdditionRef-") => {
+                exception.to_string()
+            }
+            Token::Identifier(exception) => return Err(SpdxExpressionError::InvalidIdentifier(exception.to_string())),
+            token => return Err(SpdxExpressionError::UnexpectedToken(token.to_string())),
+        };
+        // An exception applies to a single license, not to a compound expression
+        if !matches!(license, SpdxExpression::License { .. } | SpdxExpression::LicenseRef { .. }) {
+            return Err(SpdxExpressionError::UnexpectedToken("WITH".to_string()));
+        }
+        Ok(SpdxExpression::With { license: Box::new(license), exception })
+    }
+
+    fn parse_primary(&mut self) -> Result<SpdxExpression, SpdxExpressionError> {
+        match self.next()? {
+            Token::Open => {
+                if self.parentheses == MAX_DEPTH {
+                    return Err(SpdxExpressionError::TooDeep);
+                }
+                self.parentheses += 1;
+                let expression = self.parse_or()?;
+                self.parentheses -= 1;
+                match self.next()? {
+                    Token::Close => Ok(expression),
+                    token => Err(SpdxExpressionError::UnexpectedToken(token.to_string())),
+                }
+            }
+            Token::Identifier(identifier) => {
+                let (id, or_later) = match identifier.strip_suffix('+') {
+                    Some(id) => (id, true),
+                    None => (*identifier, false),
+                };
+                if is_reference(id, "LicenseRef-") {
+                    Ok(SpdxExpression::LicenseRef { id: id.to_string(), or_later })
+                } else if is_idstring(id) && !id.starts_with("DocumentRef-") {
+                    Ok(SpdxExpression::License { id: id.to_string(), or_later })
+                } else {
+                    Err(SpdxExpressionError::InvalidIdentifier(identifier.to_string()))
+                }
+            }
+            token => Err(SpdxExpressionError::UnexpectedToken(token.to_string())),
+        }
+    }
+}
+
+impl FromStr for SpdxExpression {
+    type Err = SpdxExpressionError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser { tokens: tokenize(value), position: 0, parentheses: 0 };
+        if parser.tokens.is_empty() {
+            return Err(SpdxExpressionError::Empty);
+        }
+        let expression = parser.parse_or()?;
+        match parser.peek() {
+            None => Ok(expression),
+            Some(token) => Err(SpdxExpressionError::UnexpectedToken(token.to_string())),
+        }
+    }
+}
+
+impl Display for SpdxExpression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // Compound operands are parenthesized, so that the precedence is explicit
+        let operands = |f: &mut Formatter<'_>, operands: &[SpdxExpression], operator: &str| {
+            for (i, operand) in operands.iter().enumerate() {
+                if i > 0 {
+                    write!(f, " {} ", operator)?;
+                }
+                match operand {
+                    Self::And(_) | Self::Or(_) => write!(f, "({})", operand)?,
+                    _ => write!(f, "{}", operand)?,
+                }
+            }
+            Ok(())
+        };
+        match self {
+            Self::License { id, or_later } | Self::LicenseRef { id, or_later } => {
+                write!(f, "{}{}", id, if *or_later { "+" } else { "" })
+            }
+            Self::With { license, exception } => write!(f, "{} WITH {}", license, exception),
+            Self::And(a) => operands(f, a, "AND"),
+            Self::Or(a) => operands(f, a, "OR"),
+        }
+    }
+}
+
+impl SpdxExpression {
+    /// Returns the license identifiers of the SPDX license list used in the expression,
+    /// without user defined licenses.
+    pub fn license_ids(&self) -> Vec<&str> {
+        match self {
+            Self::License { id, .. } => vec![id],
+            Self::LicenseRef { .. } => vec![],
+            Self::With { license, .. } => license.license_ids(),
+            Self::And(operands) | Self::Or(operands) => operands.iter().flat_map(|o| o.license_ids()).collect(),
+        }
+    }
+
+    /// Returns the exception identifiers of the SPDX license list used in the expression,
+    /// without user defined additions.
+    pub fn exception_ids(&self) -> Vec<&str> {
+        match self {
+            Self::License { .. } | Self::LicenseRef { .. } => vec![],
+            Self::With { exception, .. } if is_reference(exception, "AdditionRef-") => vec![],
+            Self::With { exception, .. } => vec![exception],
+            Self::And(operands) | Self::Or(operands) => operands.iter().flat_map(|o| o.exception_ids()).collect(),
+        }
+    }
+
+    /// Returns the license and exception identifiers which are not part of the license list.
+    pub fn unknown_ids(&self, list: &LicenseList) -> Vec<&str> {
+        let licenses = self.license_ids().into_iter().filter(|id| list.license(id).is_none());
+        let exceptions = self.exception_ids().into_iter().filter(|id| list.exception(id).is_none());
+        licenses.chain(exceptions).collect()
+    }
+
+    /// Returns the license and exception identifiers which are deprecated in the license list.
+    pub fn deprecated_ids(&self, list: &LicenseList) -> Vec<&str> {
+        let licenses = self.license_ids().into_iter().filter(|id| list.license(id).is_some_and(|l| l.deprecated));
+        let exceptions = self
+            .exception_ids()
+            .into_iter()
+            .filter(|id| list.exception(id).is_some_and(|e| e.deprecated));
+        licenses.chain(exceptions).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::license::expression::MAX_DEPTH;
+    use crate::csaf::license::{LicenseList, SpdxExpression, SpdxExpressionError};
+
+    fn license(id: &str) -> SpdxExpression {
+        SpdxExpression::License { id: id.to_string(), or_later: false }
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            Ok(SpdxExpression::Or(vec![
+                license("MIT"),
+                SpdxExpression::And(vec![
+                    license("Apache-2.0"),
+                    SpdxExpression::With {
+                        license: Box::new(SpdxExpression::License { id: "GPL-2.0-only".to_string(), or_later: true }),
+                        exception: "Classpath-exception-2.0".to_string(),
+                    }
+                ])
+            ])),
+            "MIT OR Apache-2.0 AND GPL-2.0-only+ WITH Classpath-exception-2.0".parse()
+        );
+        assert_eq!(
+            Ok(SpdxExpression::Or(vec![license("MIT"), license("Apache-2.0"), license("BSD-3-Clause")])),
+            "MIT OR Apache-2.0 OR BSD-3-Clause".parse()
+        );
+        assert_eq!(
+            "(MIT OR Apache-2.0) AND BSD-3-Clause",
+            "(MIT or Apache-2.0)and BSD-3-Clause".parse::<SpdxExpression>().unwrap().to_string()
+        );
+        assert_eq!(
+            Ok(SpdxExpression::LicenseRef {
+                id: "DocumentRef-spdx-tool-1.2:LicenseRef-MIT-Style-2".to_string(),
+                or_later: false
+            }),
+            "DocumentRef-spdx-tool-1.2:LicenseRef-MIT-Style-2".parse()
+        );
+        // The examples of the CSAF 2.1 schema
+        for expression in [
+            "CC-BY-4.0",
+            "LicenseRef-www.example.org-Example-CSAF-License-3.0+",
+            "LicenseRef-scancode-public-domain",
+            "MIT OR any-OSI",
+        ] {
+            assert!(expression.parse::<SpdxExpression>().is_ok(), "{}", expression);
+        }
+
+        assert_eq!(Err(SpdxExpressionError::Empty), " ".parse::<SpdxExpression>());
+        assert_eq!(Err(SpdxExpressionError::UnexpectedEnd), "MIT OR".parse::<SpdxExpression>());
+        assert_eq!(Err(SpdxExpressionError::UnexpectedEnd), "(MIT".parse::<SpdxExpression>());
+        assert_eq!(Err(SpdxExpressionError::UnexpectedToken("Apache-2.0".to_string())), "MIT Apache-2.0".parse::<SpdxExpression>());
+        assert_eq!(Err(SpdxExpressionError::UnexpectedToken("And".to_string())), "MIT And Apache-2.0".parse::<SpdxExpression>());
+        assert_eq!(Err(SpdxExpressionError::UnexpectedToken(")".to_string())), "MIT)".parse::<SpdxExpression>());
+        assert_eq!(Err(SpdxExpressionError::UnexpectedToken("WITH".to_string())), "(MIT OR BSD-3-Clause) WITH LLVM-exception".parse::<SpdxExpression>());
+        assert_eq!(Err(SpdxExpressionError::InvalidIdentifier("GPL_2.0".to_string())), "GPL_2.0".parse::<SpdxExpression>());
+        assert_eq!(Err(SpdxExpressionError::InvalidIdentifier("DocumentRef-x:MIT".to_string())), "DocumentRef-x:MIT".parse::<SpdxExpression>());
+    }
+
+    #[test]
+    fn test_parse_depth() {
+        let nested = |depth: usize| format!("{}MIT{}", "(".repeat(depth), ")".repeat(depth));
+        let chain = |operands: usize| vec!["MIT"; operands].join(" OR ");
+
+        assert!(nested(MAX_DEPTH).parse::<SpdxExpression>().is_ok());
+        assert_eq!(Err(SpdxExpressionError::TooDeep), nested(MAX_DEPTH + 1).parse::<SpdxExpression>());
+        // Would overflow the stack without the limit
+        assert_eq!(Err(SpdxExpressionError::TooDeep), nested(200_000).parse::<SpdxExpression>());
+        // Operator chains do not add to the depth, regardless of their length
+        assert!(chain(MAX_DEPTH + 2).parse::<SpdxExpression>().is_ok());
+        let expression = chain(200_000).parse::<SpdxExpression>().unwrap();
+        assert_eq!(200_000, expression.license_ids().len());
+        assert_eq!(chain(200_000), expression.to_string());
+    }
+
+    #[test]
+    fn test_identifiers() {
+        let list = LicenseList::embedded().unwrap();
+        let expression: SpdxExpression =
+            "GPL-2.0 WITH Classpath-exception-2.0 OR Example-1.0 WITH AdditionRef-Example OR LicenseRef-Own OR mit"
+                .parse()
+                .unwrap();
+        assert_eq!(vec!["GPL-2.0", "Example-1.0", "mit"], expression.license_ids());
+        assert_eq!(vec!["Classpath-exception-2.0"], expression.exception_ids());
+        assert_eq!(vec!["Example-1.0"], expression.unknown_ids(list));
+        assert_eq!(vec!["GPL-2.0"], expression.deprecated_ids(list));
+    }
+}
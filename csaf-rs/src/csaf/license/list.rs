@@ -0,0 +1,796 @@
+// This file is generated by build.rs from the SPDX license list in src/csaf/license/data.
+// Do not edit it manually.
+use super::{LicenseList, ListedIdentifier};
+
+pub(super) static LICENSE_LIST: Option<LicenseList> = Some(LicenseList {
+    version: "3.27.0",
+    licenses: &[
+        ListedIdentifier { id: "0BSD", deprecated: false },
+        ListedIdentifier { id: "3D-Slicer-1.0", deprecated: false },
+        ListedIdentifier { id: "AAL", deprecated: false },
+        ListedIdentifier { id: "Abstyles", deprecated: false },
+        ListedIdentifier { id: "AdaCore-doc", deprecated: false },
+        ListedIdentifier { id: "Adobe-2006", deprecated: false },
+        ListedIdentifier { id: "Adobe-Display-PostScript", deprecated: false },
+        ListedIdentifier { id: "Adobe-Glyph", deprecated: false },
+        ListedIdentifier { id: "Adobe-Utopia", deprecated: false },
+        ListedIdentifier { id: "ADSL", deprecated: false },
+        ListedIdentifier { id: "AFL-1.1", deprecated: false },
+        ListedIdentifier { id: "AFL-1.2", deprecated: false },
+        ListedIdentifier { id: "AFL-2.0", deprecated: false },
+        ListedIdentifier { id: "AFL-2.1", deprecated: false },
+        ListedIdentifier { id: "AFL-3.0", deprecated: false },
+        ListedIdentifier { id: "Afmparse", deprecated: false },
+        ListedIdentifier { id: "AGPL-1.0", deprecated: true },
+        ListedIdentifier { id: "AGPL-1.0-only", deprecated: false },
+        ListedIdentifier { id: "AGPL-1.0-or-later", deprecated: false },
+        ListedIdentifier { id: "AGPL-3.0", deprecated: true },
+        ListedIdentifier { id: "AGPL-3.0-only", deprecated: false },
+        ListedIdentifier { id: "AGPL-3.0-or-later", deprecated: false },
+        ListedIdentifier { id: "Aladdin", deprecated: false },
+        ListedIdentifier { id: "AMD-newlib", deprecated: false },
+        ListedIdentifier { id: "AMDPLPA", deprecated: false },
+        ListedIdentifier { id: "AML", deprecated: false },
+        ListedIdentifier { id: "AML-glslang", deprecated: false },
+        ListedIdentifier { id: "AMPAS", deprecated: false },
+        ListedIdentifier { id: "ANTLR-PD", deprecated: false },
+        ListedIdentifier { id: "ANTLR-PD-fallback", deprecated: false },
+        ListedIdentifier { id: "any-OSI", deprecated: false },
+        ListedIdentifier { id: "any-OSI-perl-modules", deprecated: false },
+        ListedIdentifier { id: "Apache-1.0", deprecated: false },
+        ListedIdentifier { id: "Apache-1.1", deprecated: false },
+        ListedIdentifier { id: "Apache-2.0", deprecated: false },
+        ListedIdentifier { id: "APAFML", deprecated: false },
+        ListedIdentifier { id: "APL-1.0", deprecated: false },
+        ListedIdentifier { id: "App-s2p", deprecated: false },
+        ListedIdentifier { id: "APSL-1.0", deprecated: false },
+        ListedIdentifier { id: "APSL-1.1", deprecated: false },
+        ListedIdentifier { id: "APSL-1.2", deprecated: false },
+        ListedIdentifier { id: "APSL-2.0", deprecated: false },
+        ListedIdentifier { id: "Arphic-1999", deprecated: false },
+        ListedIdentifier { id: "Artistic-1.0", deprecated: false },
+        ListedIdentifier { id: "Artistic-1.0-cl8", deprecated: false },
+        ListedIdentifier { id: "Artistic-1.0-Perl", deprecated: false },
+        ListedIdentifier { id: "Artistic-2.0", deprecated: false },
+        ListedIdentifier { id: "Artistic-dist", deprecated: false },
+        ListedIdentifier { id: "Aspell-RU", deprecated: false },
+        ListedIdentifier { id: "ASWF-Digital-Assets-1.0", deprecated: false },
+        ListedIdentifier { id: "ASWF-Digital-Assets-1.1", deprecated: false },
+        ListedIdentifier { id: "Baekmuk", deprecated: false },
+        ListedIdentifier { id: "Bahyph", deprecated: false },
+        ListedIdentifier { id: "Barr", deprecated: false },
+        ListedIdentifier { id: "bcrypt-Solar-Designer", deprecated: false },
+        ListedIdentifier { id: "Beerware", deprecated: false },
+        ListedIdentifier { id: "Bitstream-Charter", deprecated: false },
+        ListedIdentifier { id: "Bitstream-Vera", deprecated: false },
+        ListedIdentifier { id: "BitTorrent-1.0", deprecated: false },
+        ListedIdentifier { id: "BitTorrent-1.1", deprecated: false },
+        ListedIdentifier { id: "blessing", deprecated: false },
+        ListedIdentifier { id: "BlueOak-1.0.0", deprecated: false },
+        ListedIdentifier { id: "Boehm-GC", deprecated: false },
+        ListedIdentifier { id: "Boehm-GC-without-fee", deprecated: false },
+        ListedIdentifier { id: "Borceux", deprecated: false },
+        ListedIdentifier { id: "Brian-Gladman-2-Clause", deprecated: false },
+        ListedIdentifier { id: "Brian-Gladman-3-Clause", deprecated: false },
+        ListedIdentifier { id: "BSD-1-Clause", deprecated: false },
+        ListedIdentifier { id: "BSD-2-Clause", deprecated: false },
+        ListedIdentifier { id: "BSD-2-Clause-Darwin", deprecated: false },
+        ListedIdentifier { id: "BSD-2-Clause-first-lines", deprecated: false },
+        ListedIdentifier { id: "BSD-2-Clause-FreeBSD", deprecated: true },
+        ListedIdentifier { id: "BSD-2-Clause-NetBSD", deprecated: true },
+        ListedIdentifier { id: "BSD-2-Clause-Patent", deprecated: false },
+        ListedIdentifier { id: "BSD-2-Clause-pkgconf-disclaimer", deprecated: false },
+        ListedIdentifier { id: "BSD-2-Clause-Views", deprecated: false },
+        ListedIdentifier { id: "BSD-3-Clause", deprecated: false },
+        ListedIdentifier { id: "BSD-3-Clause-acpica", deprecated: false },
+        ListedIdentifier { id: "BSD-3-Clause-Attribution", deprecated: false },
+        ListedIdentifier { id: "BSD-3-Clause-Clear", deprecated: false },
+        ListedIdentifier { id: "BSD-3-Clause-flex", deprecated: false },
+        ListedIdentifier { id: "BSD-3-Clause-HP", deprecated: false },
+        ListedIdentifier { id: "BSD-3-Clause-LBNL", deprecated: false },
+        ListedIdentifier { id: "BSD-3-Clause-Modification", deprecated: false },
+        ListedIdentifier { id: "BSD-3-Clause-No-Military-License", deprecated: false },
+        ListedIdentifier { id: "BSD-3-Clause-No-Nuclear-License", deprecated: false },
+        ListedIdentifier { id: "BSD-3-Clause-No-Nuclear-License-2014", deprecated: false },
+        ListedIdentifier { id: "BSD-3-Clause-No-Nuclear-Warranty", deprecated: false },
+        ListedIdentifier { id: "BSD-3-Clause-Open-MPI", deprecated: false },
+        ListedIdentifier { id: "BSD-3-Clause-Sun", deprecated: false },
+        ListedIdentifier { id: "BSD-4-Clause", deprecated: false },
+        ListedIdentifier { id: "BSD-4-Clause-Shortened", deprecated: false },
+        ListedIdentifier { id: "BSD-4-Clause-UC", deprecated: false },
+        ListedIdentifier { id: "BSD-4.3RENO", deprecated: false },
+        ListedIdentifier { id: "BSD-4.3TAHOE", deprecated: false },
+        ListedIdentifier { id: "BSD-Advertising-Acknowledgement", deprecated: false },
+        ListedIdentifier { id: "BSD-Attribution-HPND-disclaimer", deprecated: false },
+        ListedIdentifier { id: "BSD-Inferno-Nettverk", deprecated: false },
+        ListedIdentifier { id: "BSD-Protection", deprecated: false },
+        ListedIdentifier { id: "BSD-Source-beginning-file", deprecated: false },
+        ListedIdentifier { id: "BSD-Source-Code", deprecated: false },
+        ListedIdentifier { id: "BSD-Systemics", deprecated: false },
+        ListedIdentifier { id: "BSD-Systemics-W3Works", deprecated: false },
+        ListedIdentifier { id: "BSL-1.0", deprecated: false },
+        ListedIdentifier { id: "BUSL-1.1", deprecated: false },
+        ListedIdentifier { id: "bzip2-1.0.5", deprecated: true },
+        ListedIdentifier { id: "bzip2-1.0.6", deprecated: false },
+        ListedIdentifier { id: "C-UDA-1.0", deprecated: false },
+        ListedIdentifier { id: "CAL-1.0", deprecated: false },
+        ListedIdentifier { id: "CAL-1.0-Combined-Work-Exception", deprecated: false },
+        ListedIdentifier { id: "Caldera", deprecated: false },
+        ListedIdentifier { id: "Caldera-no-preamble", deprecated: false },
+        ListedIdentifier { id: "Catharon", deprecated: false },
+        ListedIdentifier { id: "CATOSL-1.1", deprecated: false },
+        ListedIdentifier { id: "CC-BY-1.0", deprecated: false },
+        ListedIdentifier { id: "CC-BY-2.0", deprecated: false },
+        ListedIdentifier { id: "CC-BY-2.5", deprecated: false },
+        ListedIdentifier { id: "CC-BY-2.5-AU", deprecated: false },
+        ListedIdentifier { id: "CC-BY-3.0", deprecated: false },
+        ListedIdentifier { id: "CC-BY-3.0-AT", deprecated: false },
+        ListedIdentifier { id: "CC-BY-3.0-AU", deprecated: false },
+        ListedIdentifier { id: "CC-BY-3.0-DE", deprecated: false },
+        ListedIdentifier { id: "CC-BY-3.0-IGO", deprecated: false },
+        ListedIdentifier { id: "CC-BY-3.0-NL", deprecated: false },
+        ListedIdentifier { id: "CC-BY-3.0-US", deprecated: false },
+        ListedIdentifier { id: "CC-BY-4.0", deprecated: false },
+        ListedIdentifier { id: "CC-BY-NC-1.0", deprecated: false },
+        ListedIdentifier { id: "CC-BY-NC-2.0", deprecated: false },
+        ListedIdentifier { id: "CC-BY-NC-2.5", deprecated: false },
+        ListedIdentifier { id: "CC-BY-NC-3.0", deprecated: false },
+        ListedIdentifier { id: "CC-BY-NC-3.0-DE", deprecated: false },
+        ListedIdentifier { id: "CC-BY-NC-4.0", deprecated: false },
+        ListedIdentifier { id: "CC-BY-NC-ND-1.0", deprecated: false },
+        ListedIdentifier { id: "CC-BY-NC-ND-2.0", deprecated: false },
+        ListedIdentifier { id: "CC-BY-NC-ND-2.5", deprecated: false },
+        ListedIdentifier { id: "CC-BY-NC-ND-3.0", deprecated: false },
+        ListedIdentifier { id: "CC-BY-NC-ND-3.0-DE", deprecated: false },
+        ListedIdentifier { id: "CC-BY-NC-ND-3.0-IGO", deprecated: false },
+        ListedIdentifier { id: "CC-BY-NC-ND-4.0", deprecated: false },
+        ListedIdentifier { id: "CC-BY-NC-SA-1.0", deprecated: false },
+        ListedIdentifier { id: "CC-BY-NC-SA-2.0", deprecated: false },
+        ListedIdentifier { id: "CC-BY-NC-SA-2.0-DE", deprecated: false },
+        ListedIdentifier { id: "CC-BY-NC-SA-2.0-FR", deprecated: false },
+        ListedIdentifier { id: "CC-BY-NC-SA-2.0-UK", deprecated: false },
+        ListedIdentifier { id: "CC-BY-NC-SA-2.5", deprecated: false },
+        ListedIdentifier { id: "CC-BY-NC-SA-3.0", deprecated: false },
+        ListedIdentifier { id: "CC-BY-NC-SA-3.0-DE", deprecated: false },
+        ListedIdentifier { id: "CC-BY-NC-SA-3.0-IGO", deprecated: false },
+        ListedIdentifier { id: "CC-BY-NC-SA-4.0", deprecated: false },
+        ListedIdentifier { id: "CC-BY-ND-1.0", deprecated: false },
+        ListedIdentifier { id: "CC-BY-ND-2.0", deprecated: false },
+        ListedIdentifier { id: "CC-BY-ND-2.5", deprecated: false },
+        ListedIdentifier { id: "CC-BY-ND-3.0", deprecated: false },
+        ListedIdentifier { id: "CC-BY-ND-3.0-DE", deprecated: false },
+        ListedIdentifier { id: "CC-BY-ND-4.0", deprecated: false },
+        ListedIdentifier { id: "CC-BY-SA-1.0", deprecated: false },
+        ListedIdentifier { id: "CC-BY-SA-2.0", deprecated: false },
+        ListedIdentifier { id: "CC-BY-SA-2.0-UK", deprecated: false },
+        ListedIdentifier { id: "CC-BY-SA-2.1-JP", deprecated: false },
+        ListedIdentifier { id: "CC-BY-SA-2.5", deprecated: false },
+        ListedIdentifier { id: "CC-BY-SA-3.0", deprecated: false },
+        ListedIdentifier { id: "CC-BY-SA-3.0-AT", deprecated: false },
+        ListedIdentifier { id: "CC-BY-SA-3.0-DE", deprecated: false },
+        ListedIdentifier { id: "CC-BY-SA-3.0-IGO", deprecated: false },
+        ListedIdentifier { id: "CC-BY-SA-4.0", deprecated: false },
+        ListedIdentifier { id: "CC-PDDC", deprecated: false },
+        ListedIdentifier { id: "CC-PDM-1.0", deprecated: false },
+        ListedIdentifier { id: "CC-SA-1.0", deprecated: false },
+        ListedIdentifier { id: "CC0-1.0", deprecated: false },
+        ListedIdentifier { id: "CDDL-1.0", deprecated: false },
+        ListedIdentifier { id: "CDDL-1.1", deprecated: false },
+        ListedIdentifier { id: "CDL-1.0", deprecated: false },
+        ListedIdentifier { id: "CDLA-Permissive-1.0", deprecated: false },
+        ListedIdentifier { id: "CDLA-Permissive-2.0", deprecated: false },
+        ListedIdentifier { id: "CDLA-Sharing-1.0", deprecated: false },
+        ListedIdentifier { id: "CECILL-1.0", deprecated: false },
+        ListedIdentifier { id: "CECILL-1.1", deprecated: false },
+        ListedIdentifier { id: "CECILL-2.0", deprecated: false },
+        ListedIdentifier { id: "CECILL-2.1", deprecated: false },
+        ListedIdentifier { id: "CECILL-B", deprecated: false },
+        ListedIdentifier { id: "CECILL-C", deprecated: false },
+        ListedIdentifier { id: "CERN-OHL-1.1", deprecated: false },
+        ListedIdentifier { id: "CERN-OHL-1.2", deprecated: false },
+        ListedIdentifier { id: "CERN-OHL-P-2.0", deprecated: false },
+        ListedIdentifier { id: "CERN-OHL-S-2.0", deprecated: false },
+        ListedIdentifier { id: "CERN-OHL-W-2.0", deprecated: false },
+        ListedIdentifier { id: "CFITSIO", deprecated: false },
+        ListedIdentifier { id: "check-cvs", deprecated: false },
+        ListedIdentifier { id: "checkmk", deprecated: false },
+        ListedIdentifier { id: "ClArtistic", deprecated: false },
+        ListedIdentifier { id: "Clips", deprecated: false },
+        ListedIdentifier { id: "CMU-Mach", deprecated: false },
+        ListedIdentifier { id: "CMU-Mach-nodoc", deprecated: false },
+        ListedIdentifier { id: "CNRI-Jython", deprecated: false },
+        ListedIdentifier { id: "CNRI-Python", deprecated: false },
+        ListedIdentifier { id: "CNRI-Python-GPL-Compatible", deprecated: false },
+        ListedIdentifier { id: "COIL-1.0", deprecated: false },
+        ListedIdentifier { id: "Community-Spec-1.0", deprecated: false },
+        ListedIdentifier { id: "Condor-1.1", deprecated: false },
+        ListedIdentifier { id: "copyleft-next-0.3.0", deprecated: false },
+        ListedIdentifier { id: "copyleft-next-0.3.1", deprecated: false },
+        ListedIdentifier { id: "Cornell-Lossless-JPEG", deprecated: false },
+        ListedIdentifier { id: "CPAL-1.0", deprecated: false },
+        ListedIdentifier { id: "CPL-1.0", deprecated: false },
+        ListedIdentifier { id: "CPOL-1.02", deprecated: false },
+        ListedIdentifier { id: "Cronyx", deprecated: false },
+        ListedIdentifier { id: "Crossword", deprecated: false },
+        ListedIdentifier { id: "CryptoSwift", deprecated: false },
+        ListedIdentifier { id: "CrystalStacker", deprecated: false },
+        ListedIdentifier { id: "CUA-OPL-1.0", deprecated: false },
+        ListedIdentifier { id: "Cube", deprecated: false },
+        ListedIdentifier { id: "curl", deprecated: false },
+        ListedIdentifier { id: "cve-tou", deprecated: false },
+        ListedIdentifier { id: "D-FSL-1.0", deprecated: false },
+        ListedIdentifier { id: "DEC-3-Clause", deprecated: false },
+        ListedIdentifier { id: "diffmark", deprecated: false },
+        ListedIdentifier { id: "DL-DE-BY-2.0", deprecated: false },
+        ListedIdentifier { id: "DL-DE-ZERO-2.0", deprecated: false },
+        ListedIdentifier { id: "DOC", deprecated: false },
+        ListedIdentifier { id: "DocBook-DTD", deprecated: false },
+        ListedIdentifier { id: "DocBook-Schema", deprecated: false },
+        ListedIdentifier { id: "DocBook-Stylesheet", deprecated: false },
+        ListedIdentifier { id: "DocBook-XML", deprecated: false },
+        ListedIdentifier { id: "Dotseqn", deprecated: false },
+        ListedIdentifier { id: "DRL-1.0", deprecated: false },
+        ListedIdentifier { id: "DRL-1.1", deprecated: false },
+        ListedIdentifier { id: "DSDP", deprecated: false },
+        ListedIdentifier { id: "dtoa", deprecated: false },
+        ListedIdentifier { id: "dvipdfm", deprecated: false },
+        ListedIdentifier { id: "ECL-1.0", deprecated: false },
+        ListedIdentifier { id: "ECL-2.0", deprecated: false },
+        ListedIdentifier { id: "eCos-2.0", deprecated: true },
+        ListedIdentifier { id: "EFL-1.0", deprecated: false },
+        ListedIdentifier { id: "EFL-2.0", deprecated: false },
+        ListedIdentifier { id: "eGenix", deprecated: false },
+        ListedIdentifier { id: "Elastic-2.0", deprecated: false },
+        ListedIdentifier { id: "Entessa", deprecated: false },
+        ListedIdentifier { id: "EPICS", deprecated: false },
+        ListedIdentifier { id: "EPL-1.0", deprecated: false },
+        ListedIdentifier { id: "EPL-2.0", deprecated: false },
+        ListedIdentifier { id: "ErlPL-1.1", deprecated: false },
+        ListedIdentifier { id: "etalab-2.0", deprecated: false },
+        ListedIdentifier { id: "EUDatagrid", deprecated: false },
+        ListedIdentifier { id: "EUPL-1.0", deprecated: false },
+        ListedIdentifier { id: "EUPL-1.1", deprecated: false },
+        ListedIdentifier { id: "EUPL-1.2", deprecated: false },
+        ListedIdentifier { id: "Eurosym", deprecated: false },
+        ListedIdentifier { id: "Fair", deprecated: false },
+        ListedIdentifier { id: "FBM", deprecated: false },
+        ListedIdentifier { id: "FDK-AAC", deprecated: false },
+        ListedIdentifier { id: "Ferguson-Twofish", deprecated: false },
+        ListedIdentifier { id: "Frameworx-1.0", deprecated: false },
+        ListedIdentifier { id: "FreeBSD-DOC", deprecated: false },
+        ListedIdentifier { id: "FreeImage", deprecated: false },
+        ListedIdentifier { id: "FSFAP", deprecated: false },
+        ListedIdentifier { id: "FSFAP-no-warranty-disclaimer", deprecated: false },
+        ListedIdentifier { id: "FSFUL", deprecated: false },
+        ListedIdentifier { id: "FSFULLR", deprecated: false },
+        ListedIdentifier { id: "FSFULLRSD", deprecated: false },
+        ListedIdentifier { id: "FSFULLRWD", deprecated: false },
+        ListedIdentifier { id: "FSL-1.1-ALv2", deprecated: false },
+        ListedIdentifier { id: "FSL-1.1-MIT", deprecated: false },
+        ListedIdentifier { id: "FTL", deprecated: false },
+        ListedIdentifier { id: "Furuseth", deprecated: false },
+        ListedIdentifier { id: "fwlw", deprecated: false },
+        ListedIdentifier { id: "Game-Programming-Gems", deprecated: false },
+        ListedIdentifier { id: "GCR-docs", deprecated: false },
+        ListedIdentifier { id: "GD", deprecated: false },
+        ListedIdentifier { id: "generic-xts", deprecated: false },
+        ListedIdentifier { id: "GFDL-1.1", deprecated: true },
+        ListedIdentifier { id: "GFDL-1.1-invariants", deprecated: false },
+        ListedIdentifier { id: "GFDL-1.1-invariants-only", deprecated: false },
+        ListedIdentifier { id: "GFDL-1.1-invariants-or-later", deprecated: false },
+        ListedIdentifier { id: "GFDL-1.1-no-invariants", deprecated: false },
+        ListedIdentifier { id: "GFDL-1.1-no-invariants-only", deprecated: false },
+        ListedIdentifier { id: "GFDL-1.1-no-invariants-or-later", deprecated: false },
+        ListedIdentifier { id: "GFDL-1.1-only", deprecated: false },
+        ListedIdentifier { id: "GFDL-1.1-or-later", deprecated: false },
+        ListedIdentifier { id: "GFDL-1.2", deprecated: true },
+        ListedIdentifier { id: "GFDL-1.2-invariants", deprecated: false },
+        ListedIdentifier { id: "GFDL-1.2-invariants-only", deprecated: false },
+        ListedIdentifier { id: "GFDL-1.2-invariants-or-later", deprecated: false },
+        ListedIdentifier { id: "GFDL-1.2-no-invariants", deprecated: false },
+        ListedIdentifier { id: "GFDL-1.2-no-invariants-only", deprecated: false },
+        ListedIdentifier { id: "GFDL-1.2-no-invariants-or-later", deprecated: false },
+        ListedIdentifier { id: "GFDL-1.2-only", deprecated: false },
+        ListedIdentifier { id: "GFDL-1.2-or-later", deprecated: false },
+        ListedIdentifier { id: "GFDL-1.3", deprecated: true },
+        ListedIdentifier { id: "GFDL-1.3-invariants", deprecated: false },
+        ListedIdentifier { id: "GFDL-1.3-invariants-only", deprecated: false },
+        ListedIdentifier { id: "GFDL-1.3-invariants-or-later", deprecated: false },
+        ListedIdentifier { id: "GFDL-1.3-no-invariants", deprecated: false },
+        ListedIdentifier { id: "GFDL-1.3-no-invariants-only", deprecated: false },
+        ListedIdentifier { id: "GFDL-1.3-no-invariants-or-later", deprecated: false },
+        ListedIdentifier { id: "GFDL-1.3-only", deprecated: false },
+        ListedIdentifier { id: "GFDL-1.3-or-later", deprecated: false },
+        ListedIdentifier { id: "Giftware", deprecated: false },
+        ListedIdentifier { id: "GL2PS", deprecated: false },
+        ListedIdentifier { id: "Glide", deprecated: false },
+        ListedIdentifier { id: "Glulxe", deprecated: false },
+        ListedIdentifier { id: "GLWTPL", deprecated: false },
+        ListedIdentifier { id: "gnuplot", deprecated: false },
+        ListedIdentifier { id: "GPL-1.0", deprecated: true },
+        ListedIdentifier { id: "GPL-1.0+", deprecated: true },
+        ListedIdentifier { id: "GPL-1.0-only", deprecated: false },
+        ListedIdentifier { id: "GPL-1.0-or-later", deprecated: false },
+        ListedIdentifier { id: "GPL-2.0", deprecated: true },
+        ListedIdentifier { id: "GPL-2.0+", deprecated: true },
+        ListedIdentifier { id: "GPL-2.0-only", deprecated: false },
+        ListedIdentifier { id: "GPL-2.0-or-later", deprecated: false },
+        ListedIdentifier { id: "GPL-2.0-with-autoconf-exception", deprecated: true },
+        ListedIdentifier { id: "GPL-2.0-with-bison-exception", deprecated: true },
+        ListedIdentifier { id: "GPL-2.0-with-classpath-exception", deprecated: true },
+        ListedIdentifier { id: "GPL-2.0-with-font-exception", deprecated: true },
+        ListedIdentifier { id: "GPL-2.0-with-GCC-exception", deprecated: true },
+        ListedIdentifier { id: "GPL-3.0", deprecated: true },
+        ListedIdentifier { id: "GPL-3.0+", deprecated: true },
+        ListedIdentifier { id: "GPL-3.0-only", deprecated: false },
+        ListedIdentifier { id: "GPL-3.0-or-later", deprecated: false },
+        ListedIdentifier { id: "GPL-3.0-with-autoconf-exception", deprecated: true },
+        ListedIdentifier { id: "GPL-3.0-with-GCC-exception", deprecated: true },
+        ListedIdentifier { id: "Graphics-Gems", deprecated: false },
+        ListedIdentifier { id: "gSOAP-1.3b", deprecated: false },
+        ListedIdentifier { id: "gtkbook", deprecated: false },
+        ListedIdentifier { id: "Gutmann", deprecated: false },
+        ListedIdentifier { id: "HaskellReport", deprecated: false },
+        ListedIdentifier { id: "HDF5", deprecated: false },
+        ListedIdentifier { id: "hdparm", deprecated: false },
+        ListedIdentifier { id: "HIDAPI", deprecated: false },
+        ListedIdentifier { id: "Hippocratic-2.1", deprecated: false },
+        ListedIdentifier { id: "HP-1986", deprecated: false },
+        ListedIdentifier { id: "HP-1989", deprecated: false },
+        ListedIdentifier { id: "HPND", deprecated: false },
+        ListedIdentifier { id: "HPND-DEC", deprecated: false },
+        ListedIdentifier { id: "HPND-doc", deprecated: false },
+        ListedIdentifier { id: "HPND-doc-sell", deprecated: false },
+        ListedIdentifier { id: "HPND-export-US", deprecated: false },
+        ListedIdentifier { id: "HPND-export-US-acknowledgement", deprecated: false },
+        ListedIdentifier { id: "HPND-export-US-modify", deprecated: false },
+        ListedIdentifier { id: "HPND-export2-US", deprecated: false },
+        ListedIdentifier { id: "HPND-Fenneberg-Livingston", deprecated: false },
+        ListedIdentifier { id: "HPND-INRIA-IMAG", deprecated: false },
+        ListedIdentifier { id: "HPND-Intel", deprecated: false },
+        ListedIdentifier { id: "HPND-Kevlin-Henney", deprecated: false },
+        ListedIdentifier { id: "HPND-Markus-Kuhn", deprecated: false },
+        ListedIdentifier { id: "HPND-merchantability-variant", deprecated: false },
+        ListedIdentifier { id: "HPND-MIT-disclaimer", deprecated: false },
+        ListedIdentifier { id: "HPND-Netrek", deprecated: false },
+        ListedIdentifier { id: "HPND-Pbmplus", deprecated: false },
+        ListedIdentifier { id: "HPND-sell-MIT-disclaimer-xserver", deprecated: false },
+        ListedIdentifier { id: "HPND-sell-regexpr", deprecated: false },
+        ListedIdentifier { id: "HPND-sell-variant", deprecated: false },
+        ListedIdentifier { id: "HPND-sell-variant-MIT-disclaimer", deprecated: false },
+        ListedIdentifier { id: "HPND-sell-variant-MIT-disclaimer-rev", deprecated: false },
+        ListedIdentifier { id: "HPND-UC", deprecated: false },
+        ListedIdentifier { id: "HPND-UC-export-US", deprecated: false },
+        ListedIdentifier { id: "HTMLTIDY", deprecated: false },
+        ListedIdentifier { id: "IBM-pibs", deprecated: false },
+        ListedIdentifier { id: "ICU", deprecated: false },
+        ListedIdentifier { id: "IEC-Code-Components-EULA", deprecated: false },
+        ListedIdentifier { id: "IJG", deprecated: false },
+        ListedIdentifier { id: "IJG-short", deprecated: false },
+        ListedIdentifier { id: "ImageMagick", deprecated: false },
+        ListedIdentifier { id: "iMatix", deprecated: false },
+        ListedIdentifier { id: "Imlib2", deprecated: false },
+        ListedIdentifier { id: "Info-ZIP", deprecated: false },
+        ListedIdentifier { id: "Inner-Net-2.0", deprecated: false },
+        ListedIdentifier { id: "InnoSetup", deprecated: false },
+        ListedIdentifier { id: "Intel", deprecated: false },
+        ListedIdentifier { id: "Intel-ACPI", deprecated: false },
+        ListedIdentifier { id: "Interbase-1.0", deprecated: false },
+        ListedIdentifier { id: "IPA", deprecated: false },
+        ListedIdentifier { id: "IPL-1.0", deprecated: false },
+        ListedIdentifier { id: "ISC", deprecated: false },
+        ListedIdentifier { id: "ISC-Veillard", deprecated: false },
+        ListedIdentifier { id: "Jam", deprecated: false },
+        ListedIdentifier { id: "JasPer-2.0", deprecated: false },
+        ListedIdentifier { id: "jove", deprecated: false },
+        ListedIdentifier { id: "JPL-image", deprecated: false },
+        ListedIdentifier { id: "JPNIC", deprecated: false },
+        ListedIdentifier { id: "JSON", deprecated: false },
+        ListedIdentifier { id: "Kastrup", deprecated: false },
+        ListedIdentifier { id: "Kazlib", deprecated: false },
+        ListedIdentifier { id: "Knuth-CTAN", deprecated: false },
+        ListedIdentifier { id: "LAL-1.2", deprecated: false },
+        ListedIdentifier { id: "LAL-1.3", deprecated: false },
+        ListedIdentifier { id: "Latex2e", deprecated: false },
+        ListedIdentifier { id: "Latex2e-translated-notice", deprecated: false },
+        ListedIdentifier { id: "Leptonica", deprecated: false },
+        ListedIdentifier { id: "LGPL-2.0", deprecated: true },
+        ListedIdentifier { id: "LGPL-2.0+", deprecated: true },
+        ListedIdentifier { id: "LGPL-2.0-only", deprecated: false },
+        ListedIdentifier { id: "LGPL-2.0-or-later", deprecated: false },
+        ListedIdentifier { id: "LGPL-2.1", deprecated: true },
+        ListedIdentifier { id: "LGPL-2.1+", deprecated: true },
+        ListedIdentifier { id: "LGPL-2.1-only", deprecated: false },
+        ListedIdentifier { id: "LGPL-2.1-or-later", deprecated: false },
+        ListedIdentifier { id: "LGPL-3.0", deprecated: true },
+        ListedIdentifier { id: "LGPL-3.0+", deprecated: true },
+        ListedIdentifier { id: "LGPL-3.0-only", deprecated: false },
+        ListedIdentifier { id: "LGPL-3.0-or-later", deprecated: false },
+        ListedIdentifier { id: "LGPLLR", deprecated: false },
+        ListedIdentifier { id: "Libpng", deprecated: false },
+        ListedIdentifier { id: "libpng-1.6.35", deprecated: false },
+        ListedIdentifier { id: "libpng-2.0", deprecated: false },
+        ListedIdentifier { id: "libselinux-1.0", deprecated: false },
+        ListedIdentifier { id: "libtiff", deprecated: false },
+        ListedIdentifier { id: "libutil-David-Nugent", deprecated: false },
+        ListedIdentifier { id: "LiLiQ-P-1.1", deprecated: false },
+        ListedIdentifier { id: "LiLiQ-R-1.1", deprecated: false },
+        ListedIdentifier { id: "LiLiQ-Rplus-1.1", deprecated: false },
+        ListedIdentifier { id: "Linux-man-pages-1-para", deprecated: false },
+        ListedIdentifier { id: "Linux-man-pages-copyleft", deprecated: false },
+        ListedIdentifier { id: "Linux-man-pages-copyleft-2-para", deprecated: false },
+        ListedIdentifier { id: "Linux-man-pages-copyleft-var", deprecated: false },
+        ListedIdentifier { id: "Linux-OpenIB", deprecated: false },
+        ListedIdentifier { id: "LOOP", deprecated: false },
+        ListedIdentifier { id: "LPD-document", deprecated: false },
+        ListedIdentifier { id: "LPL-1.0", deprecated: false },
+        ListedIdentifier { id: "LPL-1.02", deprecated: false },
+        ListedIdentifier { id: "LPPL-1.0", deprecated: false },
+        ListedIdentifier { id: "LPPL-1.1", deprecated: false },
+        ListedIdentifier { id: "LPPL-1.2", deprecated: false },
+        ListedIdentifier { id: "LPPL-1.3a", deprecated: false },
+        ListedIdentifier { id: "LPPL-1.3c", deprecated: false },
+        ListedIdentifier { id: "lsof", deprecated: false },
+        ListedIdentifier { id: "Lucida-Bitmap-Fonts", deprecated: false },
+        ListedIdentifier { id: "LZMA-SDK-9.11-to-9.20", deprecated: false },
+        ListedIdentifier { id: "LZMA-SDK-9.22", deprecated: false },
+        ListedIdentifier { id: "Mackerras-3-Clause", deprecated: false },
+        ListedIdentifier { id: "Mackerras-3-Clause-acknowledgment", deprecated: false },
+        ListedIdentifier { id: "magaz", deprecated: false },
+        ListedIdentifier { id: "mailprio", deprecated: false },
+        ListedIdentifier { id: "MakeIndex", deprecated: false },
+        ListedIdentifier { id: "man2html", deprecated: false },
+        ListedIdentifier { id: "Martin-Birgmeier", deprecated: false },
+        ListedIdentifier { id: "McPhee-slideshow", deprecated: false },
+        ListedIdentifier { id: "metamail", deprecated: false },
+        ListedIdentifier { id: "Minpack", deprecated: false },
+        ListedIdentifier { id: "MIPS", deprecated: false },
+        ListedIdentifier { id: "MirOS", deprecated: false },
+        ListedIdentifier { id: "MIT", deprecated: false },
+        ListedIdentifier { id: "MIT-0", deprecated: false },
+        ListedIdentifier { id: "MIT-advertising", deprecated: false },
+        ListedIdentifier { id: "MIT-Click", deprecated: false },
+        ListedIdentifier { id: "MIT-CMU", deprecated: false },
+        ListedIdentifier { id: "MIT-enna", deprecated: false },
+        ListedIdentifier { id: "MIT-feh", deprecated: false },
+        ListedIdentifier { id: "MIT-Festival", deprecated: false },
+        ListedIdentifier { id: "MIT-Khronos-old", deprecated: false },
+        ListedIdentifier { id: "MIT-Modern-Variant", deprecated: false },
+        ListedIdentifier { id: "MIT-open-group", deprecated: false },
+        ListedIdentifier { id: "MIT-testregex", deprecated: false },
+        ListedIdentifier { id: "MIT-Wu", deprecated: false },
+        ListedIdentifier { id: "MITNFA", deprecated: false },
+        ListedIdentifier { id: "MMIXware", deprecated: false },
+        ListedIdentifier { id: "Motosoto", deprecated: false },
+        ListedIdentifier { id: "MPEG-SSG", deprecated: false },
+        ListedIdentifier { id: "mpi-permissive", deprecated: false },
+        ListedIdentifier { id: "mpich2", deprecated: false },
+        ListedIdentifier { id: "MPL-1.0", deprecated: false },
+        ListedIdentifier { id: "MPL-1.1", deprecated: false },
+        ListedIdentifier { id: "MPL-2.0", deprecated: false },
+        ListedIdentifier { id: "MPL-2.0-no-copyleft-exception", deprecated: false },
+        ListedIdentifier { id: "mplus", deprecated: false },
+        ListedIdentifier { id: "MS-LPL", deprecated: false },
+        ListedIdentifier { id: "MS-PL", deprecated: false },
+        ListedIdentifier { id: "MS-RL", deprecated: false },
+        ListedIdentifier { id: "MTLL", deprecated: false },
+        ListedIdentifier { id: "MulanPSL-1.0", deprecated: false },
+        ListedIdentifier { id: "MulanPSL-2.0", deprecated: false },
+        ListedIdentifier { id: "Multics", deprecated: false },
+        ListedIdentifier { id: "Mup", deprecated: false },
+        ListedIdentifier { id: "NAIST-2003", deprecated: false },
+        ListedIdentifier { id: "NASA-1.3", deprecated: false },
+        ListedIdentifier { id: "Naumen", deprecated: false },
+        ListedIdentifier { id: "NBPL-1.0", deprecated: false },
+        ListedIdentifier { id: "NCBI-PD", deprecated: false },
+        ListedIdentifier { id: "NCGL-UK-2.0", deprecated: false },
+        ListedIdentifier { id: "NCL", deprecated: false },
+        ListedIdentifier { id: "NCSA", deprecated: false },
+        ListedIdentifier { id: "Net-SNMP", deprecated: true },
+        ListedIdentifier { id: "NetCDF", deprecated: false },
+        ListedIdentifier { id: "Newsletr", deprecated: false },
+        ListedIdentifier { id: "NGPL", deprecated: false },
+        ListedIdentifier { id: "ngrep", deprecated: false },
+        ListedIdentifier { id: "NICTA-1.0", deprecated: false },
+        ListedIdentifier { id: "NIST-PD", deprecated: false },
+        ListedIdentifier { id: "NIST-PD-fallback", deprecated: false },
+        ListedIdentifier { id: "NIST-Software", deprecated: false },
+        ListedIdentifier { id: "NLOD-1.0", deprecated: false },
+        ListedIdentifier { id: "NLOD-2.0", deprecated: false },
+        ListedIdentifier { id: "NLPL", deprecated: false },
+        ListedIdentifier { id: "NOASSERTION", deprecated: false },
+        ListedIdentifier { id: "Nokia", deprecated: false },
+        ListedIdentifier { id: "NOSL", deprecated: false },
+        ListedIdentifier { id: "Noweb", deprecated: false },
+        ListedIdentifier { id: "NPL-1.0", deprecated: false },
+        ListedIdentifier { id: "NPL-1.1", deprecated: false },
+        ListedIdentifier { id: "NPOSL-3.0", deprecated: false },
+        ListedIdentifier { id: "NRL", deprecated: false },
+        ListedIdentifier { id: "NTIA-PD", deprecated: false },
+        ListedIdentifier { id: "NTP", deprecated: false },
+        ListedIdentifier { id: "NTP-0", deprecated: false },
+        ListedIdentifier { id: "Nunit", deprecated: true },
+        ListedIdentifier { id: "O-UDA-1.0", deprecated: false },
+        ListedIdentifier { id: "OAR", deprecated: false },
+        ListedIdentifier { id: "OCCT-PL", deprecated: false },
+        ListedIdentifier { id: "OCLC-2.0", deprecated: false },
+        ListedIdentifier { id: "ODbL-1.0", deprecated: false },
+        ListedIdentifier { id: "ODC-By-1.0", deprecated: false },
+        ListedIdentifier { id: "OFFIS", deprecated: false },
+        ListedIdentifier { id: "OFL-1.0", deprecated: false },
+        ListedIdentifier { id: "OFL-1.0-no-RFN", deprecated: false },
+        ListedIdentifier { id: "OFL-1.0-RFN", deprecated: false },
+        ListedIdentifier { id: "OFL-1.1", deprecated: false },
+        ListedIdentifier { id: "OFL-1.1-no-RFN", deprecated: false },
+        ListedIdentifier { id: "OFL-1.1-RFN", deprecated: false },
+        ListedIdentifier { id: "OGC-1.0", deprecated: false },
+        ListedIdentifier { id: "OGDL-Taiwan-1.0", deprecated: false },
+        ListedIdentifier { id: "OGL-Canada-2.0", deprecated: false },
+        ListedIdentifier { id: "OGL-UK-1.0", deprecated: false },
+        ListedIdentifier { id: "OGL-UK-2.0", deprecated: false },
+        ListedIdentifier { id: "OGL-UK-3.0", deprecated: false },
+        ListedIdentifier { id: "OGTSL", deprecated: false },
+        ListedIdentifier { id: "OLDAP-1.1", deprecated: false },
+        ListedIdentifier { id: "OLDAP-1.2", deprecated: false },
+        ListedIdentifier { id: "OLDAP-1.3", deprecated: false },
+        ListedIdentifier { id: "OLDAP-1.4", deprecated: false },
+        ListedIdentifier { id: "OLDAP-2.0", deprecated: false },
+        ListedIdentifier { id: "OLDAP-2.0.1", deprecated: false },
+        ListedIdentifier { id: "OLDAP-2.1", deprecated: false },
+        ListedIdentifier { id: "OLDAP-2.2", deprecated: false },
+        ListedIdentifier { id: "OLDAP-2.2.1", deprecated: false },
+        ListedIdentifier { id: "OLDAP-2.2.2", deprecated: false },
+        ListedIdentifier { id: "OLDAP-2.3", deprecated: false },
+        ListedIdentifier { id: "OLDAP-2.4", deprecated: false },
+        ListedIdentifier { id: "OLDAP-2.5", deprecated: false },
+        ListedIdentifier { id: "OLDAP-2.6", deprecated: false },
+        ListedIdentifier { id: "OLDAP-2.7", deprecated: false },
+        ListedIdentifier { id: "OLDAP-2.8", deprecated: false },
+        ListedIdentifier { id: "OLFL-1.3", deprecated: false },
+        ListedIdentifier { id: "OML", deprecated: false },
+        ListedIdentifier { id: "OpenPBS-2.3", deprecated: false },
+        ListedIdentifier { id: "OpenSSL", deprecated: false },
+        ListedIdentifier { id: "OpenSSL-standalone", deprecated: false },
+        ListedIdentifier { id: "OpenVision", deprecated: false },
+        ListedIdentifier { id: "OPL-1.0", deprecated: false },
+        ListedIdentifier { id: "OPL-UK-3.0", deprecated: false },
+        ListedIdentifier { id: "OPUBL-1.0", deprecated: false },
+        ListedIdentifier { id: "OSET-PL-2.1", deprecated: false },
+        ListedIdentifier { id: "OSL-1.0", deprecated: false },
+        ListedIdentifier { id: "OSL-1.1", deprecated: false },
+        ListedIdentifier { id: "OSL-2.0", deprecated: false },
+        ListedIdentifier { id: "OSL-2.1", deprecated: false },
+        ListedIdentifier { id: "OSL-3.0", deprecated: false },
+        ListedIdentifier { id: "PADL", deprecated: false },
+        ListedIdentifier { id: "Parity-6.0.0", deprecated: false },
+        ListedIdentifier { id: "Parity-7.0.0", deprecated: false },
+        ListedIdentifier { id: "PDDL-1.0", deprecated: false },
+        ListedIdentifier { id: "PHP-3.0", deprecated: false },
+        ListedIdentifier { id: "PHP-3.01", deprecated: false },
+        ListedIdentifier { id: "Pixar", deprecated: false },
+        ListedIdentifier { id: "pkgconf", deprecated: false },
+        ListedIdentifier { id: "Plexus", deprecated: false },
+        ListedIdentifier { id: "pnmstitch", deprecated: false },
+        ListedIdentifier { id: "PolyForm-Noncommercial-1.0.0", deprecated: false },
+        ListedIdentifier { id: "PolyForm-Small-Business-1.0.0", deprecated: false },
+        ListedIdentifier { id: "PostgreSQL", deprecated: false },
+        ListedIdentifier { id: "PPL", deprecated: false },
+        ListedIdentifier { id: "PSF-2.0", deprecated: false },
+        ListedIdentifier { id: "psfrag", deprecated: false },
+        ListedIdentifier { id: "psutils", deprecated: false },
+        ListedIdentifier { id: "Python-2.0", deprecated: false },
+        ListedIdentifier { id: "Python-2.0.1", deprecated: false },
+        ListedIdentifier { id: "python-ldap", deprecated: false },
+        ListedIdentifier { id: "Qhull", deprecated: false },
+        ListedIdentifier { id: "QPL-1.0", deprecated: false },
+        ListedIdentifier { id: "QPL-1.0-INRIA-2004", deprecated: false },
+        ListedIdentifier { id: "radvd", deprecated: false },
+        ListedIdentifier { id: "Rdisc", deprecated: false },
+        ListedIdentifier { id: "RHeCos-1.1", deprecated: false },
+        ListedIdentifier { id: "RPL-1.1", deprecated: false },
+        ListedIdentifier { id: "RPL-1.5", deprecated: false },
+        ListedIdentifier { id: "RPSL-1.0", deprecated: false },
+        ListedIdentifier { id: "RSA-MD", deprecated: false },
+        ListedIdentifier { id: "RSCPL", deprecated: false },
+        ListedIdentifier { id: "Ruby", deprecated: false },
+        ListedIdentifier { id: "Ruby-pty", deprecated: false },
+        ListedIdentifier { id: "SAX-PD", deprecated: false },
+        ListedIdentifier { id: "SAX-PD-2.0", deprecated: false },
+        ListedIdentifier { id: "Saxpath", deprecated: false },
+        ListedIdentifier { id: "SCEA", deprecated: false },
+        ListedIdentifier { id: "SchemeReport", deprecated: false },
+        ListedIdentifier { id: "Sendmail", deprecated: false },
+        ListedIdentifier { id: "Sendmail-8.23", deprecated: false },
+        ListedIdentifier { id: "Sendmail-Open-Source-1.1", deprecated: false },
+        ListedIdentifier { id: "SGI-B-1.0", deprecated: false },
+        ListedIdentifier { id: "SGI-B-1.1", deprecated: false },
+        ListedIdentifier { id: "SGI-B-2.0", deprecated: false },
+        ListedIdentifier { id: "SGI-OpenGL", deprecated: false },
+        ListedIdentifier { id: "SGP4", deprecated: false },
+        ListedIdentifier { id: "SHL-0.5", deprecated: false },
+        ListedIdentifier { id: "SHL-0.51", deprecated: false },
+        ListedIdentifier { id: "SimPL-2.0", deprecated: false },
+        ListedIdentifier { id: "SISSL", deprecated: false },
+        ListedIdentifier { id: "SISSL-1.2", deprecated: false },
+        ListedIdentifier { id: "SL", deprecated: false },
+        ListedIdentifier { id: "Sleepycat", deprecated: false },
+        ListedIdentifier { id: "SMAIL-GPL", deprecated: false },
+        ListedIdentifier { id: "SMLNJ", deprecated: false },
+        ListedIdentifier { id: "SMPPL", deprecated: false },
+        ListedIdentifier { id: "SNIA", deprecated: false },
+        ListedIdentifier { id: "snprintf", deprecated: false },
+        ListedIdentifier { id: "SOFA", deprecated: false },
+        ListedIdentifier { id: "softSurfer", deprecated: false },
+        ListedIdentifier { id: "Soundex", deprecated: false },
+        ListedIdentifier { id: "Spencer-86", deprecated: false },
+        ListedIdentifier { id: "Spencer-94", deprecated: false },
+        ListedIdentifier { id: "Spencer-99", deprecated: false },
+        ListedIdentifier { id: "SPL-1.0", deprecated: false },
+        ListedIdentifier { id: "ssh-keyscan", deprecated: false },
+        ListedIdentifier { id: "SSH-OpenSSH", deprecated: false },
+        ListedIdentifier { id: "SSH-short", deprecated: false },
+        ListedIdentifier { id: "SSLeay-standalone", deprecated: false },
+        ListedIdentifier { id: "SSPL-1.0", deprecated: false },
+        ListedIdentifier { id: "StandardML-NJ", deprecated: true },
+        ListedIdentifier { id: "SugarCRM-1.1.3", deprecated: false },
+        ListedIdentifier { id: "SUL-1.0", deprecated: false },
+        ListedIdentifier { id: "Sun-PPP", deprecated: false },
+        ListedIdentifier { id: "Sun-PPP-2000", deprecated: false },
+        ListedIdentifier { id: "SunPro", deprecated: false },
+        ListedIdentifier { id: "SWL", deprecated: false },
+        ListedIdentifier { id: "swrule", deprecated: false },
+        ListedIdentifier { id: "Symlinks", deprecated: false },
+        ListedIdentifier { id: "TAPR-OHL-1.0", deprecated: false },
+        ListedIdentifier { id: "TCL", deprecated: false },
+        ListedIdentifier { id: "TCP-wrappers", deprecated: false },
+        ListedIdentifier { id: "TermReadKey", deprecated: false },
+        ListedIdentifier { id: "TGPPL-1.0", deprecated: false },
+        ListedIdentifier { id: "ThirdEye", deprecated: false },
+        ListedIdentifier { id: "threeparttable", deprecated: false },
+        ListedIdentifier { id: "TMate", deprecated: false },
+        ListedIdentifier { id: "TORQUE-1.1", deprecated: false },
+        ListedIdentifier { id: "TOSL", deprecated: false },
+        ListedIdentifier { id: "TPDL", deprecated: false },
+        ListedIdentifier { id: "TPL-1.0", deprecated: false },
+        ListedIdentifier { id: "TrustedQSL", deprecated: false },
+        ListedIdentifier { id: "TTWL", deprecated: false },
+        ListedIdentifier { id: "TTYP0", deprecated: false },
+        ListedIdentifier { id: "TU-Berlin-1.0", deprecated: false },
+        ListedIdentifier { id: "TU-Berlin-2.0", deprecated: false },
+        ListedIdentifier { id: "Ubuntu-font-1.0", deprecated: false },
+        ListedIdentifier { id: "UCAR", deprecated: false },
+        ListedIdentifier { id: "UCL-1.0", deprecated: false },
+        ListedIdentifier { id: "ulem", deprecated: false },
+        ListedIdentifier { id: "UMich-Merit", deprecated: false },
+        ListedIdentifier { id: "Unicode-3.0", deprecated: false },
+        ListedIdentifier { id: "Unicode-DFS-2015", deprecated: false },
+        ListedIdentifier { id: "Unicode-DFS-2016", deprecated: false },
+        ListedIdentifier { id: "Unicode-TOU", deprecated: false },
+        ListedIdentifier { id: "UnixCrypt", deprecated: false },
+        ListedIdentifier { id: "Unlicense", deprecated: false },
+        ListedIdentifier { id: "Unlicense-libtelnet", deprecated: false },
+        ListedIdentifier { id: "Unlicense-libwhirlpool", deprecated: false },
+        ListedIdentifier { id: "UPL-1.0", deprecated: false },
+        ListedIdentifier { id: "URT-RLE", deprecated: false },
+        ListedIdentifier { id: "Vim", deprecated: false },
+        ListedIdentifier { id: "VOSTROM", deprecated: false },
+        ListedIdentifier { id: "VSL-1.0", deprecated: false },
+        ListedIdentifier { id: "W3C", deprecated: false },
+        ListedIdentifier { id: "W3C-19980720", deprecated: false },
+        ListedIdentifier { id: "W3C-20150513", deprecated: false },
+        ListedIdentifier { id: "w3m", deprecated: false },
+        ListedIdentifier { id: "Watcom-1.0", deprecated: false },
+        ListedIdentifier { id: "Widget-Workshop", deprecated: false },
+        ListedIdentifier { id: "Wsuipa", deprecated: false },
+        ListedIdentifier { id: "WTFPL", deprecated: false },
+        ListedIdentifier { id: "wwl", deprecated: false },
+        ListedIdentifier { id: "wxWindows", deprecated: true },
+        ListedIdentifier { id: "X11", deprecated: false },
+        ListedIdentifier { id: "X11-distribute-modifications-variant", deprecated: false },
+        ListedIdentifier { id: "X11-swapped", deprecated: false },
+        ListedIdentifier { id: "Xdebug-1.03", deprecated: false },
+        ListedIdentifier { id: "Xerox", deprecated: false },
+        ListedIdentifier { id: "Xfig", deprecated: false },
+        ListedIdentifier { id: "XFree86-1.1", deprecated: false },
+        ListedIdentifier { id: "xinetd", deprecated: false },
+        ListedIdentifier { id: "xkeyboard-config-Zinoviev", deprecated: false },
+        ListedIdentifier { id: "xlock", deprecated: false },
+        ListedIdentifier { id: "Xnet", deprecated: false },
+        ListedIdentifier { id: "xpp", deprecated: false },
+        ListedIdentifier { id: "XSkat", deprecated: false },
+        ListedIdentifier { id: "xzoom", deprecated: false },
+        ListedIdentifier { id: "YPL-1.0", deprecated: false },
+        ListedIdentifier { id: "YPL-1.1", deprecated: false },
+        ListedIdentifier { id: "Zed", deprecated: false },
+        ListedIdentifier { id: "Zeeff", deprecated: false },
+        ListedIdentifier { id: "Zend-2.0", deprecated: false },
+        ListedIdentifier { id: "Zimbra-1.3", deprecated: false },
+        ListedIdentifier { id: "Zimbra-1.4", deprecated: false },
+        ListedIdentifier { id: "Zlib", deprecated: false },
+        ListedIdentifier { id: "zlib-acknowledgement", deprecated: false },
+        ListedIdentifier { id: "ZPL-1.1", deprecated: false },
+        ListedIdentifier { id: "ZPL-2.0", deprecated: false },
+        ListedIdentifier { id: "ZPL-2.1", deprecated: false },
+    ],
+    exceptions: &[
+        ListedIdentifier { id: "389-exception", deprecated: false },
+        ListedIdentifier { id: "Asterisk-exception", deprecated: false },
+        ListedIdentifier { id: "Asterisk-linking-protocols-exception", deprecated: false },
+        ListedIdentifier { id: "Autoconf-exception-2.0", deprecated: false },
+        ListedIdentifier { id: "Autoconf-exception-3.0", deprecated: false },
+        ListedIdentifier { id: "Autoconf-exception-generic", deprecated: false },
+        ListedIdentifier { id: "Autoconf-exception-generic-3.0", deprecated: false },
+        ListedIdentifier { id: "Autoconf-exception-macro", deprecated: false },
+        ListedIdentifier { id: "Bison-exception-1.24", deprecated: false },
+        ListedIdentifier { id: "Bison-exception-2.2", deprecated: false },
+        ListedIdentifier { id: "Bootloader-exception", deprecated: false },
+        ListedIdentifier { id: "CGAL-linking-exception", deprecated: false },
+        ListedIdentifier { id: "Classpath-exception-2.0", deprecated: false },
+        ListedIdentifier { id: "CLISP-exception-2.0", deprecated: false },
+        ListedIdentifier { id: "cryptsetup-OpenSSL-exception", deprecated: false },
+        ListedIdentifier { id: "Digia-Qt-LGPL-exception-1.1", deprecated: false },
+        ListedIdentifier { id: "DigiRule-FOSS-exception", deprecated: false },
+        ListedIdentifier { id: "eCos-exception-2.0", deprecated: false },
+        ListedIdentifier { id: "erlang-otp-linking-exception", deprecated: false },
+        ListedIdentifier { id: "Fawkes-Runtime-exception", deprecated: false },
+        ListedIdentifier { id: "FLTK-exception", deprecated: false },
+        ListedIdentifier { id: "fmt-exception", deprecated: false },
+        ListedIdentifier { id: "Font-exception-2.0", deprecated: false },
+        ListedIdentifier { id: "freertos-exception-2.0", deprecated: false },
+        ListedIdentifier { id: "GCC-exception-2.0", deprecated: false },
+        ListedIdentifier { id: "GCC-exception-2.0-note", deprecated: false },
+        ListedIdentifier { id: "GCC-exception-3.1", deprecated: false },
+        ListedIdentifier { id: "Gmsh-exception", deprecated: false },
+        ListedIdentifier { id: "GNAT-exception", deprecated: false },
+        ListedIdentifier { id: "GNOME-examples-exception", deprecated: false },
+        ListedIdentifier { id: "GNU-compiler-exception", deprecated: false },
+        ListedIdentifier { id: "gnu-javamail-exception", deprecated: false },
+        ListedIdentifier { id: "GPL-3.0-389-ds-base-exception", deprecated: false },
+        ListedIdentifier { id: "GPL-3.0-interface-exception", deprecated: false },
+        ListedIdentifier { id: "GPL-3.0-linking-exception", deprecated: false },
+        ListedIdentifier { id: "GPL-3.0-linking-source-exception", deprecated: false },
+        ListedIdentifier { id: "GPL-CC-1.0", deprecated: false },
+        ListedIdentifier { id: "GStreamer-exception-2005", deprecated: false },
+        ListedIdentifier { id: "GStreamer-exception-2008", deprecated: false },
+        ListedIdentifier { id: "harbour-exception", deprecated: false },
+        ListedIdentifier { id: "i2p-gpl-java-exception", deprecated: false },
+        ListedIdentifier { id: "Independent-modules-exception", deprecated: false },
+        ListedIdentifier { id: "KiCad-libraries-exception", deprecated: false },
+        ListedIdentifier { id: "LGPL-3.0-linking-exception", deprecated: false },
+        ListedIdentifier { id: "libpri-OpenH323-exception", deprecated: false },
+        ListedIdentifier { id: "Libtool-exception", deprecated: false },
+        ListedIdentifier { id: "Linux-syscall-note", deprecated: false },
+        ListedIdentifier { id: "LLGPL", deprecated: false },
+        ListedIdentifier { id: "LLVM-exception", deprecated: false },
+        ListedIdentifier { id: "LZMA-exception", deprecated: false },
+        ListedIdentifier { id: "mif-exception", deprecated: false },
+        ListedIdentifier { id: "mxml-exception", deprecated: false },
+        ListedIdentifier { id: "Nokia-Qt-exception-1.1", deprecated: true },
+        ListedIdentifier { id: "OCaml-LGPL-linking-exception", deprecated: false },
+        ListedIdentifier { id: "OCCT-exception-1.0", deprecated: false },
+        ListedIdentifier { id: "OpenJDK-assembly-exception-1.0", deprecated: false },
+        ListedIdentifier { id: "openvpn-openssl-exception", deprecated: false },
+        ListedIdentifier { id: "PCRE2-exception", deprecated: false },
+        ListedIdentifier { id: "polyparse-exception", deprecated: false },
+        ListedIdentifier { id: "PS-or-PDF-font-exception-20170817", deprecated: false },
+        ListedIdentifier { id: "QPL-1.0-INRIA-2004-exception", deprecated: false },
+        ListedIdentifier { id: "Qt-GPL-exception-1.0", deprecated: false },
+        ListedIdentifier { id: "Qt-LGPL-exception-1.1", deprecated: false },
+        ListedIdentifier { id: "Qwt-exception-1.0", deprecated: false },
+        ListedIdentifier { id: "romic-exception", deprecated: false },
+        ListedIdentifier { id: "RRDtool-FLOSS-exception-2.0", deprecated: false },
+        ListedIdentifier { id: "SANE-exception", deprecated: false },
+        ListedIdentifier { id: "SHL-2.0", deprecated: false },
+        ListedIdentifier { id: "SHL-2.1", deprecated: false },
+        ListedIdentifier { id: "stunnel-exception", deprecated: false },
+        ListedIdentifier { id: "SWI-exception", deprecated: false },
+        ListedIdentifier { id: "Swift-exception", deprecated: false },
+        ListedIdentifier { id: "Texinfo-exception", deprecated: false },
+        ListedIdentifier { id: "u-boot-exception-2.0", deprecated: false },
+        ListedIdentifier { id: "UBDL-exception", deprecated: false },
+        ListedIdentifier { id: "Universal-FOSS-exception-1.0", deprecated: false },
+        ListedIdentifier { id: "vsftpd-openssl-exception", deprecated: false },
+        ListedIdentifier { id: "WxWindows-exception-3.1", deprecated: false },
+        ListedIdentifier { id: "x11vnc-openssl-exception", deprecated: false },
+    ],
+});
@@ -0,0 +1,71 @@
+//! SPDX license expressions (`/document/license_expression` in CSAF 2.1).
+//!
+//! [SpdxExpression] parses the syntax of a license expression according to annex D of the SPDX
+//! specification. [LicenseList] is the SPDX license list embedded at build time, which is
+//! generated by `build.rs` from the vendored data in `src/csaf/license/data`.
+
+mod expression;
+mod list;
+
+pub use expression::{SpdxExpression, SpdxExpressionError};
+
+/// A license or exception identifier of the SPDX license list.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ListedIdentifier {
+    /// The identifier in its canonical case, e.g. `Apache-2.0`
+    pub id: &'static str,
+    /// Whether the identifier is deprecated and should no longer be used
+    pub deprecated: bool,
+}
+
+/// The license and exception identifiers of a version of the SPDX license list, each sorted
+/// case-insensitively.
+#[derive(Debug)]
+pub struct LicenseList {
+    /// The version of the license list, e.g. `3.27.0`
+    pub version: &'static str,
+    pub licenses: &'static [ListedIdentifier],
+    pub exceptions: &'static [ListedIdentifier],
+}
+
+impl LicenseList {
+    /// Returns the license list embedded at build time, if it was vendored.
+    pub fn embedded() -> Option<&'static LicenseList> {
+        list::LICENSE_LIST.as_ref()
+    }
+
+    /// Looks up a license identifier, which is matched case-insensitively.
+    pub fn license(&self, id: &str) -> Option<&'static ListedIdentifier> {
+        Self::find(self.licenses, id)
+    }
+
+    /// Looks up a license exception identifier, which is matched case-insensitively.
+    pub fn exception(&self, id: &str) -> Option<&'static ListedIdentifier> {
+        Self::find(self.exceptions, id)
+    }
+
+    fn find(identifiers: &'static [ListedIdentifier], id: &str) -> Option<&'static ListedIdentifier> {
+        let lower_case = |s: &str| s.bytes().map(|b| b.to_ascii_lowercase()).collect::<Vec<u8>>();
+        let id = lower_case(id);
+        identifiers
+            .binary_search_by(|listed| lower_case(listed.id).cmp(&id))
+            .ok()
+            .map(|i| &identifiers[i])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::license::LicenseList;
+
+    #[test]
+    fn test_embedded_list() {
+        let list = LicenseList::embedded().expect("the SPDX license list is vendored");
+        assert_eq!(Some("Apache-2.0"), list.license("apache-2.0").map(|l| l.id));
+        assert_eq!(Some(false), list.license("MIT").map(|l| l.deprecated));
+        assert_eq!(Some(true), list.license("GPL-2.0").map(|l| l.deprecated));
+        assert_eq!(None, list.license("Example-License"));
+        assert_eq!(Some("Classpath-exception-2.0"), list.exception("classpath-exception-2.0").map(|e| e.id));
+        assert_eq!(None, list.exception("MIT"));
+    }
+}
@@ -0,0 +1,27 @@
+use crate::csaf::document::{CsafVersion, VersionedCsafDocument};
+use serde_json::Value;
+use std::io::{Error, ErrorKind};
+use std::{fs::File, io::BufReader};
+
+/// Reads the raw JSON of the document at the given path.
+pub fn load_json(path: &str) -> std::io::Result<Value> {
+    let f = File::open(path)?;
+    let reader = BufReader::new(f);
+    Ok(serde_json::from_reader(reader)?)
+}
+
+/// Returns the given version, or else the version detected from the document.
+pub fn resolve_version(json: &Value, version: Option<CsafVersion>) -> std::io::Result<CsafVersion> {
+    match version {
+        Some(version) => Ok(version),
+        None => CsafVersion::detect(json).map_err(|err| Error::new(ErrorKind::InvalidData, err)),
+    }
+}
+
+/// Loads the document at the given path as a document of its CSAF version. The version is
+/// detected from the document, unless it is explicitly given.
+pub fn load_document(path: &str, version: Option<CsafVersion>) -> std::io::Result<VersionedCsafDocument> {
+    let json = load_json(path)?;
+    let version = resolve_version(&json, version)?;
+    Ok(VersionedCsafDocument::from_json(json, version)?)
+}
@@ -1,8 +1,19 @@
 pub mod csaf2_0;
 pub mod csaf2_1;
+pub mod cvss;
+pub mod cwe;
+pub mod document;
+pub mod document_version;
 pub mod helpers;
+pub mod language;
+pub mod license;
+pub mod loader;
 pub mod product_helpers;
+pub mod profile;
+pub mod registry;
+pub mod timeline;
 pub mod validation;
+pub mod vers;
 pub mod getter_traits;
 pub mod validations;
 pub mod test_helper;
\ No newline at end of file
@@ -1,4 +1,4 @@
-use crate::csaf::getter_traits::{CsafTrait, MetricTrait, ProductGroupTrait, ProductStatusTrait, ProductTreeTrait, RelationshipTrait, RemediationTrait, ThreatTrait, VulnerabilityTrait};
+use crate::csaf::getter_traits::{CsafTrait, FirstKnownExploitationDateTrait, FlagTrait, InvolvementTrait, MetricTrait, ProductGroupTrait, ProductStatusTrait, ProductTreeTrait, RelationshipTrait, RemediationTrait, ThreatTrait, VulnerabilityTrait};
 
 pub fn gather_product_references(doc: &impl CsafTrait) -> Vec<(String, String)> {
     let mut ids = Vec::<(String, String)>::new();
@@ -79,11 +79,11 @@ pub fn gather_product_references(doc: &impl CsafTrait) -> Vec<(String, String)>
             }
         }
 
-        // /vulnerabilities[]/metrics[]/products[]
+        // /vulnerabilities[]/metrics[]/products[] (/vulnerabilities[]/scores[]/products[] in CSAF 2.0)
         if let Some(metrics) = v.get_metrics().as_ref() {
             for (metric_i, metric) in metrics.iter().enumerate() {
                 for (x_i, x) in metric.get_products().enumerate() {
-                    ids.push(((*x).to_owned(), format!("/vulnerabilities/{}/metrics/{}/products/{}", v_i, metric_i, x_i)));
+                    ids.push(((*x).to_owned(), format!("{}/products/{}", metric.get_metric_json_path(v_i, metric_i), x_i)));
                 }
             }
         }
@@ -96,7 +96,49 @@ pub fn gather_product_references(doc: &impl CsafTrait) -> Vec<(String, String)>
                 }
             }
         }
+
+        // /vulnerabilities[]/flags[]/product_ids[]
+        for (flag_i, flag) in v.get_flags().iter().flatten().enumerate() {
+            if let Some(product_ids) = flag.get_product_ids() {
+                for (x_i, x) in product_ids.enumerate() {
+                    ids.push(((*x).to_owned(), format!("/vulnerabilities/{}/flags/{}/product_ids/{}", v_i, flag_i, x_i)));
+                }
+            }
+        }
+
+        // /vulnerabilities[]/involvements[]/product_ids[] (CSAF 2.1)
+        for (involvement_i, involvement) in v.get_involvements().iter().flatten().enumerate() {
+            if let Some(product_ids) = involvement.get_product_ids() {
+                for (x_i, x) in product_ids.enumerate() {
+                    ids.push(((*x).to_owned(), format!("/vulnerabilities/{}/involvements/{}/product_ids/{}", v_i, involvement_i, x_i)));
+                }
+            }
+        }
+
+        // /vulnerabilities[]/first_known_exploitation_dates[]/product_ids[] (CSAF 2.1)
+        for (date_i, date) in v.get_first_known_exploitation_dates().enumerate() {
+            if let Some(product_ids) = date.get_product_ids() {
+                for (x_i, x) in product_ids.enumerate() {
+                    ids.push(((*x).to_owned(), format!("/vulnerabilities/{}/first_known_exploitation_dates/{}/product_ids/{}", v_i, date_i, x_i)));
+                }
+            }
+        }
     }
 
     ids
 }
+
+/// Returns the affected product IDs of a vulnerability, i.e. those listed in `first_affected`,
+/// `known_affected` and `last_affected`, together with their JSON paths.
+pub fn gather_affected_products(status: &impl ProductStatusTrait, v_i: usize) -> Vec<(String, String)> {
+    let mut ids = Vec::<(String, String)>::new();
+    let mut push = |group: &str, product_ids: Option<Vec<&String>>| {
+        for (x_i, x) in product_ids.into_iter().flatten().enumerate() {
+            ids.push(((*x).to_owned(), format!("/vulnerabilities/{}/product_status/{}/{}", v_i, group, x_i)));
+        }
+    };
+    push("first_affected", status.get_first_affected().map(|x| x.collect()));
+    push("known_affected", status.get_known_affected().map(|x| x.collect()));
+    push("last_affected", status.get_last_affected().map(|x| x.collect()));
+    ids
+}
@@ -0,0 +1,137 @@
+//! Profiles of CSAF documents (section 4 of the standard), which are selected by the
+//! document category and impose additional requirements checked by the tests 6.1.27.x.
+
+use crate::csaf::document::{CsafDocument, CsafVersion, CsafVersioned};
+use crate::csaf::getter_traits::{CsafTrait, DocumentTrait};
+use crate::csaf::validation::{into_result, ValidationError};
+use std::fmt::{Display, Formatter};
+
+/// The profile of a document, derived from `/document/category`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum DocumentProfile {
+    /// Any category not matching one of the other profiles
+    Base,
+    SecurityIncidentResponse,
+    InformationalAdvisory,
+    SecurityAdvisory,
+    Vex,
+    /// Since CSAF 2.1
+    DeprecatedSecurityAdvisory,
+    /// Since CSAF 2.1
+    Withdrawn,
+    /// Since CSAF 2.1
+    Superseded,
+}
+
+impl DocumentProfile {
+    /// All profiles with a dedicated category.
+    pub const WITH_CATEGORY: [DocumentProfile; 7] = [
+        Self::SecurityIncidentResponse,
+        Self::InformationalAdvisory,
+        Self::SecurityAdvisory,
+        Self::Vex,
+        Self::DeprecatedSecurityAdvisory,
+        Self::Withdrawn,
+        Self::Superseded,
+    ];
+
+    /// Returns the profile of the given category in the given CSAF version. All unknown
+    /// categories and the categories of profiles not defined in this version (e.g.
+    /// `csaf_withdrawn` in CSAF 2.0) use the base profile.
+    pub fn from_category(category: &str, version: CsafVersion) -> DocumentProfile {
+        Self::WITH_CATEGORY
+            .into_iter()
+            .find(|profile| profile.category() == category && profile.is_defined_in(version))
+            .unwrap_or(Self::Base)
+    }
+
+    /// Returns the profile of the given document.
+    pub fn of<T: CsafTrait + CsafVersioned>(doc: &CsafDocument<T>) -> DocumentProfile {
+        Self::from_category(doc.get_document().get_category(), T::VERSION)
+    }
+
+    /// Returns the category which selects this profile.
+    pub fn category(&self) -> &'static str {
+        match self {
+            Self::Base => "csaf_base",
+            Self::SecurityIncidentResponse => "csaf_security_incident_response",
+            Self::InformationalAdvisory => "csaf_informational_advisory",
+            Self::SecurityAdvisory => "csaf_security_advisory",
+            Self::Vex => "csaf_vex",
+            Self::DeprecatedSecurityAdvisory => "csaf_deprecated_security_advisory",
+            Self::Withdrawn => "csaf_withdrawn",
+            Self::Superseded => "csaf_superseded",
+        }
+    }
+
+    /// Returns the title of this profile as given in the standard, e.g. `Security Advisory`.
+    pub fn title(&self) -> &'static str {
+        match self {
+            Self::Base => "CSAF Base",
+            Self::SecurityIncidentResponse => "Security incident response",
+            Self::InformationalAdvisory => "Informational Advisory",
+            Self::SecurityAdvisory => "Security Advisory",
+            Self::Vex => "VEX",
+            Self::DeprecatedSecurityAdvisory => "Deprecated Security Advisory",
+            Self::Withdrawn => "Withdrawn",
+            Self::Superseded => "Superseded",
+        }
+    }
+
+    /// Checks whether the given CSAF version defines this profile.
+    pub fn is_defined_in(&self, version: CsafVersion) -> bool {
+        match self {
+            Self::DeprecatedSecurityAdvisory | Self::Withdrawn | Self::Superseded => version >= CsafVersion::V2_1,
+            _ => true,
+        }
+    }
+}
+
+impl Display for DocumentProfile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.category())
+    }
+}
+
+/// Dispatches a profile test by the profile of the document: the check is only executed for
+/// documents of one of the given profiles, all other documents pass the test. The profile
+/// depends on the CSAF version of the document, see [DocumentProfile::from_category].
+///
+/// Each profile test lists the profiles it applies to, so that a new profile is plugged in by
+/// adding it to [DocumentProfile] and to the profile lists of the tests applying to it.
+pub fn test_profiles<T: CsafTrait + CsafVersioned>(
+    doc: &CsafDocument<T>,
+    profiles: &[DocumentProfile],
+    check: impl FnOnce(&CsafDocument<T>, DocumentProfile) -> Vec<ValidationError>,
+) -> Result<(), Vec<ValidationError>> {
+    let profile = DocumentProfile::of(doc);
+    if !profiles.contains(&profile) {
+        return Ok(());
+    }
+
+    let errors = check(doc, profile);
+    into_result(errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::document::CsafVersion;
+    use crate::csaf::profile::DocumentProfile;
+
+    #[test]
+    fn test_from_category() {
+        let from_category = |category| DocumentProfile::from_category(category, CsafVersion::V2_1);
+        assert_eq!(DocumentProfile::Vex, from_category("csaf_vex"));
+        assert_eq!(DocumentProfile::Withdrawn, from_category("csaf_withdrawn"));
+        assert_eq!(DocumentProfile::Base, from_category("csaf_base"));
+        assert_eq!(DocumentProfile::Base, from_category("Example Company Security Notice"));
+        assert_eq!(DocumentProfile::Base, from_category("CSAF_VEX"));
+
+        // Profiles introduced with CSAF 2.1 are base documents in CSAF 2.0
+        assert_eq!(DocumentProfile::Vex, DocumentProfile::from_category("csaf_vex", CsafVersion::V2_0));
+        assert_eq!(
+            DocumentProfile::Base,
+            DocumentProfile::from_category("csaf_deprecated_security_advisory", CsafVersion::V2_0)
+        );
+    }
+}
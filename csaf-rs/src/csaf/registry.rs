@@ -0,0 +1,432 @@
+use crate::csaf::csaf2_1::schema::CommonSecurityAdvisoryFramework as Csaf21;
+use crate::csaf::document::{CsafDocument, CsafVersion, CsafVersioned};
+use crate::csaf::getter_traits::CsafTrait;
+use crate::csaf::validation::{Test, ValidationPreset};
+use crate::csaf::validations::test_6_1_01::test_6_1_01_missing_definition_of_product_id;
+use crate::csaf::validations::test_6_1_02::test_6_1_02_multiple_definition_of_product_id;
+use crate::csaf::validations::test_6_1_03::test_6_1_03_circular_definition_of_product_id;
+use crate::csaf::validations::test_6_1_04::test_6_1_04_missing_definition_of_product_group_id;
+use crate::csaf::validations::test_6_1_05::test_6_1_05_multiple_definition_of_product_group_id;
+use crate::csaf::validations::test_6_1_06::test_6_1_06_contradicting_product_status;
+use crate::csaf::validations::test_6_1_07::test_6_1_07_multiple_same_scores_per_product;
+use crate::csaf::validations::test_6_1_08::test_6_1_08_invalid_cvss;
+use crate::csaf::validations::test_6_1_09::test_6_1_09_invalid_cvss_computation;
+use crate::csaf::validations::test_6_1_10::test_6_1_10_inconsistent_cvss;
+use crate::csaf::validations::test_6_1_11::test_6_1_11_cwe;
+use crate::csaf::validations::test_6_1_12::test_6_1_12_language;
+use crate::csaf::validations::test_6_1_14::test_6_1_14_sorted_revision_history;
+use crate::csaf::validations::test_6_1_15::test_6_1_15_translator;
+use crate::csaf::validations::test_6_1_16::test_6_1_16_latest_document_version;
+use crate::csaf::validations::test_6_1_17::test_6_1_17_document_status_draft;
+use crate::csaf::validations::test_6_1_18::test_6_1_18_released_revision_history;
+use crate::csaf::validations::test_6_1_19::test_6_1_19_revision_history_entries_for_pre_release_versions;
+use crate::csaf::validations::test_6_1_20::test_6_1_20_non_draft_document_version;
+use crate::csaf::validations::test_6_1_21::test_6_1_21_missing_item_in_revision_history;
+use crate::csaf::validations::test_6_1_22::test_6_1_22_multiple_definition_in_revision_history;
+use crate::csaf::validations::test_6_1_23::test_6_1_23_multiple_use_of_same_cve;
+use crate::csaf::validations::test_6_1_24::test_6_1_24_multiple_definition_in_involvements;
+use crate::csaf::validations::test_6_1_25::test_6_1_25_multiple_use_of_same_hash_algorithm;
+use crate::csaf::validations::test_6_1_26::test_6_1_26_prohibited_document_category_name;
+use crate::csaf::validations::test_6_1_27_01::test_6_1_27_01_document_notes;
+use crate::csaf::validations::test_6_1_27_02::test_6_1_27_02_document_references;
+use crate::csaf::validations::test_6_1_27_03::test_6_1_27_03_vulnerabilities;
+use crate::csaf::validations::test_6_1_27_04::test_6_1_27_04_product_tree;
+use crate::csaf::validations::test_6_1_27_05::test_6_1_27_05_vulnerability_notes;
+use crate::csaf::validations::test_6_1_27_06::test_6_1_27_06_product_status;
+use crate::csaf::validations::test_6_1_27_07::test_6_1_27_07_vex_product_status;
+use crate::csaf::validations::test_6_1_27_08::test_6_1_27_08_vulnerability_id;
+use crate::csaf::validations::test_6_1_27_09::test_6_1_27_09_impact_statement;
+use crate::csaf::validations::test_6_1_27_10::test_6_1_27_10_action_statement;
+use crate::csaf::validations::test_6_1_27_11::test_6_1_27_11_vulnerabilities;
+use crate::csaf::validations::test_6_1_27_14::test_6_1_27_14_reasoning_for_withdrawal;
+use crate::csaf::validations::test_6_1_27_15::test_6_1_27_15_reasoning_for_supersession;
+use crate::csaf::validations::test_6_1_27_16::test_6_1_27_16_reference_to_superseding_document;
+use crate::csaf::validations::test_6_1_27_17::test_6_1_27_17_product_tree;
+use crate::csaf::validations::test_6_1_27_18::test_6_1_27_18_vulnerabilities;
+use crate::csaf::validations::test_6_1_28::test_6_1_28_translation;
+use crate::csaf::validations::test_6_1_29::test_6_1_29_remediation_without_product_reference;
+use crate::csaf::validations::test_6_1_30::test_6_1_30_mixed_integer_and_semantic_versioning;
+use crate::csaf::validations::test_6_1_31::test_6_1_31_version_range_in_product_version;
+use crate::csaf::validations::test_6_1_32::test_6_1_32_flag_without_product_reference;
+use crate::csaf::validations::test_6_1_33::test_6_1_33_multiple_flags_with_vex_codes_per_product;
+use crate::csaf::validations::test_6_1_34::test_6_1_34_branches_recursion_depth;
+use crate::csaf::validations::test_6_1_35::test_6_1_35_contradicting_remediations;
+use crate::csaf::validations::test_6_1_36::test_6_1_36_status_group_contradicting_remediation_categories;
+use crate::csaf::validations::test_6_1_37::test_6_1_37_date_and_time;
+use crate::csaf::validations::test_6_1_38::test_6_1_38_non_public_sharing_group_max_uuid;
+use crate::csaf::validations::test_6_1_39::test_6_1_39_public_sharing_group_with_no_max_uuid;
+use crate::csaf::validations::test_6_1_40::test_6_1_40_invalid_sharing_group_name;
+use crate::csaf::validations::test_6_1_41::test_6_1_41_missing_sharing_group_name;
+use crate::csaf::validations::test_6_1_42::test_6_1_42_purl_consistency;
+use crate::csaf::validations::test_6_1_43::test_6_1_43_multiple_stars_in_model_number;
+use crate::csaf::validations::test_6_1_44::test_6_1_44_multiple_stars_in_serial_number;
+use crate::csaf::validations::test_6_1_45::test_6_1_45_inconsistent_disclosure_date;
+use crate::csaf::validations::test_6_1_46::test_6_1_46_invalid_ssvc;
+use crate::csaf::validations::test_6_1_47::test_6_1_47_inconsistent_ssvc_id;
+use crate::csaf::validations::test_6_1_48::test_6_1_48_ssvc_decision_points;
+use crate::csaf::validations::test_6_1_49::test_6_1_49_inconsistent_ssvc_timestamp;
+use crate::csaf::validations::test_6_1_50::test_6_1_50_product_version_range_rules;
+use crate::csaf::validations::test_6_1_51::test_6_1_51_inconsistent_epss_timestamp;
+use crate::csaf::validations::test_6_1_52::test_6_1_52_inconsistent_exploitation_date;
+use crate::csaf::validations::test_6_1_54::test_6_1_54_license_expression;
+use crate::csaf::validations::test_6_2_01::test_6_2_01_unused_definition_of_product_id;
+use crate::csaf::validations::test_6_2_02::test_6_2_02_missing_remediation;
+use crate::csaf::validations::test_6_2_03::test_6_2_03_missing_metric;
+use crate::csaf::validations::test_6_2_04::test_6_2_04_build_metadata_in_revision_history;
+use crate::csaf::validations::test_6_2_05::test_6_2_05_older_initial_release_date_than_revision_history;
+use crate::csaf::validations::test_6_2_06::test_6_2_06_older_current_release_date_than_revision_history;
+use crate::csaf::validations::test_6_2_07::test_6_2_07_missing_date_in_involvements;
+use crate::csaf::validations::test_6_2_14::test_6_2_14_use_of_private_language;
+use crate::csaf::validations::test_6_2_15::test_6_2_15_use_of_default_language;
+use crate::csaf::validations::test_6_2_18::test_6_2_18_product_version_range_without_vers;
+use crate::csaf::validations::test_6_2_37::test_6_2_37_use_of_deprecated_license_identifier;
+use crate::csaf::validations::test_schema::test_schema;
+use crate::csaf::validations::test_threat_without_product_reference::test_threat_without_product_reference;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+/// Class of a test according to section 6 of the CSAF standard.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TestCategory {
+    /// Mandatory tests (section 6.1), which every valid document must pass.
+    /// The JSON schema validation is treated as mandatory test as well.
+    Mandatory,
+    /// Optional tests (section 6.2), which check for recommendations.
+    Optional,
+    /// Informative tests (section 6.3), which give hints on potential issues.
+    Informative,
+}
+
+impl Display for TestCategory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Mandatory => write!(f, "mandatory"),
+            Self::Optional => write!(f, "optional"),
+            Self::Informative => write!(f, "informative"),
+        }
+    }
+}
+
+/// Metadata describing a test.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, serde::Serialize)]
+pub struct TestInfo {
+    /// The test ID used to select the test, e.g. "6.1.1".
+    pub id: &'static str,
+    /// The human-readable title of the test.
+    pub title: &'static str,
+    /// The section of the CSAF standard defining the test, if it is defined by the standard.
+    pub section: Option<&'static str>,
+    /// The class of the test.
+    pub category: TestCategory,
+    /// The CSAF versions the test applies to.
+    pub versions: &'static [CsafVersion],
+}
+
+/// A test together with its metadata.
+pub struct RegisteredTest<VersionedDocument> {
+    pub info: TestInfo,
+    pub test: Test<VersionedDocument>,
+}
+
+const ALL_VERSIONS: &[CsafVersion] = &[CsafVersion::V2_0, CsafVersion::V2_1];
+const SINCE_2_1: &[CsafVersion] = &[CsafVersion::V2_1];
+
+/// Creates a registry entry for the given test.
+fn register<D>(
+    id: &'static str,
+    title: &'static str,
+    category: TestCategory,
+    versions: &'static [CsafVersion],
+    test: Test<D>,
+) -> RegisteredTest<D> {
+    // The tests of section 6 are named after their section of the standard. The schema test
+    // refers to the schema of section 3, all other tests are not defined by the standard.
+    let section = match id {
+        "schema" => Some("3"),
+        _ if id.starts_with("6.") => Some(id),
+        _ => None,
+    };
+    RegisteredTest {
+        info: TestInfo { id, title, section, category, versions },
+        test,
+    }
+}
+
+/// Returns all tests known to this library in execution order, regardless of the
+/// CSAF version they apply to.
+///
+/// This is the single place where tests are declared. Presets and the test lookup
+/// by ID are derived from it, see [tests_for_version] and [presets_for_version].
+pub fn registered_tests<T>() -> Vec<RegisteredTest<CsafDocument<T>>>
+where
+    T: CsafTrait + CsafVersioned,
+{
+    use TestCategory::*;
+
+    vec![
+        register("schema", "JSON Schema", Mandatory, ALL_VERSIONS, test_schema),
+        register("6.1.1", "Missing Definition of Product ID", Mandatory, ALL_VERSIONS,
+            test_6_1_01_missing_definition_of_product_id),
+        register("6.1.2", "Multiple Definition of Product ID", Mandatory, ALL_VERSIONS,
+            test_6_1_02_multiple_definition_of_product_id),
+        register("6.1.3", "Circular Definition of Product ID", Mandatory, ALL_VERSIONS,
+            test_6_1_03_circular_definition_of_product_id),
+        register("6.1.4", "Missing Definition of Product Group ID", Mandatory, ALL_VERSIONS,
+            test_6_1_04_missing_definition_of_product_group_id),
+        register("6.1.5", "Multiple Definition of Product Group ID", Mandatory, ALL_VERSIONS,
+            test_6_1_05_multiple_definition_of_product_group_id),
+        register("6.1.6", "Contradicting Product Status", Mandatory, ALL_VERSIONS,
+            test_6_1_06_contradicting_product_status),
+        register("6.1.7", "Multiple Same Metrics per Product", Mandatory, ALL_VERSIONS,
+            test_6_1_07_multiple_same_scores_per_product),
+        register("6.1.8", "Invalid CVSS", Mandatory, ALL_VERSIONS,
+            test_6_1_08_invalid_cvss),
+        register("6.1.9", "Invalid CVSS Computation", Mandatory, ALL_VERSIONS,
+            test_6_1_09_invalid_cvss_computation),
+        register("6.1.10", "Inconsistent CVSS", Mandatory, ALL_VERSIONS,
+            test_6_1_10_inconsistent_cvss),
+        register("6.1.11", "CWE", Mandatory, ALL_VERSIONS,
+            test_6_1_11_cwe),
+        register("6.1.12", "Language", Mandatory, ALL_VERSIONS,
+            test_6_1_12_language),
+        register("6.1.14", "Sorted Revision History", Mandatory, ALL_VERSIONS,
+            test_6_1_14_sorted_revision_history),
+        register("6.1.15", "Translator", Mandatory, ALL_VERSIONS,
+            test_6_1_15_translator),
+        register("6.1.16", "Latest Document Version", Mandatory, ALL_VERSIONS,
+            test_6_1_16_latest_document_version),
+        register("6.1.17", "Document Status Draft", Mandatory, ALL_VERSIONS,
+            test_6_1_17_document_status_draft),
+        register("6.1.18", "Released Revision History", Mandatory, ALL_VERSIONS,
+            test_6_1_18_released_revision_history),
+        register("6.1.19", "Revision History Entries for Pre-release Versions", Mandatory, ALL_VERSIONS,
+            test_6_1_19_revision_history_entries_for_pre_release_versions),
+        register("6.1.20", "Non-draft Document Version", Mandatory, ALL_VERSIONS,
+            test_6_1_20_non_draft_document_version),
+        register("6.1.21", "Missing Item in Revision History", Mandatory, ALL_VERSIONS,
+            test_6_1_21_missing_item_in_revision_history),
+        register("6.1.22", "Multiple Definition in Revision History", Mandatory, ALL_VERSIONS,
+            test_6_1_22_multiple_definition_in_revision_history),
+        register("6.1.23", "Multiple Use of Same CVE", Mandatory, ALL_VERSIONS,
+            test_6_1_23_multiple_use_of_same_cve),
+        register("6.1.24", "Multiple Definition in Involvements", Mandatory, ALL_VERSIONS,
+            test_6_1_24_multiple_definition_in_involvements),
+        register("6.1.25", "Multiple Use of Same Hash Algorithm", Mandatory, ALL_VERSIONS,
+            test_6_1_25_multiple_use_of_same_hash_algorithm),
+        register("6.1.26", "Prohibited Document Category Name", Mandatory, ALL_VERSIONS,
+            test_6_1_26_prohibited_document_category_name),
+        register("6.1.27.1", "Document Notes", Mandatory, ALL_VERSIONS,
+            test_6_1_27_01_document_notes),
+        register("6.1.27.2", "Document References", Mandatory, ALL_VERSIONS,
+            test_6_1_27_02_document_references),
+        register("6.1.27.3", "Vulnerabilities", Mandatory, ALL_VERSIONS,
+            test_6_1_27_03_vulnerabilities),
+        register("6.1.27.4", "Product Tree", Mandatory, ALL_VERSIONS,
+            test_6_1_27_04_product_tree),
+        register("6.1.27.5", "Vulnerability Notes", Mandatory, ALL_VERSIONS,
+            test_6_1_27_05_vulnerability_notes),
+        register("6.1.27.6", "Product Status", Mandatory, ALL_VERSIONS,
+            test_6_1_27_06_product_status),
+        register("6.1.27.7", "VEX Product Status", Mandatory, ALL_VERSIONS,
+            test_6_1_27_07_vex_product_status),
+        register("6.1.27.8", "Vulnerability ID", Mandatory, ALL_VERSIONS,
+            test_6_1_27_08_vulnerability_id),
+        register("6.1.27.9", "Impact Statement", Mandatory, ALL_VERSIONS,
+            test_6_1_27_09_impact_statement),
+        register("6.1.27.10", "Action Statement", Mandatory, ALL_VERSIONS,
+            test_6_1_27_10_action_statement),
+        register("6.1.27.11", "Vulnerabilities", Mandatory, ALL_VERSIONS,
+            test_6_1_27_11_vulnerabilities),
+        // 6.1.27.12 (Affected Products) and 6.1.27.13 (Corresponding Affected Products) are not implemented yet
+        register("6.1.27.14", "Reasoning for Withdrawal", Mandatory, SINCE_2_1,
+            test_6_1_27_14_reasoning_for_withdrawal),
+        register("6.1.27.15", "Reasoning for Supersession", Mandatory, SINCE_2_1,
+            test_6_1_27_15_reasoning_for_supersession),
+        register("6.1.27.16", "Reference to Superseding Document", Mandatory, SINCE_2_1,
+            test_6_1_27_16_reference_to_superseding_document),
+        register("6.1.27.17", "Product Tree", Mandatory, SINCE_2_1,
+            test_6_1_27_17_product_tree),
+        register("6.1.27.18", "Vulnerabilities", Mandatory, SINCE_2_1,
+            test_6_1_27_18_vulnerabilities),
+        register("6.1.28", "Translation", Mandatory, ALL_VERSIONS,
+            test_6_1_28_translation),
+        register("6.1.29", "Remediation without Product Reference", Mandatory, ALL_VERSIONS,
+            test_6_1_29_remediation_without_product_reference),
+        register("6.1.30", "Mixed Integer and Semantic Versioning", Mandatory, ALL_VERSIONS,
+            test_6_1_30_mixed_integer_and_semantic_versioning),
+        register("6.1.31", "Version Range in Product Version", Mandatory, ALL_VERSIONS,
+            test_6_1_31_version_range_in_product_version),
+        register("6.1.32", "Flag without Product Reference", Mandatory, ALL_VERSIONS,
+            test_6_1_32_flag_without_product_reference),
+        register("6.1.33", "Multiple Flags with VEX Justification Codes per Product", Mandatory, ALL_VERSIONS,
+            test_6_1_33_multiple_flags_with_vex_codes_per_product),
+        register("6.1.34", "Branches Recursion Depth", Mandatory, SINCE_2_1,
+            test_6_1_34_branches_recursion_depth),
+        register("6.1.35", "Contradicting Remediations", Mandatory, SINCE_2_1,
+            test_6_1_35_contradicting_remediations),
+        register("6.1.36", "Status Group Contradicting Remediation Categories", Mandatory, SINCE_2_1,
+            test_6_1_36_status_group_contradicting_remediation_categories),
+        register("6.1.37", "Date and Time", Mandatory, SINCE_2_1,
+            test_6_1_37_date_and_time),
+        register("6.1.38", "Non-Public Sharing Group with Max UUID", Mandatory, SINCE_2_1,
+            test_6_1_38_non_public_sharing_group_max_uuid),
+        register("6.1.39", "Public Sharing Group with no Max UUID", Mandatory, SINCE_2_1,
+            test_6_1_39_public_sharing_group_with_no_max_uuid),
+        register("6.1.40", "Invalid Sharing Group Name", Mandatory, SINCE_2_1,
+            test_6_1_40_invalid_sharing_group_name),
+        register("6.1.41", "Missing Sharing Group Name", Mandatory, SINCE_2_1,
+            test_6_1_41_missing_sharing_group_name),
+        register("6.1.42", "PURL Qualifiers", Mandatory, SINCE_2_1,
+            test_6_1_42_purl_consistency),
+        register("6.1.43", "Multiple Stars in Model Number", Mandatory, SINCE_2_1,
+            test_6_1_43_multiple_stars_in_model_number),
+        register("6.1.44", "Multiple Stars in Serial Number", Mandatory, SINCE_2_1,
+            test_6_1_44_multiple_stars_in_serial_number),
+        register("6.1.45", "Inconsistent Disclosure Date", Mandatory, SINCE_2_1,
+            test_6_1_45_inconsistent_disclosure_date),
+        register("6.1.46", "Invalid SSVC", Mandatory, SINCE_2_1,
+            test_6_1_46_invalid_ssvc),
+        register("6.1.47", "Inconsistent SSVC ID", Mandatory, SINCE_2_1,
+            test_6_1_47_inconsistent_ssvc_id),
+        register("6.1.48", "SSVC Decision Points", Mandatory, SINCE_2_1,
+            test_6_1_48_ssvc_decision_points),
+        register("6.1.49", "Inconsistent SSVC Timestamp", Mandatory, SINCE_2_1,
+            test_6_1_49_inconsistent_ssvc_timestamp),
+        register("6.1.50", "Product Version Range Rules", Mandatory, SINCE_2_1,
+            test_6_1_50_product_version_range_rules),
+        register("6.1.51", "Inconsistent EPSS Timestamp", Mandatory, SINCE_2_1,
+            test_6_1_51_inconsistent_epss_timestamp),
+        register("6.1.52", "Inconsistent Exploitation Date", Mandatory, SINCE_2_1,
+            test_6_1_52_inconsistent_exploitation_date),
+        register("6.1.54", "License Expression", Mandatory, SINCE_2_1,
+            test_6_1_54_license_expression),
+        register("6.2.1", "Unused Definition of Product ID", Optional, ALL_VERSIONS,
+            test_6_2_01_unused_definition_of_product_id),
+        register("6.2.2", "Missing Remediation", Optional, ALL_VERSIONS,
+            test_6_2_02_missing_remediation),
+        register("6.2.3", "Missing Metric", Optional, ALL_VERSIONS,
+            test_6_2_03_missing_metric),
+        register("6.2.4", "Build Metadata in Revision History", Optional, ALL_VERSIONS,
+            test_6_2_04_build_metadata_in_revision_history),
+        register("6.2.5", "Older Initial Release Date than Revision History", Optional, ALL_VERSIONS,
+            test_6_2_05_older_initial_release_date_than_revision_history),
+        register("6.2.6", "Older Current Release Date than Revision History", Optional, ALL_VERSIONS,
+            test_6_2_06_older_current_release_date_than_revision_history),
+        register("6.2.7", "Missing Date in Involvements", Optional, ALL_VERSIONS,
+            test_6_2_07_missing_date_in_involvements),
+        register("6.2.14", "Use of Private Language", Optional, ALL_VERSIONS,
+            test_6_2_14_use_of_private_language),
+        register("6.2.15", "Use of Default Language", Optional, ALL_VERSIONS,
+            test_6_2_15_use_of_default_language),
+        register("6.2.18", "Product Version Range without vers", Optional, ALL_VERSIONS,
+            test_6_2_18_product_version_range_without_vers),
+        register("6.2.37", "Use of Deprecated License Identifier", Optional, SINCE_2_1,
+            test_6_2_37_use_of_deprecated_license_identifier),
+        register("threat-without-product-reference", "Threat without Product Reference", Optional, ALL_VERSIONS,
+            test_threat_without_product_reference),
+    ]
+}
+
+/// Returns all tests applicable to documents of type `T` in execution order.
+pub fn tests_for_version<T>() -> Vec<RegisteredTest<CsafDocument<T>>>
+where
+    T: CsafTrait + CsafVersioned,
+{
+    registered_tests::<T>()
+        .into_iter()
+        .filter(|t| t.info.versions.contains(&T::VERSION))
+        .collect()
+}
+
+/// Returns whether tests of the given category are part of the given preset.
+fn preset_includes(preset: &ValidationPreset, category: TestCategory) -> bool {
+    match preset {
+        ValidationPreset::Basic => category == TestCategory::Mandatory,
+        ValidationPreset::Extended => category != TestCategory::Informative,
+        ValidationPreset::Full => true,
+    }
+}
+
+/// Derives the test IDs per preset for documents of type `T` from the registry.
+///
+/// The basic preset contains all mandatory tests (including the schema test), the extended
+/// preset adds the optional tests, and the full preset adds the informative tests. Tests which
+/// are not defined by the standard are not part of any preset, they only run if selected by ID.
+pub fn presets_for_version<T>() -> HashMap<ValidationPreset, Vec<&'static str>>
+where
+    T: CsafTrait + CsafVersioned,
+{
+    let tests = tests_for_version::<T>();
+    [ValidationPreset::Basic, ValidationPreset::Extended, ValidationPreset::Full]
+        .into_iter()
+        .map(|preset| {
+            let ids = tests
+                .iter()
+                .filter(|t| t.info.section.is_some() && preset_includes(&preset, t.info.category))
+                .map(|t| t.info.id)
+                .collect();
+            (preset, ids)
+        })
+        .collect()
+}
+
+/// Returns the metadata of all tests applicable to the given CSAF version in execution order.
+pub fn test_infos(version: CsafVersion) -> Vec<TestInfo> {
+    // The metadata does not depend on the document type the test functions are instantiated for
+    registered_tests::<Csaf21>()
+        .into_iter()
+        .map(|t| t.info)
+        .filter(|info| info.versions.contains(&version))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::csaf2_0::schema::CommonSecurityAdvisoryFramework as Csaf20;
+    use crate::csaf::csaf2_1::schema::CommonSecurityAdvisoryFramework as Csaf21;
+    use crate::csaf::document::CsafVersion;
+    use crate::csaf::registry::{presets_for_version, registered_tests, test_infos, TestCategory};
+    use crate::csaf::validation::ValidationPreset;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_registry_ids_are_unique() {
+        let tests = registered_tests::<Csaf21>();
+        let ids: HashSet<&str> = tests.iter().map(|t| t.info.id).collect();
+        assert_eq!(tests.len(), ids.len());
+    }
+
+    #[test]
+    fn test_presets_are_derived_from_registry() {
+        let presets = presets_for_version::<Csaf20>();
+        let basic = &presets[&ValidationPreset::Basic];
+        assert_eq!(Some(&"schema"), basic.first());
+        assert!(basic.contains(&"6.1.7"));
+        // Tests introduced with CSAF 2.1 are not part of the CSAF 2.0 presets
+        assert!(!basic.contains(&"6.1.34"));
+
+        let presets = presets_for_version::<Csaf21>();
+        let mandatory: Vec<&str> = test_infos(CsafVersion::V2_1)
+            .iter()
+            .filter(|info| info.category == TestCategory::Mandatory)
+            .map(|info| info.id)
+            .collect();
+        assert_eq!(&mandatory, &presets[&ValidationPreset::Basic]);
+        // Each preset contains all tests of the previous one
+        for (smaller, larger) in [
+            (ValidationPreset::Basic, ValidationPreset::Extended),
+            (ValidationPreset::Extended, ValidationPreset::Full),
+        ] {
+            assert!(presets[&smaller].iter().all(|id| presets[&larger].contains(id)));
+        }
+        // Tests not defined by the standard are only run if selected explicitly
+        let standard: Vec<&str> = test_infos(CsafVersion::V2_1)
+            .iter()
+            .filter(|info| info.section.is_some())
+            .map(|info| info.id)
+            .collect();
+        assert_eq!(&standard, &presets[&ValidationPreset::Full]);
+        assert!(!presets[&ValidationPreset::Full].contains(&"threat-without-product-reference"));
+    }
+}
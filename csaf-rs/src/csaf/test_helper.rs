@@ -2,7 +2,9 @@ use crate::csaf::csaf2_0::loader::load_document as load_document_20;
 use crate::csaf::csaf2_0::schema::CommonSecurityAdvisoryFramework as Csaf20;
 use crate::csaf::csaf2_1::loader::load_document as load_document_21;
 use crate::csaf::csaf2_1::schema::CommonSecurityAdvisoryFramework as Csaf21;
+use crate::csaf::document::CsafDocument;
 use crate::csaf::validation::{Test, ValidationError};
+use serde_json::{json, Value};
 use std::collections::HashMap;
 
 /// Generic test helper that loads all test files matching a specific test number pattern
@@ -11,7 +13,7 @@ use std::collections::HashMap;
 /// # Arguments
 /// * `test_number` - The test number to run (e.g., "36" for 6.1.36 tests)
 /// * `test_function` - The test function to execute against each document
-/// * `negative_cases` - A slice of tuples containing (file_suffix, expected_validation_error)
+/// * `expected_errors` - A map of file suffixes to all validation errors expected
 ///                     for negative test cases (starting with "0")
 ///
 /// This function assumes tests with filenames ending with numbers starting with "0"
@@ -21,7 +23,7 @@ fn run_csaf_tests<CsafType>(
     file_prefix: &str,
     document_loader: fn(&str) -> std::io::Result<CsafType>,
     test_function: Test<CsafType>,
-    expected_errors: &HashMap<&str, &ValidationError>,
+    expected_errors: &HashMap<&str, Vec<ValidationError>>,
 ) {
     use glob::glob;
 
@@ -42,14 +44,14 @@ fn run_csaf_tests<CsafType>(
 
             // Check if this is expected to be a negative or positive test case
             if test_num.starts_with('0') {
-                // Negative test case - should fail with a specific error
-                let expected_error = expected_errors.get(test_num).expect(
+                // Negative test case - should fail with all expected errors
+                let expected = expected_errors.get(test_num).expect(
                     &format!("Missing expected error definition for negative test case {}", test_num)
                 );
                 assert_eq!(
-                    Err((*expected_error).clone()),
+                    Err(expected.clone()),
                     test_function(&doc),
-                    "Negative test case {} should have failed with the expected error", test_num
+                    "Negative test case {} should have failed with the expected errors", test_num
                 );
             } else if test_num.starts_with('1') {
                 // Positive test case - should succeed
@@ -67,8 +69,8 @@ fn run_csaf_tests<CsafType>(
 
 pub fn run_csaf20_tests(
     test_number: &str,
-    test_function: Test<Csaf20>,
-    expected_errors: &HashMap<&str, &ValidationError>,
+    test_function: Test<CsafDocument<Csaf20>>,
+    expected_errors: &HashMap<&str, Vec<ValidationError>>,
 ) {
     // Find all test files matching the pattern
     let file_prefix = &format!("oasis_csaf_tc-csaf_2_0-2021-6-1-{}-", test_number);
@@ -79,8 +81,8 @@ pub fn run_csaf20_tests(
 
 pub fn run_csaf21_tests(
     test_number: &str,
-    test_function: Test<Csaf21>,
-    expected_errors: &HashMap<&str, &ValidationError>,
+    test_function: Test<CsafDocument<Csaf21>>,
+    expected_errors: &HashMap<&str, Vec<ValidationError>>,
 ) {
     // Find all test files matching the pattern
     let file_prefix = &format!("oasis_csaf_tc-csaf_2_1-2024-6-1-{}-", test_number);
@@ -88,3 +90,104 @@ pub fn run_csaf21_tests(
 
     run_csaf_tests(pattern, file_prefix, load_document_21, test_function, expected_errors);
 }
+
+pub fn run_csaf20_optional_tests(
+    test_number: &str,
+    test_function: Test<CsafDocument<Csaf20>>,
+    expected_errors: &HashMap<&str, Vec<ValidationError>>,
+) {
+    // Find all test files matching the pattern
+    let file_prefix = &format!("oasis_csaf_tc-csaf_2_0-2021-6-2-{}-", test_number);
+    let pattern = &format!("../csaf/csaf_2.0/test/validator/data/optional/{}*.json", file_prefix);
+
+    run_csaf_tests(pattern, file_prefix, load_document_20, test_function, expected_errors);
+}
+
+pub fn run_csaf21_optional_tests(
+    test_number: &str,
+    test_function: Test<CsafDocument<Csaf21>>,
+    expected_errors: &HashMap<&str, Vec<ValidationError>>,
+) {
+    // Find all test files matching the pattern
+    let file_prefix = &format!("oasis_csaf_tc-csaf_2_1-2024-6-2-{}-", test_number);
+    let pattern = &format!("../csaf/csaf_2.1/test/validator/data/optional/{}*.json", file_prefix);
+
+    run_csaf_tests(pattern, file_prefix, load_document_21, test_function, expected_errors);
+}
+
+/// Builds the JSON of a minimal document of the given CSAF version, which is valid
+/// according to the JSON schema. Tests can extend it with the properties under test.
+pub fn minimal_document(csaf_version: &str) -> Value {
+    let mut doc = json!({
+        "document": {
+            "category": "csaf_base",
+            "csaf_version": csaf_version,
+            "distribution": {
+                "tlp": {
+                    "label": "CLEAR"
+                }
+            },
+            "publisher": {
+                "category": "coordinator",
+                "name": "test",
+                "namespace": "https://example.com"
+            },
+            "title": "Test",
+            "tracking": {
+                "current_release_date": "2024-01-24T10:00:00.000Z",
+                "id": "test",
+                "initial_release_date": "2024-01-24T10:00:00.000Z",
+                "revision_history": [
+                    {
+                        "date": "2024-01-24T10:00:00.000Z",
+                        "number": "1",
+                        "summary": "Initial version."
+                    }
+                ],
+                "status": "final",
+                "version": "1"
+            }
+        }
+    });
+    match csaf_version {
+        "2.0" => doc["document"]["distribution"]["tlp"]["label"] = json!("WHITE"),
+        _ => {
+            doc["$schema"] = json!("https://docs.oasis-open.org/csaf/csaf/v2.1/schema/csaf.json");
+            doc["document"]["license_expression"] = json!("MIT");
+        }
+    }
+    doc
+}
+
+/// Sets the document version and the revision history, given as (date, number) pairs, of a
+/// document created by [minimal_document].
+pub fn set_revision_history(doc: &mut Value, version: &str, revisions: &[(&str, &str)]) {
+    doc["document"]["tracking"]["version"] = json!(version);
+    doc["document"]["tracking"]["revision_history"] = revisions
+        .iter()
+        .map(|(date, number)| json!({ "date": date, "number": number, "summary": "Changes." }))
+        .collect();
+}
+
+/// Builds a CSAF 2.1 document based on [minimal_document] with a single vulnerability, whose
+/// only metric is a CVSS v3 score, e.g. to check that the SSVC tests skip metrics without SSVC.
+pub fn document_with_cvss_v3_metric() -> Value {
+    let mut doc = minimal_document("2.1");
+    doc["product_tree"] = json!({
+        "full_product_names": [{ "name": "Product A", "product_id": "CSAFPID-9080700" }]
+    });
+    doc["vulnerabilities"] = json!([{
+        "metrics": [{
+            "products": ["CSAFPID-9080700"],
+            "content": {
+                "cvss_v3": {
+                    "version": "3.1",
+                    "vectorString": "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+                    "baseScore": 9.8,
+                    "baseSeverity": "CRITICAL"
+                }
+            }
+        }]
+    }]);
+    doc
+}
@@ -0,0 +1,200 @@
+//! The dates of the document tracking and the vulnerabilities, parsed once into a typed timeline.
+//!
+//! Tests comparing dates of a document use [Timeline] instead of parsing the date strings on
+//! their own. Dates which are no valid timestamps are `None`, as they are reported by test
+//! 6.1.37 already.
+
+use crate::csaf::csaf2_1::schema::DocumentStatus;
+use crate::csaf::document_version::RevisionHistory;
+use crate::csaf::getter_traits::{ContentTrait, CsafTrait, DocumentTrait, FirstKnownExploitationDateTrait, InvolvementTrait, MetricTrait, TrackingTrait, VulnerabilityTrait};
+use chrono::{DateTime, FixedOffset};
+
+/// A date of a CSAF document with its offset, e.g. `2024-01-24T10:00:00.000Z`.
+pub type Timestamp = DateTime<FixedOffset>;
+
+/// Parses a date of a CSAF document, returning `None` for an invalid timestamp.
+pub fn parse_timestamp(value: &str) -> Option<Timestamp> {
+    DateTime::parse_from_rfc3339(value).ok()
+}
+
+/// The status and the dates of the document tracking, and the dates of each vulnerability.
+#[derive(Debug, Clone)]
+pub struct Timeline<'a> {
+    pub status: DocumentStatus,
+    pub initial_release_date: Option<Timestamp>,
+    pub current_release_date: Option<Timestamp>,
+    /// The revision history with the parsed date of each item
+    pub revision_history: RevisionHistory<'a>,
+    /// The dates of each vulnerability, in the order of the document
+    pub vulnerabilities: Vec<VulnerabilityDates>,
+}
+
+/// The dates of a vulnerability. The lists have one entry per item of the vulnerability, so
+/// their indexes match the JSON paths.
+#[derive(Debug, Clone, Default)]
+pub struct VulnerabilityDates {
+    pub disclosure_date: Option<Timestamp>,
+    pub discovery_date: Option<Timestamp>,
+    /// The date of each involvement
+    pub involvement_dates: Vec<Option<Timestamp>>,
+    /// The EPSS timestamp of each metric, `None` for metrics without EPSS
+    pub epss_timestamps: Vec<Option<Timestamp>>,
+    /// The date of the information and the exploitation date of each first known exploitation
+    pub first_known_exploitation_dates: Vec<(Option<Timestamp>, Option<Timestamp>)>,
+}
+
+impl VulnerabilityDates {
+    /// Parses the dates of the given vulnerability.
+    pub fn of(vulnerability: &impl VulnerabilityTrait) -> Self {
+        let parse = |value: &Option<String>| value.as_deref().and_then(parse_timestamp);
+        VulnerabilityDates {
+            disclosure_date: parse(vulnerability.get_disclosure_date()),
+            discovery_date: parse(vulnerability.get_discovery_date()),
+            involvement_dates: vulnerability
+                .get_involvements()
+                .iter()
+                .flatten()
+                .map(|involvement| parse(involvement.get_date()))
+                .collect(),
+            epss_timestamps: vulnerability
+                .get_metrics()
+                .into_iter()
+                .flatten()
+                .map(|metric| metric.get_content().get_epss().as_ref().and_then(|epss| parse_timestamp(&epss.timestamp)))
+                .collect(),
+            first_known_exploitation_dates: vulnerability
+                .get_first_known_exploitation_dates()
+                .map(|exploitation| (
+                    parse_timestamp(exploitation.get_date()),
+                    parse_timestamp(exploitation.get_exploitation_date()),
+                ))
+                .collect(),
+        }
+    }
+}
+
+/// JSON path of the initial release date.
+pub const INITIAL_RELEASE_DATE_JSON_PATH: &str = "/document/tracking/initial_release_date";
+
+/// JSON path of the current release date.
+pub const CURRENT_RELEASE_DATE_JSON_PATH: &str = "/document/tracking/current_release_date";
+
+impl<'a> Timeline<'a> {
+    /// Parses the tracking dates of the given document.
+    pub fn of(doc: &'a impl CsafTrait) -> Self {
+        let tracking = doc.get_document().get_tracking();
+        Timeline {
+            status: tracking.get_status(),
+            initial_release_date: parse_timestamp(tracking.get_initial_release_date()),
+            current_release_date: parse_timestamp(tracking.get_current_release_date()),
+            revision_history: RevisionHistory::from_tracking(tracking),
+            vulnerabilities: doc.get_vulnerabilities().iter().map(VulnerabilityDates::of).collect(),
+        }
+    }
+
+    /// Whether the document is released, i.e. has the status `final` or `interim`.
+    pub fn is_released(&self) -> bool {
+        self.status == DocumentStatus::Final || self.status == DocumentStatus::Interim
+    }
+
+    /// Returns the valid dates of the revision history.
+    pub fn revision_dates(&self) -> impl Iterator<Item = Timestamp> + '_ {
+        self.revision_history.items.iter().filter_map(|item| item.date)
+    }
+
+    /// Returns the date of the oldest item in the revision history, ignoring invalid dates.
+    pub fn oldest_revision_date(&self) -> Option<Timestamp> {
+        self.revision_dates().min()
+    }
+
+    /// Returns the date of the newest item in the revision history, ignoring invalid dates.
+    pub fn newest_revision_date(&self) -> Option<Timestamp> {
+        self.revision_dates().max()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::csaf2_0::schema::CommonSecurityAdvisoryFramework as Csaf20;
+    use crate::csaf::csaf2_1::schema::CommonSecurityAdvisoryFramework as Csaf21;
+    use crate::csaf::document::CsafDocument;
+    use crate::csaf::test_helper::{minimal_document, set_revision_history};
+    use crate::csaf::timeline::{parse_timestamp, Timeline};
+    use serde_json::json;
+
+    #[test]
+    fn test_timeline() {
+        let mut doc = minimal_document("2.0");
+        set_revision_history(
+            &mut doc,
+            "3",
+            &[
+                ("2024-01-24T12:00:00.000+02:00", "2"),
+                ("2024-01-24T09:00:00.000Z", "1"),
+                ("2024-01-25", "3"),
+            ],
+        );
+        let doc = CsafDocument::<Csaf20>::from_json(doc).unwrap();
+        let timeline = Timeline::of(&doc);
+
+        assert!(timeline.is_released());
+        assert_eq!(parse_timestamp("2024-01-24T10:00:00.000Z"), timeline.initial_release_date);
+        assert_eq!(parse_timestamp("2024-01-24T09:00:00Z"), timeline.oldest_revision_date());
+        // Dates are compared by their point in time, invalid dates are ignored
+        assert_eq!(parse_timestamp("2024-01-24T10:00:00Z"), timeline.newest_revision_date());
+        assert_eq!(None, timeline.revision_history.items[2].date);
+    }
+
+    #[test]
+    fn test_vulnerability_dates() {
+        let mut doc = minimal_document("2.1");
+        doc["product_tree"] = json!({
+            "full_product_names": [{ "name": "Product A", "product_id": "CSAFPID-9080700" }]
+        });
+        doc["vulnerabilities"] = json!([
+            {
+                "disclosure_date": "2024-01-20T10:00:00.000Z",
+                "discovery_date": "2024-01-19",
+                "first_known_exploitation_dates": [{
+                    "date": "2024-01-22T10:00:00.000Z",
+                    "exploitation_date": "2024-01-21T10:00:00.000Z",
+                    "product_ids": ["CSAFPID-9080700"]
+                }],
+                "involvements": [
+                    { "party": "vendor", "status": "completed" },
+                    { "date": "2024-01-23T10:00:00.000Z", "party": "vendor", "status": "completed" }
+                ],
+                "metrics": [
+                    {
+                        "content": { "cvss_v3": {
+                            "version": "3.1",
+                            "vectorString": "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+                            "baseScore": 9.8,
+                            "baseSeverity": "CRITICAL"
+                        } },
+                        "products": ["CSAFPID-9080700"]
+                    },
+                    {
+                        "content": { "epss": { "percentile": "0.9", "probability": "0.5", "timestamp": "2024-01-24T10:00:00.000Z" } },
+                        "products": ["CSAFPID-9080700"]
+                    }
+                ]
+            },
+            {}
+        ]);
+        let doc = CsafDocument::<Csaf21>::from_json(doc).unwrap();
+        let timeline = Timeline::of(&doc);
+
+        let dates = &timeline.vulnerabilities[0];
+        assert_eq!(parse_timestamp("2024-01-20T10:00:00Z"), dates.disclosure_date);
+        assert_eq!(None, dates.discovery_date);
+        assert_eq!(vec![None, parse_timestamp("2024-01-23T10:00:00Z")], dates.involvement_dates);
+        // The indexes match the metrics, also for metrics without EPSS
+        assert_eq!(vec![None, parse_timestamp("2024-01-24T10:00:00Z")], dates.epss_timestamps);
+        assert_eq!(
+            vec![(parse_timestamp("2024-01-22T10:00:00Z"), parse_timestamp("2024-01-21T10:00:00Z"))],
+            dates.first_known_exploitation_dates
+        );
+        assert!(timeline.vulnerabilities[1].involvement_dates.is_empty());
+    }
+}
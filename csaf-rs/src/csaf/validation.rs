@@ -2,11 +2,34 @@ use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 
+/// Severity of a finding, depending on the kind of test which produced it.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ValidationSeverity {
+    /// Hint found by an informative test (section 6.3).
+    Info,
+    /// Recommendation violated in an optional test (section 6.2).
+    Warning,
+    /// Conformance failure found by a mandatory test (section 6.1) or the schema validation.
+    Error,
+}
+
+impl Display for ValidationSeverity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Info => write!(f, "Info"),
+            Self::Warning => write!(f, "Warning"),
+            Self::Error => write!(f, "Error"),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Hash, Clone, serde::Serialize)]
 pub struct ValidationError {
     pub message: String,
     #[serde(rename = "instancePath")]
     pub instance_path: String,
+    pub severity: ValidationSeverity,
 }
 
 impl std::fmt::Display for ValidationError {
@@ -19,6 +42,13 @@ impl std::fmt::Display for ValidationError {
     }
 }
 
+/// Allows to use `?` on single errors in tests, which return all errors they found.
+impl From<ValidationError> for Vec<ValidationError> {
+    fn from(error: ValidationError) -> Self {
+        vec![error]
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum ValidationPreset {
     Basic,
@@ -51,13 +81,83 @@ impl Display for ValidationPreset {
 
 pub trait Validate {
     /// Validates this object according to a validation preset
-    fn validate_preset(&'static self, preset: ValidationPreset);
+    fn validate_preset(&'static self, preset: ValidationPreset) -> ValidationReport;
 
     /// Validates this object according to a specific test ID.
-    fn validate_by_test(&self, version: &str);
+    fn validate_by_test(&self, version: &str) -> TestResult;
+}
+
+/// A test function, which returns all validation errors it found in the given document.
+pub type Test<VersionedDocument> = fn(&VersionedDocument) -> Result<(), Vec<ValidationError>>;
+
+/// Turns all validation errors found by a test into its result, which is only successful if
+/// there are none.
+pub fn into_result(errors: Vec<ValidationError>) -> Result<(), Vec<ValidationError>> {
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Outcome of a single test execution.
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum TestResultStatus {
+    /// The test was executed and the document passed it.
+    Success,
+    /// The test was executed and found at least one error.
+    Failure {
+        errors: Vec<ValidationError>,
+    },
+    /// There is no implementation for the requested test ID.
+    NotImplemented,
+    /// The test exists, but does not apply to this document.
+    NotApplicable,
+}
+
+/// Result of a single test, identified by its test ID (e.g. "6.1.1").
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize)]
+pub struct TestResult {
+    #[serde(rename = "testId")]
+    pub test_id: String,
+    #[serde(flatten)]
+    pub status: TestResultStatus,
+}
+
+impl TestResult {
+    /// Returns the errors found by this test, which is empty unless the test failed.
+    pub fn errors(&self) -> &[ValidationError] {
+        match &self.status {
+            TestResultStatus::Failure { errors } => errors,
+            _ => &[],
+        }
+    }
+}
+
+/// Report of a validation run, listing the results of all executed tests in execution order.
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize)]
+pub struct ValidationReport {
+    pub tests: Vec<TestResult>,
 }
 
-pub type Test<VersionedDocument> = fn(&VersionedDocument) -> Result<(), ValidationError>;
+impl ValidationReport {
+    /// Returns `true` if none of the executed tests found an error of severity
+    /// [ValidationSeverity::Error]. Warnings and infos do not render a document invalid.
+    pub fn is_valid(&self) -> bool {
+        !self.has_findings(ValidationSeverity::Error)
+    }
+
+    /// Returns `true` if any of the executed tests found an error with the given severity.
+    pub fn has_findings(&self, severity: ValidationSeverity) -> bool {
+        self.errors().any(|e| e.severity == severity)
+    }
+
+    /// Returns all errors found by the executed tests.
+    pub fn errors(&self) -> impl Iterator<Item = &ValidationError> + '_ {
+        self.tests.iter().flat_map(|t| t.errors())
+    }
+}
 
 /// Represents something which is validatable according to the CSAF standard.
 /// This trait MUST be implemented by the struct that represents a CSAF document
@@ -71,40 +171,103 @@ pub trait Validatable<VersionedDocument> {
     /// Returns a hashmap containing the test function per test ID
     fn tests(&self) -> HashMap<&str, Test<VersionedDocument>>;
 
+    /// Returns the IDs of all known tests, including those which do not apply to this version
+    fn known_test_ids(&self) -> Vec<&str>;
+
     fn doc(&self) -> &VersionedDocument;
 }
 
 /// Executes all tests of the specified [preset] against the [target]
 /// (which is of type [VersionedDocument], e.g. a CSAF 2.0 document).
+///
+/// Returns a [ValidationReport] with the results of all tests of the preset.
+/// The report is empty if the preset does not define any tests.
 pub fn validate_by_preset<VersionedDocument>(
     target: &impl Validatable<VersionedDocument>,
     preset: ValidationPreset,
-) {
-    println!("Validating document with {:?} preset... \n", preset);
-
-    // Loop through tests
-    if let Some(tests) = target.presets().get(&preset) {
-        for test_id in tests {
-            println!("Executing Test {}... ", test_id);
-            validate_by_test(target, test_id);
+) -> ValidationReport {
+    let tests = match target.presets().get(&preset) {
+        Some(test_ids) => test_ids
+            .iter()
+            .map(|test_id| validate_by_test(target, test_id))
+            .collect(),
+        None => vec![],
+    };
 
-            println!()
-        }
-    } else {
-        println!("No tests found for preset")
-    }
+    ValidationReport { tests }
 }
 
+/// Executes the test with the given [test_id] against the [target].
+///
+/// The result is [TestResultStatus::NotApplicable] if the test exists, but does not apply to the
+/// version of the [target], and [TestResultStatus::NotImplemented] if the test is unknown.
 pub fn validate_by_test<VersionedDocument>(
     target: &impl Validatable<VersionedDocument>,
     test_id: &str,
-) {
-    if let Some(test_fn) = target.tests().get(test_id) {
-        let _ = match test_fn(target.doc()) {
-            Ok(()) => println!("> Test Success"),
-            Err(e) => println!("> Error: {}", e),
+) -> TestResult {
+    let status = match target.tests().get(test_id) {
+        Some(test_fn) => match test_fn(target.doc()) {
+            Ok(()) => TestResultStatus::Success,
+            Err(errors) => TestResultStatus::Failure { errors },
+        },
+        None if target.known_test_ids().contains(&test_id) => TestResultStatus::NotApplicable,
+        None => TestResultStatus::NotImplemented,
+    };
+
+    TestResult {
+        test_id: test_id.to_string(),
+        status,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::validation::{TestResult, TestResultStatus, ValidationError, ValidationReport, ValidationSeverity};
+
+    #[test]
+    fn test_report_validity() {
+        let error = ValidationError {
+            message: "Missing definition of product_id: CSAFPID-9080700".to_string(),
+            instance_path: "/product_tree/product_groups/0/product_ids/0".to_string(),
+            severity: ValidationSeverity::Error,
         };
-    } else {
-        println!("Test with ID {} is missing implementation", test_id);
+        let mut report = ValidationReport {
+            tests: vec![
+                TestResult { test_id: "6.1.1".to_string(), status: TestResultStatus::Success },
+                TestResult { test_id: "6.1.99".to_string(), status: TestResultStatus::NotImplemented },
+            ],
+        };
+        assert!(report.is_valid());
+        assert_eq!(0, report.errors().count());
+
+        report.tests.push(TestResult {
+            test_id: "6.1.2".to_string(),
+            status: TestResultStatus::Failure { errors: vec![error.clone()] },
+        });
+        assert!(!report.is_valid());
+        assert_eq!(vec![&error], report.errors().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_report_validity_ignores_warnings_and_infos() {
+        let warning = ValidationError {
+            message: "Unused definition of product_id: CSAFPID-9080700".to_string(),
+            instance_path: "/product_tree/full_product_names/0/product_id".to_string(),
+            severity: ValidationSeverity::Warning,
+        };
+        let info = ValidationError {
+            severity: ValidationSeverity::Info,
+            ..warning.clone()
+        };
+        let report = ValidationReport {
+            tests: vec![TestResult {
+                test_id: "6.2.1".to_string(),
+                status: TestResultStatus::Failure { errors: vec![warning, info] },
+            }],
+        };
+        assert!(report.is_valid());
+        assert!(report.has_findings(ValidationSeverity::Warning));
+        assert!(report.has_findings(ValidationSeverity::Info));
+        assert!(!report.has_findings(ValidationSeverity::Error));
     }
 }
@@ -1,3 +1,5 @@
+pub mod test_schema;
+
 pub mod test_6_1_01;
 pub mod test_6_1_02;
 pub mod test_6_1_03;
@@ -5,6 +7,46 @@ pub mod test_6_1_04;
 pub mod test_6_1_05;
 pub mod test_6_1_06;
 pub mod test_6_1_07;
+pub mod test_6_1_08;
+pub mod test_6_1_09;
+pub mod test_6_1_10;
+pub mod test_6_1_11;
+pub mod test_6_1_12;
+pub mod test_6_1_14;
+pub mod test_6_1_15;
+pub mod test_6_1_16;
+pub mod test_6_1_17;
+pub mod test_6_1_18;
+pub mod test_6_1_19;
+pub mod test_6_1_20;
+pub mod test_6_1_21;
+pub mod test_6_1_22;
+pub mod test_6_1_23;
+pub mod test_6_1_24;
+pub mod test_6_1_25;
+pub mod test_6_1_26;
+pub mod test_6_1_27_01;
+pub mod test_6_1_27_02;
+pub mod test_6_1_27_03;
+pub mod test_6_1_27_04;
+pub mod test_6_1_27_05;
+pub mod test_6_1_27_06;
+pub mod test_6_1_27_07;
+pub mod test_6_1_27_08;
+pub mod test_6_1_27_09;
+pub mod test_6_1_27_10;
+pub mod test_6_1_27_11;
+pub mod test_6_1_27_14;
+pub mod test_6_1_27_15;
+pub mod test_6_1_27_16;
+pub mod test_6_1_27_17;
+pub mod test_6_1_27_18;
+pub mod test_6_1_28;
+pub mod test_6_1_29;
+pub mod test_6_1_30;
+pub mod test_6_1_31;
+pub mod test_6_1_32;
+pub mod test_6_1_33;
 
 pub mod test_6_1_34;
 pub mod test_6_1_35;
@@ -22,3 +64,21 @@ pub mod test_6_1_46;
 pub mod test_6_1_47;
 pub mod test_6_1_48;
 pub mod test_6_1_49;
+pub mod test_6_1_50;
+pub mod test_6_1_51;
+pub mod test_6_1_52;
+pub mod test_6_1_54;
+
+pub mod test_6_2_01;
+pub mod test_6_2_02;
+pub mod test_6_2_03;
+pub mod test_6_2_04;
+pub mod test_6_2_05;
+pub mod test_6_2_06;
+pub mod test_6_2_07;
+pub mod test_6_2_14;
+pub mod test_6_2_15;
+pub mod test_6_2_18;
+pub mod test_6_2_37;
+
+pub mod test_threat_without_product_reference;
@@ -1,11 +1,11 @@
 use crate::csaf::getter_traits::{CsafTrait, ProductTrait, ProductTreeTrait};
 use crate::csaf::product_helpers::gather_product_references;
 use std::collections::HashSet;
-use crate::csaf::validation::ValidationError;
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
 
 pub fn test_6_1_01_missing_definition_of_product_id(
     doc: &impl CsafTrait,
-) -> Result<(), ValidationError> {
+) -> Result<(), Vec<ValidationError>> {
     let mut definitions_set = HashSet::<String>::new();
     if let Some(tree) = doc.get_product_tree().as_ref() {
         _ = tree.visit_all_products(&mut |fpn, _path| {
@@ -14,36 +14,102 @@ pub fn test_6_1_01_missing_definition_of_product_id(
         });
     }
     
-    let references = gather_product_references(doc);
-    for (ref_id, ref_path) in references.iter() {
-        if !definitions_set.contains(ref_id) {
-            return Err(ValidationError {
-                message: format!("Missing definition of product_id: {}", ref_id),
-                instance_path: ref_path.to_string(),
-            })
-        }
-    }
+    let errors: Vec<ValidationError> = gather_product_references(doc)
+        .into_iter()
+        .filter(|(ref_id, _)| !definitions_set.contains(ref_id))
+        .map(|(ref_id, ref_path)| ValidationError {
+            message: format!("Missing definition of product_id: {}", ref_id),
+            instance_path: ref_path,
+            severity: ValidationSeverity::Error,
+        })
+        .collect();
 
-    Ok(())
+    into_result(errors)
 }
 
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
-    use crate::csaf::test_helper::{run_csaf20_tests, run_csaf21_tests};
-    use crate::csaf::validation::ValidationError;
+    use crate::csaf::csaf2_0::schema::CommonSecurityAdvisoryFramework as Csaf20;
+    use crate::csaf::csaf2_1::schema::CommonSecurityAdvisoryFramework as Csaf21;
+    use crate::csaf::document::CsafDocument;
+    use crate::csaf::test_helper::{minimal_document, run_csaf20_tests, run_csaf21_tests};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
     use crate::csaf::validations::test_6_1_01::test_6_1_01_missing_definition_of_product_id;
+    use serde_json::{json, Value};
 
     #[test]
     fn test_6_1_01() {
         let error01 = ValidationError {
             message: "Missing definition of product_id: CSAFPID-9080700".to_string(),
             instance_path: "/product_tree/product_groups/0/product_ids/0".to_string(),
+            severity: ValidationSeverity::Error,
         };
         let errors = &HashMap::from([
-            ("01", &error01)
+            ("01", vec![error01])
         ]);
-        run_csaf20_tests("01", test_6_1_01_missing_definition_of_product_id, &errors);
-        run_csaf21_tests("01", test_6_1_01_missing_definition_of_product_id, &errors);
+        run_csaf20_tests("01", test_6_1_01_missing_definition_of_product_id, errors);
+        run_csaf21_tests("01", test_6_1_01_missing_definition_of_product_id, errors);
+    }
+
+    fn missing(product_id: &str, instance_path: &str) -> ValidationError {
+        ValidationError {
+            message: format!("Missing definition of product_id: {}", product_id),
+            instance_path: instance_path.to_string(),
+            severity: ValidationSeverity::Error,
+        }
+    }
+
+    fn with_product_tree(mut doc: Value) -> Value {
+        doc["product_tree"] = json!({
+            "full_product_names": [{ "name": "Product A", "product_id": "CSAFPID-9080700" }]
+        });
+        doc
+    }
+
+    #[test]
+    fn test_6_1_01_vulnerability_references_csaf20() {
+        let mut doc = with_product_tree(minimal_document("2.0"));
+        doc["vulnerabilities"] = json!([{
+            "flags": [{ "label": "component_not_present", "product_ids": ["CSAFPID-9080700", "CSAFPID-9080701"] }],
+            "scores": [{
+                "cvss_v3": {
+                    "version": "3.1",
+                    "vectorString": "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+                    "baseScore": 9.8,
+                    "baseSeverity": "CRITICAL"
+                },
+                "products": ["CSAFPID-9080702"]
+            }]
+        }]);
+        assert_eq!(
+            Err(vec![
+                missing("CSAFPID-9080702", "/vulnerabilities/0/scores/0/products/0"),
+                missing("CSAFPID-9080701", "/vulnerabilities/0/flags/0/product_ids/1"),
+            ]),
+            test_6_1_01_missing_definition_of_product_id(&CsafDocument::<Csaf20>::from_json(doc).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_6_1_01_vulnerability_references_csaf21() {
+        let mut doc = with_product_tree(minimal_document("2.1"));
+        doc["vulnerabilities"] = json!([{
+            "first_known_exploitation_dates": [{
+                "date": "2024-01-24T10:00:00.000Z",
+                "exploitation_date": "2024-01-20T10:00:00.000Z",
+                "product_ids": ["CSAFPID-9080703"]
+            }],
+            "flags": [{ "label": "component_not_present", "product_ids": ["CSAFPID-9080701"] }],
+            "involvements": [{ "party": "vendor", "status": "completed", "product_ids": ["CSAFPID-9080700", "CSAFPID-9080702"] }]
+        }]);
+        assert_eq!(
+            Err(vec![
+                missing("CSAFPID-9080701", "/vulnerabilities/0/flags/0/product_ids/0"),
+                missing("CSAFPID-9080702", "/vulnerabilities/0/involvements/0/product_ids/1"),
+                missing("CSAFPID-9080703", "/vulnerabilities/0/first_known_exploitation_dates/0/product_ids/0"),
+            ]),
+            test_6_1_01_missing_definition_of_product_id(&CsafDocument::<Csaf21>::from_json(doc).unwrap())
+        );
     }
 }
@@ -1,34 +1,36 @@
 use crate::csaf::getter_traits::{CsafTrait, ProductTrait, ProductTreeTrait};
-use crate::csaf::validation::ValidationError;
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
 use std::collections::HashSet;
 
 pub fn test_6_1_02_multiple_definition_of_product_id(
     doc: &impl CsafTrait,
-) -> Result<(), ValidationError> {
-    // Map to store each key with all of its paths
+) -> Result<(), Vec<ValidationError>> {
+    // Set of all product IDs defined so far
     let mut conflicts = HashSet::<String>::new();
+    let mut errors = Vec::<ValidationError>::new();
 
     if let Some(tree) = doc.get_product_tree().as_ref() {
         tree.visit_all_products(&mut |product, path| {
             if conflicts.contains(product.get_product_id()) {
-                Err(ValidationError {
+                errors.push(ValidationError {
                     message: format!("Duplicate definition for product ID {}", product.get_product_id()),
                     instance_path: format!("{}/product_id", path),
-                })
+                    severity: ValidationSeverity::Error,
+                });
             } else {
                 conflicts.insert(product.get_product_id().to_owned());
-                Ok(())
             }
+            Ok(())
         })?;
     }
 
-    Ok(())
+    into_result(errors)
 }
 
 #[cfg(test)]
 mod tests {
     use crate::csaf::test_helper::{run_csaf20_tests, run_csaf21_tests};
-    use crate::csaf::validation::ValidationError;
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
     use crate::csaf::validations::test_6_1_02::test_6_1_02_multiple_definition_of_product_id;
     use std::collections::HashMap;
 
@@ -37,9 +39,10 @@ mod tests {
         let error01 = ValidationError {
             message: "Duplicate definition for product ID CSAFPID-9080700".to_string(),
             instance_path: "/product_tree/full_product_names/1/product_id".to_string(),
+            severity: ValidationSeverity::Error,
         };
         let errors = HashMap::from([
-            ("01", &error01)
+            ("01", vec![error01])
         ]);
         run_csaf20_tests("02", test_6_1_02_multiple_definition_of_product_id, &errors);
         run_csaf21_tests("02", test_6_1_02_multiple_definition_of_product_id, &errors);
@@ -1,6 +1,6 @@
 use crate::csaf::getter_traits::{CsafTrait, ProductTrait, ProductTreeTrait, RelationshipTrait};
-use crate::csaf::validation::ValidationError;
-use std::collections::HashMap;
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
+use std::collections::{BTreeSet, HashMap, HashSet};
 
 /// Find the first cycle in the given `relation_map`, if any.
 ///
@@ -43,22 +43,26 @@ pub fn find_cycle<'a>(
 
 pub fn test_6_1_03_circular_definition_of_product_id(
     doc: &impl CsafTrait,
-) -> Result<(), ValidationError> {
+) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::<ValidationError>::new();
+
     if let Some(tree) = doc.get_product_tree().as_ref() {
         let mut relation_map = HashMap::<String, HashMap<String, usize>>::new();
 
         for (i_r, r) in tree.get_relationships().iter().enumerate() {
             let rel_prod_id = r.get_full_product_name().get_product_id();
             if r.get_product_reference() == rel_prod_id {
-                return Err(ValidationError {
+                errors.push(ValidationError {
                     message: "Relationship references itself via product_reference".to_string(),
                     instance_path: format!("/product_tree/relationships/{}/product_reference", i_r),
-                })
+                    severity: ValidationSeverity::Error,
+                });
             } else if r.get_relates_to_product_reference() == rel_prod_id {
-                return Err(ValidationError {
+                errors.push(ValidationError {
                     message: "Relationship references itself via relates_to_product_reference".to_string(),
                     instance_path: format!("/product_tree/relationships/{}/relates_to_product_reference", i_r),
-                })
+                    severity: ValidationSeverity::Error,
+                });
             } else {
                 match relation_map.get_mut(r.get_product_reference()) {
                     Some(v) => {
@@ -74,25 +78,32 @@ pub fn test_6_1_03_circular_definition_of_product_id(
             }
         }
 
-        // Perform cycle check
-        for product_id in relation_map.keys() {
+        // Perform cycle check, starting at each product ID in a stable order. Each cycle
+        // is only reported once, even though it is found from each of its members.
+        let mut product_ids: Vec<&String> = relation_map.keys().collect();
+        product_ids.sort();
+        let mut reported_cycles = HashSet::<BTreeSet<String>>::new();
+        for product_id in product_ids {
             let mut vec: Vec<&str> = vec!();
             if let Some((_, cycle, relation_index)) = find_cycle(&relation_map, product_id, &mut vec) {
-                return Err(ValidationError {
-                    message: format!("Found product relationship cycle: {}", cycle.join(" -> ")),
-                    instance_path: format!("/product_tree/relationships/{}", relation_index),
-                })
+                if reported_cycles.insert(cycle.iter().cloned().collect()) {
+                    errors.push(ValidationError {
+                        message: format!("Found product relationship cycle: {}", cycle.join(" -> ")),
+                        instance_path: format!("/product_tree/relationships/{}", relation_index),
+                        severity: ValidationSeverity::Error,
+                    });
+                }
             }
         }
     }
 
-    Ok(())
+    into_result(errors)
 }
 
 #[cfg(test)]
 mod tests {
     use crate::csaf::test_helper::{run_csaf20_tests, run_csaf21_tests};
-    use crate::csaf::validation::ValidationError;
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
     use crate::csaf::validations::test_6_1_03::test_6_1_03_circular_definition_of_product_id;
     use std::collections::HashMap;
 
@@ -101,9 +112,10 @@ mod tests {
         let error01 = ValidationError {
             message: "Relationship references itself via relates_to_product_reference".to_string(),
             instance_path: "/product_tree/relationships/0/relates_to_product_reference".to_string(),
+            severity: ValidationSeverity::Error,
         };
         let errors = HashMap::from([
-            ("01", &error01)
+            ("01", vec![error01])
         ]);
         run_csaf20_tests("03", test_6_1_03_circular_definition_of_product_id, &errors);
         run_csaf21_tests("03", test_6_1_03_circular_definition_of_product_id, &errors);
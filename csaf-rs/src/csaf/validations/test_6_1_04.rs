@@ -1,10 +1,12 @@
 use crate::csaf::getter_traits::{CsafTrait, DocumentTrait, ProductGroupTrait, ProductTreeTrait, VulnerabilityTrait, WithGroupIds};
-use crate::csaf::validation::ValidationError;
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
 use std::collections::HashSet;
 
 pub fn test_6_1_04_missing_definition_of_product_group_id(
     doc: &impl CsafTrait,
-) -> Result<(), ValidationError> {
+) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::<ValidationError>::new();
+
     if let Some(tree) = doc.get_product_tree().as_ref() {
         let mut known_groups = HashSet::<String>::new();
         // Collect all known product group IDs
@@ -18,9 +20,10 @@ pub fn test_6_1_04_missing_definition_of_product_group_id(
                 if let Some(group_ids) = note.get_group_ids() {
                     for (i_g, group_id) in group_ids.enumerate() {
                         if !known_groups.contains(group_id) {
-                            return Err(ValidationError {
+                            errors.push(ValidationError {
                                 message: format!("Missing definition of product_group_id: {}", group_id),
                                 instance_path: format!("/document/notes/{}/group_ids/{}", i_n, i_g),
+                                severity: ValidationSeverity::Error,
                             });
                         }
                     }
@@ -36,9 +39,10 @@ pub fn test_6_1_04_missing_definition_of_product_group_id(
                     if let Some(group_ids) = flag.get_group_ids() {
                         for (i_g, group_id) in group_ids.enumerate() {
                             if !known_groups.contains(group_id) {
-                                return Err(ValidationError {
+                                errors.push(ValidationError {
                                     message: format!("Missing definition of product_group_id: {}", group_id),
                                     instance_path: format!("/vulnerabilities/{}/flags/{}/group_ids/{}", i_v, i_f, i_g),
+                                    severity: ValidationSeverity::Error,
                                 });
                             }
                         }
@@ -52,9 +56,10 @@ pub fn test_6_1_04_missing_definition_of_product_group_id(
                     if let Some(group_ids) = note.get_group_ids() {
                         for (i_g, group_id) in group_ids.enumerate() {
                             if !known_groups.contains(group_id) {
-                                return Err(ValidationError {
+                                errors.push(ValidationError {
                                     message: format!("Missing definition of product_group_id: {}", group_id),
                                     instance_path: format!("/vulnerabilities/{}/notes/{}/group_ids/{}", i_v, i_n, i_g),
+                                    severity: ValidationSeverity::Error,
                                 });
                             }
                         }
@@ -67,9 +72,10 @@ pub fn test_6_1_04_missing_definition_of_product_group_id(
                 if let Some(group_ids) = remediation.get_group_ids() {
                     for (i_g, group_id) in group_ids.collect::<Vec<_>>().iter().enumerate() {
                         if !known_groups.contains(*group_id) {
-                            return Err(ValidationError {
+                            errors.push(ValidationError {
                                 message: format!("Missing definition of product_group_id: {}", group_id),
                                 instance_path: format!("/vulnerabilities/{}/remediations/{}/group_ids/{}", i_v, i_r, i_g),
+                                severity: ValidationSeverity::Error,
                             });
                         }
                     }
@@ -81,9 +87,10 @@ pub fn test_6_1_04_missing_definition_of_product_group_id(
                 if let Some(group_ids) = threat.get_group_ids() {
                     for (i_g, group_id) in group_ids.collect::<Vec<_>>().iter().enumerate() {
                         if !known_groups.contains(*group_id) {
-                            return Err(ValidationError {
+                            errors.push(ValidationError {
                                 message: format!("Missing definition of product_group_id: {}", group_id),
                                 instance_path: format!("/vulnerabilities/{}/threats/{}/group_ids/{}", i_v, i_t, i_g),
+                                severity: ValidationSeverity::Error,
                             });
                         }
                     }
@@ -92,13 +99,13 @@ pub fn test_6_1_04_missing_definition_of_product_group_id(
         }
     }
 
-    Ok(())
+    into_result(errors)
 }
 
 #[cfg(test)]
 mod tests {
     use crate::csaf::test_helper::{run_csaf20_tests, run_csaf21_tests};
-    use crate::csaf::validation::ValidationError;
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
     use crate::csaf::validations::test_6_1_04::test_6_1_04_missing_definition_of_product_group_id;
     use std::collections::HashMap;
 
@@ -107,9 +114,10 @@ mod tests {
         let error01 = ValidationError {
             message: "Missing definition of product_group_id: CSAFGID-1020301".to_string(),
             instance_path: "/vulnerabilities/0/threats/0/group_ids/0".to_string(),
+            severity: ValidationSeverity::Error,
         };
         let errors = HashMap::from([
-            ("01", &error01)
+            ("01", vec![error01])
         ]);
         run_csaf20_tests("04", test_6_1_04_missing_definition_of_product_group_id, &errors);
         run_csaf21_tests("04", test_6_1_04_missing_definition_of_product_group_id, &errors);
@@ -1,33 +1,36 @@
 use crate::csaf::getter_traits::{CsafTrait, ProductGroupTrait, ProductTreeTrait};
-use crate::csaf::validation::ValidationError;
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
 use std::collections::HashSet;
 
 pub fn test_6_1_05_multiple_definition_of_product_group_id(
     doc: &impl CsafTrait,
-) -> Result<(), ValidationError> {
+) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::<ValidationError>::new();
+
     // Map to store each key with all of its paths
     let mut conflicts = HashSet::<String>::new();
 
     if let Some(tree) = doc.get_product_tree().as_ref() {
         for (i_g, g) in tree.get_product_groups().iter().enumerate() {
             if conflicts.contains(g.get_group_id()) {
-                return Err(ValidationError {
+                errors.push(ValidationError {
                     message: format!("Duplicate definition for product group ID {}", g.get_group_id()),
                     instance_path: format!("/product_tree/product_groups/{}/group_id", i_g),
-                })
+                    severity: ValidationSeverity::Error,
+                });
             } else {
                 conflicts.insert(g.get_group_id().to_owned());
             }
         }
     }
 
-    Ok(())
+    into_result(errors)
 }
 
 #[cfg(test)]
 mod tests {
     use crate::csaf::test_helper::{run_csaf20_tests, run_csaf21_tests};
-    use crate::csaf::validation::ValidationError;
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
     use crate::csaf::validations::test_6_1_05::test_6_1_05_multiple_definition_of_product_group_id;
     use std::collections::HashMap;
 
@@ -36,9 +39,10 @@ mod tests {
         let error01 = ValidationError {
             message: "Duplicate definition for product group ID CSAFGID-1020300".to_string(),
             instance_path: "/product_tree/product_groups/1/group_id".to_string(),
+            severity: ValidationSeverity::Error,
         };
         let errors = HashMap::from([
-            ("01", &error01)
+            ("01", vec![error01])
         ]);
         run_csaf20_tests("05", test_6_1_05_multiple_definition_of_product_group_id, &errors);
         run_csaf21_tests("05", test_6_1_05_multiple_definition_of_product_group_id, &errors);
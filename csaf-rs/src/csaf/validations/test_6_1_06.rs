@@ -1,5 +1,5 @@
 use crate::csaf::getter_traits::{CsafTrait, ProductStatusTrait, VulnerabilityTrait};
-use crate::csaf::validation::ValidationError;
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
 use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
 
@@ -32,7 +32,9 @@ impl Display for ProductStatusGroup {
 
 pub fn test_6_1_06_contradicting_product_status(
     doc: &impl CsafTrait,
-) -> Result<(), ValidationError> {
+) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::<ValidationError>::new();
+
     for (v_i, v) in doc.get_vulnerabilities().iter().enumerate() {
         if let Some(product_status) = v.get_product_status() {
             // Map of product IDs to product status groups (mutually exclusive, therefore only one allowed)
@@ -47,54 +49,61 @@ pub fn test_6_1_06_contradicting_product_status(
             check_status_group(
                 v_i,
                 &mut product_statuses,
+                &mut errors,
                 product_status.get_known_not_affected(),
                 ProductStatusGroup::NotAffected,
                 "known_not_affected",
-            )?;
+            );
 
             check_status_group(
                 v_i,
                 &mut product_statuses,
+                &mut errors,
                 product_status.get_first_fixed(),
                 ProductStatusGroup::Fixed,
                 "first_fixed",
-            )?;
+            );
             check_status_group(
                 v_i,
                 &mut product_statuses,
+                &mut errors,
                 product_status.get_fixed(),
                 ProductStatusGroup::Fixed,
                 "fixed",
-            )?;
+            );
 
             check_status_group(
                 v_i,
                 &mut product_statuses,
+                &mut errors,
                 product_status.get_under_investigation(),
                 ProductStatusGroup::UnderInvestigation,
                 "under_investigation",
-            )?;
+            );
 
             check_status_group(
                 v_i,
                 &mut product_statuses,
+                &mut errors,
                 product_status.get_unknown(),
                 ProductStatusGroup::Unknown,
                 "unknown",
-            )?;
+            );
         }
     }
-    Ok(())
+
+    into_result(errors)
 }
 
-// Helper function to check for status group conflicts
+// Helper function to check for status group conflicts, adding one error per conflicting product ID
 fn check_status_group<'a>(
     v_i: usize,
     product_statuses: &mut HashMap<String, ProductStatusGroup>,
+    errors: &mut Vec<ValidationError>,
     product_ids: Option<impl IntoIterator<Item = &'a String>>,
     status_group: ProductStatusGroup,
     field_name: &str,
-) -> Result<(), ValidationError> {
+) {
     if let Some(products) = product_ids {
         for (i_pid, pid) in products.into_iter().enumerate() {
             match product_statuses.get(pid) {
@@ -103,7 +112,7 @@ fn check_status_group<'a>(
                 }
                 Some(existing_status) => {
                     if *existing_status != status_group {
-                        return Err(ValidationError {
+                        errors.push(ValidationError {
                             message: format!(
                                 "Product {} is marked with product status group \"{}\" but has conflicting product status belonging to group \"{}\"",
                                 pid,
@@ -111,19 +120,19 @@ fn check_status_group<'a>(
                                 existing_status.to_string()
                             ),
                             instance_path: format!("/vulnerabilities/{}/product_status/{}/{}", v_i, field_name, i_pid),
+                            severity: ValidationSeverity::Error,
                         });
                     }
                 }
             }
         }
     }
-    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use crate::csaf::test_helper::run_csaf21_tests;
-    use crate::csaf::validation::ValidationError;
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
     use crate::csaf::validations::test_6_1_06::test_6_1_06_contradicting_product_status;
     use std::collections::HashMap;
 
@@ -135,30 +144,36 @@ mod tests {
             "06",
             test_6_1_06_contradicting_product_status,
             &HashMap::from([
-                ("01", &ValidationError {
+                ("01", vec![ValidationError {
                     message: first_error_message.to_string(),
-                    instance_path: first_error_path.to_string()
-                }),
-                ("02", &ValidationError {
+                    instance_path: first_error_path.to_string(),
+                    severity: ValidationSeverity::Error,
+                }]),
+                ("02", vec![ValidationError {
                     message: first_error_message.to_string(),
-                    instance_path: first_error_path.to_string()
-                }),
-                ("03", &ValidationError {
+                    instance_path: first_error_path.to_string(),
+                    severity: ValidationSeverity::Error,
+                }]),
+                ("03", vec![ValidationError {
                     message: first_error_message.to_string(),
-                    instance_path: first_error_path.to_string()
-                }),
-                ("04", &ValidationError {
+                    instance_path: first_error_path.to_string(),
+                    severity: ValidationSeverity::Error,
+                }]),
+                ("04", vec![ValidationError {
                     message: "Product CSAFPID-9080701 is marked with product status group \"fixed\" but has conflicting product status belonging to group \"not affected\"".to_string(),
                     instance_path: "/vulnerabilities/0/product_status/fixed/0".to_string(),
-                }),
-                ("05", &ValidationError {
+                    severity: ValidationSeverity::Error,
+                }]),
+                ("05", vec![ValidationError {
                     message: "Product CSAFPID-9080702 is marked with product status group \"fixed\" but has conflicting product status belonging to group \"affected\"".to_string(),
                     instance_path: "/vulnerabilities/0/product_status/first_fixed/0".to_string(),
-                }),
-                ("06", &ValidationError {
+                    severity: ValidationSeverity::Error,
+                }]),
+                ("06", vec![ValidationError {
                     message: "Product CSAFPID-9080700 is marked with product status group \"unknown\" but has conflicting product status belonging to group \"affected\"".to_string(),
                     instance_path: "/vulnerabilities/0/product_status/unknown/0".to_string(),
-                }),
+                    severity: ValidationSeverity::Error,
+                }]),
             ]),
         );
     }
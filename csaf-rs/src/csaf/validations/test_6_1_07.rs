@@ -1,5 +1,5 @@
 use crate::csaf::getter_traits::{ContentTrait, CsafTrait, MetricTrait, VulnerabilityTrait};
-use crate::csaf::validation::ValidationError;
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
 use crate::csaf::validations::test_6_1_07::VulnerabilityMetrics::{CvssV2, CvssV30, CvssV31, CvssV4, Epss, SsvcV1};
 use std::collections::{HashMap, HashSet};
 use std::fmt::{Display, Formatter};
@@ -45,7 +45,9 @@ fn get_metric_prop_name(metric: VulnerabilityMetrics) -> &'static str {
 /// vulnerability.
 pub fn test_6_1_07_multiple_same_scores_per_product(
     doc: &impl CsafTrait,
-) -> Result<(), ValidationError> {
+) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::<ValidationError>::new();
+
     for (v_i, v) in doc.get_vulnerabilities().iter().enumerate() {
         let mut seen_metrics: HashMap<String, HashSet<(VulnerabilityMetrics, &Option<String>)>> = HashMap::new();
         if let Some(metrics) = v.get_metrics() {
@@ -65,13 +67,14 @@ pub fn test_6_1_07_multiple_same_scores_per_product(
                         } else if version == "3.0" {
                             content_metrics.push((CvssV30, m.get_source()));
                         } else {
-                            return Err(ValidationError {
+                            errors.push(ValidationError {
                                 message: format!("CVSS-v3 version {} is not supported.", version),
                                 instance_path: format!(
                                     "{}/{}",
                                     content.get_content_json_path(v_i, m_i),
                                     get_metric_prop_name(CvssV30),
                                 ),
+                                severity: ValidationSeverity::Error,
                             });
                         }
                     }
@@ -86,7 +89,7 @@ pub fn test_6_1_07_multiple_same_scores_per_product(
                     let metrics_set = seen_metrics.entry(p.to_string()).or_insert_with(|| HashSet::new());
                     for cm_src in content_metrics.iter() {
                         if metrics_set.contains(cm_src) {
-                            return Err(ValidationError {
+                            errors.push(ValidationError {
                                 message: format!(
                                     "Product {} already has another metric \"{}\" {} assigned.",
                                     p,
@@ -101,6 +104,7 @@ pub fn test_6_1_07_multiple_same_scores_per_product(
                                     content.get_content_json_path(v_i, m_i),
                                     get_metric_prop_name(cm_src.0.to_owned())
                                 ),
+                                severity: ValidationSeverity::Error,
                             });
                         } else {
                             metrics_set.insert(cm_src.to_owned());
@@ -110,13 +114,14 @@ pub fn test_6_1_07_multiple_same_scores_per_product(
             }
         }
     }
-    Ok(())
+
+    into_result(errors)
 }
 
 #[cfg(test)]
 mod tests {
     use crate::csaf::test_helper::{run_csaf20_tests, run_csaf21_tests};
-    use crate::csaf::validation::ValidationError;
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
     use crate::csaf::validations::test_6_1_07::test_6_1_07_multiple_same_scores_per_product;
     use std::collections::HashMap;
 
@@ -129,37 +134,43 @@ mod tests {
             "07",
             test_6_1_07_multiple_same_scores_per_product,
             &HashMap::from([
-                ("01", &ValidationError {
+                ("01", vec![ValidationError {
                     message: cvss_v31_error_message.to_string(),
                     instance_path: format!("{}/cvss_v3", csaf_20_path_prefix),
-                }),
+                    severity: ValidationSeverity::Error,
+                }]),
             ]),
         );
         run_csaf21_tests(
             "07",
             test_6_1_07_multiple_same_scores_per_product,
             &HashMap::from([
-                ("01", &ValidationError {
+                ("01", vec![ValidationError {
                     message: cvss_v31_error_message.to_string(),
                     instance_path: format!("{}/cvss_v3", csaf_21_path_prefix),
-                }),
-                ("02", &ValidationError {
+                    severity: ValidationSeverity::Error,
+                }]),
+                ("02", vec![ValidationError {
                     message: "Product CSAFPID-9080700 already has another metric \"CVSS-v3.0\" without a source assigned.".to_string(),
                     instance_path: format!("{}/cvss_v3", csaf_21_path_prefix),
-                }),
-                ("03", &ValidationError {
+                    severity: ValidationSeverity::Error,
+                }]),
+                ("03", vec![ValidationError {
                     message: "Product CSAFPID-9080700 already has another metric \"CVSS-v2\" without a source assigned.".to_string(),
                     instance_path: format!("{}/cvss_v2", csaf_21_path_prefix),
-                }),
-                ("04", &ValidationError {
+                    severity: ValidationSeverity::Error,
+                }]),
+                ("04", vec![ValidationError {
                     message: "Product CSAFPID-9080700 already has another metric \"CVSS-v4\" without a source assigned.".to_string(),
                     instance_path: format!("{}/cvss_v4", csaf_21_path_prefix),
-                }),
-                ("05", &ValidationError {
+                    severity: ValidationSeverity::Error,
+                }]),
+                ("05", vec![ValidationError {
                     message: "Product CSAFPID-9080700 already has another metric \"CVSS-v3.1\" with the same source \
                     \"https://www.example.com/.well-known/csaf/clear/2024/esa-2024-0001.json\" assigned.".to_string(),
                     instance_path: format!("{}/cvss_v3", csaf_21_path_prefix),
-                }),
+                    severity: ValidationSeverity::Error,
+                }]),
             ]),
         );
     }
@@ -0,0 +1,115 @@
+use crate::csaf::getter_traits::{ContentTrait, CsafTrait, MetricTrait, VulnerabilityTrait};
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
+
+pub fn test_6_1_08_invalid_cvss(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::<ValidationError>::new();
+
+    for (v_i, v) in doc.get_vulnerabilities().iter().enumerate() {
+        if let Some(metrics) = v.get_metrics() {
+            for (m_i, m) in metrics.iter().enumerate() {
+                let content = m.get_content();
+                let content_path = content.get_content_json_path(v_i, m_i);
+                if let Some(Err(e)) = content.get_cvss_v2_parsed() {
+                    errors.push(ValidationError {
+                        message: format!("Invalid CVSS v2 object: {}", e),
+                        instance_path: format!("{}/cvss_v2", content_path),
+                        severity: ValidationSeverity::Error,
+                    });
+                }
+                if let Some(Err(e)) = content.get_cvss_v3_parsed() {
+                    errors.push(ValidationError {
+                        message: format!("Invalid CVSS v3 object: {}", e),
+                        instance_path: format!("{}/cvss_v3", content_path),
+                        severity: ValidationSeverity::Error,
+                    });
+                }
+                if let Some(Err(e)) = content.get_cvss_v4_parsed() {
+                    errors.push(ValidationError {
+                        message: format!("Invalid CVSS v4 object: {}", e),
+                        instance_path: format!("{}/cvss_v4", content_path),
+                        severity: ValidationSeverity::Error,
+                    });
+                }
+            }
+        }
+    }
+
+    into_result(errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::csaf2_0::schema::CommonSecurityAdvisoryFramework as Csaf20;
+    use crate::csaf::csaf2_1::schema::CommonSecurityAdvisoryFramework as Csaf21;
+    use crate::csaf::document::CsafDocument;
+    use crate::csaf::test_helper::{minimal_document, run_csaf20_tests, run_csaf21_tests};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_1_08::test_6_1_08_invalid_cvss;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_1_08() {
+        run_csaf20_tests(
+            "08",
+            test_6_1_08_invalid_cvss,
+            &HashMap::from([
+                ("01", vec![ValidationError {
+                    message: "Invalid CVSS v3 object: missing field `baseSeverity`".to_string(),
+                    instance_path: "/vulnerabilities/0/scores/0/cvss_v3".to_string(),
+                    severity: ValidationSeverity::Error,
+                }]),
+            ]),
+        );
+        run_csaf21_tests(
+            "08",
+            test_6_1_08_invalid_cvss,
+            &HashMap::from([
+                ("01", vec![ValidationError {
+                    message: "Invalid CVSS v3 object: missing field `baseSeverity`".to_string(),
+                    instance_path: "/vulnerabilities/0/metrics/0/content/cvss_v3".to_string(),
+                    severity: ValidationSeverity::Error,
+                }]),
+            ]),
+        );
+    }
+
+    #[test]
+    fn test_test_6_1_08_cvss_v2_and_v4() {
+        let mut doc = minimal_document("2.0");
+        doc["vulnerabilities"] = json!([{
+            "scores": [{
+                "products": ["CSAFPID-9080700"],
+                "cvss_v2": {
+                    "version": "2.0",
+                    "vectorString": "AV:N/AC:L/Au:N/C:P/I:P",
+                    "baseScore": 7.5
+                }
+            }]
+        }]);
+        let errors = test_6_1_08_invalid_cvss(&CsafDocument::<Csaf20>::from_json(doc).unwrap()).unwrap_err();
+        let paths: Vec<&str> = errors.iter().map(|e| e.instance_path.as_str()).collect();
+        assert_eq!(vec!["/vulnerabilities/0/scores/0/cvss_v2"], paths);
+
+        let mut doc = minimal_document("2.1");
+        doc["vulnerabilities"] = json!([{
+            "metrics": [
+                { "products": ["CSAFPID-9080700"], "content": { "cvss_v4": {
+                    "version": "4.0",
+                    "vectorString": "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N",
+                    "baseScore": 9.3,
+                    "baseSeverity": "CRITICAL"
+                } } },
+                { "products": ["CSAFPID-9080700"], "content": { "cvss_v4": {
+                    "version": "4.0",
+                    "vectorString": "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H",
+                    "baseScore": 9.3,
+                    "baseSeverity": "CRITICAL"
+                } } }
+            ]
+        }]);
+        let errors = test_6_1_08_invalid_cvss(&CsafDocument::<Csaf21>::from_json(doc).unwrap()).unwrap_err();
+        let paths: Vec<&str> = errors.iter().map(|e| e.instance_path.as_str()).collect();
+        assert_eq!(vec!["/vulnerabilities/0/metrics/1/content/cvss_v4"], paths);
+    }
+}
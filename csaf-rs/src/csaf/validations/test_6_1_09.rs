@@ -0,0 +1,129 @@
+use crate::csaf::getter_traits::{ContentTrait, CsafTrait, MetricTrait, VulnerabilityTrait};
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
+
+pub fn test_6_1_09_invalid_cvss_computation(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::<ValidationError>::new();
+
+    for (v_i, v) in doc.get_vulnerabilities().iter().enumerate() {
+        if let Some(metrics) = v.get_metrics() {
+            for (m_i, m) in metrics.iter().enumerate() {
+                let content = m.get_content();
+                let content_path = content.get_content_json_path(v_i, m_i);
+                // Invalid CVSS objects are reported by 6.1.8
+                let mut mismatches = Vec::new();
+                if let Some(Ok(cvss)) = content.get_cvss_v2_parsed() {
+                    mismatches.extend(cvss.computation_mismatches().into_iter().map(|m| ("cvss_v2", m)));
+                }
+                if let Some(Ok(cvss)) = content.get_cvss_v3_parsed() {
+                    mismatches.extend(cvss.computation_mismatches().into_iter().map(|m| ("cvss_v3", m)));
+                }
+                if let Some(Ok(cvss)) = content.get_cvss_v4_parsed() {
+                    mismatches.extend(cvss.computation_mismatches().into_iter().map(|m| ("cvss_v4", m)));
+                }
+                for (cvss_property, mismatch) in mismatches {
+                    errors.push(ValidationError {
+                        message: format!(
+                            "The {} is {}, but the computed value for the vectorString is {}",
+                            mismatch.property, mismatch.actual, mismatch.expected
+                        ),
+                        instance_path: format!("{}/{}/{}", content_path, cvss_property, mismatch.property),
+                        severity: ValidationSeverity::Error,
+                    });
+                }
+            }
+        }
+    }
+
+    into_result(errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::csaf2_0::schema::CommonSecurityAdvisoryFramework as Csaf20;
+    use crate::csaf::csaf2_1::schema::CommonSecurityAdvisoryFramework as Csaf21;
+    use crate::csaf::document::CsafDocument;
+    use crate::csaf::test_helper::{minimal_document, run_csaf20_tests, run_csaf21_tests};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_1_09::test_6_1_09_invalid_cvss_computation;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_1_09() {
+        run_csaf20_tests(
+            "09",
+            test_6_1_09_invalid_cvss_computation,
+            &HashMap::from([
+                ("01", vec![
+                    ValidationError {
+                        message: "The baseScore is 10, but the computed value for the vectorString is 6.5".to_string(),
+                        instance_path: "/vulnerabilities/0/scores/0/cvss_v3/baseScore".to_string(),
+                        severity: ValidationSeverity::Error,
+                    },
+                    ValidationError {
+                        message: "The baseSeverity is LOW, but the computed value for the vectorString is MEDIUM".to_string(),
+                        instance_path: "/vulnerabilities/0/scores/0/cvss_v3/baseSeverity".to_string(),
+                        severity: ValidationSeverity::Error,
+                    },
+                ]),
+            ]),
+        );
+        run_csaf21_tests(
+            "09",
+            test_6_1_09_invalid_cvss_computation,
+            &HashMap::from([
+                ("01", vec![
+                    ValidationError {
+                        message: "The baseScore is 10, but the computed value for the vectorString is 6.5".to_string(),
+                        instance_path: "/vulnerabilities/0/metrics/0/content/cvss_v3/baseScore".to_string(),
+                        severity: ValidationSeverity::Error,
+                    },
+                    ValidationError {
+                        message: "The baseSeverity is LOW, but the computed value for the vectorString is MEDIUM".to_string(),
+                        instance_path: "/vulnerabilities/0/metrics/0/content/cvss_v3/baseSeverity".to_string(),
+                        severity: ValidationSeverity::Error,
+                    },
+                ]),
+            ]),
+        );
+    }
+
+    #[test]
+    fn test_test_6_1_09_cvss_v2_and_v4() {
+        let cvss_v2 = json!({
+            "version": "2.0",
+            "vectorString": "AV:N/AC:L/Au:N/C:P/I:P/A:P",
+            "baseScore": 7.5
+        });
+
+        let mut doc = minimal_document("2.0");
+        doc["vulnerabilities"] = json!([{
+            "scores": [{ "products": ["CSAFPID-9080700"], "cvss_v2": cvss_v2 }]
+        }]);
+        assert_eq!(
+            Ok(()),
+            test_6_1_09_invalid_cvss_computation(&CsafDocument::<Csaf20>::from_json(doc).unwrap())
+        );
+
+        let mut doc = minimal_document("2.1");
+        doc["vulnerabilities"] = json!([{
+            "metrics": [
+                { "products": ["CSAFPID-9080700"], "content": { "cvss_v2": cvss_v2 } },
+                { "products": ["CSAFPID-9080700"], "content": { "cvss_v4": {
+                    "version": "4.0",
+                    "vectorString": "CVSS:4.0/AV:L/AC:L/AT:N/PR:L/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N",
+                    "baseScore": 8.7,
+                    "baseSeverity": "HIGH"
+                } } }
+            ]
+        }]);
+        assert_eq!(
+            Err(vec![ValidationError {
+                message: "The baseScore is 8.7, but the computed value for the vectorString is 8.5".to_string(),
+                instance_path: "/vulnerabilities/0/metrics/1/content/cvss_v4/baseScore".to_string(),
+                severity: ValidationSeverity::Error,
+            }]),
+            test_6_1_09_invalid_cvss_computation(&CsafDocument::<Csaf21>::from_json(doc).unwrap())
+        );
+    }
+}
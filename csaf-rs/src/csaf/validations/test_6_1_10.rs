@@ -0,0 +1,161 @@
+use crate::csaf::getter_traits::{ContentTrait, CsafTrait, MetricTrait, VulnerabilityTrait};
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
+
+pub fn test_6_1_10_inconsistent_cvss(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::<ValidationError>::new();
+
+    for (v_i, v) in doc.get_vulnerabilities().iter().enumerate() {
+        if let Some(metrics) = v.get_metrics() {
+            for (m_i, m) in metrics.iter().enumerate() {
+                let content = m.get_content();
+                let content_path = content.get_content_json_path(v_i, m_i);
+                // Invalid CVSS objects are reported by 6.1.8
+                let mut mismatches = Vec::new();
+                if let Some(Ok(cvss)) = content.get_cvss_v2_parsed() {
+                    mismatches.extend(cvss.property_mismatches().into_iter().map(|m| ("cvss_v2", m)));
+                }
+                if let Some(Ok(cvss)) = content.get_cvss_v3_parsed() {
+                    mismatches.extend(cvss.property_mismatches().into_iter().map(|m| ("cvss_v3", m)));
+                }
+                if let Some(Ok(cvss)) = content.get_cvss_v4_parsed() {
+                    mismatches.extend(cvss.property_mismatches().into_iter().map(|m| ("cvss_v4", m)));
+                }
+                for (cvss_property, mismatch) in mismatches {
+                    errors.push(ValidationError {
+                        message: format!(
+                            "The {} is {}, but the vectorString defines it as {}",
+                            mismatch.property, mismatch.actual, mismatch.expected
+                        ),
+                        instance_path: format!("{}/{}/{}", content_path, cvss_property, mismatch.property),
+                        severity: ValidationSeverity::Error,
+                    });
+                }
+            }
+        }
+    }
+
+    into_result(errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::csaf2_0::schema::CommonSecurityAdvisoryFramework as Csaf20;
+    use crate::csaf::csaf2_1::schema::CommonSecurityAdvisoryFramework as Csaf21;
+    use crate::csaf::document::CsafDocument;
+    use crate::csaf::test_helper::{minimal_document, run_csaf20_tests, run_csaf21_tests};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_1_10::test_6_1_10_inconsistent_cvss;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_1_10() {
+        run_csaf20_tests(
+            "10",
+            test_6_1_10_inconsistent_cvss,
+            &HashMap::from([
+                ("01", vec![
+                    ValidationError {
+                        message: "The attackVector is NETWORK, but the vectorString defines it as LOCAL".to_string(),
+                        instance_path: "/vulnerabilities/0/scores/0/cvss_v3/attackVector".to_string(),
+                        severity: ValidationSeverity::Error,
+                    },
+                    ValidationError {
+                        message: "The privilegesRequired is LOW, but the vectorString defines it as HIGH".to_string(),
+                        instance_path: "/vulnerabilities/0/scores/0/cvss_v3/privilegesRequired".to_string(),
+                        severity: ValidationSeverity::Error,
+                    },
+                    ValidationError {
+                        message: "The userInteraction is NONE, but the vectorString defines it as REQUIRED".to_string(),
+                        instance_path: "/vulnerabilities/0/scores/0/cvss_v3/userInteraction".to_string(),
+                        severity: ValidationSeverity::Error,
+                    },
+                    ValidationError {
+                        message: "The scope is CHANGED, but the vectorString defines it as UNCHANGED".to_string(),
+                        instance_path: "/vulnerabilities/0/scores/0/cvss_v3/scope".to_string(),
+                        severity: ValidationSeverity::Error,
+                    },
+                    ValidationError {
+                        message: "The availabilityImpact is LOW, but the vectorString defines it as HIGH".to_string(),
+                        instance_path: "/vulnerabilities/0/scores/0/cvss_v3/availabilityImpact".to_string(),
+                        severity: ValidationSeverity::Error,
+                    },
+                ]),
+            ]),
+        );
+        run_csaf21_tests(
+            "10",
+            test_6_1_10_inconsistent_cvss,
+            &HashMap::from([
+                ("01", vec![
+                    ValidationError {
+                        message: "The attackVector is NETWORK, but the vectorString defines it as LOCAL".to_string(),
+                        instance_path: "/vulnerabilities/0/metrics/0/content/cvss_v3/attackVector".to_string(),
+                        severity: ValidationSeverity::Error,
+                    },
+                    ValidationError {
+                        message: "The privilegesRequired is LOW, but the vectorString defines it as HIGH".to_string(),
+                        instance_path: "/vulnerabilities/0/metrics/0/content/cvss_v3/privilegesRequired".to_string(),
+                        severity: ValidationSeverity::Error,
+                    },
+                    ValidationError {
+                        message: "The userInteraction is NONE, but the vectorString defines it as REQUIRED".to_string(),
+                        instance_path: "/vulnerabilities/0/metrics/0/content/cvss_v3/userInteraction".to_string(),
+                        severity: ValidationSeverity::Error,
+                    },
+                    ValidationError {
+                        message: "The scope is CHANGED, but the vectorString defines it as UNCHANGED".to_string(),
+                        instance_path: "/vulnerabilities/0/metrics/0/content/cvss_v3/scope".to_string(),
+                        severity: ValidationSeverity::Error,
+                    },
+                    ValidationError {
+                        message: "The availabilityImpact is LOW, but the vectorString defines it as HIGH".to_string(),
+                        instance_path: "/vulnerabilities/0/metrics/0/content/cvss_v3/availabilityImpact".to_string(),
+                        severity: ValidationSeverity::Error,
+                    },
+                ]),
+            ]),
+        );
+    }
+
+    #[test]
+    fn test_test_6_1_10_cvss_v2_and_v4() {
+        let mut doc = minimal_document("2.1");
+        doc["vulnerabilities"] = json!([{
+            "metrics": [{ "products": ["CSAFPID-9080700"], "content": { "cvss_v4": {
+                "version": "4.0",
+                "vectorString": "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N/E:P",
+                "exploitMaturity": "ATTACKED",
+                "baseScore": 9.3,
+                "baseSeverity": "CRITICAL"
+            } } }]
+        }]);
+        assert_eq!(
+            Err(vec![ValidationError {
+                message: "The exploitMaturity is ATTACKED, but the vectorString defines it as PROOF_OF_CONCEPT"
+                    .to_string(),
+                instance_path: "/vulnerabilities/0/metrics/0/content/cvss_v4/exploitMaturity".to_string(),
+                severity: ValidationSeverity::Error,
+            }]),
+            test_6_1_10_inconsistent_cvss(&CsafDocument::<Csaf21>::from_json(doc).unwrap())
+        );
+
+        let mut doc = minimal_document("2.0");
+        doc["vulnerabilities"] = json!([{
+            "scores": [{
+                "products": ["CSAFPID-9080700"],
+                "cvss_v2": {
+                    "version": "2.0",
+                    "vectorString": "AV:N/AC:L/Au:N/C:P/I:P/A:P",
+                    "accessVector": "NETWORK",
+                    "authentication": "NONE",
+                    "baseScore": 7.5
+                }
+            }]
+        }]);
+        assert_eq!(
+            Ok(()),
+            test_6_1_10_inconsistent_cvss(&CsafDocument::<Csaf20>::from_json(doc).unwrap())
+        );
+    }
+}
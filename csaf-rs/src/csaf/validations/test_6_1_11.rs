@@ -0,0 +1,211 @@
+use crate::csaf::cwe::{find_version, CweCatalog};
+use crate::csaf::getter_traits::{CsafTrait, CweTrait, VulnerabilityTrait};
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
+
+pub fn test_6_1_11_cwe(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    test_cwes_against(doc, CweCatalog::all())
+}
+
+/// Checks all CWEs against the given catalogs. CSAF 2.1 specifies the CWE version, CSAF 2.0
+/// CWEs are checked against the newest catalog. CWEs which no catalog covers are reported as
+/// infos, as they could not be checked.
+fn test_cwes_against(doc: &impl CsafTrait, catalogs: &[CweCatalog]) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::<ValidationError>::new();
+
+    for (v_i, v) in doc.get_vulnerabilities().iter().enumerate() {
+        for (c_i, cwe) in v.get_cwes().iter().enumerate() {
+            let path = cwe.get_cwe_json_path(v_i, c_i);
+            let catalog = match cwe.get_version() {
+                Some(version) => find_version(catalogs, version),
+                None => catalogs.last(),
+            };
+            let Some(catalog) = catalog else {
+                errors.push(ValidationError {
+                    message: match cwe.get_version() {
+                        Some(version) => format!(
+                            "The CWE {} could not be checked, as CWE version {} is not embedded",
+                            cwe.get_id(),
+                            version
+                        ),
+                        None => format!("The CWE {} could not be checked, as no CWE catalog is embedded", cwe.get_id()),
+                    },
+                    instance_path: path,
+                    severity: ValidationSeverity::Info,
+                });
+                continue;
+            };
+            match catalog.get(cwe.get_id()) {
+                None => errors.push(ValidationError {
+                    message: format!("The CWE {} does not exist in CWE version {}", cwe.get_id(), catalog.version()),
+                    instance_path: format!("{}/id", path),
+                    severity: ValidationSeverity::Error,
+                }),
+                Some(weakness) if weakness.name != cwe.get_name() => errors.push(ValidationError {
+                    message: format!(
+                        "The name of {} is '{}', but it is '{}' in CWE version {}",
+                        cwe.get_id(),
+                        cwe.get_name(),
+                        weakness.name,
+                        catalog.version()
+                    ),
+                    instance_path: format!("{}/name", path),
+                    severity: ValidationSeverity::Error,
+                }),
+                Some(_) => {}
+            }
+        }
+    }
+
+    into_result(errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::csaf2_0::schema::CommonSecurityAdvisoryFramework as Csaf20;
+    use crate::csaf::csaf2_1::schema::CommonSecurityAdvisoryFramework as Csaf21;
+    use crate::csaf::cwe::{CweCatalog, Weakness};
+    use crate::csaf::document::CsafDocument;
+    use crate::csaf::test_helper::{minimal_document, run_csaf20_tests, run_csaf21_tests};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_1_11::{test_6_1_11_cwe, test_cwes_against};
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    static V4_15: [Weakness; 1] = [Weakness { id: 787, name: "Out-of-bounds Write" }];
+    static V4_16: [Weakness; 1] = [Weakness { id: 20, name: "Improper Input Validation" }];
+
+    /// The name of CWE-79 in all CWE versions since 4.0
+    const XSS: &str = "Improper Neutralization of Input During Web Page Generation ('Cross-site Scripting')";
+
+    #[test]
+    #[cfg_attr(not(cwe_catalog), ignore = "requires a CWE CSV export in src/csaf/cwe/data")]
+    fn test_test_6_1_11() {
+        let version = CweCatalog::latest().unwrap().version();
+        run_csaf20_tests(
+            "11",
+            test_6_1_11_cwe,
+            &HashMap::from([
+                ("01", vec![ValidationError {
+                    message: format!(
+                        "The name of CWE-79 is 'Improper Input Validation', but it is '{}' in CWE version {}",
+                        XSS, version
+                    ),
+                    instance_path: "/vulnerabilities/0/cwe/name".to_string(),
+                    severity: ValidationSeverity::Error,
+                }]),
+            ]),
+        );
+        run_csaf21_tests(
+            "11",
+            test_6_1_11_cwe,
+            &HashMap::from([
+                ("01", vec![ValidationError {
+                    message: format!(
+                        "The name of CWE-79 is 'Improper Input Validation', but it is '{}' in CWE version 4.13",
+                        XSS
+                    ),
+                    instance_path: "/vulnerabilities/0/cwes/0/name".to_string(),
+                    severity: ValidationSeverity::Error,
+                }]),
+            ]),
+        );
+    }
+
+    #[test]
+    fn test_test_6_1_11_catalogs() {
+        let catalogs = [CweCatalog::new("4.15", &V4_15), CweCatalog::new("4.16", &V4_16)];
+
+        let mut doc = minimal_document("2.1");
+        doc["vulnerabilities"] = json!([{
+            "cwes": [
+                { "id": "CWE-787", "name": "Out-of-bounds Write", "version": "4.15" },
+                { "id": "CWE-787", "name": "Out-of-bounds Write", "version": "4.16" },
+                { "id": "CWE-20", "name": "Input Validation", "version": "4.16" },
+                { "id": "CWE-1", "name": "Unknown version", "version": "1.0" }
+            ]
+        }]);
+        assert_eq!(
+            Err(vec![
+                ValidationError {
+                    message: "The CWE CWE-787 does not exist in CWE version 4.16".to_string(),
+                    instance_path: "/vulnerabilities/0/cwes/1/id".to_string(),
+                    severity: ValidationSeverity::Error,
+                },
+                ValidationError {
+                    message: "The name of CWE-20 is 'Input Validation', but it is 'Improper Input Validation' \
+                        in CWE version 4.16"
+                        .to_string(),
+                    instance_path: "/vulnerabilities/0/cwes/2/name".to_string(),
+                    severity: ValidationSeverity::Error,
+                },
+                ValidationError {
+                    message: "The CWE CWE-1 could not be checked, as CWE version 1.0 is not embedded".to_string(),
+                    instance_path: "/vulnerabilities/0/cwes/3".to_string(),
+                    severity: ValidationSeverity::Info,
+                },
+            ]),
+            test_cwes_against(&CsafDocument::<Csaf21>::from_json(doc).unwrap(), &catalogs)
+        );
+
+        let mut doc = minimal_document("2.0");
+        doc["vulnerabilities"] = json!([
+            { "cwe": { "id": "CWE-20", "name": "Improper Input Validation" } },
+            { "cwe": { "id": "CWE-787", "name": "Out-of-bounds Write" } }
+        ]);
+        let doc = CsafDocument::<Csaf20>::from_json(doc).unwrap();
+        assert_eq!(
+            Err(vec![ValidationError {
+                message: "The CWE CWE-787 does not exist in CWE version 4.16".to_string(),
+                instance_path: "/vulnerabilities/1/cwe/id".to_string(),
+                severity: ValidationSeverity::Error,
+            }]),
+            test_cwes_against(&doc, &catalogs)
+        );
+        assert_eq!(
+            Err(vec![
+                ValidationError {
+                    message: "The CWE CWE-20 could not be checked, as no CWE catalog is embedded".to_string(),
+                    instance_path: "/vulnerabilities/0/cwe".to_string(),
+                    severity: ValidationSeverity::Info,
+                },
+                ValidationError {
+                    message: "The CWE CWE-787 could not be checked, as no CWE catalog is embedded".to_string(),
+                    instance_path: "/vulnerabilities/1/cwe".to_string(),
+                    severity: ValidationSeverity::Info,
+                },
+            ]),
+            test_cwes_against(&doc, &[])
+        );
+    }
+
+    #[test]
+    #[cfg_attr(not(cwe_catalog), ignore = "requires a CWE CSV export in src/csaf/cwe/data")]
+    fn test_test_6_1_11_embedded_catalog() {
+        let version = CweCatalog::latest().unwrap().version();
+
+        let mut doc = minimal_document("2.0");
+        doc["vulnerabilities"] = json!([
+            { "cwe": { "id": "CWE-787", "name": "Out-of-bounds Write" } },
+            { "cwe": { "id": "CWE-20", "name": "Input Validation" } },
+            { "cwe": { "id": "CWE-999999", "name": "Unknown Weakness" } }
+        ]);
+        assert_eq!(
+            Err(vec![
+                ValidationError {
+                    message: format!(
+                        "The name of CWE-20 is 'Input Validation', but it is 'Improper Input Validation' in CWE version {}",
+                        version
+                    ),
+                    instance_path: "/vulnerabilities/1/cwe/name".to_string(),
+                    severity: ValidationSeverity::Error,
+                },
+                ValidationError {
+                    message: format!("The CWE CWE-999999 does not exist in CWE version {}", version),
+                    instance_path: "/vulnerabilities/2/cwe/id".to_string(),
+                    severity: ValidationSeverity::Error,
+                },
+            ]),
+            test_6_1_11_cwe(&CsafDocument::<Csaf20>::from_json(doc).unwrap())
+        );
+    }
+}
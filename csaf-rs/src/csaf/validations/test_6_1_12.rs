@@ -0,0 +1,120 @@
+use crate::csaf::getter_traits::CsafTrait;
+use crate::csaf::helpers::get_language_tags;
+use crate::csaf::language::{LanguageTag, SubtagRegistry};
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
+
+pub fn test_6_1_12_language(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    test_language_tags_against(doc, SubtagRegistry::embedded())
+}
+
+/// Checks that all language tags are well-formed and, if a registry is available,
+/// that all their subtags are registered.
+fn test_language_tags_against(
+    doc: &impl CsafTrait,
+    registry: Option<&SubtagRegistry>,
+) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::<ValidationError>::new();
+
+    for (path, lang) in get_language_tags(doc) {
+        match lang.parse::<LanguageTag>() {
+            Err(err) => errors.push(ValidationError {
+                message: format!("Invalid language tag '{}': {}", lang, err),
+                instance_path: path.to_string(),
+                severity: ValidationSeverity::Error,
+            }),
+            Ok(tag) => {
+                for subtag in registry.map(|r| tag.unregistered_subtags(r)).unwrap_or_default() {
+                    errors.push(ValidationError {
+                        message: format!("The language tag '{}' contains the unregistered subtag '{}'", lang, subtag),
+                        instance_path: path.to_string(),
+                        severity: ValidationSeverity::Error,
+                    });
+                }
+            }
+        }
+    }
+
+    into_result(errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::csaf2_0::schema::CommonSecurityAdvisoryFramework as Csaf20;
+    use crate::csaf::csaf2_1::schema::CommonSecurityAdvisoryFramework as Csaf21;
+    use crate::csaf::document::CsafDocument;
+    use crate::csaf::language::{SubtagRange, SubtagRegistry};
+    use crate::csaf::test_helper::{minimal_document, run_csaf20_tests, run_csaf21_tests};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_1_12::{test_6_1_12_language, test_language_tags_against};
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[test]
+    #[cfg_attr(not(language_registry), ignore = "requires the IANA Language Subtag Registry in src/csaf/language/data")]
+    fn test_test_6_1_12() {
+        let errors = HashMap::from([
+            ("01", vec![ValidationError {
+                message: "The language tag 'EZ' contains the unregistered subtag 'ez'".to_string(),
+                instance_path: "/document/lang".to_string(),
+                severity: ValidationSeverity::Error,
+            }]),
+        ]);
+        run_csaf20_tests("12", test_6_1_12_language, &errors);
+        run_csaf21_tests("12", test_6_1_12_language, &errors);
+    }
+
+    static REGISTRY: SubtagRegistry = SubtagRegistry {
+        file_date: "2024-01-01",
+        languages: &[SubtagRange("de", "de"), SubtagRange("en", "en")],
+        extlangs: &[],
+        scripts: &[],
+        regions: &[SubtagRange("de", "de"), SubtagRange("us", "us")],
+        variants: &[],
+        grandfathered: &[],
+    };
+
+    #[test]
+    fn test_test_6_1_12_registry() {
+        let mut doc = minimal_document("2.0");
+        doc["document"]["lang"] = json!("EZ");
+        doc["document"]["source_lang"] = json!("de-DE");
+        let doc = CsafDocument::<Csaf20>::from_json(doc).unwrap();
+        assert_eq!(
+            Err(vec![ValidationError {
+                message: "The language tag 'EZ' contains the unregistered subtag 'ez'".to_string(),
+                instance_path: "/document/lang".to_string(),
+                severity: ValidationSeverity::Error,
+            }]),
+            test_language_tags_against(&doc, Some(&REGISTRY))
+        );
+        assert_eq!(Ok(()), test_language_tags_against(&doc, None));
+
+        let mut doc = minimal_document("2.1");
+        doc["document"]["lang"] = json!("sl-rozaj-rozaj");
+        let doc = CsafDocument::<Csaf21>::from_json(doc).unwrap();
+        assert_eq!(
+            Err(vec![ValidationError {
+                message: "Invalid language tag 'sl-rozaj-rozaj': the variant 'rozaj' occurs more than once".to_string(),
+                instance_path: "/document/lang".to_string(),
+                severity: ValidationSeverity::Error,
+            }]),
+            test_language_tags_against(&doc, None)
+        );
+    }
+
+    #[test]
+    #[cfg_attr(not(language_registry), ignore = "requires the IANA Language Subtag Registry in src/csaf/language/data")]
+    fn test_test_6_1_12_embedded_registry() {
+        let mut doc = minimal_document("2.0");
+        doc["document"]["lang"] = json!("EZ");
+        doc["document"]["source_lang"] = json!("de-DE");
+        assert_eq!(
+            Err(vec![ValidationError {
+                message: "The language tag 'EZ' contains the unregistered subtag 'ez'".to_string(),
+                instance_path: "/document/lang".to_string(),
+                severity: ValidationSeverity::Error,
+            }]),
+            test_6_1_12_language(&CsafDocument::<Csaf20>::from_json(doc).unwrap())
+        );
+    }
+}
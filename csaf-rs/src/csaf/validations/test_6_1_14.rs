@@ -0,0 +1,68 @@
+use crate::csaf::document_version::RevisionHistory;
+use crate::csaf::getter_traits::{CsafTrait, DocumentTrait};
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
+use std::cmp::Ordering;
+
+pub fn test_6_1_14_sorted_revision_history(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::<ValidationError>::new();
+
+    let history = RevisionHistory::from_tracking(doc.get_document().get_tracking());
+    for pair in history.sorted_by_date().windows(2) {
+        let (previous, current) = (pair[0], pair[1]);
+        // Mixed versioning schemes are reported by 6.1.30
+        if let (Some(previous_version), Some(current_version)) = (&previous.version, &current.version) {
+            if current_version.precedence(previous_version) == Some(Ordering::Less) {
+                errors.push(ValidationError {
+                    message: format!(
+                        "The revision history is not sorted ascending by number when sorted by date: \
+                        version {} follows version {}",
+                        current.number, previous.number
+                    ),
+                    instance_path: current.number_json_path(),
+                    severity: ValidationSeverity::Error,
+                });
+            }
+        }
+    }
+
+    into_result(errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::csaf2_1::schema::CommonSecurityAdvisoryFramework as Csaf21;
+    use crate::csaf::document::CsafDocument;
+    use crate::csaf::test_helper::{minimal_document, run_csaf20_tests, run_csaf21_tests, set_revision_history};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_1_14::test_6_1_14_sorted_revision_history;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_1_14() {
+        let errors = HashMap::from([
+            ("01", vec![ValidationError {
+                message: "The revision history is not sorted ascending by number when sorted by date: version 1 follows version 2".to_string(),
+                instance_path: "/document/tracking/revision_history/0/number".to_string(),
+                severity: ValidationSeverity::Error,
+            }]),
+        ]);
+        run_csaf20_tests("14", test_6_1_14_sorted_revision_history, &errors);
+        run_csaf21_tests("14", test_6_1_14_sorted_revision_history, &errors);
+    }
+
+    #[test]
+    fn test_test_6_1_14_semantic_versioning() {
+        // Pre-releases precede their release, so this history is sorted
+        let mut doc = minimal_document("2.1");
+        doc["document"]["tracking"]["status"] = serde_json::json!("draft");
+        set_revision_history(&mut doc, "1.0.0", &[
+            ("2021-07-23T10:00:00.000Z", "1.0.0"),
+            ("2021-07-21T10:00:00.000Z", "1.0.0-rc.1"),
+            ("2021-07-22T10:00:00.000Z", "1.0.0-rc.2"),
+        ]);
+        assert_eq!(
+            Ok(()),
+            test_6_1_14_sorted_revision_history(&CsafDocument::<Csaf21>::from_json(doc).unwrap())
+        );
+    }
+}
@@ -0,0 +1,39 @@
+use crate::csaf::csaf2_1::schema::CategoryOfPublisher;
+use crate::csaf::getter_traits::{CsafTrait, DocumentTrait, PublisherTrait};
+use crate::csaf::validation::{ValidationError, ValidationSeverity};
+
+pub fn test_6_1_15_translator(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let document = doc.get_document();
+
+    if document.get_publisher().get_category() == CategoryOfPublisher::Translator
+        && document.get_source_lang().is_none()
+    {
+        return Err(vec![ValidationError {
+            message: "The publisher is a translator, but the document does not state its source language".to_string(),
+            instance_path: "/document/source_lang".to_string(),
+            severity: ValidationSeverity::Error,
+        }]);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::test_helper::{run_csaf20_tests, run_csaf21_tests};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_1_15::test_6_1_15_translator;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_1_15() {
+        let errors = HashMap::from([
+            ("01", vec![ValidationError {
+                message: "The publisher is a translator, but the document does not state its source language".to_string(),
+                instance_path: "/document/source_lang".to_string(),
+                severity: ValidationSeverity::Error,
+            }]),
+        ]);
+        run_csaf20_tests("15", test_6_1_15_translator, &errors);
+        run_csaf21_tests("15", test_6_1_15_translator, &errors);
+    }
+}
@@ -0,0 +1,78 @@
+use crate::csaf::csaf2_1::schema::DocumentStatus;
+use crate::csaf::document_version::{RevisionHistory, VERSION_JSON_PATH};
+use crate::csaf::getter_traits::{CsafTrait, DocumentTrait, TrackingTrait};
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
+use std::cmp::Ordering;
+
+pub fn test_6_1_16_latest_document_version(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::<ValidationError>::new();
+
+    let tracking = doc.get_document().get_tracking();
+    let history = RevisionHistory::from_tracking(tracking);
+    if let (Some(version), Some(latest)) = (&history.version, history.latest()) {
+        if let Some(latest_version) = &latest.version {
+            // Build metadata is ignored by the precedence, the pre-release part is ignored for drafts
+            let matches = if tracking.get_status() == DocumentStatus::Draft {
+                version.without_pre_release().precedence(&latest_version.without_pre_release())
+            } else {
+                version.precedence(latest_version)
+            } == Some(Ordering::Equal);
+            if !matches && version.scheme() == latest_version.scheme() {
+                errors.push(ValidationError {
+                    message: format!(
+                        "The document version {} does not match the number {} of the latest revision history item",
+                        history.version_number, latest.number
+                    ),
+                    instance_path: VERSION_JSON_PATH.to_string(),
+                    severity: ValidationSeverity::Error,
+                });
+            }
+        }
+    }
+
+    into_result(errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::csaf2_1::schema::CommonSecurityAdvisoryFramework as Csaf21;
+    use crate::csaf::document::CsafDocument;
+    use crate::csaf::test_helper::{minimal_document, run_csaf20_tests, run_csaf21_tests, set_revision_history};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_1_16::test_6_1_16_latest_document_version;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_1_16() {
+        let errors = HashMap::from([
+            ("01", vec![ValidationError {
+                message: "The document version 1 does not match the number 2 of the latest revision history item".to_string(),
+                instance_path: "/document/tracking/version".to_string(),
+                severity: ValidationSeverity::Error,
+            }]),
+        ]);
+        run_csaf20_tests("16", test_6_1_16_latest_document_version, &errors);
+        run_csaf21_tests("16", test_6_1_16_latest_document_version, &errors);
+    }
+
+    #[test]
+    fn test_test_6_1_16_semantic_versioning() {
+        // Build metadata is ignored
+        let mut doc = minimal_document("2.1");
+        set_revision_history(&mut doc, "1.0.0+build.2", &[("2021-07-21T10:00:00.000Z", "1.0.0+build.1")]);
+        assert_eq!(
+            Ok(()),
+            test_6_1_16_latest_document_version(&CsafDocument::<Csaf21>::from_json(doc.clone()).unwrap())
+        );
+
+        // Pre-release parts are only ignored for drafts
+        set_revision_history(&mut doc, "1.0.0-rc.1", &[("2021-07-21T10:00:00.000Z", "1.0.0")]);
+        assert!(test_6_1_16_latest_document_version(&CsafDocument::<Csaf21>::from_json(doc.clone()).unwrap()).is_err());
+        doc["document"]["tracking"]["status"] = json!("draft");
+        assert_eq!(
+            Ok(()),
+            test_6_1_16_latest_document_version(&CsafDocument::<Csaf21>::from_json(doc).unwrap())
+        );
+    }
+}
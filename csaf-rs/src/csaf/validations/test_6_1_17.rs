@@ -0,0 +1,63 @@
+use crate::csaf::csaf2_1::schema::DocumentStatus;
+use crate::csaf::document_version::DocumentVersion;
+use crate::csaf::getter_traits::{CsafTrait, DocumentTrait, TrackingTrait};
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
+
+pub fn test_6_1_17_document_status_draft(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::<ValidationError>::new();
+
+    let tracking = doc.get_document().get_tracking();
+    if let Ok(version) = tracking.get_version().parse::<DocumentVersion>() {
+        let status = tracking.get_status();
+        if status != DocumentStatus::Draft && (version.is_initial_development() || version.is_pre_release()) {
+            errors.push(ValidationError {
+                message: format!(
+                    "The document status is {}, but it must be draft for the document version {}",
+                    status,
+                    tracking.get_version()
+                ),
+                instance_path: "/document/tracking/status".to_string(),
+                severity: ValidationSeverity::Error,
+            });
+        }
+    }
+
+    into_result(errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::csaf2_1::schema::CommonSecurityAdvisoryFramework as Csaf21;
+    use crate::csaf::document::CsafDocument;
+    use crate::csaf::test_helper::{minimal_document, run_csaf20_tests, run_csaf21_tests, set_revision_history};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_1_17::test_6_1_17_document_status_draft;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_1_17() {
+        let errors = HashMap::from([
+            ("01", vec![ValidationError {
+                message: "The document status is final, but it must be draft for the document version 0.9.5".to_string(),
+                instance_path: "/document/tracking/status".to_string(),
+                severity: ValidationSeverity::Error,
+            }]),
+        ]);
+        run_csaf20_tests("17", test_6_1_17_document_status_draft, &errors);
+        run_csaf21_tests("17", test_6_1_17_document_status_draft, &errors);
+    }
+
+    #[test]
+    fn test_test_6_1_17_pre_release() {
+        let mut doc = minimal_document("2.1");
+        doc["document"]["tracking"]["status"] = json!("interim");
+        set_revision_history(&mut doc, "1.0.0-alpha", &[("2021-07-21T10:00:00.000Z", "1.0.0-alpha")]);
+        assert!(test_6_1_17_document_status_draft(&CsafDocument::<Csaf21>::from_json(doc.clone()).unwrap()).is_err());
+        doc["document"]["tracking"]["status"] = json!("draft");
+        assert_eq!(
+            Ok(()),
+            test_6_1_17_document_status_draft(&CsafDocument::<Csaf21>::from_json(doc).unwrap())
+        );
+    }
+}
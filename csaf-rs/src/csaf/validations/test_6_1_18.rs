@@ -0,0 +1,64 @@
+use crate::csaf::csaf2_1::schema::DocumentStatus;
+use crate::csaf::document_version::RevisionHistory;
+use crate::csaf::getter_traits::{CsafTrait, DocumentTrait, TrackingTrait};
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
+
+pub fn test_6_1_18_released_revision_history(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::<ValidationError>::new();
+
+    let tracking = doc.get_document().get_tracking();
+    let status = tracking.get_status();
+    if status != DocumentStatus::Final && status != DocumentStatus::Interim {
+        return Ok(());
+    }
+
+    for item in RevisionHistory::from_tracking(tracking).items {
+        if item.version.as_ref().is_some_and(|v| v.is_initial_development()) {
+            errors.push(ValidationError {
+                message: format!(
+                    "The revision history of a document with status {} contains the version {}",
+                    status, item.number
+                ),
+                instance_path: item.number_json_path(),
+                severity: ValidationSeverity::Error,
+            });
+        }
+    }
+
+    into_result(errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::csaf2_1::schema::CommonSecurityAdvisoryFramework as Csaf21;
+    use crate::csaf::document::CsafDocument;
+    use crate::csaf::test_helper::{minimal_document, run_csaf20_tests, run_csaf21_tests, set_revision_history};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_1_18::test_6_1_18_released_revision_history;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_1_18() {
+        let errors = HashMap::from([
+            ("01", vec![ValidationError {
+                message: "The revision history of a document with status final contains the version 0".to_string(),
+                instance_path: "/document/tracking/revision_history/0/number".to_string(),
+                severity: ValidationSeverity::Error,
+            }]),
+        ]);
+        run_csaf20_tests("18", test_6_1_18_released_revision_history, &errors);
+        run_csaf21_tests("18", test_6_1_18_released_revision_history, &errors);
+    }
+
+    #[test]
+    fn test_test_6_1_18_draft() {
+        let mut doc = minimal_document("2.1");
+        doc["document"]["tracking"]["status"] = json!("draft");
+        set_revision_history(&mut doc, "1", &[("2021-05-23T10:00:00.000Z", "0"), ("2021-07-21T10:00:00.000Z", "1")]);
+        assert_eq!(
+            Ok(()),
+            test_6_1_18_released_revision_history(&CsafDocument::<Csaf21>::from_json(doc).unwrap())
+        );
+    }
+}
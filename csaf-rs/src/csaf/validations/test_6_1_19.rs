@@ -0,0 +1,42 @@
+use crate::csaf::document_version::RevisionHistory;
+use crate::csaf::getter_traits::{CsafTrait, DocumentTrait};
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
+
+pub fn test_6_1_19_revision_history_entries_for_pre_release_versions(
+    doc: &impl CsafTrait,
+) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::<ValidationError>::new();
+
+    for item in RevisionHistory::from_tracking(doc.get_document().get_tracking()).items {
+        if item.version.as_ref().is_some_and(|v| v.is_pre_release()) {
+            errors.push(ValidationError {
+                message: format!("The revision history contains the pre-release version {}", item.number),
+                instance_path: item.number_json_path(),
+                severity: ValidationSeverity::Error,
+            });
+        }
+    }
+
+    into_result(errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::test_helper::{run_csaf20_tests, run_csaf21_tests};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_1_19::test_6_1_19_revision_history_entries_for_pre_release_versions;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_1_19() {
+        let errors = HashMap::from([
+            ("01", vec![ValidationError {
+                message: "The revision history contains the pre-release version 1.0.0-rc".to_string(),
+                instance_path: "/document/tracking/revision_history/0/number".to_string(),
+                severity: ValidationSeverity::Error,
+            }]),
+        ]);
+        run_csaf20_tests("19", test_6_1_19_revision_history_entries_for_pre_release_versions, &errors);
+        run_csaf21_tests("19", test_6_1_19_revision_history_entries_for_pre_release_versions, &errors);
+    }
+}
@@ -0,0 +1,63 @@
+use crate::csaf::csaf2_1::schema::DocumentStatus;
+use crate::csaf::document_version::{DocumentVersion, VERSION_JSON_PATH};
+use crate::csaf::getter_traits::{CsafTrait, DocumentTrait, TrackingTrait};
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
+
+pub fn test_6_1_20_non_draft_document_version(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::<ValidationError>::new();
+
+    let tracking = doc.get_document().get_tracking();
+    let status = tracking.get_status();
+    if status != DocumentStatus::Final && status != DocumentStatus::Interim {
+        return Ok(());
+    }
+
+    if tracking.get_version().parse::<DocumentVersion>().is_ok_and(|v| v.is_pre_release()) {
+        errors.push(ValidationError {
+            message: format!(
+                "The document version {} of a document with status {} contains a pre-release part",
+                tracking.get_version(),
+                status
+            ),
+            instance_path: VERSION_JSON_PATH.to_string(),
+            severity: ValidationSeverity::Error,
+        });
+    }
+
+    into_result(errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::csaf2_1::schema::CommonSecurityAdvisoryFramework as Csaf21;
+    use crate::csaf::document::CsafDocument;
+    use crate::csaf::test_helper::{minimal_document, run_csaf20_tests, run_csaf21_tests, set_revision_history};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_1_20::test_6_1_20_non_draft_document_version;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_1_20() {
+        let errors = HashMap::from([
+            ("01", vec![ValidationError {
+                message: "The document version 1.0.0-alpha of a document with status interim contains a pre-release part".to_string(),
+                instance_path: "/document/tracking/version".to_string(),
+                severity: ValidationSeverity::Error,
+            }]),
+        ]);
+        run_csaf20_tests("20", test_6_1_20_non_draft_document_version, &errors);
+        run_csaf21_tests("20", test_6_1_20_non_draft_document_version, &errors);
+    }
+
+    #[test]
+    fn test_test_6_1_20_draft() {
+        let mut doc = minimal_document("2.1");
+        doc["document"]["tracking"]["status"] = json!("draft");
+        set_revision_history(&mut doc, "1.0.0-alpha", &[("2021-07-21T10:00:00.000Z", "1.0.0")]);
+        assert_eq!(
+            Ok(()),
+            test_6_1_20_non_draft_document_version(&CsafDocument::<Csaf21>::from_json(doc).unwrap())
+        );
+    }
+}
@@ -0,0 +1,107 @@
+use crate::csaf::document_version::RevisionHistory;
+use crate::csaf::getter_traits::{CsafTrait, DocumentTrait};
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
+
+pub fn test_6_1_21_missing_item_in_revision_history(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::<ValidationError>::new();
+
+    let history = RevisionHistory::from_tracking(doc.get_document().get_tracking());
+    let sorted = history.sorted_by_date();
+    // Mixed versioning schemes are reported by 6.1.30
+    let Some(versions) = sorted.iter().map(|item| item.version.as_ref()).collect::<Option<Vec<_>>>() else {
+        return Ok(());
+    };
+    if versions.windows(2).any(|pair| pair[0].scheme() != pair[1].scheme()) {
+        return Ok(());
+    }
+
+    // For semantic versioning, only the major version must not have gaps
+    if let (Some(first), Some(first_version)) = (sorted.first(), versions.first()) {
+        if first_version.major() > 1 {
+            errors.push(ValidationError {
+                message: format!(
+                    "The revision history starts with version {}, but must start with major version 0 or 1",
+                    first.number
+                ),
+                instance_path: first.number_json_path(),
+                severity: ValidationSeverity::Error,
+            });
+        }
+    }
+    for (i, pair) in versions.windows(2).enumerate() {
+        if pair[1].major() > pair[0].major() + 1 {
+            errors.push(ValidationError {
+                message: format!(
+                    "The revision history is missing items between version {} and {}",
+                    sorted[i].number,
+                    sorted[i + 1].number
+                ),
+                instance_path: sorted[i + 1].number_json_path(),
+                severity: ValidationSeverity::Error,
+            });
+        }
+    }
+
+    into_result(errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::csaf2_0::schema::CommonSecurityAdvisoryFramework as Csaf20;
+    use crate::csaf::csaf2_1::schema::CommonSecurityAdvisoryFramework as Csaf21;
+    use crate::csaf::document::CsafDocument;
+    use crate::csaf::test_helper::{minimal_document, run_csaf20_tests, run_csaf21_tests, set_revision_history};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_1_21::test_6_1_21_missing_item_in_revision_history;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_1_21() {
+        let errors = HashMap::from([
+            ("01", vec![ValidationError {
+                message: "The revision history is missing items between version 1 and 3".to_string(),
+                instance_path: "/document/tracking/revision_history/1/number".to_string(),
+                severity: ValidationSeverity::Error,
+            }]),
+        ]);
+        run_csaf20_tests("21", test_6_1_21_missing_item_in_revision_history, &errors);
+        run_csaf21_tests("21", test_6_1_21_missing_item_in_revision_history, &errors);
+    }
+
+    #[test]
+    fn test_test_6_1_21_first_and_unsorted_items() {
+        let mut doc = minimal_document("2.0");
+        set_revision_history(&mut doc, "4", &[
+            ("2021-07-21T10:00:00.000Z", "2"),
+            ("2021-07-23T10:00:00.000Z", "4"),
+        ]);
+        assert_eq!(
+            Err(vec![
+                ValidationError {
+                    message: "The revision history starts with version 2, but must start with major version 0 or 1"
+                        .to_string(),
+                    instance_path: "/document/tracking/revision_history/0/number".to_string(),
+                    severity: ValidationSeverity::Error,
+                },
+                ValidationError {
+                    message: "The revision history is missing items between version 2 and 4".to_string(),
+                    instance_path: "/document/tracking/revision_history/1/number".to_string(),
+                    severity: ValidationSeverity::Error,
+                },
+            ]),
+            test_6_1_21_missing_item_in_revision_history(&CsafDocument::<Csaf20>::from_json(doc).unwrap())
+        );
+
+        // Items are compared by their version, not by their position
+        let mut doc = minimal_document("2.1");
+        set_revision_history(&mut doc, "2.0.0", &[
+            ("2021-07-23T10:00:00.000Z", "2.0.0"),
+            ("2021-07-21T10:00:00.000Z", "1.0.0"),
+            ("2021-07-22T10:00:00.000Z", "1.1.0"),
+        ]);
+        assert_eq!(
+            Ok(()),
+            test_6_1_21_missing_item_in_revision_history(&CsafDocument::<Csaf21>::from_json(doc).unwrap())
+        );
+    }
+}
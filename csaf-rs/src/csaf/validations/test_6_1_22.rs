@@ -0,0 +1,43 @@
+use crate::csaf::getter_traits::{CsafTrait, DocumentTrait, RevisionTrait, TrackingTrait};
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
+use std::collections::HashSet;
+
+pub fn test_6_1_22_multiple_definition_in_revision_history(
+    doc: &impl CsafTrait,
+) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::<ValidationError>::new();
+
+    let mut numbers = HashSet::new();
+    for (i_rev, rev) in doc.get_document().get_tracking().get_revision_history().iter().enumerate() {
+        if !numbers.insert(rev.get_number()) {
+            errors.push(ValidationError {
+                message: format!("The version {} is defined more than once in the revision history", rev.get_number()),
+                instance_path: format!("/document/tracking/revision_history/{}/number", i_rev),
+                severity: ValidationSeverity::Error,
+            });
+        }
+    }
+
+    into_result(errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::test_helper::{run_csaf20_tests, run_csaf21_tests};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_1_22::test_6_1_22_multiple_definition_in_revision_history;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_1_22() {
+        let errors = HashMap::from([
+            ("01", vec![ValidationError {
+                message: "The version 1 is defined more than once in the revision history".to_string(),
+                instance_path: "/document/tracking/revision_history/1/number".to_string(),
+                severity: ValidationSeverity::Error,
+            }]),
+        ]);
+        run_csaf20_tests("22", test_6_1_22_multiple_definition_in_revision_history, &errors);
+        run_csaf21_tests("22", test_6_1_22_multiple_definition_in_revision_history, &errors);
+    }
+}
@@ -0,0 +1,72 @@
+use crate::csaf::getter_traits::{CsafTrait, VulnerabilityTrait};
+use crate::csaf::helpers::find_duplicates;
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
+
+pub fn test_6_1_23_multiple_use_of_same_cve(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let vulnerabilities = doc.get_vulnerabilities();
+    let errors: Vec<ValidationError> = find_duplicates(vulnerabilities.iter().map(|v| v.get_cve()))
+        .into_iter()
+        .map(|v_i| ValidationError {
+            message: format!(
+                "CVE {} is used by multiple vulnerabilities",
+                vulnerabilities[v_i].get_cve().unwrap()
+            ),
+            instance_path: format!("/vulnerabilities/{}/cve", v_i),
+            severity: ValidationSeverity::Error,
+        })
+        .collect();
+
+    into_result(errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::csaf2_0::schema::CommonSecurityAdvisoryFramework as Csaf20;
+    use crate::csaf::document::CsafDocument;
+    use crate::csaf::test_helper::{minimal_document, run_csaf20_tests, run_csaf21_tests};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_1_23::test_6_1_23_multiple_use_of_same_cve;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_1_23() {
+        let errors = HashMap::from([
+            ("01", vec![
+                ValidationError {
+                    message: "CVE CVE-2017-0145 is used by multiple vulnerabilities".to_string(),
+                    instance_path: "/vulnerabilities/0/cve".to_string(),
+                    severity: ValidationSeverity::Error,
+                },
+                ValidationError {
+                    message: "CVE CVE-2017-0145 is used by multiple vulnerabilities".to_string(),
+                    instance_path: "/vulnerabilities/1/cve".to_string(),
+                    severity: ValidationSeverity::Error,
+                },
+            ]),
+        ]);
+        run_csaf20_tests("23", test_6_1_23_multiple_use_of_same_cve, &errors);
+        run_csaf21_tests("23", test_6_1_23_multiple_use_of_same_cve, &errors);
+    }
+
+    #[test]
+    fn test_test_6_1_23_without_cve() {
+        let mut doc = minimal_document("2.0");
+        doc["vulnerabilities"] = json!([
+            { "cve": "CVE-2017-0145" },
+            { "cve": "CVE-2017-0146" },
+            { "title": "Without CVE" },
+            { "title": "Without CVE" },
+            { "cve": "CVE-2017-0145" }
+        ]);
+        let error = |v_i: usize| ValidationError {
+            message: "CVE CVE-2017-0145 is used by multiple vulnerabilities".to_string(),
+            instance_path: format!("/vulnerabilities/{}/cve", v_i),
+            severity: ValidationSeverity::Error,
+        };
+        assert_eq!(
+            Err(vec![error(0), error(4)]),
+            test_6_1_23_multiple_use_of_same_cve(&CsafDocument::<Csaf20>::from_json(doc).unwrap())
+        );
+    }
+}
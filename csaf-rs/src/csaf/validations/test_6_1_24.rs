@@ -0,0 +1,93 @@
+use crate::csaf::getter_traits::{CsafTrait, InvolvementTrait, VulnerabilityTrait};
+use crate::csaf::helpers::find_duplicates;
+use crate::csaf::timeline::Timeline;
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
+
+pub fn test_6_1_24_multiple_definition_in_involvements(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::<ValidationError>::new();
+    let timeline = Timeline::of(doc);
+
+    for (v_i, v) in doc.get_vulnerabilities().iter().enumerate() {
+        let Some(involvements) = v.get_involvements() else {
+            continue;
+        };
+        // The same point in time may be written with different offsets, so valid dates are
+        // compared by their parsed value and invalid ones by their text
+        let dates = &timeline.vulnerabilities[v_i].involvement_dates;
+        let keys = involvements.iter().enumerate().map(|(i_i, i)| {
+            let date = i.get_date().as_ref().map(|date| dates[i_i].ok_or(date));
+            Some((i.get_party(), date))
+        });
+        for i_i in find_duplicates(keys) {
+            let involvement = &involvements[i_i];
+            errors.push(ValidationError {
+                message: format!(
+                    "Party '{}' is involved multiple times {}",
+                    involvement.get_party(),
+                    match involvement.get_date() {
+                        Some(date) => format!("at {}", date),
+                        None => "without a date".to_string(),
+                    }
+                ),
+                instance_path: format!("/vulnerabilities/{}/involvements/{}", v_i, i_i),
+                severity: ValidationSeverity::Error,
+            });
+        }
+    }
+
+    into_result(errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::csaf2_1::schema::CommonSecurityAdvisoryFramework as Csaf21;
+    use crate::csaf::document::CsafDocument;
+    use crate::csaf::test_helper::{minimal_document, run_csaf20_tests, run_csaf21_tests};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_1_24::test_6_1_24_multiple_definition_in_involvements;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_1_24() {
+        let errors = HashMap::from([
+            ("01", vec![
+                ValidationError {
+                    message: "Party 'vendor' is involved multiple times at 2021-04-23T10:00:00.000Z".to_string(),
+                    instance_path: "/vulnerabilities/0/involvements/0".to_string(),
+                    severity: ValidationSeverity::Error,
+                },
+                ValidationError {
+                    message: "Party 'vendor' is involved multiple times at 2021-04-23T10:00:00.000Z".to_string(),
+                    instance_path: "/vulnerabilities/0/involvements/1".to_string(),
+                    severity: ValidationSeverity::Error,
+                },
+            ]),
+        ]);
+        run_csaf20_tests("24", test_6_1_24_multiple_definition_in_involvements, &errors);
+        run_csaf21_tests("24", test_6_1_24_multiple_definition_in_involvements, &errors);
+    }
+
+    #[test]
+    fn test_test_6_1_24_offsets() {
+        // The same point in time is a duplicate, even if written with another offset
+        let mut doc = minimal_document("2.1");
+        doc["vulnerabilities"] = json!([{
+            "involvements": [
+                { "date": "2021-04-23T10:00:00.000Z", "party": "vendor", "status": "completed" },
+                { "date": "2021-04-23T10:00:00.000Z", "party": "coordinator", "status": "in_progress" },
+                { "date": "2021-04-23T12:00:00.000+02:00", "party": "vendor", "status": "in_progress" },
+                { "date": "2021-04-24T10:00:00.000Z", "party": "vendor", "status": "in_progress" }
+            ]
+        }]);
+        let error = |i_i: usize, date: &str| ValidationError {
+            message: format!("Party 'vendor' is involved multiple times at {}", date),
+            instance_path: format!("/vulnerabilities/0/involvements/{}", i_i),
+            severity: ValidationSeverity::Error,
+        };
+        assert_eq!(
+            Err(vec![error(0, "2021-04-23T10:00:00.000Z"), error(2, "2021-04-23T12:00:00.000+02:00")]),
+            test_6_1_24_multiple_definition_in_involvements(&CsafDocument::<Csaf21>::from_json(doc).unwrap())
+        );
+    }
+}
@@ -0,0 +1,109 @@
+use crate::csaf::getter_traits::{
+    CsafTrait, FileHashTrait, HashTrait, ProductIdentificationHelperTrait, ProductTrait, ProductTreeTrait,
+};
+use crate::csaf::helpers::find_duplicates;
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
+
+pub fn test_6_1_25_multiple_use_of_same_hash_algorithm(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::<ValidationError>::new();
+
+    if let Some(product_tree) = doc.get_product_tree() {
+        product_tree.visit_all_products(&mut |product, path| {
+            if let Some(helper) = product.get_product_identification_helper() {
+                for (h_i, hash) in helper.get_hashes().iter().enumerate() {
+                    let file_hashes = hash.get_file_hashes();
+                    for f_i in find_duplicates(file_hashes.iter().map(|f| Some(f.get_algorithm()))) {
+                        errors.push(ValidationError {
+                            message: format!(
+                                "Hash algorithm {} is used multiple times for file {}",
+                                file_hashes[f_i].get_algorithm(),
+                                hash.get_filename()
+                            ),
+                            instance_path: format!(
+                                "{}/product_identification_helper/hashes/{}/file_hashes/{}/algorithm",
+                                path, h_i, f_i
+                            ),
+                            severity: ValidationSeverity::Error,
+                        });
+                    }
+                }
+            }
+            Ok(())
+        })?;
+    }
+
+    into_result(errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::csaf2_0::schema::CommonSecurityAdvisoryFramework as Csaf20;
+    use crate::csaf::document::CsafDocument;
+    use crate::csaf::test_helper::{minimal_document, run_csaf20_tests, run_csaf21_tests};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_1_25::test_6_1_25_multiple_use_of_same_hash_algorithm;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_1_25() {
+        let errors = HashMap::from([
+            ("01", vec![
+                ValidationError {
+                    message: "Hash algorithm sha256 is used multiple times for file product_a.so".to_string(),
+                    instance_path: "/product_tree/full_product_names/0/product_identification_helper/hashes/0/file_hashes/0/algorithm".to_string(),
+                    severity: ValidationSeverity::Error,
+                },
+                ValidationError {
+                    message: "Hash algorithm sha256 is used multiple times for file product_a.so".to_string(),
+                    instance_path: "/product_tree/full_product_names/0/product_identification_helper/hashes/0/file_hashes/1/algorithm".to_string(),
+                    severity: ValidationSeverity::Error,
+                },
+            ]),
+        ]);
+        run_csaf20_tests("25", test_6_1_25_multiple_use_of_same_hash_algorithm, &errors);
+        run_csaf21_tests("25", test_6_1_25_multiple_use_of_same_hash_algorithm, &errors);
+    }
+
+    #[test]
+    fn test_test_6_1_25_multiple_files() {
+        let sha256 = "026a37919b182ef7c63791e82c9645e2f897a3f0b73c7a6028c7febf62e93838";
+        let mut doc = minimal_document("2.0");
+        doc["product_tree"] = json!({
+            "full_product_names": [{
+                "name": "Product A",
+                "product_id": "CSAFPID-9080700",
+                "product_identification_helper": {
+                    "hashes": [
+                        {
+                            "file_hashes": [
+                                { "algorithm": "sha256", "value": sha256 },
+                                { "algorithm": "sha512", "value": format!("{}{}", sha256, sha256) }
+                            ],
+                            "filename": "product_a.so"
+                        },
+                        {
+                            "file_hashes": [
+                                { "algorithm": "sha256", "value": sha256 },
+                                { "algorithm": "sha256", "value": sha256 }
+                            ],
+                            "filename": "product_a.exe"
+                        }
+                    ]
+                }
+            }]
+        });
+        let error = |f_i: usize| ValidationError {
+            message: "Hash algorithm sha256 is used multiple times for file product_a.exe".to_string(),
+            instance_path: format!(
+                "/product_tree/full_product_names/0/product_identification_helper/hashes/1/file_hashes/{}/algorithm",
+                f_i
+            ),
+            severity: ValidationSeverity::Error,
+        };
+        assert_eq!(
+            Err(vec![error(0), error(1)]),
+            test_6_1_25_multiple_use_of_same_hash_algorithm(&CsafDocument::<Csaf20>::from_json(doc).unwrap())
+        );
+    }
+}
@@ -0,0 +1,144 @@
+use crate::csaf::document::{CsafDocument, CsafVersioned};
+use crate::csaf::getter_traits::{CsafTrait, DocumentTrait};
+use crate::csaf::profile::DocumentProfile;
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
+
+/// The prefix reserved for the categories of the profiles defined by the standard.
+const RESERVED_PREFIX: &str = "csaf_";
+
+pub fn test_6_1_26_prohibited_document_category_name<T: CsafTrait + CsafVersioned>(
+    doc: &CsafDocument<T>,
+) -> Result<(), Vec<ValidationError>> {
+    let category = doc.get_document().get_category();
+    let profiles: Vec<DocumentProfile> = DocumentProfile::WITH_CATEGORY
+        .into_iter()
+        .filter(|profile| profile.is_defined_in(T::VERSION))
+        .collect();
+
+    // The official categories are always allowed, including csaf_base
+    if category == DocumentProfile::Base.category() || profiles.iter().any(|p| p.category() == category) {
+        return Ok(());
+    }
+
+    let mut errors = Vec::<ValidationError>::new();
+    // Compare without the reserved prefix, so that e.g. `CSAF VEX` matches as well as `VEX`
+    let normalized = normalize(category);
+    let normalized = normalized.strip_prefix("csaf").unwrap_or(&normalized);
+    if let Some(profile) = profiles.iter().find(|p| {
+        normalize(&p.category()[RESERVED_PREFIX.len()..]) == normalized || normalize(p.title()) == normalized
+    }) {
+        errors.push(ValidationError {
+            message: format!(
+                "Document category '{}' is prohibited, as it matches the name of the profile '{}' ({})",
+                category,
+                profile.title(),
+                profile.category()
+            ),
+            instance_path: "/document/category".to_string(),
+            severity: ValidationSeverity::Error,
+        });
+    } else if category.to_lowercase().starts_with(RESERVED_PREFIX) {
+        errors.push(ValidationError {
+            message: format!(
+                "Document category '{}' is prohibited, as the prefix '{}' is reserved for the official profiles",
+                category, RESERVED_PREFIX
+            ),
+            instance_path: "/document/category".to_string(),
+            severity: ValidationSeverity::Error,
+        });
+    }
+
+    into_result(errors)
+}
+
+/// Normalizes a category for the comparison: lower case without whitespace, dashes and underscores.
+fn normalize(category: &str) -> String {
+    category
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '-' && *c != '_')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::csaf2_0::schema::CommonSecurityAdvisoryFramework as Csaf20;
+    use crate::csaf::csaf2_1::schema::CommonSecurityAdvisoryFramework as Csaf21;
+    use crate::csaf::document::{CsafDocument, CsafVersioned};
+    use crate::csaf::getter_traits::CsafTrait;
+    use crate::csaf::test_helper::{minimal_document, run_csaf20_tests, run_csaf21_tests};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_1_26::test_6_1_26_prohibited_document_category_name;
+    use serde::de::DeserializeOwned;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_1_26() {
+        let errors = HashMap::from([
+            ("01", vec![ValidationError {
+                message: "Document category 'Security_Incident_Response' is prohibited, as it matches the name of the profile 'Security incident response' (csaf_security_incident_response)".to_string(),
+                instance_path: "/document/category".to_string(),
+                severity: ValidationSeverity::Error,
+            }]),
+        ]);
+        run_csaf20_tests("26", test_6_1_26_prohibited_document_category_name, &errors);
+        run_csaf21_tests("26", test_6_1_26_prohibited_document_category_name, &errors);
+    }
+
+    fn test_category<T: DeserializeOwned + CsafTrait + CsafVersioned>(
+        version: &str,
+        category: &str,
+    ) -> Result<(), Vec<ValidationError>> {
+        let mut doc = minimal_document(version);
+        doc["document"]["category"] = json!(category);
+        test_6_1_26_prohibited_document_category_name(&CsafDocument::<T>::from_json(doc).unwrap())
+    }
+
+    fn profile_error(category: &str, title: &str, profile: &str) -> Result<(), Vec<ValidationError>> {
+        Err(vec![ValidationError {
+            message: format!(
+                "Document category '{}' is prohibited, as it matches the name of the profile '{}' ({})",
+                category, title, profile
+            ),
+            instance_path: "/document/category".to_string(),
+            severity: ValidationSeverity::Error,
+        }])
+    }
+
+    #[test]
+    fn test_test_6_1_26_profiles_and_prefix() {
+        for category in ["csaf_base", "csaf_vex", "csaf_security_advisory", "Example Company Security Notice"] {
+            assert_eq!(Ok(()), test_category::<Csaf20>("2.0", category));
+            assert_eq!(Ok(()), test_category::<Csaf21>("2.1", category));
+        }
+        assert_eq!(Ok(()), test_category::<Csaf21>("2.1", "csaf_withdrawn"));
+
+        assert_eq!(
+            profile_error("Security Advisory", "Security Advisory", "csaf_security_advisory"),
+            test_category::<Csaf20>("2.0", "Security Advisory")
+        );
+        assert_eq!(
+            profile_error("CSAF VEX", "VEX", "csaf_vex"),
+            test_category::<Csaf21>("2.1", "CSAF VEX")
+        );
+        assert_eq!(
+            profile_error("Superseded", "Superseded", "csaf_superseded"),
+            test_category::<Csaf21>("2.1", "Superseded")
+        );
+        // Superseded is no profile of CSAF 2.0
+        assert_eq!(Ok(()), test_category::<Csaf20>("2.0", "Superseded"));
+
+        assert_eq!(
+            Err(vec![ValidationError {
+                message: "Document category 'csaf_security_advisory_v2' is prohibited, as the prefix 'csaf_' is \
+                    reserved for the official profiles"
+                    .to_string(),
+                instance_path: "/document/category".to_string(),
+                severity: ValidationSeverity::Error,
+            }]),
+            test_category::<Csaf20>("2.0", "csaf_security_advisory_v2")
+        );
+        assert!(test_category::<Csaf20>("2.0", "csaf_withdrawn").is_err());
+    }
+}
@@ -0,0 +1,63 @@
+use crate::csaf::csaf2_1::schema::NoteCategory;
+use crate::csaf::document::{CsafDocument, CsafVersioned};
+use crate::csaf::getter_traits::{CsafTrait, DocumentTrait, NoteTrait};
+use crate::csaf::profile::{test_profiles, DocumentProfile};
+use crate::csaf::validation::{ValidationError, ValidationSeverity};
+
+pub fn test_6_1_27_01_document_notes<T: CsafTrait + CsafVersioned>(
+    doc: &CsafDocument<T>,
+) -> Result<(), Vec<ValidationError>> {
+    let profiles = [DocumentProfile::InformationalAdvisory, DocumentProfile::SecurityIncidentResponse];
+    test_profiles(doc, &profiles, |doc, profile| {
+        let has_note = doc.get_document().get_notes().is_some_and(|notes| {
+            notes.iter().any(|note| {
+                matches!(
+                    note.get_category(),
+                    NoteCategory::Description | NoteCategory::Details | NoteCategory::General | NoteCategory::Summary
+                )
+            })
+        });
+        if has_note {
+            return vec![];
+        }
+        vec![ValidationError {
+            message: format!(
+                "A document with category {} must have at least one note with category description, details, \
+                general or summary",
+                profile
+            ),
+            instance_path: "/document/notes".to_string(),
+            severity: ValidationSeverity::Error,
+        }]
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::csaf2_1::schema::CommonSecurityAdvisoryFramework as Csaf21;
+    use crate::csaf::document::CsafDocument;
+    use crate::csaf::test_helper::{minimal_document, run_csaf20_tests, run_csaf21_tests};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_1_27_01::test_6_1_27_01_document_notes;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_1_27_01() {
+        let errors = HashMap::from([
+            ("01", vec![ValidationError {
+                message: "A document with category csaf_security_incident_response must have at least one note with category description, details, general or summary".to_string(),
+                instance_path: "/document/notes".to_string(),
+                severity: ValidationSeverity::Error,
+            }]),
+        ]);
+        run_csaf20_tests("27-01", test_6_1_27_01_document_notes, &errors);
+        run_csaf21_tests("27-01", test_6_1_27_01_document_notes, &errors);
+    }
+
+    #[test]
+    fn test_test_6_1_27_01_base_profile() {
+        // The base profile has no requirements on notes
+        let doc = minimal_document("2.1");
+        assert_eq!(Ok(()), test_6_1_27_01_document_notes(&CsafDocument::<Csaf21>::from_json(doc).unwrap()));
+    }
+}
@@ -0,0 +1,55 @@
+use crate::csaf::csaf2_1::schema::CategoryOfReference;
+use crate::csaf::document::{CsafDocument, CsafVersioned};
+use crate::csaf::getter_traits::{CsafTrait, DocumentTrait, ReferenceTrait};
+use crate::csaf::profile::{test_profiles, DocumentProfile};
+use crate::csaf::validation::{ValidationError, ValidationSeverity};
+
+pub fn test_6_1_27_02_document_references<T: CsafTrait + CsafVersioned>(
+    doc: &CsafDocument<T>,
+) -> Result<(), Vec<ValidationError>> {
+    let profiles = [DocumentProfile::InformationalAdvisory, DocumentProfile::SecurityIncidentResponse];
+    test_profiles(doc, &profiles, |doc, profile| {
+        let references = doc.get_document().get_references();
+        if references.is_some_and(|r| r.iter().any(|r| r.get_category() == CategoryOfReference::External)) {
+            return vec![];
+        }
+        vec![ValidationError {
+            message: format!("A document with category {} must have at least one external reference", profile),
+            instance_path: "/document/references".to_string(),
+            severity: ValidationSeverity::Error,
+        }]
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::csaf2_0::schema::CommonSecurityAdvisoryFramework as Csaf20;
+    use crate::csaf::document::CsafDocument;
+    use crate::csaf::test_helper::{minimal_document, run_csaf20_tests, run_csaf21_tests};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_1_27_02::test_6_1_27_02_document_references;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_1_27_02() {
+        let errors = HashMap::from([
+            ("01", vec![ValidationError {
+                message: "A document with category csaf_security_incident_response must have at least one external reference".to_string(),
+                instance_path: "/document/references".to_string(),
+                severity: ValidationSeverity::Error,
+            }]),
+        ]);
+        run_csaf20_tests("27-02", test_6_1_27_02_document_references, &errors);
+        run_csaf21_tests("27-02", test_6_1_27_02_document_references, &errors);
+    }
+
+    #[test]
+    fn test_test_6_1_27_02_default_category() {
+        // The category defaults to external
+        let mut doc = minimal_document("2.0");
+        doc["document"]["category"] = json!("csaf_informational_advisory");
+        doc["document"]["references"] = json!([{ "summary": "Details", "url": "https://example.com/details" }]);
+        assert_eq!(Ok(()), test_6_1_27_02_document_references(&CsafDocument::<Csaf20>::from_json(doc).unwrap()));
+    }
+}
@@ -0,0 +1,40 @@
+use crate::csaf::document::{CsafDocument, CsafVersioned};
+use crate::csaf::getter_traits::CsafTrait;
+use crate::csaf::profile::{test_profiles, DocumentProfile};
+use crate::csaf::validation::{ValidationError, ValidationSeverity};
+
+pub fn test_6_1_27_03_vulnerabilities<T: CsafTrait + CsafVersioned>(
+    doc: &CsafDocument<T>,
+) -> Result<(), Vec<ValidationError>> {
+    test_profiles(doc, &[DocumentProfile::InformationalAdvisory], |doc, profile| {
+        if doc.get_vulnerabilities().is_empty() {
+            return vec![];
+        }
+        vec![ValidationError {
+            message: format!("A document with category {} must not have vulnerabilities", profile),
+            instance_path: "/vulnerabilities".to_string(),
+            severity: ValidationSeverity::Error,
+        }]
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::test_helper::{run_csaf20_tests, run_csaf21_tests};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_1_27_03::test_6_1_27_03_vulnerabilities;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_1_27_03() {
+        let errors = HashMap::from([
+            ("01", vec![ValidationError {
+                message: "A document with category csaf_informational_advisory must not have vulnerabilities".to_string(),
+                instance_path: "/vulnerabilities".to_string(),
+                severity: ValidationSeverity::Error,
+            }]),
+        ]);
+        run_csaf20_tests("27-03", test_6_1_27_03_vulnerabilities, &errors);
+        run_csaf21_tests("27-03", test_6_1_27_03_vulnerabilities, &errors);
+    }
+}
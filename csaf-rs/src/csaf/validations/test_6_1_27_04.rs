@@ -0,0 +1,45 @@
+use crate::csaf::document::{CsafDocument, CsafVersioned};
+use crate::csaf::getter_traits::CsafTrait;
+use crate::csaf::profile::{test_profiles, DocumentProfile};
+use crate::csaf::validation::{ValidationError, ValidationSeverity};
+
+pub fn test_6_1_27_04_product_tree<T: CsafTrait + CsafVersioned>(
+    doc: &CsafDocument<T>,
+) -> Result<(), Vec<ValidationError>> {
+    let profiles = [
+        DocumentProfile::SecurityAdvisory,
+        DocumentProfile::DeprecatedSecurityAdvisory,
+        DocumentProfile::Vex,
+    ];
+    test_profiles(doc, &profiles, |doc, profile| {
+        if doc.get_product_tree().is_some() {
+            return vec![];
+        }
+        vec![ValidationError {
+            message: format!("A document with category {} must have a product tree", profile),
+            instance_path: "/product_tree".to_string(),
+            severity: ValidationSeverity::Error,
+        }]
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::test_helper::{run_csaf20_tests, run_csaf21_tests};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_1_27_04::test_6_1_27_04_product_tree;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_1_27_04() {
+        let errors = HashMap::from([
+            ("01", vec![ValidationError {
+                message: "A document with category csaf_security_advisory must have a product tree".to_string(),
+                instance_path: "/product_tree".to_string(),
+                severity: ValidationSeverity::Error,
+            }]),
+        ]);
+        run_csaf20_tests("27-04", test_6_1_27_04_product_tree, &errors);
+        run_csaf21_tests("27-04", test_6_1_27_04_product_tree, &errors);
+    }
+}
@@ -0,0 +1,47 @@
+use crate::csaf::document::{CsafDocument, CsafVersioned};
+use crate::csaf::getter_traits::{CsafTrait, VulnerabilityTrait};
+use crate::csaf::profile::{test_profiles, DocumentProfile};
+use crate::csaf::validation::{ValidationError, ValidationSeverity};
+
+pub fn test_6_1_27_05_vulnerability_notes<T: CsafTrait + CsafVersioned>(
+    doc: &CsafDocument<T>,
+) -> Result<(), Vec<ValidationError>> {
+    let profiles = [
+        DocumentProfile::SecurityAdvisory,
+        DocumentProfile::DeprecatedSecurityAdvisory,
+        DocumentProfile::Vex,
+    ];
+    test_profiles(doc, &profiles, |doc, profile| {
+        doc.get_vulnerabilities()
+            .iter()
+            .enumerate()
+            .filter(|(_, v)| v.get_notes().is_none())
+            .map(|(v_i, _)| ValidationError {
+                message: format!("The vulnerabilities of a document with category {} must have notes", profile),
+                instance_path: format!("/vulnerabilities/{}/notes", v_i),
+                severity: ValidationSeverity::Error,
+            })
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::test_helper::{run_csaf20_tests, run_csaf21_tests};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_1_27_05::test_6_1_27_05_vulnerability_notes;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_1_27_05() {
+        let errors = HashMap::from([
+            ("01", vec![ValidationError {
+                message: "The vulnerabilities of a document with category csaf_security_advisory must have notes".to_string(),
+                instance_path: "/vulnerabilities/0/notes".to_string(),
+                severity: ValidationSeverity::Error,
+            }]),
+        ]);
+        run_csaf20_tests("27-05", test_6_1_27_05_vulnerability_notes, &errors);
+        run_csaf21_tests("27-05", test_6_1_27_05_vulnerability_notes, &errors);
+    }
+}
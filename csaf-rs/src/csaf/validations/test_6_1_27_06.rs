@@ -0,0 +1,46 @@
+use crate::csaf::document::{CsafDocument, CsafVersioned};
+use crate::csaf::getter_traits::{CsafTrait, VulnerabilityTrait};
+use crate::csaf::profile::{test_profiles, DocumentProfile};
+use crate::csaf::validation::{ValidationError, ValidationSeverity};
+
+pub fn test_6_1_27_06_product_status<T: CsafTrait + CsafVersioned>(
+    doc: &CsafDocument<T>,
+) -> Result<(), Vec<ValidationError>> {
+    let profiles = [DocumentProfile::SecurityAdvisory, DocumentProfile::DeprecatedSecurityAdvisory];
+    test_profiles(doc, &profiles, |doc, profile| {
+        doc.get_vulnerabilities()
+            .iter()
+            .enumerate()
+            .filter(|(_, v)| v.get_product_status().is_none())
+            .map(|(v_i, _)| ValidationError {
+                message: format!(
+                    "The vulnerabilities of a document with category {} must have a product status",
+                    profile
+                ),
+                instance_path: format!("/vulnerabilities/{}/product_status", v_i),
+                severity: ValidationSeverity::Error,
+            })
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::test_helper::{run_csaf20_tests, run_csaf21_tests};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_1_27_06::test_6_1_27_06_product_status;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_1_27_06() {
+        let errors = HashMap::from([
+            ("01", vec![ValidationError {
+                message: "The vulnerabilities of a document with category csaf_security_advisory must have a product status".to_string(),
+                instance_path: "/vulnerabilities/0/product_status".to_string(),
+                severity: ValidationSeverity::Error,
+            }]),
+        ]);
+        run_csaf20_tests("27-06", test_6_1_27_06_product_status, &errors);
+        run_csaf21_tests("27-06", test_6_1_27_06_product_status, &errors);
+    }
+}
@@ -0,0 +1,57 @@
+use crate::csaf::document::{CsafDocument, CsafVersioned};
+use crate::csaf::getter_traits::{CsafTrait, ProductStatusTrait, VulnerabilityTrait};
+use crate::csaf::profile::{test_profiles, DocumentProfile};
+use crate::csaf::validation::{ValidationError, ValidationSeverity};
+
+pub fn test_6_1_27_07_vex_product_status<T: CsafTrait + CsafVersioned>(
+    doc: &CsafDocument<T>,
+) -> Result<(), Vec<ValidationError>> {
+    test_profiles(doc, &[DocumentProfile::Vex], |doc, profile| {
+        let mut errors = Vec::<ValidationError>::new();
+        for (v_i, v) in doc.get_vulnerabilities().iter().enumerate() {
+            let has_vex_status = v.get_product_status().as_ref().is_some_and(|status| {
+                has_products(status.get_fixed())
+                    || has_products(status.get_known_affected())
+                    || has_products(status.get_known_not_affected())
+                    || has_products(status.get_under_investigation())
+            });
+            if !has_vex_status {
+                errors.push(ValidationError {
+                    message: format!(
+                        "The vulnerabilities of a document with category {} must list at least one product as \
+                        fixed, known_affected, known_not_affected or under_investigation",
+                        profile
+                    ),
+                    instance_path: format!("/vulnerabilities/{}/product_status", v_i),
+                    severity: ValidationSeverity::Error,
+                });
+            }
+        }
+        errors
+    })
+}
+
+fn has_products<'a>(products: Option<impl Iterator<Item = &'a String>>) -> bool {
+    products.is_some_and(|mut products| products.next().is_some())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::test_helper::{run_csaf20_tests, run_csaf21_tests};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_1_27_07::test_6_1_27_07_vex_product_status;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_1_27_07() {
+        let errors = HashMap::from([
+            ("01", vec![ValidationError {
+                message: "The vulnerabilities of a document with category csaf_vex must list at least one product as fixed, known_affected, known_not_affected or under_investigation".to_string(),
+                instance_path: "/vulnerabilities/0/product_status".to_string(),
+                severity: ValidationSeverity::Error,
+            }]),
+        ]);
+        run_csaf20_tests("27-07", test_6_1_27_07_vex_product_status, &errors);
+        run_csaf21_tests("27-07", test_6_1_27_07_vex_product_status, &errors);
+    }
+}
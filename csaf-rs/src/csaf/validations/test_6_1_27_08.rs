@@ -0,0 +1,68 @@
+use crate::csaf::document::{CsafDocument, CsafVersioned};
+use crate::csaf::getter_traits::{CsafTrait, VulnerabilityTrait};
+use crate::csaf::profile::{test_profiles, DocumentProfile};
+use crate::csaf::validation::{ValidationError, ValidationSeverity};
+
+pub fn test_6_1_27_08_vulnerability_id<T: CsafTrait + CsafVersioned>(
+    doc: &CsafDocument<T>,
+) -> Result<(), Vec<ValidationError>> {
+    test_profiles(doc, &[DocumentProfile::Vex], |doc, profile| {
+        doc.get_vulnerabilities()
+            .iter()
+            .enumerate()
+            .filter(|(_, v)| v.get_cve().is_none() && v.get_ids().is_none())
+            .map(|(v_i, _)| ValidationError {
+                message: format!(
+                    "The vulnerabilities of a document with category {} must have a CVE or IDs",
+                    profile
+                ),
+                instance_path: format!("/vulnerabilities/{}", v_i),
+                severity: ValidationSeverity::Error,
+            })
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::csaf2_1::schema::CommonSecurityAdvisoryFramework as Csaf21;
+    use crate::csaf::document::CsafDocument;
+    use crate::csaf::test_helper::{minimal_document, run_csaf20_tests, run_csaf21_tests};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_1_27_08::test_6_1_27_08_vulnerability_id;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_1_27_08() {
+        let errors = HashMap::from([
+            ("01", vec![ValidationError {
+                message: "The vulnerabilities of a document with category csaf_vex must have a CVE or IDs".to_string(),
+                instance_path: "/vulnerabilities/0".to_string(),
+                severity: ValidationSeverity::Error,
+            }]),
+        ]);
+        run_csaf20_tests("27-08", test_6_1_27_08_vulnerability_id, &errors);
+        run_csaf21_tests("27-08", test_6_1_27_08_vulnerability_id, &errors);
+    }
+
+    #[test]
+    fn test_test_6_1_27_08_ids() {
+        // IDs are an alternative to the CVE
+        let mut doc = minimal_document("2.1");
+        doc["document"]["category"] = json!("csaf_vex");
+        doc["vulnerabilities"] = json!([
+            { "title": "Without ID" },
+            { "cve": "CVE-2021-44228" },
+            { "ids": [{ "system_name": "GitHub", "text": "GHSA-jfh8-c2jp-5v3q" }] }
+        ]);
+        assert_eq!(
+            Err(vec![ValidationError {
+                message: "The vulnerabilities of a document with category csaf_vex must have a CVE or IDs".to_string(),
+                instance_path: "/vulnerabilities/0".to_string(),
+                severity: ValidationSeverity::Error,
+            }]),
+            test_6_1_27_08_vulnerability_id(&CsafDocument::<Csaf21>::from_json(doc).unwrap())
+        );
+    }
+}
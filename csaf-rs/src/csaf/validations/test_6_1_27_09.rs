@@ -0,0 +1,106 @@
+use crate::csaf::csaf2_1::schema::CategoryOfTheThreat;
+use crate::csaf::document::{CsafDocument, CsafVersioned};
+use crate::csaf::getter_traits::{
+    CsafTrait, FlagTrait, ProductStatusTrait, ThreatTrait, VulnerabilityTrait, WithGroupIds,
+};
+use crate::csaf::helpers::resolve_products;
+use crate::csaf::profile::{test_profiles, DocumentProfile};
+use crate::csaf::validation::{ValidationError, ValidationSeverity};
+use std::collections::BTreeSet;
+
+pub fn test_6_1_27_09_impact_statement<T: CsafTrait + CsafVersioned>(
+    doc: &CsafDocument<T>,
+) -> Result<(), Vec<ValidationError>> {
+    test_profiles(doc, &[DocumentProfile::Vex], |doc, profile| {
+        let mut errors = Vec::<ValidationError>::new();
+        for (v_i, v) in doc.get_vulnerabilities().iter().enumerate() {
+            let Some(known_not_affected) = v.get_product_status().as_ref().and_then(|s| s.get_known_not_affected())
+            else {
+                continue;
+            };
+
+            // Products with an impact statement, either by a flag or by an impact threat
+            let mut with_statement = BTreeSet::<String>::new();
+            for flag in v.get_flags().iter().flatten() {
+                with_statement.extend(resolve_products(doc, flag.get_product_ids(), flag.get_group_ids()));
+            }
+            for threat in v.get_threats() {
+                if threat.get_category() == CategoryOfTheThreat::Impact {
+                    with_statement.extend(resolve_products(doc, threat.get_product_ids(), threat.get_group_ids()));
+                }
+            }
+
+            for (p_i, product_id) in known_not_affected.enumerate() {
+                if !with_statement.contains(product_id) {
+                    errors.push(ValidationError {
+                        message: format!(
+                            "Product {} is listed as known_not_affected in a document with category {}, \
+                            but has no impact statement",
+                            product_id, profile
+                        ),
+                        instance_path: format!("/vulnerabilities/{}/product_status/known_not_affected/{}", v_i, p_i),
+                        severity: ValidationSeverity::Error,
+                    });
+                }
+            }
+        }
+        errors
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::csaf2_0::schema::CommonSecurityAdvisoryFramework as Csaf20;
+    use crate::csaf::document::CsafDocument;
+    use crate::csaf::test_helper::{minimal_document, run_csaf20_tests, run_csaf21_tests};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_1_27_09::test_6_1_27_09_impact_statement;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_1_27_09() {
+        let errors = HashMap::from([
+            ("01", vec![ValidationError {
+                message: "Product CSAFPID-9080702 is listed as known_not_affected in a document with category csaf_vex, but has no impact statement".to_string(),
+                instance_path: "/vulnerabilities/0/product_status/known_not_affected/2".to_string(),
+                severity: ValidationSeverity::Error,
+            }]),
+        ]);
+        run_csaf20_tests("27-09", test_6_1_27_09_impact_statement, &errors);
+        run_csaf21_tests("27-09", test_6_1_27_09_impact_statement, &errors);
+    }
+
+    #[test]
+    fn test_test_6_1_27_09_flags_and_threat_categories() {
+        let mut doc = minimal_document("2.0");
+        doc["document"]["category"] = json!("csaf_vex");
+        doc["product_tree"] = json!({
+            "full_product_names": [
+                { "name": "Product A", "product_id": "CSAFPID-9080700" },
+                { "name": "Product B", "product_id": "CSAFPID-9080701" },
+                { "name": "Product C", "product_id": "CSAFPID-9080702" }
+            ],
+            "product_groups": [{ "group_id": "CSAFGID-1020300", "product_ids": ["CSAFPID-9080700", "CSAFPID-9080701"] }]
+        });
+        doc["vulnerabilities"] = json!([{
+            "product_status": { "known_not_affected": ["CSAFPID-9080700", "CSAFPID-9080701", "CSAFPID-9080702"] },
+            "flags": [{ "label": "component_not_present", "group_ids": ["CSAFGID-1020300"] }],
+            "threats": [{ "category": "exploit_status", "details": "None", "product_ids": ["CSAFPID-9080702"] }]
+        }]);
+        // Flags are impact statements, threats only with the category impact
+        assert_eq!(
+            Err(vec![ValidationError {
+                message: "Product CSAFPID-9080702 is listed as known_not_affected in a document with category \
+                    csaf_vex, but has no impact statement"
+                    .to_string(),
+                instance_path: "/vulnerabilities/0/product_status/known_not_affected/2".to_string(),
+                severity: ValidationSeverity::Error,
+            }]),
+            test_6_1_27_09_impact_statement(&CsafDocument::<Csaf20>::from_json(doc.clone()).unwrap())
+        );
+
+        doc["vulnerabilities"][0]["threats"][0]["category"] = json!("impact");
+        assert_eq!(Ok(()), test_6_1_27_09_impact_statement(&CsafDocument::<Csaf20>::from_json(doc).unwrap()));
+    }
+}
@@ -0,0 +1,61 @@
+use crate::csaf::document::{CsafDocument, CsafVersioned};
+use crate::csaf::getter_traits::{CsafTrait, ProductStatusTrait, RemediationTrait, VulnerabilityTrait};
+use crate::csaf::profile::{test_profiles, DocumentProfile};
+use crate::csaf::validation::{ValidationError, ValidationSeverity};
+use std::collections::BTreeSet;
+
+pub fn test_6_1_27_10_action_statement<T: CsafTrait + CsafVersioned>(
+    doc: &CsafDocument<T>,
+) -> Result<(), Vec<ValidationError>> {
+    test_profiles(doc, &[DocumentProfile::Vex], |doc, profile| {
+        let mut errors = Vec::<ValidationError>::new();
+        for (v_i, v) in doc.get_vulnerabilities().iter().enumerate() {
+            let Some(known_affected) = v.get_product_status().as_ref().and_then(|s| s.get_known_affected()) else {
+                continue;
+            };
+
+            let with_statement: BTreeSet<String> = v
+                .get_remediations()
+                .iter()
+                .filter_map(|r| r.get_all_product_ids(doc))
+                .flatten()
+                .collect();
+
+            for (p_i, product_id) in known_affected.enumerate() {
+                if !with_statement.contains(product_id) {
+                    errors.push(ValidationError {
+                        message: format!(
+                            "Product {} is listed as known_affected in a document with category {}, \
+                            but has no action statement",
+                            product_id, profile
+                        ),
+                        instance_path: format!("/vulnerabilities/{}/product_status/known_affected/{}", v_i, p_i),
+                        severity: ValidationSeverity::Error,
+                    });
+                }
+            }
+        }
+        errors
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::test_helper::{run_csaf20_tests, run_csaf21_tests};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_1_27_10::test_6_1_27_10_action_statement;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_1_27_10() {
+        let errors = HashMap::from([
+            ("01", vec![ValidationError {
+                message: "Product CSAFPID-9080702 is listed as known_affected in a document with category csaf_vex, but has no action statement".to_string(),
+                instance_path: "/vulnerabilities/0/product_status/known_affected/2".to_string(),
+                severity: ValidationSeverity::Error,
+            }]),
+        ]);
+        run_csaf20_tests("27-10", test_6_1_27_10_action_statement, &errors);
+        run_csaf21_tests("27-10", test_6_1_27_10_action_statement, &errors);
+    }
+}
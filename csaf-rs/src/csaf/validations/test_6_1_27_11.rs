@@ -0,0 +1,56 @@
+use crate::csaf::document::{CsafDocument, CsafVersioned};
+use crate::csaf::getter_traits::CsafTrait;
+use crate::csaf::profile::{test_profiles, DocumentProfile};
+use crate::csaf::validation::{ValidationError, ValidationSeverity};
+
+pub fn test_6_1_27_11_vulnerabilities<T: CsafTrait + CsafVersioned>(
+    doc: &CsafDocument<T>,
+) -> Result<(), Vec<ValidationError>> {
+    let profiles = [
+        DocumentProfile::SecurityAdvisory,
+        DocumentProfile::DeprecatedSecurityAdvisory,
+        DocumentProfile::Vex,
+    ];
+    test_profiles(doc, &profiles, |doc, profile| {
+        if !doc.get_vulnerabilities().is_empty() {
+            return vec![];
+        }
+        vec![ValidationError {
+            message: format!("A document with category {} must have vulnerabilities", profile),
+            instance_path: "/vulnerabilities".to_string(),
+            severity: ValidationSeverity::Error,
+        }]
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::csaf2_0::schema::CommonSecurityAdvisoryFramework as Csaf20;
+    use crate::csaf::document::CsafDocument;
+    use crate::csaf::test_helper::{minimal_document, run_csaf20_tests, run_csaf21_tests};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_1_27_11::test_6_1_27_11_vulnerabilities;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_1_27_11() {
+        let errors = HashMap::from([
+            ("01", vec![ValidationError {
+                message: "A document with category csaf_vex must have vulnerabilities".to_string(),
+                instance_path: "/vulnerabilities".to_string(),
+                severity: ValidationSeverity::Error,
+            }]),
+        ]);
+        run_csaf20_tests("27-11", test_6_1_27_11_vulnerabilities, &errors);
+        run_csaf21_tests("27-11", test_6_1_27_11_vulnerabilities, &errors);
+    }
+
+    #[test]
+    fn test_test_6_1_27_11_csaf20() {
+        // The deprecated security advisory profile does not exist in CSAF 2.0
+        let mut doc = minimal_document("2.0");
+        doc["document"]["category"] = json!("csaf_deprecated_security_advisory");
+        assert_eq!(Ok(()), test_6_1_27_11_vulnerabilities(&CsafDocument::<Csaf20>::from_json(doc).unwrap()));
+    }
+}
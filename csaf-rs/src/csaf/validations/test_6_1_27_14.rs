@@ -0,0 +1,36 @@
+use crate::csaf::document::{CsafDocument, CsafVersioned};
+use crate::csaf::getter_traits::CsafTrait;
+use crate::csaf::profile::{test_profiles, DocumentProfile};
+use crate::csaf::validation::ValidationError;
+use crate::csaf::validations::test_6_1_27_15::test_reasoning_note;
+
+pub fn test_6_1_27_14_reasoning_for_withdrawal<T: CsafTrait + CsafVersioned>(
+    doc: &CsafDocument<T>,
+) -> Result<(), Vec<ValidationError>> {
+    test_profiles(doc, &[DocumentProfile::Withdrawn], |doc, profile| {
+        test_reasoning_note(doc, profile, "Reasoning for Withdrawal")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::test_helper::run_csaf21_tests;
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_1_27_14::test_6_1_27_14_reasoning_for_withdrawal;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_1_27_14() {
+        run_csaf21_tests(
+            "27-14",
+            test_6_1_27_14_reasoning_for_withdrawal,
+            &HashMap::from([
+                ("01", vec![ValidationError {
+                    message: "A document with category csaf_withdrawn must have a note with category description and title 'Reasoning for Withdrawal'".to_string(),
+                    instance_path: "/document/notes".to_string(),
+                    severity: ValidationSeverity::Error,
+                }]),
+            ]),
+        );
+    }
+}
@@ -0,0 +1,57 @@
+use crate::csaf::csaf2_1::schema::NoteCategory;
+use crate::csaf::document::{CsafDocument, CsafVersioned};
+use crate::csaf::getter_traits::{CsafTrait, DocumentTrait, NoteTrait};
+use crate::csaf::profile::{test_profiles, DocumentProfile};
+use crate::csaf::validation::{ValidationError, ValidationSeverity};
+
+pub fn test_6_1_27_15_reasoning_for_supersession<T: CsafTrait + CsafVersioned>(
+    doc: &CsafDocument<T>,
+) -> Result<(), Vec<ValidationError>> {
+    test_profiles(doc, &[DocumentProfile::Superseded], |doc, profile| {
+        test_reasoning_note(doc, profile, "Reasoning for Supersession")
+    })
+}
+
+/// Checks that the document has a description note with the given title, which explains
+/// why a document was withdrawn or superseded.
+pub(crate) fn test_reasoning_note(doc: &impl CsafTrait, profile: DocumentProfile, title: &str) -> Vec<ValidationError> {
+    let has_note = doc.get_document().get_notes().is_some_and(|notes| {
+        notes.iter().any(|note| {
+            note.get_category() == NoteCategory::Description && note.get_title().is_some_and(|t| t == title)
+        })
+    });
+    if has_note {
+        return vec![];
+    }
+    vec![ValidationError {
+        message: format!(
+            "A document with category {} must have a note with category description and title '{}'",
+            profile, title
+        ),
+        instance_path: "/document/notes".to_string(),
+        severity: ValidationSeverity::Error,
+    }]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::test_helper::run_csaf21_tests;
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_1_27_15::test_6_1_27_15_reasoning_for_supersession;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_1_27_15() {
+        run_csaf21_tests(
+            "27-15",
+            test_6_1_27_15_reasoning_for_supersession,
+            &HashMap::from([
+                ("01", vec![ValidationError {
+                    message: "A document with category csaf_superseded must have a note with category description and title 'Reasoning for Supersession'".to_string(),
+                    instance_path: "/document/notes".to_string(),
+                    severity: ValidationSeverity::Error,
+                }]),
+            ]),
+        );
+    }
+}
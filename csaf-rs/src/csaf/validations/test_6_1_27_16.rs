@@ -0,0 +1,82 @@
+use crate::csaf::csaf2_1::schema::CategoryOfReference;
+use crate::csaf::document::{CsafDocument, CsafVersioned};
+use crate::csaf::getter_traits::{CsafTrait, DocumentTrait, ReferenceTrait};
+use crate::csaf::profile::{test_profiles, DocumentProfile};
+use crate::csaf::validation::{ValidationError, ValidationSeverity};
+
+pub fn test_6_1_27_16_reference_to_superseding_document<T: CsafTrait + CsafVersioned>(
+    doc: &CsafDocument<T>,
+) -> Result<(), Vec<ValidationError>> {
+    test_profiles(doc, &[DocumentProfile::Superseded], |doc, profile| {
+        let has_reference = doc.get_document().get_references().is_some_and(|references| {
+            references.iter().any(|r| {
+                r.get_category() == CategoryOfReference::External && r.get_summary().starts_with("Superseding Document")
+            })
+        });
+        if has_reference {
+            return vec![];
+        }
+        vec![ValidationError {
+            message: format!(
+                "A document with category {} must have an external reference with a summary starting with \
+                'Superseding Document'",
+                profile
+            ),
+            instance_path: "/document/references".to_string(),
+            severity: ValidationSeverity::Error,
+        }]
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::csaf2_1::schema::CommonSecurityAdvisoryFramework as Csaf21;
+    use crate::csaf::document::CsafDocument;
+    use crate::csaf::test_helper::{minimal_document, run_csaf21_tests};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_1_27_16::test_6_1_27_16_reference_to_superseding_document;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_1_27_16() {
+        run_csaf21_tests(
+            "27-16",
+            test_6_1_27_16_reference_to_superseding_document,
+            &HashMap::from([
+                ("01", vec![ValidationError {
+                    message: "A document with category csaf_superseded must have an external reference with a summary starting with 'Superseding Document'".to_string(),
+                    instance_path: "/document/references".to_string(),
+                    severity: ValidationSeverity::Error,
+                }]),
+            ]),
+        );
+    }
+
+    #[test]
+    fn test_test_6_1_27_16_self_reference() {
+        let mut doc = minimal_document("2.1");
+        doc["document"]["category"] = json!("csaf_superseded");
+        doc["document"]["references"] = json!([{
+            "category": "self",
+            "summary": "Superseding Document",
+            "url": "https://example.com/advisory-2.json"
+        }]);
+        assert_eq!(
+            Err(vec![ValidationError {
+                message: "A document with category csaf_superseded must have an external reference with a summary \
+                    starting with 'Superseding Document'"
+                    .to_string(),
+                instance_path: "/document/references".to_string(),
+                severity: ValidationSeverity::Error,
+            }]),
+            test_6_1_27_16_reference_to_superseding_document(&CsafDocument::<Csaf21>::from_json(doc.clone()).unwrap())
+        );
+
+        doc["document"]["references"][0]["category"] = json!("external");
+        assert_eq!(
+            Ok(()),
+            test_6_1_27_16_reference_to_superseding_document(&CsafDocument::<Csaf21>::from_json(doc).unwrap())
+        );
+    }
+}
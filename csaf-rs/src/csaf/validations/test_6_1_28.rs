@@ -0,0 +1,68 @@
+use crate::csaf::getter_traits::{CsafTrait, DocumentTrait};
+use crate::csaf::language::LanguageTag;
+use crate::csaf::validation::{ValidationError, ValidationSeverity};
+
+pub fn test_6_1_28_translation(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let document = doc.get_document();
+
+    // Compare the normalized tags, invalid tags are reported by 6.1.12
+    if let (Some(lang), Some(source_lang)) = (document.get_lang(), document.get_source_lang()) {
+        if let (Ok(lang_tag), Ok(source_lang_tag)) = (lang.parse::<LanguageTag>(), source_lang.parse::<LanguageTag>()) {
+            if lang_tag == source_lang_tag {
+                return Err(vec![ValidationError {
+                    message: format!("The document language '{}' is the same as its source language", lang),
+                    instance_path: "/document/lang".to_string(),
+                    severity: ValidationSeverity::Error,
+                }]);
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::csaf2_1::schema::CommonSecurityAdvisoryFramework as Csaf21;
+    use crate::csaf::document::CsafDocument;
+    use crate::csaf::test_helper::{minimal_document, run_csaf20_tests, run_csaf21_tests};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_1_28::test_6_1_28_translation;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_1_28() {
+        let errors = HashMap::from([
+            ("01", vec![ValidationError {
+                message: "The document language 'en-US' is the same as its source language".to_string(),
+                instance_path: "/document/lang".to_string(),
+                severity: ValidationSeverity::Error,
+            }]),
+        ]);
+        run_csaf20_tests("28", test_6_1_28_translation, &errors);
+        run_csaf21_tests("28", test_6_1_28_translation, &errors);
+    }
+
+    #[test]
+    fn test_test_6_1_28_normalized_tags() {
+        let mut doc = minimal_document("2.1");
+        doc["document"]["lang"] = json!("en-US");
+        doc["document"]["source_lang"] = json!("en-us");
+        assert_eq!(
+            Err(vec![ValidationError {
+                message: "The document language 'en-US' is the same as its source language".to_string(),
+                instance_path: "/document/lang".to_string(),
+                severity: ValidationSeverity::Error,
+            }]),
+            test_6_1_28_translation(&CsafDocument::<Csaf21>::from_json(doc.clone()).unwrap())
+        );
+
+        doc["document"]["lang"] = json!("de-DE");
+        assert_eq!(Ok(()), test_6_1_28_translation(&CsafDocument::<Csaf21>::from_json(doc.clone()).unwrap()));
+
+        // Grandfathered tags are compared as a whole
+        doc["document"]["lang"] = json!("zh-min-nan");
+        doc["document"]["source_lang"] = json!("zh-min");
+        assert_eq!(Ok(()), test_6_1_28_translation(&CsafDocument::<Csaf21>::from_json(doc).unwrap()));
+    }
+}
@@ -0,0 +1,95 @@
+use crate::csaf::getter_traits::{CsafTrait, RemediationTrait, VulnerabilityTrait, WithGroupIds};
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
+
+pub fn test_6_1_29_remediation_without_product_reference(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::<ValidationError>::new();
+
+    for (v_i, v) in doc.get_vulnerabilities().iter().enumerate() {
+        for (r_i, remediation) in v.get_remediations().iter().enumerate() {
+            if remediation.get_product_ids().is_none() && remediation.get_group_ids().is_none() {
+                errors.push(ValidationError {
+                    message: format!(
+                        "Remediation of category '{}' neither references products nor product groups",
+                        remediation.get_category()
+                    ),
+                    instance_path: format!("/vulnerabilities/{}/remediations/{}", v_i, r_i),
+                    severity: ValidationSeverity::Error,
+                });
+            }
+        }
+    }
+
+    into_result(errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::csaf2_0::schema::CommonSecurityAdvisoryFramework as Csaf20;
+    use crate::csaf::csaf2_1::schema::CommonSecurityAdvisoryFramework as Csaf21;
+    use crate::csaf::document::CsafDocument;
+    use crate::csaf::test_helper::{minimal_document, run_csaf20_tests, run_csaf21_tests};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_1_29::test_6_1_29_remediation_without_product_reference;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_1_29() {
+        let errors = HashMap::from([
+            ("01", vec![ValidationError {
+                message: "Remediation of category 'mitigation' neither references products nor product groups".to_string(),
+                instance_path: "/vulnerabilities/0/remediations/0".to_string(),
+                severity: ValidationSeverity::Error,
+            }]),
+        ]);
+        run_csaf20_tests("29", test_6_1_29_remediation_without_product_reference, &errors);
+        run_csaf21_tests("29", test_6_1_29_remediation_without_product_reference, &errors);
+    }
+
+    fn error(message: &str, instance_path: &str) -> ValidationError {
+        ValidationError {
+            message: message.to_string(),
+            instance_path: instance_path.to_string(),
+            severity: ValidationSeverity::Error,
+        }
+    }
+
+    #[test]
+    fn test_test_6_1_29_remediation_categories() {
+        let mut doc = minimal_document("2.0");
+        doc["product_tree"] = json!({
+            "full_product_names": [{ "name": "Product A", "product_id": "CSAFPID-9080700" }]
+        });
+        doc["vulnerabilities"] = json!([{
+            "remediations": [
+                { "category": "vendor_fix", "details": "Update to version 2.0.", "product_ids": ["CSAFPID-9080700"] },
+                { "category": "mitigation", "details": "Disable the service." }
+            ]
+        }]);
+        assert_eq!(
+            Err(vec![error(
+                "Remediation of category 'mitigation' neither references products nor product groups",
+                "/vulnerabilities/0/remediations/1"
+            )]),
+            test_6_1_29_remediation_without_product_reference(&CsafDocument::<Csaf20>::from_json(doc).unwrap())
+        );
+
+        let mut doc = minimal_document("2.1");
+        doc["product_tree"] = json!({
+            "full_product_names": [{ "name": "Product A", "product_id": "CSAFPID-9080700" }]
+        });
+        doc["vulnerabilities"] = json!([{
+            "remediations": [
+                { "category": "optional_patch", "details": "Apply the hardening patch.", "product_ids": ["CSAFPID-9080700"] },
+                { "category": "fix_planned", "details": "A fix is planned for the next release." }
+            ]
+        }]);
+        assert_eq!(
+            Err(vec![error(
+                "Remediation of category 'fix_planned' neither references products nor product groups",
+                "/vulnerabilities/0/remediations/1"
+            )]),
+            test_6_1_29_remediation_without_product_reference(&CsafDocument::<Csaf21>::from_json(doc).unwrap())
+        );
+    }
+}
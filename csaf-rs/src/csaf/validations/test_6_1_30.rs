@@ -0,0 +1,52 @@
+use crate::csaf::document_version::RevisionHistory;
+use crate::csaf::getter_traits::{CsafTrait, DocumentTrait};
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
+
+pub fn test_6_1_30_mixed_integer_and_semantic_versioning(
+    doc: &impl CsafTrait,
+) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::<ValidationError>::new();
+
+    // The versioning scheme of the document version is the reference for the revision history
+    let history = RevisionHistory::from_tracking(doc.get_document().get_tracking());
+    if let Some(version) = &history.version {
+        for item in &history.items {
+            if let Some(item_version) = item.version.as_ref().filter(|v| v.scheme() != version.scheme()) {
+                errors.push(ValidationError {
+                    message: format!(
+                        "The version {} uses {} versioning, but the document version {} uses {} versioning",
+                        item.number,
+                        item_version.scheme(),
+                        history.version_number,
+                        version.scheme()
+                    ),
+                    instance_path: item.number_json_path(),
+                    severity: ValidationSeverity::Error,
+                });
+            }
+        }
+    }
+
+    into_result(errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::test_helper::{run_csaf20_tests, run_csaf21_tests};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_1_30::test_6_1_30_mixed_integer_and_semantic_versioning;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_1_30() {
+        let errors = HashMap::from([
+            ("01", vec![ValidationError {
+                message: "The version 1 uses integer versioning, but the document version 2.0.0 uses semantic versioning".to_string(),
+                instance_path: "/document/tracking/revision_history/0/number".to_string(),
+                severity: ValidationSeverity::Error,
+            }]),
+        ]);
+        run_csaf20_tests("30", test_6_1_30_mixed_integer_and_semantic_versioning, &errors);
+        run_csaf21_tests("30", test_6_1_30_mixed_integer_and_semantic_versioning, &errors);
+    }
+}
@@ -0,0 +1,56 @@
+use crate::csaf::csaf2_1::schema::CategoryOfTheBranch;
+use crate::csaf::getter_traits::{BranchTrait, CsafTrait, ProductTreeTrait};
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// Operators and words indicating a version range instead of a single version.
+static VERSION_RANGE_REGEX: LazyLock<Regex> = LazyLock::new(||
+    Regex::new(r"(?i)<|>|\b(?:after|all|before|earlier|later|prior|versions)\b|^vers:").unwrap()
+);
+
+pub fn test_6_1_31_version_range_in_product_version(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::<ValidationError>::new();
+
+    if let Some(product_tree) = doc.get_product_tree() {
+        product_tree.visit_all_branches(&mut |branch, path| {
+            if branch.get_category() == CategoryOfTheBranch::ProductVersion
+                && VERSION_RANGE_REGEX.is_match(branch.get_name())
+            {
+                errors.push(ValidationError {
+                    message: format!(
+                        "Branch with category product_version must not contain a version range, \
+                        but has name '{}'",
+                        branch.get_name()
+                    ),
+                    instance_path: format!("{}/name", path),
+                    severity: ValidationSeverity::Error,
+                });
+            }
+            Ok(())
+        })?;
+    }
+
+    into_result(errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::test_helper::{run_csaf20_tests, run_csaf21_tests};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_1_31::test_6_1_31_version_range_in_product_version;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_1_31() {
+        let errors = HashMap::from([
+            ("01", vec![ValidationError {
+                message: "Branch with category product_version must not contain a version range, but has name 'prior to 4.2'".to_string(),
+                instance_path: "/product_tree/branches/0/branches/0/branches/0/name".to_string(),
+                severity: ValidationSeverity::Error,
+            }]),
+        ]);
+        run_csaf20_tests("31", test_6_1_31_version_range_in_product_version, &errors);
+        run_csaf21_tests("31", test_6_1_31_version_range_in_product_version, &errors);
+    }
+}
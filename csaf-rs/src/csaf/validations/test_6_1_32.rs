@@ -0,0 +1,77 @@
+use crate::csaf::getter_traits::{CsafTrait, FlagTrait, VulnerabilityTrait, WithGroupIds};
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
+
+pub fn test_6_1_32_flag_without_product_reference(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::<ValidationError>::new();
+
+    for (v_i, v) in doc.get_vulnerabilities().iter().enumerate() {
+        for (f_i, flag) in v.get_flags().iter().flatten().enumerate() {
+            if flag.get_product_ids().is_none() && flag.get_group_ids().is_none() {
+                errors.push(ValidationError {
+                    message: format!(
+                        "Flag '{}' neither references products nor product groups",
+                        flag.get_label()
+                    ),
+                    instance_path: format!("/vulnerabilities/{}/flags/{}", v_i, f_i),
+                    severity: ValidationSeverity::Error,
+                });
+            }
+        }
+    }
+
+    into_result(errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::csaf2_0::schema::CommonSecurityAdvisoryFramework as Csaf20;
+    use crate::csaf::document::CsafDocument;
+    use crate::csaf::test_helper::{minimal_document, run_csaf20_tests, run_csaf21_tests};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_1_32::test_6_1_32_flag_without_product_reference;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_1_32() {
+        let errors = HashMap::from([
+            ("01", vec![ValidationError {
+                message: "Flag 'component_not_present' neither references products nor product groups".to_string(),
+                instance_path: "/vulnerabilities/0/flags/0".to_string(),
+                severity: ValidationSeverity::Error,
+            }]),
+        ]);
+        run_csaf20_tests("32", test_6_1_32_flag_without_product_reference, &errors);
+        run_csaf21_tests("32", test_6_1_32_flag_without_product_reference, &errors);
+    }
+
+    #[test]
+    fn test_test_6_1_32_group_ids() {
+        let mut doc = minimal_document("2.0");
+        doc["product_tree"] = json!({
+            "full_product_names": [
+                { "name": "Product A", "product_id": "CSAFPID-9080700" },
+                { "name": "Product B", "product_id": "CSAFPID-9080701" }
+            ],
+            "product_groups": [
+                { "group_id": "CSAFGID-1020300", "product_ids": ["CSAFPID-9080700", "CSAFPID-9080701"] }
+            ]
+        });
+        doc["vulnerabilities"] = json!([{
+            "flags": [
+                { "label": "component_not_present", "product_ids": ["CSAFPID-9080700"] },
+                { "label": "vulnerable_code_not_present", "group_ids": ["CSAFGID-1020300"] },
+                { "label": "inline_mitigations_already_exist" }
+            ]
+        }]);
+        assert_eq!(
+            Err(vec![ValidationError {
+                message: "Flag 'inline_mitigations_already_exist' neither references products nor product groups"
+                    .to_string(),
+                instance_path: "/vulnerabilities/0/flags/2".to_string(),
+                severity: ValidationSeverity::Error,
+            }]),
+            test_6_1_32_flag_without_product_reference(&CsafDocument::<Csaf20>::from_json(doc).unwrap())
+        );
+    }
+}
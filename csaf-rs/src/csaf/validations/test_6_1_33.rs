@@ -0,0 +1,96 @@
+use crate::csaf::csaf2_1::schema::LabelOfTheFlag;
+use crate::csaf::getter_traits::{CsafTrait, FlagTrait, VulnerabilityTrait, WithGroupIds};
+use crate::csaf::helpers::resolve_products;
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
+use std::collections::BTreeMap;
+
+pub fn test_6_1_33_multiple_flags_with_vex_codes_per_product(
+    doc: &impl CsafTrait,
+) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::<ValidationError>::new();
+
+    for (v_i, v) in doc.get_vulnerabilities().iter().enumerate() {
+        // The first flag (index and label) referencing each product, directly or via a product group
+        let mut flagged = BTreeMap::<String, (usize, LabelOfTheFlag)>::new();
+        for (f_i, flag) in v.get_flags().iter().flatten().enumerate() {
+            for product_id in resolve_products(doc, flag.get_product_ids(), flag.get_group_ids()) {
+                if let Some((first_i, first_label)) = flagged.get(&product_id) {
+                    errors.push(ValidationError {
+                        message: format!(
+                            "Product {} is already flagged as '{}' by flag {}, but also as '{}'",
+                            product_id, first_label, first_i, flag.get_label()
+                        ),
+                        instance_path: format!("/vulnerabilities/{}/flags/{}", v_i, f_i),
+                        severity: ValidationSeverity::Error,
+                    });
+                } else {
+                    flagged.insert(product_id, (f_i, flag.get_label()));
+                }
+            }
+        }
+    }
+
+    into_result(errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::csaf2_1::schema::CommonSecurityAdvisoryFramework as Csaf21;
+    use crate::csaf::document::CsafDocument;
+    use crate::csaf::test_helper::{minimal_document, run_csaf20_tests, run_csaf21_tests};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_1_33::test_6_1_33_multiple_flags_with_vex_codes_per_product;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_1_33() {
+        let errors = HashMap::from([
+            ("01", vec![ValidationError {
+                message: "Product CSAFPID-9080700 is already flagged as 'component_not_present' by flag 0, but also as 'vulnerable_code_not_present'".to_string(),
+                instance_path: "/vulnerabilities/0/flags/1".to_string(),
+                severity: ValidationSeverity::Error,
+            }]),
+        ]);
+        run_csaf20_tests("33", test_6_1_33_multiple_flags_with_vex_codes_per_product, &errors);
+        run_csaf21_tests("33", test_6_1_33_multiple_flags_with_vex_codes_per_product, &errors);
+    }
+
+    #[test]
+    fn test_test_6_1_33_product_groups() {
+        let mut doc = minimal_document("2.1");
+        doc["product_tree"] = json!({
+            "full_product_names": [
+                { "name": "Product A", "product_id": "CSAFPID-9080700" },
+                { "name": "Product B", "product_id": "CSAFPID-9080701" },
+                { "name": "Product C", "product_id": "CSAFPID-9080702" }
+            ],
+            "product_groups": [
+                { "group_id": "CSAFGID-1020300", "product_ids": ["CSAFPID-9080700", "CSAFPID-9080701"] }
+            ]
+        });
+        doc["vulnerabilities"] = json!([
+            {
+                "flags": [
+                    { "label": "component_not_present", "group_ids": ["CSAFGID-1020300"] },
+                    { "label": "vulnerable_code_not_present", "product_ids": ["CSAFPID-9080701", "CSAFPID-9080702"] }
+                ]
+            },
+            {
+                "flags": [
+                    { "label": "component_not_present", "product_ids": ["CSAFPID-9080701"] }
+                ]
+            }
+        ]);
+        assert_eq!(
+            Err(vec![ValidationError {
+                message: "Product CSAFPID-9080701 is already flagged as 'component_not_present' by flag 0, \
+                    but also as 'vulnerable_code_not_present'"
+                    .to_string(),
+                instance_path: "/vulnerabilities/0/flags/1".to_string(),
+                severity: ValidationSeverity::Error,
+            }]),
+            test_6_1_33_multiple_flags_with_vex_codes_per_product(&CsafDocument::<Csaf21>::from_json(doc).unwrap())
+        );
+    }
+}
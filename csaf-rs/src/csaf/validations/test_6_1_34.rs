@@ -1,30 +1,34 @@
 use crate::csaf::getter_traits::{BranchTrait, CsafTrait, ProductTreeTrait};
-use crate::csaf::validation::ValidationError;
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
 
 static MAX_DEPTH: u32 = 30;
 
 pub fn test_6_1_34_branches_recursion_depth(
     doc: &impl CsafTrait,
-) -> Result<(), ValidationError> {
+) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::<ValidationError>::new();
+
     if let Some(tree) = doc.get_product_tree().as_ref() {
         if let Some(branches) = tree.get_branches() {
             for (i, branch) in branches.iter().enumerate() {
-                if let Some(path) = branch.find_excessive_branch_depth(MAX_DEPTH) {
-                    return Err(ValidationError {
+                for path in branch.find_excessive_branch_depths(MAX_DEPTH) {
+                    errors.push(ValidationError {
                         message: format!("Branches recursion depth too big (> {})", MAX_DEPTH),
-                        instance_path: format!("/product_tree/branches/{}{}", i, path)
+                        instance_path: format!("/product_tree/branches/{}{}", i, path),
+                        severity: ValidationSeverity::Error,
                     });
                 }
             }
         }
     }
-    Ok(())
+
+    into_result(errors)
 }
 
 #[cfg(test)]
 mod tests {
     use crate::csaf::test_helper::run_csaf21_tests;
-    use crate::csaf::validation::ValidationError;
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
     use crate::csaf::validations::test_6_1_34::test_6_1_34_branches_recursion_depth;
     use std::collections::HashMap;
 
@@ -34,22 +38,24 @@ mod tests {
             "34",
             test_6_1_34_branches_recursion_depth,
             &HashMap::from([
-                ("01", &ValidationError {
+                ("01", vec![ValidationError {
                     message: "Branches recursion depth too big (> 30)".to_string(),
                     instance_path: "/product_tree/branches/0/branches/0/branches/0/branches/0\
                     /branches/0/branches/0/branches/0/branches/0/branches/0/branches/0/branches/0\
                     /branches/0/branches/0/branches/0/branches/0/branches/0/branches/0/branches/0\
                     /branches/0/branches/0/branches/0/branches/0/branches/0/branches/0/branches/0\
                     /branches/0/branches/0/branches/0/branches/0/branches/0/branches/0".to_string(),
-                }),
-                ("02", &ValidationError {
+                    severity: ValidationSeverity::Error,
+                }]),
+                ("02", vec![ValidationError {
                     message: "Branches recursion depth too big (> 30)".to_string(),
                     instance_path: "/product_tree/branches/0/branches/0/branches/1/branches/0\
                     /branches/0/branches/0/branches/0/branches/0/branches/0/branches/0/branches/0\
                     /branches/0/branches/0/branches/0/branches/0/branches/0/branches/0/branches/0\
                     /branches/0/branches/0/branches/0/branches/0/branches/0/branches/0/branches/0\
                     /branches/0/branches/0/branches/0/branches/0/branches/0/branches/0".to_string(),
-                }),
+                    severity: ValidationSeverity::Error,
+                }]),
             ]),
         );
     }
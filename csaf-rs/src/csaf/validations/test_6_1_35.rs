@@ -1,6 +1,6 @@
 use crate::csaf::csaf2_1::schema::CategoryOfTheRemediation;
 use crate::csaf::getter_traits::{CsafTrait, RemediationTrait, VulnerabilityTrait};
-use crate::csaf::validation::ValidationError;
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
 use std::collections::BTreeMap;
 
 /// Totally exclusive categories that cannot be combined with any other category.
@@ -18,7 +18,9 @@ static MUT_EX_STATES: &[CategoryOfTheRemediation] = &[
 
 pub fn test_6_1_35_contradicting_remediations(
     doc: &impl CsafTrait,
-) -> Result<(), ValidationError> {
+) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::<ValidationError>::new();
+
     for (v_i, v) in doc.get_vulnerabilities().iter().enumerate() {
         // Data struct to store observed remediation categories per product IT
         let mut product_categories: BTreeMap<String, Vec<CategoryOfTheRemediation>> = BTreeMap::new();
@@ -38,7 +40,7 @@ pub fn test_6_1_35_contradicting_remediations(
                             // Checks if the current category conflicts with any other in the group of mutually exclusive ones.
                             || MUT_EX_STATES.contains(&cat) && exist_cat_set.iter().any(|e_cat| MUT_EX_STATES.contains(e_cat))
                         {
-                            return Err(ValidationError {
+                            errors.push(ValidationError {
                                 message: format!(
                                     "Product {} has contradicting remediations: {} and {}",
                                     p,
@@ -46,6 +48,7 @@ pub fn test_6_1_35_contradicting_remediations(
                                     cat
                                 ),
                                 instance_path: format!("/vulnerabilities/{}/remediations/{}", v_i, r_i),
+                                severity: ValidationSeverity::Error,
                             });
                         }
                         exist_cat_set.push(cat);
@@ -56,13 +59,14 @@ pub fn test_6_1_35_contradicting_remediations(
             }
         }
     }
-    Ok(())
+
+    into_result(errors)
 }
 
 #[cfg(test)]
 mod tests {
     use crate::csaf::test_helper::run_csaf21_tests;
-    use crate::csaf::validation::ValidationError;
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
     use crate::csaf::validations::test_6_1_35::test_6_1_35_contradicting_remediations;
     use std::collections::HashMap;
 
@@ -72,22 +76,26 @@ mod tests {
             "35",
             test_6_1_35_contradicting_remediations,
             &HashMap::from([
-                ("01", &ValidationError {
+                ("01", vec![ValidationError {
                     message: "Product CSAFPID-9080700 has contradicting remediations: no_fix_planned and vendor_fix".to_string(),
                     instance_path: "/vulnerabilities/0/remediations/1".to_string(),
-                }),
-                ("02", &ValidationError {
+                    severity: ValidationSeverity::Error,
+                }]),
+                ("02", vec![ValidationError {
                     message: "Product CSAFPID-9080700 has contradicting remediations: none_available and mitigation".to_string(),
                     instance_path: "/vulnerabilities/0/remediations/1".to_string(),
-                }),
-                ("03", &ValidationError {
+                    severity: ValidationSeverity::Error,
+                }]),
+                ("03", vec![ValidationError {
                     message: "Product CSAFPID-9080702 has contradicting remediations: workaround, fix_planned and optional_patch".to_string(),
                     instance_path: "/vulnerabilities/0/remediations/2".to_string(),
-                }),
-                ("04", &ValidationError {
+                    severity: ValidationSeverity::Error,
+                }]),
+                ("04", vec![ValidationError {
                     message: "Product CSAFPID-9080701 has contradicting remediations: mitigation, fix_planned and optional_patch".to_string(),
                     instance_path: "/vulnerabilities/0/remediations/2".to_string(),
-                }),
+                    severity: ValidationSeverity::Error,
+                }]),
             ]),
         );
     }
@@ -1,6 +1,6 @@
 use crate::csaf::csaf2_1::schema::CategoryOfTheRemediation;
 use crate::csaf::getter_traits::{CsafTrait, ProductStatusTrait, RemediationTrait, VulnerabilityTrait};
-use crate::csaf::validation::ValidationError;
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
 use std::collections::HashSet;
 
 /// Remediation categories that conflict with the product status "not affected".
@@ -23,7 +23,9 @@ const FIXED_CONFLICTS: &[CategoryOfTheRemediation] = &[
 
 pub fn test_6_1_36_status_group_contradicting_remediation_categories(
     doc: &impl CsafTrait,
-) -> Result<(), ValidationError> {
+) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::<ValidationError>::new();
+
     for (v_i, v) in doc.get_vulnerabilities().iter().enumerate() {
         if let Some(product_status) = v.get_product_status() {
             // Collect Product IDs that may cause conflicts
@@ -42,33 +44,36 @@ pub fn test_6_1_36_status_group_contradicting_remediation_categories(
                     // Iterate over product IDs
                     for p in product_ids {
                         if affected_products.contains(&p) && cat == CategoryOfTheRemediation::OptionalPatch {
-                            return Err(ValidationError {
+                            errors.push(ValidationError {
                                 message: format!(
                                     "Product {} is listed as affected but has conflicting remediation category {}",
                                     p,
                                     cat
                                 ),
                                 instance_path: format!("/vulnerabilities/{}/remediations/{}", v_i, r_i),
+                                severity: ValidationSeverity::Error,
                             });
                         }
                         if not_affected_products.contains(&p) && NOT_AFFECTED_CONFLICTS.contains(&cat) {
-                            return Err(ValidationError {
+                            errors.push(ValidationError {
                                 message: format!(
                                     "Product {} is listed as not affected but has conflicting remediation category {}",
                                     p,
                                     cat
                                 ),
                                 instance_path: format!("/vulnerabilities/{}/remediations/{}", v_i, r_i),
+                                severity: ValidationSeverity::Error,
                             });
                         }
                         if fixed_products.contains(&p) && FIXED_CONFLICTS.contains(&cat) {
-                            return Err(ValidationError {
+                            errors.push(ValidationError {
                                 message: format!(
                                     "Product {} is listed as fixed but has conflicting remediation category {}",
                                     p,
                                     cat
                                 ),
                                 instance_path: format!("/vulnerabilities/{}/remediations/{}", v_i, r_i),
+                                severity: ValidationSeverity::Error,
                             });
                         }
                     }
@@ -76,14 +81,15 @@ pub fn test_6_1_36_status_group_contradicting_remediation_categories(
             }
         }
     }
-    Ok(())
+
+    into_result(errors)
 }
 
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
     use crate::csaf::test_helper::run_csaf21_tests;
-    use crate::csaf::validation::ValidationError;
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
     use crate::csaf::validations::test_6_1_36::test_6_1_36_status_group_contradicting_remediation_categories;
 
     #[test]
@@ -92,22 +98,26 @@ mod tests {
             "36",
             test_6_1_36_status_group_contradicting_remediation_categories,
             &HashMap::from([
-                ("01", &ValidationError {
+                ("01", vec![ValidationError {
                     message: "Product CSAFPID-9080700 is listed as not affected but has conflicting remediation category vendor_fix".to_string(),
-                    instance_path: "/vulnerabilities/0/remediations/0".to_string()
-                }),
-                ("02", &ValidationError {
+                    instance_path: "/vulnerabilities/0/remediations/0".to_string(),
+                    severity: ValidationSeverity::Error,
+                }]),
+                ("02", vec![ValidationError {
                     message: "Product CSAFPID-9080703 is listed as fixed but has conflicting remediation category none_available".to_string(),
-                    instance_path: "/vulnerabilities/0/remediations/0".to_string()
-                }),
-                ("03", &ValidationError {
+                    instance_path: "/vulnerabilities/0/remediations/0".to_string(),
+                    severity: ValidationSeverity::Error,
+                }]),
+                ("03", vec![ValidationError {
                     message: "Product CSAFPID-9080700 is listed as affected but has conflicting remediation category optional_patch".to_string(),
                     instance_path: "/vulnerabilities/0/remediations/0".to_string(),
-                }),
-                ("04", &ValidationError {
+                    severity: ValidationSeverity::Error,
+                }]),
+                ("04", vec![ValidationError {
                     message: "Product CSAFPID-9080700 is listed as fixed but has conflicting remediation category no_fix_planned".to_string(),
                     instance_path: "/vulnerabilities/0/remediations/0".to_string(),
-                }),
+                    severity: ValidationSeverity::Error,
+                }]),
             ]),
         );
     }
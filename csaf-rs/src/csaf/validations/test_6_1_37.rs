@@ -1,5 +1,5 @@
-use crate::csaf::getter_traits::{CsafTrait, DocumentTrait, FlagTrait, GeneratorTrait, InvolvementTrait, RemediationTrait, RevisionTrait, ThreatTrait, TrackingTrait, VulnerabilityTrait};
-use crate::csaf::validation::ValidationError;
+use crate::csaf::getter_traits::{ContentTrait, CsafTrait, DocumentTrait, FirstKnownExploitationDateTrait, FlagTrait, GeneratorTrait, InvolvementTrait, MetricTrait, RemediationTrait, RevisionTrait, ThreatTrait, TrackingTrait, VulnerabilityTrait};
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
 use regex::Regex;
 use std::sync::LazyLock;
 
@@ -14,47 +14,49 @@ static CSAF_RFC3339_REGEX: LazyLock<Regex> = LazyLock::new(||
 /// vulnerability disclosure/discovery dates, remediation dates, threat dates, etc.
 pub fn test_6_1_37_date_and_time(
     doc: &impl CsafTrait,
-) -> Result<(), ValidationError> {
+) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::<ValidationError>::new();
     let tracking = doc.get_document().get_tracking();
 
     // Check the initial release date
-    check_datetime(tracking.get_initial_release_date(), "/document/tracking/initial_release_date")?;
+    check_datetime(&mut errors, tracking.get_initial_release_date(), "/document/tracking/initial_release_date");
 
     // Check the current release date
-    check_datetime(tracking.get_current_release_date(), "/document/tracking/current_release_date")?;
+    check_datetime(&mut errors, tracking.get_current_release_date(), "/document/tracking/current_release_date");
 
     // Check the generator date if present
     if let Some(generator) = tracking.get_generator() {
         if let Some(date) = generator.get_date() {
-            check_datetime(date, "/document/tracking/generator/date")?;
+            check_datetime(&mut errors, date, "/document/tracking/generator/date");
         }
     }
 
     // Check revision history dates if present
     for (i_r, revision) in tracking.get_revision_history().iter().enumerate() {
         check_datetime(
+            &mut errors,
             revision.get_date(),
             &format!("/document/tracking/revision_history/{}/date", i_r)
-        )?;
+        );
     }
 
     // Check vulnerability-related dates
     for (i_v, vuln) in doc.get_vulnerabilities().iter().enumerate() {
         // Check disclosure date if present
         if let Some(date) = vuln.get_disclosure_date() {
-            check_datetime(date, &format!("/vulnerabilities/{}/disclosure_date", i_v))?;
+            check_datetime(&mut errors, date, &format!("/vulnerabilities/{}/disclosure_date", i_v));
         }
 
         // Check the discovery date if present
         if let Some(date) = vuln.get_discovery_date() {
-            check_datetime(date, &format!("/vulnerabilities/{}/discovery_date", i_v))?;
+            check_datetime(&mut errors, date, &format!("/vulnerabilities/{}/discovery_date", i_v));
         }
 
         // Check flags dates if present
         if let Some(flags) = vuln.get_flags() {
             for (i_f, flag) in flags.iter().enumerate() {
                 if let Some(date) = flag.get_date() {
-                    check_datetime(date, &format!("/vulnerabilities/{}/flags/{}/date", i_v, i_f))?;
+                    check_datetime(&mut errors, date, &format!("/vulnerabilities/{}/flags/{}/date", i_v, i_f));
                 }
             }
         }
@@ -64,9 +66,10 @@ pub fn test_6_1_37_date_and_time(
             for (i_i, involvement) in involvements.iter().enumerate() {
                 if let Some(date) = involvement.get_date() {
                     check_datetime(
+                        &mut errors,
                         date,
                         &format!("/vulnerabilities/{}/involvements/{}/date", i_v, i_i)
-                    )?;
+                    );
                 }
             }
         }
@@ -74,36 +77,53 @@ pub fn test_6_1_37_date_and_time(
         // Check remediation dates if present
         for (i_r, remediation) in vuln.get_remediations().iter().enumerate() {
             if let Some(date) = remediation.get_date() {
-                check_datetime(date, &format!("/vulnerabilities/{}/remediations/{}/date", i_v, i_r))?;
+                check_datetime(&mut errors, date, &format!("/vulnerabilities/{}/remediations/{}/date", i_v, i_r));
             }
         }
 
         // Check threat dates if present
         for (i_t, threat) in vuln.get_threats().iter().enumerate() {
             if let Some(date) = threat.get_date() {
-                check_datetime(date, &format!("/vulnerabilities/{}/threats/{}/date", i_v, i_t))?;
+                check_datetime(&mut errors, date, &format!("/vulnerabilities/{}/threats/{}/date", i_v, i_t));
+            }
+        }
+
+        // Check first known exploitation dates if present (CSAF 2.1)
+        for (i_e, exploitation) in vuln.get_first_known_exploitation_dates().enumerate() {
+            let path = format!("/vulnerabilities/{}/first_known_exploitation_dates/{}", i_v, i_e);
+            check_datetime(&mut errors, exploitation.get_date(), &format!("{}/date", path));
+            check_datetime(&mut errors, exploitation.get_exploitation_date(), &format!("{}/exploitation_date", path));
+        }
+
+        // Check EPSS timestamps if present (CSAF 2.1)
+        for (i_m, metric) in vuln.get_metrics().into_iter().flatten().enumerate() {
+            if let Some(epss) = metric.get_content().get_epss() {
+                let path = format!("{}/epss/timestamp", metric.get_content().get_content_json_path(i_v, i_m));
+                check_datetime(&mut errors, &epss.timestamp, &path);
             }
         }
     }
 
-    Ok(())
+    into_result(errors)
 }
 
-fn check_datetime(date_time: &String, instance_path: &str) -> Result<(), ValidationError> {
+/// Adds a validation error to `errors` if the given date-time string is not valid.
+fn check_datetime(errors: &mut Vec<ValidationError>, date_time: &String, instance_path: &str) {
     if CSAF_RFC3339_REGEX.is_match(date_time) {
         // Add chrono-based plausibility check
-        match chrono::DateTime::parse_from_rfc3339(date_time) {
-            Ok(_) => Ok(()), // Successfully parsed as a valid RFC3339 datetime
-            Err(e) => Err(ValidationError {
+        if let Err(e) = chrono::DateTime::parse_from_rfc3339(date_time) {
+            errors.push(ValidationError {
                 message: format!("Date-time string {} matched RFC3339 regex but failed chrono parsing: {}", date_time, e),
                 instance_path: instance_path.to_string(),
-            }),
+                severity: ValidationSeverity::Error,
+            });
         }
     } else {
-        Err(ValidationError {
+        errors.push(ValidationError {
             message: format!("Invalid date-time string {}, expected RFC3339-compliant format with non-empty timezone and no leap seconds", date_time),
             instance_path: instance_path.to_string(),
-        })
+            severity: ValidationSeverity::Error,
+        });
     }
 }
 
@@ -113,7 +133,7 @@ mod tests {
     Ignored because of https://github.com/oasis-tcs/csaf/issues/963
     
     use crate::csaf::test_helper::run_csaf21_tests;
-    use crate::csaf::validation::ValidationError;
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
     use crate::csaf::validations::test_6_1_37::test_6_1_37_date_and_time;
     use std::collections::HashMap;
     
@@ -122,32 +142,79 @@ mod tests {
         run_csaf21_tests(
             "37",
             test_6_1_37_date_and_time, &HashMap::from([
-                ("01", &ValidationError {
+                ("01", vec![ValidationError {
                     message: "Invalid date-time string 2024-01-24 10:00:00.000Z, expected RFC3339-compliant format with non-empty timezone and no leap seconds".to_string(),
                     instance_path: "/document/tracking/initial_release_date".to_string(),
-                }),
-                ("02", &ValidationError {
+                    severity: ValidationSeverity::Error,
+                }]),
+                ("02", vec![ValidationError {
                     message: "Invalid date-time string 2024-01-24T10:00:00.000z, expected RFC3339-compliant format with non-empty timezone and no leap seconds".to_string(),
                     instance_path: "/document/tracking/initial_release_date".to_string(),
-                }),
-                ("03", &ValidationError {
+                    severity: ValidationSeverity::Error,
+                }]),
+                ("03", vec![ValidationError {
                     message: "Invalid date-time string 2017-01-01T02:59:60+04:00, expected RFC3339-compliant format with non-empty timezone and no leap seconds".to_string(),
                     instance_path: "/vulnerabilities/0/disclosure_date".to_string(),
-                }),
-                ("04", &ValidationError {
+                    severity: ValidationSeverity::Error,
+                }]),
+                ("04", vec![ValidationError {
                     message: "Date-time string 2023-04-31T00:00:00+01:00 matched RFC3339 regex but failed chrono parsing: input is out of range".to_string(),
                     instance_path: "/vulnerabilities/0/disclosure_date".to_string(),
-                }),
-                ("05", &ValidationError {
+                    severity: ValidationSeverity::Error,
+                }]),
+                ("05", vec![ValidationError {
                     message: "Date-time string 2023-02-29T00:00:00+01:00 matched RFC3339 regex but failed chrono parsing: input is out of range".to_string(),
                     instance_path: "/vulnerabilities/0/disclosure_date".to_string(),
-                }),
-                ("06", &ValidationError {
+                    severity: ValidationSeverity::Error,
+                }]),
+                ("06", vec![ValidationError {
                     message: "Invalid date-time string 2016-12-31T00:00:60+23:59, expected RFC3339-compliant format with non-empty timezone and no leap seconds".to_string(),
                     instance_path: "/vulnerabilities/0/disclosure_date".to_string(),
-                }),
+                    severity: ValidationSeverity::Error,
+                }]),
             ])
         );
     }
     */
+
+    use crate::csaf::csaf2_1::schema::CommonSecurityAdvisoryFramework as Csaf21;
+    use crate::csaf::document::CsafDocument;
+    use crate::csaf::test_helper::minimal_document;
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_1_37::test_6_1_37_date_and_time;
+    use serde_json::json;
+
+    #[test]
+    fn test_test_6_1_37_epss_and_exploitation_dates() {
+        let mut doc = minimal_document("2.1");
+        doc["product_tree"] = json!({
+            "full_product_names": [{ "name": "Product A", "product_id": "CSAFPID-9080700" }]
+        });
+        doc["vulnerabilities"] = json!([{
+            "first_known_exploitation_dates": [{
+                "date": "2024-01-24T10:00:00.000Z",
+                "exploitation_date": "2024-01-20 10:00:00.000Z",
+                "product_ids": ["CSAFPID-9080700"]
+            }],
+            "metrics": [{
+                "content": { "epss": { "percentile": "0.9", "probability": "0.5", "timestamp": "2024-02-30T10:00:00.000Z" } },
+                "products": ["CSAFPID-9080700"]
+            }]
+        }]);
+        assert_eq!(
+            Err(vec![
+                ValidationError {
+                    message: "Invalid date-time string 2024-01-20 10:00:00.000Z, expected RFC3339-compliant format with non-empty timezone and no leap seconds".to_string(),
+                    instance_path: "/vulnerabilities/0/first_known_exploitation_dates/0/exploitation_date".to_string(),
+                    severity: ValidationSeverity::Error,
+                },
+                ValidationError {
+                    message: "Date-time string 2024-02-30T10:00:00.000Z matched RFC3339 regex but failed chrono parsing: input is out of range".to_string(),
+                    instance_path: "/vulnerabilities/0/metrics/0/content/epss/timestamp".to_string(),
+                    severity: ValidationSeverity::Error,
+                },
+            ]),
+            test_6_1_37_date_and_time(&CsafDocument::<Csaf21>::from_json(doc).unwrap())
+        );
+    }
 }
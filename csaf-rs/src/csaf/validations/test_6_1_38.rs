@@ -1,6 +1,6 @@
 use crate::csaf::csaf2_1::schema::LabelOfTlp::Clear;
 use crate::csaf::getter_traits::{CsafTrait, DistributionTrait, DocumentTrait, SharingGroupTrait, TlpTrait};
-use crate::csaf::validation::ValidationError;
+use crate::csaf::validation::{ValidationError, ValidationSeverity};
 
 static MAX_UUID: &str = "ffffffff-ffff-ffff-ffff-ffffffffffff";
 
@@ -18,19 +18,20 @@ static MAX_UUID: &str = "ffffffff-ffff-ffff-ffff-ffffffffffff";
 /// # Returns
 ///
 /// * `Ok(())` if the validation passes.
-/// * `Err(ValidationError)` if the validation fails, with a message explaining the reason
+/// * `Err(Vec<ValidationError>)` if the validation fails, with a message explaining the reason
 ///   and the JSON path to the invalid element.
 pub fn test_6_1_38_non_public_sharing_group_max_uuid(
     doc: &impl CsafTrait,
-) -> Result<(), ValidationError> {
+) -> Result<(), Vec<ValidationError>> {
     let distribution = doc.get_document().get_distribution_21()?;
 
     if let Some(sharing_group) = distribution.get_sharing_group() {
         if sharing_group.get_id() == MAX_UUID && distribution.get_tlp_21()?.get_label() != Clear {
-            return Err(ValidationError {
+            return Err(vec![ValidationError {
                 message: "Document must be public (TLD CLEAR) when using max UUID as sharing group ID.".to_string(),
-                instance_path: "/document/distribution/sharing_group/tlp/label".to_string()
-            })
+                instance_path: "/document/distribution/sharing_group/tlp/label".to_string(),
+                severity: ValidationSeverity::Error,
+            }])
         }
     }
 
@@ -40,7 +41,7 @@ pub fn test_6_1_38_non_public_sharing_group_max_uuid(
 #[cfg(test)]
 mod tests {
     use crate::csaf::test_helper::run_csaf21_tests;
-    use crate::csaf::validation::ValidationError;
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
     use crate::csaf::validations::test_6_1_38::test_6_1_38_non_public_sharing_group_max_uuid;
     use std::collections::HashMap;
 
@@ -49,13 +50,14 @@ mod tests {
         let expected_error = ValidationError {
             message: "Document must be public (TLD CLEAR) when using max UUID as sharing group ID.".to_string(),
             instance_path: "/document/distribution/sharing_group/tlp/label".to_string(),
+            severity: ValidationSeverity::Error,
         };
 
         run_csaf21_tests("38", test_6_1_38_non_public_sharing_group_max_uuid, &HashMap::from([
-            ("01", &expected_error),
-            ("02", &expected_error),
-            ("03", &expected_error),
-            ("04", &expected_error),
+            ("01", vec![expected_error.clone()]),
+            ("02", vec![expected_error.clone()]),
+            ("03", vec![expected_error.clone()]),
+            ("04", vec![expected_error.clone()]),
         ]));
     }
 }
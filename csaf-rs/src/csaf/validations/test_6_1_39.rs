@@ -1,6 +1,6 @@
 use crate::csaf::csaf2_1::schema::DocumentStatus;
 use crate::csaf::getter_traits::{CsafTrait, DistributionTrait, DocumentTrait, SharingGroupTrait, TlpTrait, TrackingTrait};
-use crate::csaf::validation::ValidationError;
+use crate::csaf::validation::{ValidationError, ValidationSeverity};
 use crate::csaf::csaf2_1::schema::LabelOfTlp::Clear;
 
 static MAX_UUID: &str = "ffffffff-ffff-ffff-ffff-ffffffffffff";
@@ -21,10 +21,10 @@ static NIL_UUID: &str = "00000000-0000-0000-0000-000000000000";
 /// # Returns
 ///
 /// - `Ok(())` if the validation passes.
-/// - `Err(ValidationError)` if the requirements are not met.
+/// - `Err(Vec<ValidationError>)` if the requirements are not met.
 pub fn test_6_1_39_public_sharing_group_with_no_max_uuid(
     doc: &impl CsafTrait,
-) -> Result<(), ValidationError> {
+) -> Result<(), Vec<ValidationError>> {
     let distribution = doc.get_document().get_distribution_21()?;
 
     if distribution.get_tlp_21()?.get_label() == Clear {
@@ -35,10 +35,11 @@ pub fn test_6_1_39_public_sharing_group_with_no_max_uuid(
             } else if sharing_group_id == NIL_UUID && doc.get_document().get_tracking().get_status() == DocumentStatus::Draft {
                 Ok(())
             } else {
-                Err(ValidationError {
+                Err(vec![ValidationError {
                     message: "Document with TLP CLEAR and sharing group must use max UUID or nil UUID plus draft status.".to_string(),
                     instance_path: "/document/distribution/sharing_group/id".to_string(),
-                })
+                    severity: ValidationSeverity::Error,
+                }])
             };
         }
     }
@@ -50,7 +51,7 @@ pub fn test_6_1_39_public_sharing_group_with_no_max_uuid(
 mod tests {
     use std::collections::HashMap;
     use crate::csaf::test_helper::run_csaf21_tests;
-    use crate::csaf::validation::ValidationError;
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
     use crate::csaf::validations::test_6_1_39::test_6_1_39_public_sharing_group_with_no_max_uuid;
 
     #[test]
@@ -58,11 +59,12 @@ mod tests {
         let expected_error = ValidationError {
             message: "Document with TLP CLEAR and sharing group must use max UUID or nil UUID plus draft status.".to_string(),
             instance_path: "/document/distribution/sharing_group/id".to_string(),
+            severity: ValidationSeverity::Error,
         };
 
         run_csaf21_tests("39", test_6_1_39_public_sharing_group_with_no_max_uuid, &HashMap::from([
-            ("01", &expected_error),
-            ("02", &expected_error),
+            ("01", vec![expected_error.clone()]),
+            ("02", vec![expected_error.clone()]),
         ]));
     }
 }
@@ -1,5 +1,5 @@
 use crate::csaf::getter_traits::{CsafTrait, DistributionTrait, DocumentTrait, SharingGroupTrait};
-use crate::csaf::validation::ValidationError;
+use crate::csaf::validation::{ValidationError, ValidationSeverity};
 
 static NAME_PUBLIC: &str = "Public";
 static NAME_PRIVATE: &str = "No sharing allowed";
@@ -23,28 +23,30 @@ static NIL_UUID: &str = "00000000-0000-0000-0000-000000000000";
 /// # Returns
 ///
 /// * `Ok(())` if the validation passes.
-/// * `Err(ValidationError)` if the validation fails, with a message explaining the reason
+/// * `Err(Vec<ValidationError>)` if the validation fails, with a message explaining the reason
 ///   and the JSON path to the invalid element.
 pub fn test_6_1_40_invalid_sharing_group_name(
     doc: &impl CsafTrait,
-) -> Result<(), ValidationError> {
+) -> Result<(), Vec<ValidationError>> {
     let distribution = doc.get_document().get_distribution_21()?;
 
     if let Some(sharing_group) = distribution.get_sharing_group() {
         if let Some(sharing_group_name) = sharing_group.get_name() {
             if sharing_group_name == NAME_PUBLIC {
                 if sharing_group.get_id() != MAX_UUID {
-                    return Err(ValidationError {
+                    return Err(vec![ValidationError {
                         message: format!("Sharing group name \"{}\" is prohibited without max UUID.", NAME_PUBLIC),
-                        instance_path: "/document/distribution/sharing_group/name".to_string()
-                    })
+                        instance_path: "/document/distribution/sharing_group/name".to_string(),
+                        severity: ValidationSeverity::Error,
+                    }])
                 }
             } else if sharing_group_name == NAME_PRIVATE {
                 if sharing_group.get_id() != NIL_UUID {
-                    return Err(ValidationError {
+                    return Err(vec![ValidationError {
                         message: format!("Sharing group name \"{}\" is prohibited without nil UUID.", NAME_PRIVATE),
-                        instance_path: "/document/distribution/sharing_group/name".to_string()
-                    })
+                        instance_path: "/document/distribution/sharing_group/name".to_string(),
+                        severity: ValidationSeverity::Error,
+                    }])
                 }
             }
         }
@@ -57,7 +59,7 @@ pub fn test_6_1_40_invalid_sharing_group_name(
 mod tests {
     use std::collections::HashMap;
     use crate::csaf::test_helper::run_csaf21_tests;
-    use crate::csaf::validation::ValidationError;
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
     use crate::csaf::validations::test_6_1_40::{test_6_1_40_invalid_sharing_group_name, NAME_PRIVATE, NAME_PUBLIC};
 
     #[test]
@@ -65,14 +67,16 @@ mod tests {
         run_csaf21_tests(
             "40",
             test_6_1_40_invalid_sharing_group_name, &HashMap::from([
-                ("01", &ValidationError {
+                ("01", vec![ValidationError {
                     message: format!("Sharing group name \"{}\" is prohibited without max UUID.", NAME_PUBLIC),
-                    instance_path: "/document/distribution/sharing_group/name".to_string()
-                }),
-                ("02", &ValidationError {
+                    instance_path: "/document/distribution/sharing_group/name".to_string(),
+                    severity: ValidationSeverity::Error,
+                }]),
+                ("02", vec![ValidationError {
                     message: format!("Sharing group name \"{}\" is prohibited without nil UUID.", NAME_PRIVATE),
-                    instance_path: "/document/distribution/sharing_group/name".to_string()
-                }),
+                    instance_path: "/document/distribution/sharing_group/name".to_string(),
+                    severity: ValidationSeverity::Error,
+                }]),
             ])
         );
     }
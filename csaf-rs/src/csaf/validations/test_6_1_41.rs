@@ -1,5 +1,5 @@
 use crate::csaf::getter_traits::{CsafTrait, DistributionTrait, DocumentTrait, SharingGroupTrait};
-use crate::csaf::validation::ValidationError;
+use crate::csaf::validation::{ValidationError, ValidationSeverity};
 
 static NAME_PUBLIC: &str = "Public";
 static NAME_PRIVATE: &str = "No sharing allowed";
@@ -21,11 +21,11 @@ static NIL_UUID: &str = "00000000-0000-0000-0000-000000000000";
 /// # Returns
 ///
 /// * `Ok(())` if the validation passes.
-/// * `Err(ValidationError)` if the validation fails, with a message explaining the reason
+/// * `Err(Vec<ValidationError>)` if the validation fails, with a message explaining the reason
 ///   and the JSON path to the invalid element.
 pub fn test_6_1_41_missing_sharing_group_name(
     doc: &impl CsafTrait,
-) -> Result<(), ValidationError> {
+) -> Result<(), Vec<ValidationError>> {
     let distribution = doc.get_document().get_distribution_21()?;
 
     if let Some(sharing_group) = distribution.get_sharing_group() {
@@ -34,10 +34,11 @@ pub fn test_6_1_41_missing_sharing_group_name(
             // If max UUID is used, name must exist and be NAME_PUBLIC
             match sharing_group.get_name() {
                 Some(name) if name == NAME_PUBLIC => {},
-                _ => return Err(ValidationError {
+                _ => return Err(vec![ValidationError {
                     message: format!("Max UUID requires sharing group name to be \"{}\".", NAME_PUBLIC),
-                    instance_path: "/document/distribution/sharing_group/name".to_string()
-                })
+                    instance_path: "/document/distribution/sharing_group/name".to_string(),
+                    severity: ValidationSeverity::Error,
+                }])
             }
         }
         // Check if nil UUID is used
@@ -45,10 +46,11 @@ pub fn test_6_1_41_missing_sharing_group_name(
             // If nil UUID is used, name must exist and be NAME_PRIVATE
             match sharing_group.get_name() {
                 Some(name) if name == NAME_PRIVATE => {},
-                _ => return Err(ValidationError {
+                _ => return Err(vec![ValidationError {
                     message: format!("Nil UUID requires sharing group name to be \"{}\".", NAME_PRIVATE),
-                    instance_path: "/document/distribution/sharing_group/name".to_string()
-                })
+                    instance_path: "/document/distribution/sharing_group/name".to_string(),
+                    severity: ValidationSeverity::Error,
+                }])
             }
         }
     }
@@ -60,7 +62,7 @@ pub fn test_6_1_41_missing_sharing_group_name(
 mod tests {
     use std::collections::HashMap;
     use crate::csaf::test_helper::run_csaf21_tests;
-    use crate::csaf::validation::ValidationError;
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
     use crate::csaf::validations::test_6_1_41::{test_6_1_41_missing_sharing_group_name, NAME_PRIVATE, NAME_PUBLIC};
 
     #[test]
@@ -68,22 +70,26 @@ mod tests {
         run_csaf21_tests(
             "41",
             test_6_1_41_missing_sharing_group_name, &HashMap::from([
-                ("01", &ValidationError {
+                ("01", vec![ValidationError {
                     message: format!("Max UUID requires sharing group name to be \"{}\".", NAME_PUBLIC),
-                    instance_path: "/document/distribution/sharing_group/name".to_string()
-                }),
-                ("02", &ValidationError {
+                    instance_path: "/document/distribution/sharing_group/name".to_string(),
+                    severity: ValidationSeverity::Error,
+                }]),
+                ("02", vec![ValidationError {
                     message: format!("Nil UUID requires sharing group name to be \"{}\".", NAME_PRIVATE),
-                    instance_path: "/document/distribution/sharing_group/name".to_string()
-                }),
-                ("03", &ValidationError {
+                    instance_path: "/document/distribution/sharing_group/name".to_string(),
+                    severity: ValidationSeverity::Error,
+                }]),
+                ("03", vec![ValidationError {
                     message: format!("Max UUID requires sharing group name to be \"{}\".", NAME_PUBLIC),
-                    instance_path: "/document/distribution/sharing_group/name".to_string()
-                }),
-                ("04", &ValidationError {
+                    instance_path: "/document/distribution/sharing_group/name".to_string(),
+                    severity: ValidationSeverity::Error,
+                }]),
+                ("04", vec![ValidationError {
                     message: format!("Nil UUID requires sharing group name to be \"{}\".", NAME_PRIVATE),
-                    instance_path: "/document/distribution/sharing_group/name".to_string()
-                }),
+                    instance_path: "/document/distribution/sharing_group/name".to_string(),
+                    severity: ValidationSeverity::Error,
+                }]),
             ])
         );
     }
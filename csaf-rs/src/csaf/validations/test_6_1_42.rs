@@ -1,10 +1,12 @@
 use crate::csaf::getter_traits::{CsafTrait, ProductIdentificationHelperTrait, ProductTrait, ProductTreeTrait};
-use crate::csaf::validation::ValidationError;
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
 use purl::GenericPurl;
 
 pub fn test_6_1_42_purl_consistency(
     doc: &impl CsafTrait,
-) -> Result<(), ValidationError> {
+) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::<ValidationError>::new();
+
     if let Some(product_tree) = doc.get_product_tree() {
         product_tree.visit_all_products(&mut |product, path| {
             if let Some(helper) = product.get_product_identification_helper() {
@@ -20,10 +22,12 @@ pub fn test_6_1_42_purl_consistency(
                         let purl = match purl_str.parse::<GenericPurl<String>>() {
                             Ok(p) => p,
                             Err(_) => {
-                                return Err(ValidationError {
+                                errors.push(ValidationError {
                                     message: format!("Invalid PURL format: {}", purl_str),
                                     instance_path: format!("{}/product_identification_helper/purls/{}", path, i),
+                                    severity: ValidationSeverity::Error,
                                 });
+                                continue;
                             }
                         };
 
@@ -31,19 +35,22 @@ pub fn test_6_1_42_purl_consistency(
                         let current_parts = match purl.into_builder().without_qualifiers().build() {
                             Ok(purl) => purl.to_string(),
                             Err(_) => {
-                                return Err(ValidationError {
+                                errors.push(ValidationError {
                                     message: format!("Error whilst stripping qualifiers from PURL: {}", purl_str),
                                     instance_path: format!("{}/product_identification_helper/purls/{}", path, i),
+                                    severity: ValidationSeverity::Error,
                                 });
+                                continue;
                             },
                         };
 
                         if let Some(ref base) = base_parts {
                             // Must always match
                             if current_parts != *base {
-                                return Err(ValidationError {
+                                errors.push(ValidationError {
                                     message: String::from("PURLs within the same product_identification_helper must only differ in qualifiers"),
                                     instance_path: format!("{}/product_identification_helper/purls/{}", path, i),
+                                    severity: ValidationSeverity::Error,
                                 });
                             }
                         } else {
@@ -56,14 +63,15 @@ pub fn test_6_1_42_purl_consistency(
             Ok(())
         })?;
     }
-    Ok(())
+
+    into_result(errors)
 }
 
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
     use crate::csaf::test_helper::run_csaf21_tests;
-    use crate::csaf::validation::ValidationError;
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
     use crate::csaf::validations::test_6_1_42::test_6_1_42_purl_consistency;
 
     static ERROR_MESSAGE: &str = "PURLs within the same product_identification_helper must only differ in qualifiers";
@@ -73,14 +81,16 @@ mod tests {
         run_csaf21_tests(
             "42",
             test_6_1_42_purl_consistency, &HashMap::from([
-                ("01", &ValidationError {
+                ("01", vec![ValidationError {
                     message: ERROR_MESSAGE.to_string(),
                     instance_path: "/product_tree/full_product_names/0/product_identification_helper/purls/1".to_string(),
-                }),
-                ("02", &ValidationError {
+                    severity: ValidationSeverity::Error,
+                }]),
+                ("02", vec![ValidationError {
                     message: ERROR_MESSAGE.to_string(),
                     instance_path: "/product_tree/branches/0/branches/0/branches/0/product/product_identification_helper/purls/2".to_string(),
-                }),
+                    severity: ValidationSeverity::Error,
+                }]),
             ])
         );
     }
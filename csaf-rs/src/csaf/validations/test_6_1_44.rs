@@ -1,19 +1,22 @@
 use crate::csaf::getter_traits::{CsafTrait, ProductIdentificationHelperTrait, ProductTrait, ProductTreeTrait};
 use crate::csaf::helpers::count_unescaped_stars;
-use crate::csaf::validation::ValidationError;
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
 
 pub fn test_6_1_44_multiple_stars_in_serial_number(
     doc: &impl CsafTrait,
-) -> Result<(), ValidationError> {
+) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::<ValidationError>::new();
+
     if let Some(product_tree) = doc.get_product_tree() {
         product_tree.visit_all_products(&mut |product, path| {
             if let Some(helper) = product.get_product_identification_helper() {
                 if let Some(serial_numbers) = helper.get_serial_numbers() {
                     for (index, serial_number) in serial_numbers.enumerate() {
                         if count_unescaped_stars(serial_number) > 1 {
-                            return Err(ValidationError {
+                            errors.push(ValidationError {
                                 message: "Serial number must not contain multiple unescaped asterisks (stars)".to_string(),
                                 instance_path: format!("{}/product_identification_helper/serial_numbers/{}", path, index),
+                                severity: ValidationSeverity::Error,
                             });
                         }
                     }
@@ -22,13 +25,14 @@ pub fn test_6_1_44_multiple_stars_in_serial_number(
             Ok(())
         })?;
     }
-    Ok(())
+
+    into_result(errors)
 }
 
 #[cfg(test)]
 mod tests {
     use crate::csaf::test_helper::run_csaf21_tests;
-    use crate::csaf::validation::ValidationError;
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
     use std::collections::HashMap;
     use crate::csaf::validations::test_6_1_44::test_6_1_44_multiple_stars_in_serial_number;
 
@@ -37,13 +41,14 @@ mod tests {
         let expected_error = ValidationError {
             message: "Serial number must not contain multiple unescaped asterisks (stars)".to_string(),
             instance_path: "/product_tree/full_product_names/0/product_identification_helper/serial_numbers/0".to_string(),
+            severity: ValidationSeverity::Error,
         };
 
         run_csaf21_tests(
             "44",
             test_6_1_44_multiple_stars_in_serial_number, &HashMap::from([
-                ("01", &expected_error),
-                ("02", &expected_error),
+                ("01", vec![expected_error.clone()]),
+                ("02", vec![expected_error.clone()]),
             ])
         );
     }
@@ -1,20 +1,14 @@
-use crate::csaf::csaf2_1::schema::{DocumentStatus, LabelOfTlp};
-use crate::csaf::getter_traits::{CsafTrait, DistributionTrait, DocumentTrait, RevisionTrait, TlpTrait, TrackingTrait, VulnerabilityTrait};
-use crate::csaf::validation::ValidationError;
-use chrono::{DateTime, FixedOffset};
+use crate::csaf::csaf2_1::schema::LabelOfTlp;
+use crate::csaf::getter_traits::{CsafTrait, DistributionTrait, DocumentTrait, TlpTrait, VulnerabilityTrait};
+use crate::csaf::helpers::get_newest_revision_date;
+use crate::csaf::timeline::Timeline;
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
 
 pub fn test_6_1_45_inconsistent_disclosure_date(
     doc: &impl CsafTrait,
-) -> Result<(), ValidationError> {
+) -> Result<(), Vec<ValidationError>> {
     // Only check if document is TLP:CLEAR and status is final or interim
-    let document = doc.get_document();
-    let status = document.get_tracking().get_status();
-
-    if status != DocumentStatus::Final && status != DocumentStatus::Interim {
-        return Ok(());
-    }
-
-    let is_tlp_clear = match document.get_distribution_21() {
+    let is_tlp_clear = match doc.get_document().get_distribution_21() {
         Ok(distribution) => match distribution.get_tlp_21() {
             Ok(tlp) => tlp.get_label() == LabelOfTlp::Clear,
             Err(_) => false,
@@ -26,64 +20,48 @@ pub fn test_6_1_45_inconsistent_disclosure_date(
         return Ok(());
     }
 
-    // Get the newest revision history date
-    let mut newest_revision_date: Option<DateTime<FixedOffset>> = None;
-    let revision_history = document.get_tracking().get_revision_history();
-    for (i_rev, rev) in revision_history.iter().enumerate() {
-        chrono::DateTime::parse_from_rfc3339(rev.get_date())
-            .map(|rev_datetime| {
-                println!(
-                    "rev_datetime: {:?}, newest_revision_date: {:?}",
-                    rev_datetime,
-                    newest_revision_date
-                );
-                newest_revision_date = match newest_revision_date {
-                    None => Some(rev_datetime),
-                    Some(prev_max) => Some(prev_max.max(rev_datetime)),
-                }
-            })
-            .map_err(|_| ValidationError {
-                message: format!("Invalid date format in revision history: {}", rev.get_date()),
-                instance_path: format!("/document/tracking/revision_history/{}", i_rev),
-            })?;
-    }
+    let timeline = Timeline::of(doc);
+    let Some(newest_date) = get_newest_revision_date(doc, &timeline)? else {
+        return Ok(());
+    };
 
-    if let Some(newest_date) = newest_revision_date {
-        // Check each vulnerability's disclosure date
-        for (i_v, v) in doc.get_vulnerabilities().iter().enumerate() {
-            if let Some(disclosure_date) = v.get_disclosure_date() {
-                match chrono::DateTime::parse_from_rfc3339(disclosure_date) {
-                    Ok(disclosure_datetime) => {
-                        println!(
-                            "disclosure_datetime: {:?}, newest_date: {:?}",
-                            disclosure_datetime, newest_date
-                        );
-                        if disclosure_datetime > newest_date {
-                            return Err(ValidationError {
-                                message: "Disclosure date must not be later than the newest revision history date for TLP:CLEAR documents with final or interim status".to_string(),
-                                instance_path: format!("/vulnerabilities/{}/discovery_date", i_v),
-                            });
-                        }
-                    },
-                    Err(_) => {
-                        return Err(ValidationError {
-                            message: format!("Invalid disclosure date format: {}", disclosure_date),
+    let mut errors = Vec::<ValidationError>::new();
+
+    // Check each vulnerability's disclosure date
+    for (i_v, v) in doc.get_vulnerabilities().iter().enumerate() {
+        if let Some(disclosure_date) = v.get_disclosure_date() {
+            match timeline.vulnerabilities[i_v].disclosure_date {
+                Some(disclosure_datetime) => {
+                    if disclosure_datetime > newest_date {
+                        errors.push(ValidationError {
+                            message: "Disclosure date must not be later than the newest revision history date for TLP:CLEAR documents with final or interim status".to_string(),
                             instance_path: format!("/vulnerabilities/{}/discovery_date", i_v),
+                            severity: ValidationSeverity::Error,
                         });
                     }
+                },
+                None => {
+                    errors.push(ValidationError {
+                        message: format!("Invalid disclosure date format: {}", disclosure_date),
+                        instance_path: format!("/vulnerabilities/{}/discovery_date", i_v),
+                        severity: ValidationSeverity::Error,
+                    });
                 }
             }
         }
     }
 
-    Ok(())
+    into_result(errors)
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::csaf::test_helper::run_csaf21_tests;
-    use crate::csaf::validation::ValidationError;
+    use crate::csaf::csaf2_1::schema::CommonSecurityAdvisoryFramework as Csaf21;
+    use crate::csaf::document::CsafDocument;
+    use crate::csaf::test_helper::{minimal_document, run_csaf21_tests, set_revision_history};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
     use crate::csaf::validations::test_6_1_45::test_6_1_45_inconsistent_disclosure_date;
+    use serde_json::json;
     use std::collections::HashMap;
 
     #[test]
@@ -91,15 +69,45 @@ mod tests {
         let expected_error = ValidationError {
             message: "Disclosure date must not be later than the newest revision history date for TLP:CLEAR documents with final or interim status".to_string(),
             instance_path: "/vulnerabilities/0/discovery_date".to_string(),
+            severity: ValidationSeverity::Error,
         };
 
         run_csaf21_tests(
             "45",
             test_6_1_45_inconsistent_disclosure_date, &HashMap::from([
-                ("01", &expected_error),
-                ("02", &expected_error),
-                ("03", &expected_error),
+                ("01", vec![expected_error.clone()]),
+                ("02", vec![expected_error.clone()]),
+                ("03", vec![expected_error.clone()]),
             ])
         );
     }
+
+    #[test]
+    fn test_test_6_1_45_invalid_revision_dates() {
+        let run = |revisions: &[(&str, &str)]| {
+            let mut doc = minimal_document("2.1");
+            set_revision_history(&mut doc, "2", revisions);
+            doc["vulnerabilities"] = json!([{ "disclosure_date": "2024-01-25T10:00:00.000Z" }]);
+            test_6_1_45_inconsistent_disclosure_date(&CsafDocument::<Csaf21>::from_json(doc).unwrap())
+        };
+
+        // Invalid revision dates are reported like in the other tests using the newest revision date
+        assert_eq!(
+            Err(vec![ValidationError {
+                message: "Invalid date format in revision history: 2024-01-26".to_string(),
+                instance_path: "/document/tracking/revision_history/1/date".to_string(),
+                severity: ValidationSeverity::Error,
+            }]),
+            run(&[("2024-01-24T10:00:00.000Z", "1"), ("2024-01-26", "2")])
+        );
+        assert_eq!(Ok(()), run(&[("2024-01-24T10:00:00.000Z", "1"), ("2024-01-26T10:00:00.000Z", "2")]));
+        assert_eq!(
+            Err(vec![ValidationError {
+                message: "Revision history must not be empty for status final or interim".to_string(),
+                instance_path: "/document/tracking/revision_history".to_string(),
+                severity: ValidationSeverity::Error,
+            }]),
+            run(&[])
+        );
+    }
 }
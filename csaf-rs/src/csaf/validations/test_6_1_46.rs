@@ -1,30 +1,38 @@
 use crate::csaf::getter_traits::{ContentTrait, CsafTrait, MetricTrait, VulnerabilityTrait};
-use crate::csaf::validation::ValidationError;
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
 
 pub fn test_6_1_46_invalid_ssvc(
     doc: &impl CsafTrait,
-) -> Result<(), ValidationError> {
+) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::<ValidationError>::new();
+
     // /vulnerabilities[]/metrics[]/content/ssvc_v1
     for (i_v, v) in doc.get_vulnerabilities().iter().enumerate() {
         if let Some(metrics) = v.get_metrics() {
             for (i_m, m) in metrics.iter().enumerate() {
-                m.get_content().get_ssvc_v1().map_err(|e| {
-                    ValidationError {
+                if !m.get_content().has_ssvc_v1() {
+                    continue;
+                }
+                if let Err(e) = m.get_content().get_ssvc_v1() {
+                    errors.push(ValidationError {
                         message: format!("Invalid SSVC object: {}", e),
                         instance_path: format!("/vulnerabilities/{}/metrics/{}/content/ssvc_v1", i_v, i_m),
-                    }
-                })?;
+                        severity: ValidationSeverity::Error,
+                    });
+                }
             }
         }
     }
 
-    Ok(())
+    into_result(errors)
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::csaf::test_helper::run_csaf21_tests;
-    use crate::csaf::validation::ValidationError;
+    use crate::csaf::csaf2_1::schema::CommonSecurityAdvisoryFramework as Csaf21;
+    use crate::csaf::document::CsafDocument;
+    use crate::csaf::test_helper::{document_with_cvss_v3_metric, run_csaf21_tests};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
     use crate::csaf::validations::test_6_1_46::test_6_1_46_invalid_ssvc;
     use std::collections::HashMap;
 
@@ -33,15 +41,23 @@ mod tests {
         run_csaf21_tests(
             "46",
             test_6_1_46_invalid_ssvc, &HashMap::from([
-                ("01", &ValidationError {
+                ("01", vec![ValidationError {
                     message: "Invalid SSVC object: missing field `selections`".to_string(),
                     instance_path: "/vulnerabilities/0/metrics/0/content/ssvc_v1".to_string(),
-                }),
-                ("02", &ValidationError {
+                    severity: ValidationSeverity::Error,
+                }]),
+                ("02", vec![ValidationError {
                     message: "Invalid SSVC object: unknown field `value`, expected one of `name`, `namespace`, `values`, `version`".to_string(),
                     instance_path: "/vulnerabilities/0/metrics/0/content/ssvc_v1".to_string(),
-                }),
+                    severity: ValidationSeverity::Error,
+                }]),
             ])
         );
     }
+
+    #[test]
+    fn test_test_6_1_46_without_ssvc() {
+        let doc = CsafDocument::<Csaf21>::from_json(document_with_cvss_v3_metric()).unwrap();
+        assert_eq!(Ok(()), test_6_1_46_invalid_ssvc(&doc));
+    }
 }
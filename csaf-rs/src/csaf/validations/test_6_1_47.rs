@@ -1,16 +1,21 @@
 use std::ops::Deref;
 use crate::csaf::getter_traits::{ContentTrait, CsafTrait, DocumentTrait, MetricTrait, TrackingTrait, VulnerabilityIdTrait, VulnerabilityTrait};
-use crate::csaf::validation::ValidationError;
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
 
 pub fn test_6_1_47_inconsistent_ssvc_id(
     doc: &impl CsafTrait,
-) -> Result<(), ValidationError> {
+) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::<ValidationError>::new();
+
     let vulnerabilities = doc.get_vulnerabilities();
 
     for (i_v, v) in vulnerabilities.iter().enumerate() {
         if let Some(metrics) = v.get_metrics() {
             for (i_m, m) in metrics.iter().enumerate() {
-                return match m.get_content().get_ssvc_v1() {
+                if !m.get_content().has_ssvc_v1() {
+                    continue;
+                }
+                match m.get_content().get_ssvc_v1() {
                     Ok(ssvc) => {
                         // Get the SSVC ID
                         let ssvc_id = ssvc.id.deref();
@@ -20,9 +25,10 @@ pub fn test_6_1_47_inconsistent_ssvc_id(
                         if ssvc_id == document_id {
                             // If there are multiple vulnerabilities, the validation must fail here.
                             if vulnerabilities.len() > 1 {
-                                return Err(ValidationError {
+                                errors.push(ValidationError {
                                     message: format!("The SSVC ID equals the document ID '{}' and the document contains multiple vulnerabilities", document_id),
                                     instance_path: format!("/vulnerabilities/{}/metrics/{}/content/ssvc_v1/id", i_v, i_m),
+                                    severity: ValidationSeverity::Error,
                                 });
                             }
                             // SSVC ID is valid, go to next metrics object
@@ -45,28 +51,33 @@ pub fn test_6_1_47_inconsistent_ssvc_id(
                             }
                         }
 
-                        // Return error if SSVC ID is not valid
-                        Err(ValidationError {
+                        // Add error if SSVC ID is not valid
+                        errors.push(ValidationError {
                             message: format!("The SSVC ID '{}' does not match the document ID, the CVE ID or any ID in the IDs array of the vulnerability", ssvc_id),
                             instance_path: format!("/vulnerabilities/{}/metrics/{}/content/ssvc_v1/id", i_v, i_m),
-                        })
+                            severity: ValidationSeverity::Error,
+                        });
                     },
-                    Err(err) => Err(ValidationError {
+                    Err(err) => errors.push(ValidationError {
                         message: format!("Invalid SSVC object: {}", err),
                         instance_path: format!("/vulnerabilities/{}/metrics/{}/content/ssvc_v1", i_v, i_m),
+                        severity: ValidationSeverity::Error,
                     }),
                 }
             }
         }
     }
 
-    Ok(())
+
+    into_result(errors)
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::csaf::test_helper::run_csaf21_tests;
-    use crate::csaf::validation::ValidationError;
+    use crate::csaf::csaf2_1::schema::CommonSecurityAdvisoryFramework as Csaf21;
+    use crate::csaf::document::CsafDocument;
+    use crate::csaf::test_helper::{document_with_cvss_v3_metric, run_csaf21_tests};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
     use crate::csaf::validations::test_6_1_47::test_6_1_47_inconsistent_ssvc_id;
     use std::collections::HashMap;
 
@@ -78,23 +89,33 @@ mod tests {
             "47",
             test_6_1_47_inconsistent_ssvc_id,
             &HashMap::from([
-                ("01", &ValidationError {
+                ("01", vec![ValidationError {
                     message: "The SSVC ID 'CVE-1900-0002' does not match the document ID, the CVE ID or any ID in the IDs array of the vulnerability".to_string(),
                     instance_path: instance_path.clone(),
-                }),
-                ("02", &ValidationError {
+                    severity: ValidationSeverity::Error,
+                }]),
+                ("02", vec![ValidationError {
                     message: "The SSVC ID 'CVE-1900-0001' does not match the document ID, the CVE ID or any ID in the IDs array of the vulnerability".to_string(),
                     instance_path: instance_path.clone(),
-                }),
-                ("03", &ValidationError {
+                    severity: ValidationSeverity::Error,
+                }]),
+                ("03", vec![ValidationError {
                     message: "The SSVC ID '2723' does not match the document ID, the CVE ID or any ID in the IDs array of the vulnerability".to_string(),
                     instance_path: instance_path.clone(),
-                }),
-                ("04", &ValidationError {
+                    severity: ValidationSeverity::Error,
+                }]),
+                ("04", vec![ValidationError {
                     message: "The SSVC ID 'Bug#2723' does not match the document ID, the CVE ID or any ID in the IDs array of the vulnerability".to_string(),
                     instance_path: instance_path.clone(),
-                }),
+                    severity: ValidationSeverity::Error,
+                }]),
             ])
         );
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_test_6_1_47_without_ssvc() {
+        let doc = CsafDocument::<Csaf21>::from_json(document_with_cvss_v3_metric()).unwrap();
+        assert_eq!(Ok(()), test_6_1_47_inconsistent_ssvc_id(&doc));
+    }
+}
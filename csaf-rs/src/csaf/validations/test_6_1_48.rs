@@ -1,16 +1,21 @@
 use crate::csaf::getter_traits::{ContentTrait, CsafTrait, MetricTrait, VulnerabilityTrait};
-use crate::csaf::validation::ValidationError;
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
 use std::ops::Deref;
 use crate::csaf::helpers::{SSVC_DECISION_POINTS, DP_VAL_LOOKUP, REGISTERED_SSVC_NAMESPACES};
 
 pub fn test_6_1_48_ssvc_decision_points(
     doc: &impl CsafTrait,
-) -> Result<(), ValidationError> {
+) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::<ValidationError>::new();
+
     let vulnerabilities = doc.get_vulnerabilities();
 
     for (i_v, v) in vulnerabilities.iter().enumerate() {
         if let Some(metrics) = v.get_metrics() {
             for (i_m, m) in metrics.iter().enumerate() {
+                if !m.get_content().has_ssvc_v1() {
+                    continue;
+                }
                 match m.get_content().get_ssvc_v1() {
                     Ok(ssvc) => {
                         for (i_s, selection) in ssvc.selections.iter().enumerate() {
@@ -35,7 +40,7 @@ pub fn test_6_1_48_ssvc_decision_points(
                                     // Check if all values exist and are correctly ordered
                                     for (i_val, value) in selection.values.iter().map(|v| v.deref()).enumerate() {
                                         match reference_indices.get(value) {
-                                            None => return Err(ValidationError {
+                                            None => errors.push(ValidationError {
                                                 message: format!(
                                                     "The SSVC decision point '{}::{}' (version {}) doesn't have the value '{}'",
                                                     namespace, name, version, value
@@ -44,10 +49,11 @@ pub fn test_6_1_48_ssvc_decision_points(
                                                     "/vulnerabilities/{}/metrics/{}/content/ssvc_v1/selections/{}/values/{}",
                                                     i_v, i_m, i_s, i_val
                                                 ),
+                                                severity: ValidationSeverity::Error,
                                             }),
                                             Some(i_dp_val) => {
                                                 if last_index > *i_dp_val {
-                                                    return Err(ValidationError {
+                                                    errors.push(ValidationError {
                                                         message: format!(
                                                             "The values for SSVC decision point '{}::{}' (version {}) are not in correct order",
                                                             namespace, name, version
@@ -56,6 +62,7 @@ pub fn test_6_1_48_ssvc_decision_points(
                                                             "/vulnerabilities/{}/metrics/{}/content/ssvc_v1/selections/{}/values/{}",
                                                             i_v, i_m, i_s, i_val
                                                         ),
+                                                        severity: ValidationSeverity::Error,
                                                     });
                                                 } else {
                                                     last_index = *i_dp_val;
@@ -65,7 +72,7 @@ pub fn test_6_1_48_ssvc_decision_points(
                                     }
                                 },
                                 None => {
-                                    return Err(ValidationError {
+                                    errors.push(ValidationError {
                                         message: format!(
                                             "Unknown SSVC decision point '{}::{}' with version '{}'",
                                             namespace, name, version
@@ -74,15 +81,17 @@ pub fn test_6_1_48_ssvc_decision_points(
                                             "/vulnerabilities/{}/metrics/{}/content/ssvc_v1/selections/{}",
                                             i_v, i_m, i_s
                                         ),
+                                        severity: ValidationSeverity::Error,
                                     });
                                 }
                             }
                         }
                     },
                     Err(err) => {
-                        return Err(ValidationError {
+                        errors.push(ValidationError {
                             message: format!("Invalid SSVC object: {}", err),
                             instance_path: format!("/vulnerabilities/{}/metrics/{}/content/ssvc_v1", i_v, i_m),
+                            severity: ValidationSeverity::Error,
                         });
                     },
                 }
@@ -90,13 +99,16 @@ pub fn test_6_1_48_ssvc_decision_points(
         }
     }
 
-    Ok(())
+
+    into_result(errors)
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::csaf::test_helper::run_csaf21_tests;
-    use crate::csaf::validation::ValidationError;
+    use crate::csaf::csaf2_1::schema::CommonSecurityAdvisoryFramework as Csaf21;
+    use crate::csaf::document::CsafDocument;
+    use crate::csaf::test_helper::{document_with_cvss_v3_metric, run_csaf21_tests};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
     use crate::csaf::validations::test_6_1_48::test_6_1_48_ssvc_decision_points;
     use std::collections::HashMap;
 
@@ -108,31 +120,43 @@ mod tests {
             "48",
             test_6_1_48_ssvc_decision_points,
             &HashMap::from([
-                ("01", &ValidationError {
+                ("01", vec![ValidationError {
                     message: "The SSVC decision point 'ssvc::Mission Impact' (version 1.0.0) doesn't have the value 'Degraded'".to_string(),
                     instance_path: "/vulnerabilities/0/metrics/0/content/ssvc_v1/selections/0/values/1".to_string(),
-                }),
-                ("02", &ValidationError {
+                    severity: ValidationSeverity::Error,
+                }]),
+                ("02", vec![ValidationError {
                     message: "Unknown SSVC decision point 'ssvc::Safety Impacts' with version '2.0.0'".to_string(),
                     instance_path: instance_path.clone(),
-                }),
-                ("03", &ValidationError {
+                    severity: ValidationSeverity::Error,
+                }]),
+                ("03", vec![ValidationError {
                     message: "The values for SSVC decision point 'ssvc::Safety Impact' (version 2.0.0) are not in correct order".to_string(),
                     instance_path: "/vulnerabilities/0/metrics/0/content/ssvc_v1/selections/0/values/1".to_string(),
-                }),
-                ("04", &ValidationError {
+                    severity: ValidationSeverity::Error,
+                }]),
+                ("04", vec![ValidationError {
                     message: "Unknown SSVC decision point 'ssvc::Safety Impact' with version '1.9.7'".to_string(),
                     instance_path: instance_path.clone(),
-                }),
-                ("05", &ValidationError {
+                    severity: ValidationSeverity::Error,
+                }]),
+                ("05", vec![ValidationError {
                     message: "The SSVC decision point 'cvss::Attack Complexity' (version 3.0.1) doesn't have the value 'Easy'".to_string(),
                     instance_path: "/vulnerabilities/0/metrics/0/content/ssvc_v1/selections/0/values/0".to_string(),
-                }),
-                ("06", &ValidationError {
+                    severity: ValidationSeverity::Error,
+                }]),
+                ("06", vec![ValidationError {
                     message: "The values for SSVC decision point 'cvss::Exploit Maturity' (version 2.0.0) are not in correct order".to_string(),
                     instance_path: "/vulnerabilities/0/metrics/0/content/ssvc_v1/selections/0/values/1".to_string(),
-                }),
+                    severity: ValidationSeverity::Error,
+                }]),
             ])
         );
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_test_6_1_48_without_ssvc() {
+        let doc = CsafDocument::<Csaf21>::from_json(document_with_cvss_v3_metric()).unwrap();
+        assert_eq!(Ok(()), test_6_1_48_ssvc_decision_points(&doc));
+    }
+}
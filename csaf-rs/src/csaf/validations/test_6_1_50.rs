@@ -0,0 +1,99 @@
+use crate::csaf::csaf2_1::schema::CategoryOfTheBranch;
+use crate::csaf::getter_traits::{BranchTrait, CsafTrait, ProductTreeTrait};
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
+use crate::csaf::vers::{Vers, VersError, VERS_PREFIX};
+
+pub fn test_6_1_50_product_version_range_rules(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::<ValidationError>::new();
+
+    if let Some(product_tree) = doc.get_product_tree() {
+        product_tree.visit_all_branches(&mut |branch, path| {
+            // Ranges not using the vers syntax are reported by test 6.2.18
+            if branch.get_category() != CategoryOfTheBranch::ProductVersionRange
+                || !branch.get_name().starts_with(VERS_PREFIX)
+            {
+                return Ok(());
+            }
+            // Versions of unsupported schemes cannot be compared, so only the syntax is checked
+            let result = branch.get_name().parse::<Vers>().and_then(|vers| match vers.validate() {
+                Err(VersError::UnsupportedScheme(_)) => Ok(()),
+                result => result,
+            });
+            if let Err(err) = result {
+                errors.push(ValidationError {
+                    message: format!(
+                        "Product version range '{}' does not conform to the vers specification: {}",
+                        branch.get_name(),
+                        err
+                    ),
+                    instance_path: format!("{}/name", path),
+                    severity: ValidationSeverity::Error,
+                });
+            }
+            Ok(())
+        })?;
+    }
+
+    into_result(errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::csaf2_1::schema::CommonSecurityAdvisoryFramework as Csaf21;
+    use crate::csaf::document::CsafDocument;
+    use crate::csaf::test_helper::{minimal_document, run_csaf21_tests};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_1_50::test_6_1_50_product_version_range_rules;
+    use serde_json::{json, Value};
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_1_50() {
+        run_csaf21_tests(
+            "50",
+            test_6_1_50_product_version_range_rules,
+            &HashMap::from([
+                ("01", vec![ValidationError {
+                    message: "Product version range 'vers:semver/<2.0.0|>=1.0.0' does not conform to the vers specification: the constraints are not sorted by version at '1.0.0'".to_string(),
+                    instance_path: "/product_tree/branches/0/branches/0/branches/0/name".to_string(),
+                    severity: ValidationSeverity::Error,
+                }]),
+            ]),
+        );
+    }
+
+    fn range_branch(name: &str, product_id: &str) -> Value {
+        json!({
+            "category": "product_version_range",
+            "name": name,
+            "product": { "name": format!("Product A {}", name), "product_id": product_id }
+        })
+    }
+
+    #[test]
+    fn test_test_6_1_50_multiple_ranges() {
+        let mut doc = minimal_document("2.1");
+        doc["product_tree"] = json!({
+            "branches": [{
+                "category": "product_name",
+                "name": "Product A",
+                "branches": [
+                    range_branch("vers:semver/>=1.0.0|<2.0.0", "CSAFPID-9080700"),
+                    range_branch("vers:gem/>=3.0", "CSAFPID-9080701"),
+                    range_branch("<4.2", "CSAFPID-9080702"),
+                    range_branch("vers:semver/<2.0.0|>=1.0.0", "CSAFPID-9080703")
+                ]
+            }]
+        });
+        assert_eq!(
+            Err(vec![ValidationError {
+                message: "Product version range 'vers:semver/<2.0.0|>=1.0.0' does not conform to the vers \
+                    specification: the constraints are not sorted by version at '1.0.0'"
+                    .to_string(),
+                instance_path: "/product_tree/branches/0/branches/3/name".to_string(),
+                severity: ValidationSeverity::Error,
+            }]),
+            test_6_1_50_product_version_range_rules(&CsafDocument::<Csaf21>::from_json(doc).unwrap())
+        );
+    }
+}
@@ -0,0 +1,98 @@
+use crate::csaf::getter_traits::{ContentTrait, CsafTrait, MetricTrait, VulnerabilityTrait};
+use crate::csaf::helpers::get_newest_revision_date;
+use crate::csaf::timeline::Timeline;
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
+
+pub fn test_6_1_51_inconsistent_epss_timestamp(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let timeline = Timeline::of(doc);
+    let Some(newest_revision_date) = get_newest_revision_date(doc, &timeline)? else {
+        return Ok(());
+    };
+
+    let mut errors = Vec::<ValidationError>::new();
+
+    for (i_v, vulnerability) in doc.get_vulnerabilities().iter().enumerate() {
+        let dates = &timeline.vulnerabilities[i_v];
+        for (i_m, metric) in vulnerability.get_metrics().into_iter().flatten().enumerate() {
+            let content = metric.get_content();
+            // Metrics without EPSS have no timestamp, invalid timestamps are reported by test 6.1.37
+            if let Some(timestamp) = dates.epss_timestamps[i_m] {
+                if timestamp > newest_revision_date {
+                    errors.push(ValidationError {
+                        message: format!(
+                            "EPSS timestamp ({}) for vulnerability at index {} is later than the newest revision date ({})",
+                            timestamp.to_rfc3339(), i_v, newest_revision_date.to_rfc3339()
+                        ),
+                        instance_path: format!("{}/epss/timestamp", content.get_content_json_path(i_v, i_m)),
+                        severity: ValidationSeverity::Error,
+                    });
+                }
+            }
+        }
+    }
+
+    into_result(errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::csaf2_1::schema::CommonSecurityAdvisoryFramework as Csaf21;
+    use crate::csaf::document::CsafDocument;
+    use crate::csaf::test_helper::{minimal_document, run_csaf21_tests, set_revision_history};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_1_51::test_6_1_51_inconsistent_epss_timestamp;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_1_51() {
+        run_csaf21_tests(
+            "51",
+            test_6_1_51_inconsistent_epss_timestamp,
+            &HashMap::from([
+                ("01", vec![ValidationError {
+                    message: "EPSS timestamp (2024-01-24T10:00:00.001+00:00) for vulnerability at index 0 is later than the newest revision date (2024-01-24T10:00:00+00:00)".to_string(),
+                    instance_path: "/vulnerabilities/0/metrics/0/content/epss/timestamp".to_string(),
+                    severity: ValidationSeverity::Error,
+                }]),
+            ]),
+        );
+    }
+
+    #[test]
+    fn test_test_6_1_51_drafts() {
+        let mut doc = minimal_document("2.1");
+        set_revision_history(
+            &mut doc,
+            "2",
+            &[("2024-01-24T10:00:00.000Z", "1"), ("2024-02-29T10:00:00.000Z", "2")],
+        );
+        doc["product_tree"] = json!({
+            "full_product_names": [{ "name": "Product A", "product_id": "CSAFPID-9080700" }]
+        });
+        let epss = |timestamp: &str| {
+            json!({
+                "content": { "epss": { "percentile": "0.9", "probability": "0.5", "timestamp": timestamp } },
+                "products": ["CSAFPID-9080700"]
+            })
+        };
+        doc["vulnerabilities"] = json!([{
+            "metrics": [epss("2024-02-29T10:00:00.000Z"), epss("2024-02-29T10:30:00.000Z")]
+        }]);
+        assert_eq!(
+            Err(vec![ValidationError {
+                message: "EPSS timestamp (2024-02-29T10:30:00+00:00) for vulnerability at index 0 is later than the newest revision date (2024-02-29T10:00:00+00:00)".to_string(),
+                instance_path: "/vulnerabilities/0/metrics/1/content/epss/timestamp".to_string(),
+                severity: ValidationSeverity::Error,
+            }]),
+            test_6_1_51_inconsistent_epss_timestamp(&CsafDocument::<Csaf21>::from_json(doc.clone()).unwrap())
+        );
+
+        // Drafts are not checked
+        doc["document"]["tracking"]["status"] = json!("draft");
+        assert_eq!(
+            Ok(()),
+            test_6_1_51_inconsistent_epss_timestamp(&CsafDocument::<Csaf21>::from_json(doc).unwrap())
+        );
+    }
+}
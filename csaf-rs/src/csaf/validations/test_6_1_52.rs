@@ -0,0 +1,122 @@
+use crate::csaf::getter_traits::CsafTrait;
+use crate::csaf::helpers::get_newest_revision_date;
+use crate::csaf::timeline::Timeline;
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
+
+pub fn test_6_1_52_inconsistent_exploitation_date(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let timeline = Timeline::of(doc);
+    let newest_revision_date = get_newest_revision_date(doc, &timeline)?;
+
+    let mut errors = Vec::<ValidationError>::new();
+
+    for (i_v, dates) in timeline.vulnerabilities.iter().enumerate() {
+        for (i_e, exploitation) in dates.first_known_exploitation_dates.iter().enumerate() {
+            let path = format!("/vulnerabilities/{}/first_known_exploitation_dates/{}", i_v, i_e);
+            // Invalid dates are reported by test 6.1.37
+            let (Some(date), Some(exploitation_date)) = *exploitation else {
+                continue;
+            };
+            if exploitation_date > date {
+                errors.push(ValidationError {
+                    message: format!(
+                        "Exploitation date ({}) is later than the date of the information ({})",
+                        exploitation_date.to_rfc3339(), date.to_rfc3339()
+                    ),
+                    instance_path: format!("{}/exploitation_date", path),
+                    severity: ValidationSeverity::Error,
+                });
+            }
+            if let Some(newest_revision_date) = newest_revision_date {
+                if date > newest_revision_date {
+                    errors.push(ValidationError {
+                        message: format!(
+                            "Date of the first known exploitation ({}) is later than the newest revision date ({})",
+                            date.to_rfc3339(), newest_revision_date.to_rfc3339()
+                        ),
+                        instance_path: format!("{}/date", path),
+                        severity: ValidationSeverity::Error,
+                    });
+                }
+            }
+        }
+    }
+
+    into_result(errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::csaf2_1::schema::CommonSecurityAdvisoryFramework as Csaf21;
+    use crate::csaf::document::CsafDocument;
+    use crate::csaf::test_helper::{minimal_document, run_csaf21_tests};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_1_52::test_6_1_52_inconsistent_exploitation_date;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_1_52() {
+        run_csaf21_tests(
+            "52",
+            test_6_1_52_inconsistent_exploitation_date,
+            &HashMap::from([
+                ("01", vec![ValidationError {
+                    message: "Exploitation date (2024-01-24T13:00:00+00:00) is later than the date of the information (2024-01-24T10:00:00+00:00)".to_string(),
+                    instance_path: "/vulnerabilities/0/first_known_exploitation_dates/0/exploitation_date".to_string(),
+                    severity: ValidationSeverity::Error,
+                }]),
+            ]),
+        );
+    }
+
+    #[test]
+    fn test_test_6_1_52_drafts() {
+        let mut doc = minimal_document("2.1");
+        doc["product_tree"] = json!({
+            "full_product_names": [{ "name": "Product A", "product_id": "CSAFPID-9080700" }]
+        });
+        doc["vulnerabilities"] = json!([{
+            "first_known_exploitation_dates": [
+                {
+                    "date": "2024-01-24T10:00:00.000Z",
+                    "exploitation_date": "2024-01-20T10:00:00.000Z",
+                    "product_ids": ["CSAFPID-9080700"]
+                },
+                {
+                    "date": "2024-01-23T10:00:00.000Z",
+                    "exploitation_date": "2024-01-23T12:00:00.000+01:00",
+                    "product_ids": ["CSAFPID-9080700"]
+                },
+                {
+                    "date": "2024-01-25T10:00:00.000Z",
+                    "exploitation_date": "2024-01-20T10:00:00.000Z",
+                    "product_ids": ["CSAFPID-9080700"]
+                }
+            ]
+        }]);
+        assert_eq!(
+            Err(vec![
+                ValidationError {
+                    message: "Exploitation date (2024-01-23T12:00:00+01:00) is later than the date of the information (2024-01-23T10:00:00+00:00)".to_string(),
+                    instance_path: "/vulnerabilities/0/first_known_exploitation_dates/1/exploitation_date".to_string(),
+                    severity: ValidationSeverity::Error,
+                },
+                ValidationError {
+                    message: "Date of the first known exploitation (2024-01-25T10:00:00+00:00) is later than the newest revision date (2024-01-24T10:00:00+00:00)".to_string(),
+                    instance_path: "/vulnerabilities/0/first_known_exploitation_dates/2/date".to_string(),
+                    severity: ValidationSeverity::Error,
+                },
+            ]),
+            test_6_1_52_inconsistent_exploitation_date(&CsafDocument::<Csaf21>::from_json(doc.clone()).unwrap())
+        );
+
+        // Drafts are only checked for the consistency of both dates
+        doc["document"]["tracking"]["status"] = json!("draft");
+        assert_eq!(
+            1,
+            test_6_1_52_inconsistent_exploitation_date(&CsafDocument::<Csaf21>::from_json(doc).unwrap())
+                .unwrap_err()
+                .len()
+        );
+    }
+}
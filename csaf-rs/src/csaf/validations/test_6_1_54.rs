@@ -0,0 +1,97 @@
+use crate::csaf::getter_traits::{CsafTrait, DocumentTrait};
+use crate::csaf::license::{LicenseList, SpdxExpression};
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
+
+pub fn test_6_1_54_license_expression(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::<ValidationError>::new();
+
+    if let Some(license_expression) = doc.get_document().get_license_expression() {
+        let mut error = |message: String| {
+            errors.push(ValidationError {
+                message,
+                instance_path: "/document/license_expression".to_string(),
+                severity: ValidationSeverity::Error,
+            })
+        };
+        match license_expression.parse::<SpdxExpression>() {
+            Err(e) => error(format!("Invalid license expression '{}': {}", license_expression, e)),
+            // Identifiers can only be checked if the SPDX license list was vendored
+            Ok(expression) => {
+                if let Some(list) = LicenseList::embedded() {
+                    for id in expression.unknown_ids(list) {
+                        error(format!(
+                            "The license expression '{}' uses '{}', which is not listed in the SPDX license list {}",
+                            license_expression, id, list.version
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    into_result(errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::csaf2_1::schema::CommonSecurityAdvisoryFramework as Csaf21;
+    use crate::csaf::document::CsafDocument;
+    use crate::csaf::license::LicenseList;
+    use crate::csaf::test_helper::{minimal_document, run_csaf21_tests};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_1_54::test_6_1_54_license_expression;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_1_54() {
+        run_csaf21_tests(
+            "54",
+            test_6_1_54_license_expression,
+            &HashMap::from([
+                ("01", vec![ValidationError {
+                    message: "Invalid license expression 'MIT OR': the license expression ends unexpectedly".to_string(),
+                    instance_path: "/document/license_expression".to_string(),
+                    severity: ValidationSeverity::Error,
+                }]),
+            ]),
+        );
+    }
+
+    #[test]
+    fn test_test_6_1_54_spdx_license_list() {
+        let run = |license_expression: &str| {
+            let mut doc = minimal_document("2.1");
+            doc["document"]["license_expression"] = json!(license_expression);
+            test_6_1_54_license_expression(&CsafDocument::<Csaf21>::from_json(doc).unwrap())
+        };
+        let version = LicenseList::embedded().unwrap().version;
+
+        assert_eq!(Ok(()), run("CC-BY-4.0"));
+        assert_eq!(Ok(()), run("MIT OR (Apache-2.0 AND LicenseRef-www.example.org-Example-CSAF-License-3.0+)"));
+        assert_eq!(Ok(()), run("GPL-2.0-or-later WITH Classpath-exception-2.0"));
+        assert_eq!(
+            Err(vec![ValidationError {
+                message: "Invalid license expression 'MIT OR': the license expression ends unexpectedly".to_string(),
+                instance_path: "/document/license_expression".to_string(),
+                severity: ValidationSeverity::Error,
+            }]),
+            run("MIT OR")
+        );
+        assert_eq!(
+            Err(vec![
+                ValidationError {
+                    message: format!("The license expression 'Example-1.0 WITH Example-exception' uses 'Example-1.0', which is not listed in the SPDX license list {}", version),
+                    instance_path: "/document/license_expression".to_string(),
+                    severity: ValidationSeverity::Error,
+                },
+                ValidationError {
+                    message: format!("The license expression 'Example-1.0 WITH Example-exception' uses 'Example-exception', which is not listed in the SPDX license list {}", version),
+                    instance_path: "/document/license_expression".to_string(),
+                    severity: ValidationSeverity::Error,
+                },
+            ]),
+            run("Example-1.0 WITH Example-exception")
+        );
+    }
+}
@@ -0,0 +1,98 @@
+use crate::csaf::getter_traits::{CsafTrait, ProductTrait, ProductTreeTrait};
+use crate::csaf::product_helpers::gather_product_references;
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
+use std::collections::HashSet;
+
+pub fn test_6_2_01_unused_definition_of_product_id(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::<ValidationError>::new();
+
+    // References in product groups and relationships count as well
+    let references: HashSet<String> = gather_product_references(doc).into_iter().map(|(id, _)| id).collect();
+
+    if let Some(tree) = doc.get_product_tree().as_ref() {
+        tree.visit_all_products(&mut |fpn, path| {
+            if !references.contains(fpn.get_product_id()) {
+                errors.push(ValidationError {
+                    message: format!("Product ID {} is defined, but never used", fpn.get_product_id()),
+                    instance_path: format!("{}/product_id", path),
+                    severity: ValidationSeverity::Warning,
+                });
+            }
+            Ok(())
+        })?;
+    }
+
+    into_result(errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::csaf2_0::schema::CommonSecurityAdvisoryFramework as Csaf20;
+    use crate::csaf::document::CsafDocument;
+    use crate::csaf::test_helper::{minimal_document, run_csaf20_optional_tests, run_csaf21_optional_tests};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_2_01::test_6_2_01_unused_definition_of_product_id;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_2_01() {
+        let errors = HashMap::from([
+            ("01", vec![ValidationError {
+                message: "Product ID CSAFPID-9080700 is defined, but never used".to_string(),
+                instance_path: "/product_tree/full_product_names/0/product_id".to_string(),
+                severity: ValidationSeverity::Warning,
+            }]),
+        ]);
+        run_csaf20_optional_tests("01", test_6_2_01_unused_definition_of_product_id, &errors);
+        run_csaf21_optional_tests("01", test_6_2_01_unused_definition_of_product_id, &errors);
+    }
+
+    #[test]
+    fn test_test_6_2_01_branches_groups_and_relationships() {
+        let mut doc = minimal_document("2.0");
+        doc["product_tree"] = json!({
+            "branches": [{
+                "category": "vendor",
+                "name": "Example Company",
+                "branches": [
+                    {
+                        "category": "product_name",
+                        "name": "Product A",
+                        "product": { "name": "Example Company Product A", "product_id": "CSAFPID-9080700" }
+                    },
+                    {
+                        "category": "product_name",
+                        "name": "Product B",
+                        "product": { "name": "Example Company Product B", "product_id": "CSAFPID-9080701" }
+                    }
+                ]
+            }],
+            "full_product_names": [
+                { "name": "Product C", "product_id": "CSAFPID-9080702" },
+                { "name": "Product D", "product_id": "CSAFPID-9080703" },
+                { "name": "Product E", "product_id": "CSAFPID-9080704" }
+            ],
+            "product_groups": [
+                { "group_id": "CSAFGID-1020300", "product_ids": ["CSAFPID-9080702", "CSAFPID-9080705"] }
+            ],
+            "relationships": [{
+                "category": "installed_on",
+                "full_product_name": { "name": "Product A on Product D", "product_id": "CSAFPID-9080705" },
+                "product_reference": "CSAFPID-9080700",
+                "relates_to_product_reference": "CSAFPID-9080703"
+            }]
+        });
+        doc["vulnerabilities"] = json!([{
+            "flags": [{ "label": "component_not_present", "product_ids": ["CSAFPID-9080704"] }]
+        }]);
+        assert_eq!(
+            Err(vec![ValidationError {
+                message: "Product ID CSAFPID-9080701 is defined, but never used".to_string(),
+                instance_path: "/product_tree/branches/0/branches/1/product/product_id".to_string(),
+                severity: ValidationSeverity::Warning,
+            }]),
+            test_6_2_01_unused_definition_of_product_id(&CsafDocument::<Csaf20>::from_json(doc).unwrap())
+        );
+    }
+}
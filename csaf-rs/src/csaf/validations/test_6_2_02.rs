@@ -0,0 +1,98 @@
+use crate::csaf::getter_traits::{CsafTrait, RemediationTrait, VulnerabilityTrait};
+use crate::csaf::product_helpers::gather_affected_products;
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
+use std::collections::BTreeSet;
+
+pub fn test_6_2_02_missing_remediation(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::<ValidationError>::new();
+
+    for (v_i, v) in doc.get_vulnerabilities().iter().enumerate() {
+        let Some(product_status) = v.get_product_status() else {
+            continue;
+        };
+
+        // Products with a remediation, either directly or via a product group
+        let remediated: BTreeSet<String> = v
+            .get_remediations()
+            .iter()
+            .filter_map(|r| r.get_all_product_ids(doc))
+            .flatten()
+            .collect();
+
+        for (product_id, path) in gather_affected_products(product_status, v_i) {
+            if !remediated.contains(&product_id) {
+                errors.push(ValidationError {
+                    message: format!("Product {} is listed as affected, but has no remediation", product_id),
+                    instance_path: path,
+                    severity: ValidationSeverity::Warning,
+                });
+            }
+        }
+    }
+
+    into_result(errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::csaf2_0::schema::CommonSecurityAdvisoryFramework as Csaf20;
+    use crate::csaf::document::CsafDocument;
+    use crate::csaf::test_helper::{minimal_document, run_csaf20_optional_tests, run_csaf21_optional_tests};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_2_02::test_6_2_02_missing_remediation;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_2_02() {
+        let errors = HashMap::from([
+            ("01", vec![ValidationError {
+                message: "Product CSAFPID-9080700 is listed as affected, but has no remediation".to_string(),
+                instance_path: "/vulnerabilities/0/product_status/known_affected/0".to_string(),
+                severity: ValidationSeverity::Warning,
+            }]),
+        ]);
+        run_csaf20_optional_tests("02", test_6_2_02_missing_remediation, &errors);
+        run_csaf21_optional_tests("02", test_6_2_02_missing_remediation, &errors);
+    }
+
+    #[test]
+    fn test_test_6_2_02_product_groups_and_relationships() {
+        let mut doc = minimal_document("2.0");
+        doc["product_tree"] = json!({
+            "full_product_names": [
+                { "name": "Product A", "product_id": "CSAFPID-9080700" },
+                { "name": "Product B", "product_id": "CSAFPID-9080701" },
+                { "name": "Product C", "product_id": "CSAFPID-9080702" }
+            ],
+            "product_groups": [
+                { "group_id": "CSAFGID-1020300", "product_ids": ["CSAFPID-9080700", "CSAFPID-9080701"] }
+            ],
+            "relationships": [{
+                "category": "installed_on",
+                "full_product_name": { "name": "Product A on Product C", "product_id": "CSAFPID-9080703" },
+                "product_reference": "CSAFPID-9080700",
+                "relates_to_product_reference": "CSAFPID-9080702"
+            }]
+        });
+        doc["vulnerabilities"] = json!([{
+            "product_status": {
+                "known_affected": ["CSAFPID-9080700", "CSAFPID-9080703"],
+                "last_affected": ["CSAFPID-9080701"],
+                "known_not_affected": ["CSAFPID-9080702"]
+            },
+            "remediations": [
+                { "category": "vendor_fix", "details": "Update to version 2.0.", "group_ids": ["CSAFGID-1020300"] }
+            ]
+        }]);
+        // A remediation of Product A does not apply to Product A installed on Product C
+        assert_eq!(
+            Err(vec![ValidationError {
+                message: "Product CSAFPID-9080703 is listed as affected, but has no remediation".to_string(),
+                instance_path: "/vulnerabilities/0/product_status/known_affected/1".to_string(),
+                severity: ValidationSeverity::Warning,
+            }]),
+            test_6_2_02_missing_remediation(&CsafDocument::<Csaf20>::from_json(doc).unwrap())
+        );
+    }
+}
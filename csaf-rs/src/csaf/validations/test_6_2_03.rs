@@ -0,0 +1,93 @@
+use crate::csaf::getter_traits::{CsafTrait, MetricTrait, VulnerabilityTrait};
+use crate::csaf::product_helpers::gather_affected_products;
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
+use std::collections::BTreeSet;
+
+pub fn test_6_2_03_missing_metric(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::<ValidationError>::new();
+
+    for (v_i, v) in doc.get_vulnerabilities().iter().enumerate() {
+        let Some(product_status) = v.get_product_status() else {
+            continue;
+        };
+
+        // Metrics (scores in CSAF 2.0) reference products directly only
+        let scored: BTreeSet<&String> = v.get_metrics().into_iter().flatten().flat_map(|m| m.get_products()).collect();
+
+        for (product_id, path) in gather_affected_products(product_status, v_i) {
+            if !scored.contains(&product_id) {
+                errors.push(ValidationError {
+                    message: format!("Product {} is listed as affected, but has no metric", product_id),
+                    instance_path: path,
+                    severity: ValidationSeverity::Warning,
+                });
+            }
+        }
+    }
+
+    into_result(errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::csaf2_0::schema::CommonSecurityAdvisoryFramework as Csaf20;
+    use crate::csaf::csaf2_1::schema::CommonSecurityAdvisoryFramework as Csaf21;
+    use crate::csaf::document::CsafDocument;
+    use crate::csaf::test_helper::{minimal_document, run_csaf20_optional_tests, run_csaf21_optional_tests};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_2_03::test_6_2_03_missing_metric;
+    use serde_json::{json, Value};
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_2_03() {
+        let errors = HashMap::from([
+            ("01", vec![ValidationError {
+                message: "Product CSAFPID-9080700 is listed as affected, but has no metric".to_string(),
+                instance_path: "/vulnerabilities/0/product_status/known_affected/0".to_string(),
+                severity: ValidationSeverity::Warning,
+            }]),
+        ]);
+        run_csaf20_optional_tests("03", test_6_2_03_missing_metric, &errors);
+        run_csaf21_optional_tests("03", test_6_2_03_missing_metric, &errors);
+    }
+
+    fn document(csaf_version: &str, metrics: (&str, Value)) -> Value {
+        let mut doc = minimal_document(csaf_version);
+        doc["product_tree"] = json!({
+            "full_product_names": [
+                { "name": "Product A", "product_id": "CSAFPID-9080700" },
+                { "name": "Product B", "product_id": "CSAFPID-9080701" }
+            ]
+        });
+        doc["vulnerabilities"] = json!([{
+            "product_status": { "first_affected": ["CSAFPID-9080700", "CSAFPID-9080701"] }
+        }]);
+        doc["vulnerabilities"][0][metrics.0] = metrics.1;
+        doc
+    }
+
+    #[test]
+    fn test_test_6_2_03_scores_and_metrics() {
+        let cvss_v3 = json!({
+            "version": "3.1",
+            "vectorString": "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+            "baseScore": 9.8,
+            "baseSeverity": "CRITICAL"
+        });
+        let expected = Err(vec![ValidationError {
+            message: "Product CSAFPID-9080701 is listed as affected, but has no metric".to_string(),
+            instance_path: "/vulnerabilities/0/product_status/first_affected/1".to_string(),
+            severity: ValidationSeverity::Warning,
+        }]);
+
+        let doc = document("2.0", ("scores", json!([{ "products": ["CSAFPID-9080700"], "cvss_v3": cvss_v3 }])));
+        assert_eq!(expected, test_6_2_03_missing_metric(&CsafDocument::<Csaf20>::from_json(doc).unwrap()));
+
+        let doc = document(
+            "2.1",
+            ("metrics", json!([{ "products": ["CSAFPID-9080700"], "content": { "cvss_v3": cvss_v3 } }])),
+        );
+        assert_eq!(expected, test_6_2_03_missing_metric(&CsafDocument::<Csaf21>::from_json(doc).unwrap()));
+    }
+}
@@ -0,0 +1,41 @@
+use crate::csaf::document_version::{DocumentVersion, RevisionHistory};
+use crate::csaf::getter_traits::{CsafTrait, DocumentTrait};
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
+
+pub fn test_6_2_04_build_metadata_in_revision_history(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let revision_history = RevisionHistory::from_tracking(doc.get_document().get_tracking());
+
+    let errors: Vec<ValidationError> = revision_history
+        .items
+        .iter()
+        .filter(|item| matches!(&item.version, Some(DocumentVersion::Semantic(version)) if !version.build.is_empty()))
+        .map(|item| ValidationError {
+            message: format!("Revision history item number {} contains build metadata", item.number),
+            instance_path: item.number_json_path(),
+            severity: ValidationSeverity::Warning,
+        })
+        .collect();
+
+    into_result(errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::test_helper::{run_csaf20_optional_tests, run_csaf21_optional_tests};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_2_04::test_6_2_04_build_metadata_in_revision_history;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_2_04() {
+        let errors = HashMap::from([
+            ("01", vec![ValidationError {
+                message: "Revision history item number 1.0.0+exp.sha.ac00785 contains build metadata".to_string(),
+                instance_path: "/document/tracking/revision_history/0/number".to_string(),
+                severity: ValidationSeverity::Warning,
+            }]),
+        ]);
+        run_csaf20_optional_tests("04", test_6_2_04_build_metadata_in_revision_history, &errors);
+        run_csaf21_optional_tests("04", test_6_2_04_build_metadata_in_revision_history, &errors);
+    }
+}
@@ -0,0 +1,91 @@
+use crate::csaf::getter_traits::CsafTrait;
+use crate::csaf::timeline::{Timeline, INITIAL_RELEASE_DATE_JSON_PATH};
+use crate::csaf::validation::{ValidationError, ValidationSeverity};
+
+pub fn test_6_2_05_older_initial_release_date_than_revision_history(
+    doc: &impl CsafTrait,
+) -> Result<(), Vec<ValidationError>> {
+    let timeline = Timeline::of(doc);
+
+    // Invalid dates are reported by test 6.1.37
+    let (Some(initial_release_date), Some(oldest_revision_date)) =
+        (timeline.initial_release_date, timeline.oldest_revision_date())
+    else {
+        return Ok(());
+    };
+
+    if initial_release_date < oldest_revision_date {
+        Err(vec![ValidationError {
+            message: format!(
+                "Initial release date {} is older than the oldest revision history date {}",
+                initial_release_date.to_rfc3339(),
+                oldest_revision_date.to_rfc3339()
+            ),
+            instance_path: INITIAL_RELEASE_DATE_JSON_PATH.to_string(),
+            severity: ValidationSeverity::Warning,
+        }])
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::csaf2_0::schema::CommonSecurityAdvisoryFramework as Csaf20;
+    use crate::csaf::document::CsafDocument;
+    use crate::csaf::test_helper::{minimal_document, run_csaf20_optional_tests, run_csaf21_optional_tests, set_revision_history};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_2_05::test_6_2_05_older_initial_release_date_than_revision_history;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_2_05() {
+        run_csaf20_optional_tests(
+            "05",
+            test_6_2_05_older_initial_release_date_than_revision_history,
+            &HashMap::from([
+                ("01", vec![ValidationError {
+                    message: "Initial release date 2021-04-22T10:00:00+00:00 is older than the oldest revision history date 2021-07-21T10:00:00+00:00".to_string(),
+                    instance_path: "/document/tracking/initial_release_date".to_string(),
+                    severity: ValidationSeverity::Warning,
+                }]),
+            ]),
+        );
+        run_csaf21_optional_tests(
+            "05",
+            test_6_2_05_older_initial_release_date_than_revision_history,
+            &HashMap::from([
+                ("01", vec![ValidationError {
+                    message: "Initial release date 2023-12-22T10:00:00+00:00 is older than the oldest revision history date 2024-01-24T10:00:00+00:00".to_string(),
+                    instance_path: "/document/tracking/initial_release_date".to_string(),
+                    severity: ValidationSeverity::Warning,
+                }]),
+            ]),
+        );
+    }
+
+    #[test]
+    fn test_test_6_2_05_offsets() {
+        let mut doc = minimal_document("2.0");
+        set_revision_history(&mut doc, "2", &[("2024-01-24T12:00:00.000+02:00", "1"), ("2024-02-01T10:00:00.000Z", "2")]);
+        // 10:00 UTC in both dates, the offsets are taken into account
+        assert_eq!(
+            Ok(()),
+            test_6_2_05_older_initial_release_date_than_revision_history(
+                &CsafDocument::<Csaf20>::from_json(doc.clone()).unwrap()
+            )
+        );
+
+        doc["document"]["tracking"]["initial_release_date"] = "2024-01-23T10:00:00.000Z".into();
+        assert_eq!(
+            Err(vec![ValidationError {
+                message: "Initial release date 2024-01-23T10:00:00+00:00 is older than the oldest revision history date 2024-01-24T12:00:00+02:00".to_string(),
+                instance_path: "/document/tracking/initial_release_date".to_string(),
+                severity: ValidationSeverity::Warning,
+            }]),
+            test_6_2_05_older_initial_release_date_than_revision_history(
+                &CsafDocument::<Csaf20>::from_json(doc).unwrap()
+            )
+        );
+    }
+}
@@ -0,0 +1,91 @@
+use crate::csaf::getter_traits::CsafTrait;
+use crate::csaf::timeline::{Timeline, CURRENT_RELEASE_DATE_JSON_PATH};
+use crate::csaf::validation::{ValidationError, ValidationSeverity};
+
+pub fn test_6_2_06_older_current_release_date_than_revision_history(
+    doc: &impl CsafTrait,
+) -> Result<(), Vec<ValidationError>> {
+    let timeline = Timeline::of(doc);
+
+    // Invalid dates are reported by test 6.1.37
+    let (Some(current_release_date), Some(newest_revision_date)) =
+        (timeline.current_release_date, timeline.newest_revision_date())
+    else {
+        return Ok(());
+    };
+
+    if current_release_date < newest_revision_date {
+        Err(vec![ValidationError {
+            message: format!(
+                "Current release date {} is older than the newest revision history date {}",
+                current_release_date.to_rfc3339(),
+                newest_revision_date.to_rfc3339()
+            ),
+            instance_path: CURRENT_RELEASE_DATE_JSON_PATH.to_string(),
+            severity: ValidationSeverity::Warning,
+        }])
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::csaf2_1::schema::CommonSecurityAdvisoryFramework as Csaf21;
+    use crate::csaf::document::CsafDocument;
+    use crate::csaf::test_helper::{minimal_document, run_csaf20_optional_tests, run_csaf21_optional_tests, set_revision_history};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_2_06::test_6_2_06_older_current_release_date_than_revision_history;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_2_06() {
+        run_csaf20_optional_tests(
+            "06",
+            test_6_2_06_older_current_release_date_than_revision_history,
+            &HashMap::from([
+                ("01", vec![ValidationError {
+                    message: "Current release date 2021-04-22T10:00:00+00:00 is older than the newest revision history date 2021-07-21T10:00:00+00:00".to_string(),
+                    instance_path: "/document/tracking/current_release_date".to_string(),
+                    severity: ValidationSeverity::Warning,
+                }]),
+            ]),
+        );
+        run_csaf21_optional_tests(
+            "06",
+            test_6_2_06_older_current_release_date_than_revision_history,
+            &HashMap::from([
+                ("01", vec![ValidationError {
+                    message: "Current release date 2023-12-22T10:00:00+00:00 is older than the newest revision history date 2024-01-24T10:00:00+00:00".to_string(),
+                    instance_path: "/document/tracking/current_release_date".to_string(),
+                    severity: ValidationSeverity::Warning,
+                }]),
+            ]),
+        );
+    }
+
+    #[test]
+    fn test_test_6_2_06_offsets() {
+        let mut doc = minimal_document("2.1");
+        set_revision_history(&mut doc, "2", &[("2024-01-24T10:00:00.000Z", "1"), ("2024-01-25T10:00:00.000Z", "2")]);
+        doc["document"]["tracking"]["current_release_date"] = "2024-01-25T12:00:00.000+02:00".into();
+        assert_eq!(
+            Ok(()),
+            test_6_2_06_older_current_release_date_than_revision_history(
+                &CsafDocument::<Csaf21>::from_json(doc.clone()).unwrap()
+            )
+        );
+
+        doc["document"]["tracking"]["current_release_date"] = "2024-01-24T11:00:00.000Z".into();
+        assert_eq!(
+            Err(vec![ValidationError {
+                message: "Current release date 2024-01-24T11:00:00+00:00 is older than the newest revision history date 2024-01-25T10:00:00+00:00".to_string(),
+                instance_path: "/document/tracking/current_release_date".to_string(),
+                severity: ValidationSeverity::Warning,
+            }]),
+            test_6_2_06_older_current_release_date_than_revision_history(
+                &CsafDocument::<Csaf21>::from_json(doc).unwrap()
+            )
+        );
+    }
+}
@@ -0,0 +1,44 @@
+use crate::csaf::getter_traits::{CsafTrait, InvolvementTrait, VulnerabilityTrait};
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
+
+pub fn test_6_2_07_missing_date_in_involvements(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::<ValidationError>::new();
+
+    for (v_i, v) in doc.get_vulnerabilities().iter().enumerate() {
+        let Some(involvements) = v.get_involvements() else {
+            continue;
+        };
+        for (i_i, involvement) in involvements.iter().enumerate() {
+            if involvement.get_date().is_none() {
+                errors.push(ValidationError {
+                    message: "Involvement has no date".to_string(),
+                    instance_path: format!("/vulnerabilities/{}/involvements/{}", v_i, i_i),
+                    severity: ValidationSeverity::Warning,
+                });
+            }
+        }
+    }
+
+    into_result(errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::test_helper::{run_csaf20_optional_tests, run_csaf21_optional_tests};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_2_07::test_6_2_07_missing_date_in_involvements;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_2_07() {
+        let errors = HashMap::from([
+            ("01", vec![ValidationError {
+                message: "Involvement has no date".to_string(),
+                instance_path: "/vulnerabilities/0/involvements/0".to_string(),
+                severity: ValidationSeverity::Warning,
+            }]),
+        ]);
+        run_csaf20_optional_tests("07", test_6_2_07_missing_date_in_involvements, &errors);
+        run_csaf21_optional_tests("07", test_6_2_07_missing_date_in_involvements, &errors);
+    }
+}
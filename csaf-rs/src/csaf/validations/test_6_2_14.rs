@@ -0,0 +1,49 @@
+use crate::csaf::getter_traits::CsafTrait;
+use crate::csaf::helpers::get_language_tags;
+use crate::csaf::language::LanguageTag;
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
+
+pub fn test_6_2_14_use_of_private_language(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::<ValidationError>::new();
+
+    for (path, lang) in get_language_tags(doc) {
+        // Tags which are not well-formed are reported by 6.1.12
+        if let Ok(tag) = lang.parse::<LanguageTag>() {
+            let private_use = tag.private_use_subtags();
+            if !private_use.is_empty() {
+                errors.push(ValidationError {
+                    message: format!(
+                        "The language tag '{}' contains subtags reserved for private use: {}",
+                        lang,
+                        private_use.join(", ")
+                    ),
+                    instance_path: path.to_string(),
+                    severity: ValidationSeverity::Warning,
+                });
+            }
+        }
+    }
+
+    into_result(errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::test_helper::{run_csaf20_optional_tests, run_csaf21_optional_tests};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_2_14::test_6_2_14_use_of_private_language;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_2_14() {
+        let errors = HashMap::from([
+            ("01", vec![ValidationError {
+                message: "The language tag 'qtx' contains subtags reserved for private use: qtx".to_string(),
+                instance_path: "/document/lang".to_string(),
+                severity: ValidationSeverity::Warning,
+            }]),
+        ]);
+        run_csaf20_optional_tests("14", test_6_2_14_use_of_private_language, &errors);
+        run_csaf21_optional_tests("14", test_6_2_14_use_of_private_language, &errors);
+    }
+}
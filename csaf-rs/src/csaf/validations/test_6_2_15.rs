@@ -0,0 +1,59 @@
+use crate::csaf::getter_traits::CsafTrait;
+use crate::csaf::helpers::get_language_tags;
+use crate::csaf::language::LanguageTag;
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
+
+pub fn test_6_2_15_use_of_default_language(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::<ValidationError>::new();
+
+    for (path, lang) in get_language_tags(doc) {
+        if lang.parse::<LanguageTag>().is_ok_and(|tag| tag.is_default()) {
+            errors.push(ValidationError {
+                message: format!("The language tag '{}' denotes the default language", lang),
+                instance_path: path.to_string(),
+                severity: ValidationSeverity::Warning,
+            });
+        }
+    }
+
+    into_result(errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::csaf2_1::schema::CommonSecurityAdvisoryFramework as Csaf21;
+    use crate::csaf::document::CsafDocument;
+    use crate::csaf::test_helper::{minimal_document, run_csaf20_optional_tests, run_csaf21_optional_tests};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_2_15::test_6_2_15_use_of_default_language;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_2_15() {
+        let errors = HashMap::from([
+            ("01", vec![ValidationError {
+                message: "The language tag 'i-default' denotes the default language".to_string(),
+                instance_path: "/document/lang".to_string(),
+                severity: ValidationSeverity::Warning,
+            }]),
+        ]);
+        run_csaf20_optional_tests("15", test_6_2_15_use_of_default_language, &errors);
+        run_csaf21_optional_tests("15", test_6_2_15_use_of_default_language, &errors);
+    }
+
+    #[test]
+    fn test_test_6_2_15_source_lang() {
+        let mut doc = minimal_document("2.1");
+        doc["document"]["lang"] = json!("de");
+        doc["document"]["source_lang"] = json!("i-default");
+        assert_eq!(
+            Err(vec![ValidationError {
+                message: "The language tag 'i-default' denotes the default language".to_string(),
+                instance_path: "/document/source_lang".to_string(),
+                severity: ValidationSeverity::Warning,
+            }]),
+            test_6_2_15_use_of_default_language(&CsafDocument::<Csaf21>::from_json(doc).unwrap())
+        );
+    }
+}
@@ -0,0 +1,46 @@
+use crate::csaf::csaf2_1::schema::CategoryOfTheBranch;
+use crate::csaf::getter_traits::{BranchTrait, CsafTrait, ProductTreeTrait};
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
+use crate::csaf::vers::VERS_PREFIX;
+
+pub fn test_6_2_18_product_version_range_without_vers(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::<ValidationError>::new();
+
+    if let Some(product_tree) = doc.get_product_tree() {
+        product_tree.visit_all_branches(&mut |branch, path| {
+            if branch.get_category() == CategoryOfTheBranch::ProductVersionRange
+                && !branch.get_name().starts_with(VERS_PREFIX)
+            {
+                errors.push(ValidationError {
+                    message: format!("Product version range '{}' does not use the vers syntax", branch.get_name()),
+                    instance_path: format!("{}/name", path),
+                    severity: ValidationSeverity::Warning,
+                });
+            }
+            Ok(())
+        })?;
+    }
+
+    into_result(errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::test_helper::{run_csaf20_optional_tests, run_csaf21_optional_tests};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_2_18::test_6_2_18_product_version_range_without_vers;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_2_18() {
+        let errors = HashMap::from([
+            ("01", vec![ValidationError {
+                message: "Product version range '>4.2' does not use the vers syntax".to_string(),
+                instance_path: "/product_tree/branches/0/branches/0/branches/0/name".to_string(),
+                severity: ValidationSeverity::Warning,
+            }]),
+        ]);
+        run_csaf20_optional_tests("18", test_6_2_18_product_version_range_without_vers, &errors);
+        run_csaf21_optional_tests("18", test_6_2_18_product_version_range_without_vers, &errors);
+    }
+}
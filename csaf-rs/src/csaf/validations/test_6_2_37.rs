@@ -0,0 +1,72 @@
+use crate::csaf::getter_traits::{CsafTrait, DocumentTrait};
+use crate::csaf::license::{LicenseList, SpdxExpression};
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
+
+pub fn test_6_2_37_use_of_deprecated_license_identifier(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::<ValidationError>::new();
+
+    // Invalid expressions are reported by test 6.1.54
+    if let (Some(license_expression), Some(list)) =
+        (doc.get_document().get_license_expression(), LicenseList::embedded())
+    {
+        let expression = license_expression.parse::<SpdxExpression>().ok();
+        for id in expression.iter().flat_map(|e| e.deprecated_ids(list)) {
+            errors.push(ValidationError {
+                message: format!(
+                    "The license expression '{}' uses the deprecated identifier '{}'",
+                    license_expression, id
+                ),
+                instance_path: "/document/license_expression".to_string(),
+                severity: ValidationSeverity::Warning,
+            });
+        }
+    }
+
+    into_result(errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::csaf2_1::schema::CommonSecurityAdvisoryFramework as Csaf21;
+    use crate::csaf::document::CsafDocument;
+    use crate::csaf::test_helper::{minimal_document, run_csaf21_optional_tests};
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_6_2_37::test_6_2_37_use_of_deprecated_license_identifier;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_test_6_2_37() {
+        run_csaf21_optional_tests(
+            "37",
+            test_6_2_37_use_of_deprecated_license_identifier,
+            &HashMap::from([
+                ("01", vec![ValidationError {
+                    message: "The license expression 'GPL-2.0 OR MIT' uses the deprecated identifier 'GPL-2.0'".to_string(),
+                    instance_path: "/document/license_expression".to_string(),
+                    severity: ValidationSeverity::Warning,
+                }]),
+            ]),
+        );
+    }
+
+    #[test]
+    fn test_test_6_2_37_valid_and_invalid_expressions() {
+        let run = |license_expression: &str| {
+            let mut doc = minimal_document("2.1");
+            doc["document"]["license_expression"] = json!(license_expression);
+            test_6_2_37_use_of_deprecated_license_identifier(&CsafDocument::<Csaf21>::from_json(doc).unwrap())
+        };
+
+        assert_eq!(Ok(()), run("GPL-2.0-only OR MIT"));
+        assert_eq!(Ok(()), run("GPL-2.0 OR"));
+        assert_eq!(
+            Err(vec![ValidationError {
+                message: "The license expression 'GPL-2.0 OR MIT' uses the deprecated identifier 'GPL-2.0'".to_string(),
+                instance_path: "/document/license_expression".to_string(),
+                severity: ValidationSeverity::Warning,
+            }]),
+            run("GPL-2.0 OR MIT")
+        );
+    }
+}
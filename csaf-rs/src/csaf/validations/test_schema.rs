@@ -0,0 +1,100 @@
+use crate::csaf::document::{CsafDocument, CsafVersion, CsafVersioned};
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
+use jsonschema::Validator;
+use serde_json::Value;
+use std::sync::LazyLock;
+
+/// Validator for the CSAF 2.0 JSON schema, including format checks (e.g. `date-time`).
+static CSAF20_SCHEMA: LazyLock<Validator> = LazyLock::new(||
+    build_validator(include_str!("../csaf2_0/csaf_json_schema.json"))
+);
+
+/// Validator for the CSAF 2.1 JSON schema, including format checks (e.g. `date-time`).
+static CSAF21_SCHEMA: LazyLock<Validator> = LazyLock::new(||
+    build_validator(include_str!("../csaf2_1/csaf.json"))
+);
+
+/// Builds a draft 2020-12 validator for the given embedded schema.
+///
+/// CSAF 2.1 references its own meta schema in `$schema`, which would have to be fetched.
+/// As it is based on draft 2020-12, the reference is dropped and the draft is set explicitly.
+fn build_validator(schema: &str) -> Validator {
+    let mut schema: Value = serde_json::from_str(schema).expect("embedded CSAF JSON schema is valid JSON");
+    if let Some(schema) = schema.as_object_mut() {
+        schema.remove("$schema");
+    }
+    jsonschema::draft202012::options()
+        .should_validate_formats(true)
+        .build(&schema)
+        .expect("embedded CSAF JSON schema is a valid JSON schema")
+}
+
+/// Validates the raw JSON against the given schema and converts all schema violations
+/// into [ValidationError]s pointing to the offending instance.
+fn validate_schema(validator: &Validator, json: &Value) -> Result<(), Vec<ValidationError>> {
+    let errors: Vec<ValidationError> = validator
+        .iter_errors(json)
+        .map(|e| ValidationError {
+            message: e.to_string(),
+            instance_path: e.instance_path.to_string(),
+            severity: ValidationSeverity::Error,
+        })
+        .collect();
+
+    into_result(errors)
+}
+
+/// Validates the given JSON value against the CSAF 2.0 JSON schema.
+pub fn validate_csaf20_schema(json: &Value) -> Result<(), Vec<ValidationError>> {
+    validate_schema(&CSAF20_SCHEMA, json)
+}
+
+/// Validates the given JSON value against the CSAF 2.1 JSON schema.
+pub fn validate_csaf21_schema(json: &Value) -> Result<(), Vec<ValidationError>> {
+    validate_schema(&CSAF21_SCHEMA, json)
+}
+
+/// Validates the given JSON value against the JSON schema of the given CSAF version.
+pub fn validate_csaf_schema(version: CsafVersion, json: &Value) -> Result<(), Vec<ValidationError>> {
+    match version {
+        CsafVersion::V2_0 => validate_csaf20_schema(json),
+        CsafVersion::V2_1 => validate_csaf21_schema(json),
+    }
+}
+
+/// Schema test: Checks that a document is valid according to the JSON schema of its CSAF version.
+pub fn test_schema<T: CsafVersioned>(doc: &CsafDocument<T>) -> Result<(), Vec<ValidationError>> {
+    validate_csaf_schema(T::VERSION, doc.json())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::test_helper::minimal_document;
+    use crate::csaf::validation::ValidationSeverity;
+    use crate::csaf::validations::test_schema::{validate_csaf20_schema, validate_csaf21_schema};
+    use serde_json::json;
+
+    #[test]
+    fn test_schema_valid() {
+        assert_eq!(Ok(()), validate_csaf20_schema(&minimal_document("2.0")));
+
+        let csaf21 = minimal_document("2.1");
+        let validation = validate_csaf21_schema(&csaf21);
+        assert_eq!(Ok(()), validation, "{:?}", validation);
+    }
+
+    #[test]
+    fn test_schema_invalid() {
+        let mut doc = minimal_document("2.0");
+        // Constraints which are not enforced by deserializing into the generated types
+        doc["document"]["tracking"]["current_release_date"] = json!("2024-01-24");
+        doc["document"]["notes"] = json!([]);
+
+        let errors = validate_csaf20_schema(&doc).unwrap_err();
+        let paths: Vec<&str> = errors.iter().map(|e| e.instance_path.as_str()).collect();
+        assert_eq!(2, errors.len(), "{:?}", errors);
+        assert!(paths.contains(&"/document/tracking/current_release_date"));
+        assert!(paths.contains(&"/document/notes"));
+        assert!(errors.iter().all(|e| e.severity == ValidationSeverity::Error));
+    }
+}
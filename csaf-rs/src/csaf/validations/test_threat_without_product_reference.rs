@@ -0,0 +1,63 @@
+use crate::csaf::getter_traits::{CsafTrait, ThreatTrait, VulnerabilityTrait, WithGroupIds};
+use crate::csaf::validation::{into_result, ValidationError, ValidationSeverity};
+
+/// Checks that each threat references the products or product groups it applies to.
+///
+/// This is not a test of the standard: threats without product references are valid, but
+/// usually an oversight, like remediations without product reference (6.1.29).
+pub fn test_threat_without_product_reference(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::<ValidationError>::new();
+
+    for (v_i, v) in doc.get_vulnerabilities().iter().enumerate() {
+        for (t_i, threat) in v.get_threats().iter().enumerate() {
+            if threat.get_product_ids().is_none() && threat.get_group_ids().is_none() {
+                errors.push(ValidationError {
+                    message: format!(
+                        "Threat of category '{}' neither references products nor product groups",
+                        threat.get_category()
+                    ),
+                    instance_path: format!("/vulnerabilities/{}/threats/{}", v_i, t_i),
+                    severity: ValidationSeverity::Warning,
+                });
+            }
+        }
+    }
+
+    into_result(errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::csaf2_0::schema::CommonSecurityAdvisoryFramework as Csaf20;
+    use crate::csaf::document::CsafDocument;
+    use crate::csaf::test_helper::minimal_document;
+    use crate::csaf::validation::{ValidationError, ValidationSeverity};
+    use crate::csaf::validations::test_threat_without_product_reference::test_threat_without_product_reference;
+    use serde_json::json;
+
+    #[test]
+    fn test_test_threat_without_product_reference() {
+        let mut doc = minimal_document("2.0");
+        doc["product_tree"] = json!({
+            "full_product_names": [
+                { "name": "Product A", "product_id": "CSAFPID-9080700" },
+                { "name": "Product B", "product_id": "CSAFPID-9080701" }
+            ],
+            "product_groups": [{ "group_id": "CSAFGID-1020300", "product_ids": ["CSAFPID-9080700", "CSAFPID-9080701"] }]
+        });
+        doc["vulnerabilities"] = json!([{
+            "threats": [
+                { "category": "impact", "details": "Remote code execution.", "group_ids": ["CSAFGID-1020300"] },
+                { "category": "exploit_status", "details": "Exploited in the wild." }
+            ]
+        }]);
+        assert_eq!(
+            Err(vec![ValidationError {
+                message: "Threat of category 'exploit_status' neither references products nor product groups".to_string(),
+                instance_path: "/vulnerabilities/0/threats/1".to_string(),
+                severity: ValidationSeverity::Warning,
+            }]),
+            test_threat_without_product_reference(&CsafDocument::<Csaf20>::from_json(doc).unwrap())
+        );
+    }
+}
@@ -0,0 +1,403 @@
+//! Version range specifiers (vers) as used by `product_version_range` branches.
+//!
+//! A specifier like `vers:npm/>=1.2.0|<2.0.0|!=1.5.0` consists of a versioning scheme and a
+//! list of constraints, see <https://github.com/package-url/purl-spec/blob/main/VERSION-RANGE-SPEC.rst>.
+//! [Vers] parses the syntax of a specifier, validates its constraints and checks whether a
+//! version is contained in the range. The versions are compared according to the rules of the
+//! versioning scheme, see [VersionScheme] for the supported schemes.
+
+mod scheme;
+
+pub use scheme::VersionScheme;
+
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// The prefix of all version range specifiers.
+pub const VERS_PREFIX: &str = "vers:";
+
+/// Error for a version range specifier which is not well-formed or not valid.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum VersError {
+    /// The specifier does not start with `vers:`.
+    MissingPrefix,
+    /// The versioning scheme is empty or contains characters other than lower case ASCII
+    /// letters, digits, `.`, `+` and `-`.
+    InvalidScheme(String),
+    /// The specifier has no constraints or contains an empty constraint.
+    EmptyConstraint,
+    /// The version of a constraint is not properly percent-encoded.
+    InvalidEncoding(String),
+    /// The versioning scheme is not supported, so that versions cannot be compared.
+    UnsupportedScheme(String),
+    /// The version is not valid according to the versioning scheme.
+    InvalidVersion(String),
+    /// The version is used by more than one constraint.
+    DuplicateVersion(String),
+    /// The constraints are not sorted by their versions, the version is out of order.
+    UnsortedConstraints(String),
+    /// The comparators of the constraints do not form a sequence of disjoint ranges.
+    InvalidComparators,
+}
+
+impl Display for VersError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingPrefix => write!(f, "the specifier does not start with '{}'", VERS_PREFIX),
+            Self::InvalidScheme(scheme) => write!(f, "the versioning scheme '{}' is invalid", scheme),
+            Self::EmptyConstraint => write!(f, "the specifier contains an empty constraint"),
+            Self::InvalidEncoding(version) => write!(f, "the version '{}' is not properly percent-encoded", version),
+            Self::UnsupportedScheme(scheme) => write!(f, "the versioning scheme '{}' is not supported", scheme),
+            Self::InvalidVersion(version) => write!(f, "the version '{}' is invalid for its versioning scheme", version),
+            Self::DuplicateVersion(version) => write!(f, "the version '{}' is used by more than one constraint", version),
+            Self::UnsortedConstraints(version) => write!(f, "the constraints are not sorted by version at '{}'", version),
+            Self::InvalidComparators => write!(f, "the comparators of the constraints do not form disjoint ranges"),
+        }
+    }
+}
+
+impl std::error::Error for VersError {}
+
+/// The comparator of a version constraint.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Comparator {
+    Equal,
+    NotEqual,
+    LessThan,
+    LessThanOrEqual,
+    GreaterThan,
+    GreaterThanOrEqual,
+}
+
+impl Comparator {
+    /// All comparators, ordered so that no comparator is a prefix of one listed before it.
+    const ALL: [Comparator; 6] = [
+        Self::GreaterThanOrEqual,
+        Self::LessThanOrEqual,
+        Self::NotEqual,
+        Self::LessThan,
+        Self::GreaterThan,
+        Self::Equal,
+    ];
+
+    /// Returns the symbol of the comparator, e.g. `>=`.
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Self::Equal => "=",
+            Self::NotEqual => "!=",
+            Self::LessThan => "<",
+            Self::LessThanOrEqual => "<=",
+            Self::GreaterThan => ">",
+            Self::GreaterThanOrEqual => ">=",
+        }
+    }
+
+    /// Checks whether a version satisfies the comparator, given the ordering of the version
+    /// relative to the version of the constraint.
+    pub fn matches(&self, ordering: Ordering) -> bool {
+        match self {
+            Self::Equal => ordering == Ordering::Equal,
+            Self::NotEqual => ordering != Ordering::Equal,
+            Self::LessThan => ordering == Ordering::Less,
+            Self::LessThanOrEqual => ordering != Ordering::Greater,
+            Self::GreaterThan => ordering == Ordering::Greater,
+            Self::GreaterThanOrEqual => ordering != Ordering::Less,
+        }
+    }
+
+    fn is_lower_bound(&self) -> bool {
+        matches!(self, Self::GreaterThan | Self::GreaterThanOrEqual)
+    }
+
+    fn is_upper_bound(&self) -> bool {
+        matches!(self, Self::LessThan | Self::LessThanOrEqual)
+    }
+}
+
+/// A single constraint of a version range specifier, e.g. `>=1.2.0`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct VersionConstraint {
+    pub comparator: Comparator,
+    /// The version, with percent-encoding removed
+    pub version: String,
+}
+
+impl VersionConstraint {
+    fn parse(constraint: &str) -> Result<Self, VersError> {
+        let (comparator, version) = Comparator::ALL
+            .into_iter()
+            .find_map(|c| constraint.strip_prefix(c.symbol()).map(|version| (c, version)))
+            .unwrap_or((Comparator::Equal, constraint));
+        if version.is_empty() {
+            return Err(VersError::EmptyConstraint);
+        }
+        Ok(VersionConstraint {
+            comparator,
+            version: percent_decode(version)?,
+        })
+    }
+}
+
+impl Display for VersionConstraint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // The equal comparator is implied
+        if self.comparator != Comparator::Equal {
+            write!(f, "{}", self.comparator.symbol())?;
+        }
+        write!(f, "{}", percent_encode(&self.version))
+    }
+}
+
+/// A version range specifier, e.g. `vers:npm/>=1.2.0|<2.0.0`.
+///
+/// Parsing only checks the syntax of the specifier, [Vers::validate] checks the constraints
+/// according to the versioning scheme.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Vers {
+    /// The versioning scheme, e.g. `npm`
+    pub scheme: String,
+    /// The constraints in the given order, which is empty for the star constraint `*`
+    /// matching all versions
+    pub constraints: Vec<VersionConstraint>,
+}
+
+impl FromStr for Vers {
+    type Err = VersError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        // Whitespace is not significant
+        let value: String = value.chars().filter(|c| !c.is_whitespace()).collect();
+        let rest = value.strip_prefix(VERS_PREFIX).ok_or(VersError::MissingPrefix)?;
+        let (scheme, constraints) = rest
+            .split_once('/')
+            .ok_or_else(|| VersError::InvalidScheme(rest.to_string()))?;
+        if scheme.is_empty()
+            || !scheme
+                .bytes()
+                .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || matches!(b, b'.' | b'+' | b'-'))
+        {
+            return Err(VersError::InvalidScheme(scheme.to_string()));
+        }
+
+        let constraints = constraints.trim_matches('|');
+        let constraints = match constraints {
+            "" => return Err(VersError::EmptyConstraint),
+            "*" => Vec::new(),
+            _ => constraints.split('|').map(VersionConstraint::parse).collect::<Result<_, _>>()?,
+        };
+        Ok(Vers {
+            scheme: scheme.to_string(),
+            constraints,
+        })
+    }
+}
+
+impl Display for Vers {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}/", VERS_PREFIX, self.scheme)?;
+        if self.matches_all() {
+            return write!(f, "*");
+        }
+        for (i, constraint) in self.constraints.iter().enumerate() {
+            if i > 0 {
+                write!(f, "|")?;
+            }
+            write!(f, "{}", constraint)?;
+        }
+        Ok(())
+    }
+}
+
+impl Vers {
+    /// Checks whether this is the star constraint `*` matching all versions.
+    pub fn matches_all(&self) -> bool {
+        self.constraints.is_empty()
+    }
+
+    /// Returns the versioning scheme, if it is supported.
+    pub fn version_scheme(&self) -> Result<VersionScheme, VersError> {
+        VersionScheme::from_name(&self.scheme).ok_or_else(|| VersError::UnsupportedScheme(self.scheme.clone()))
+    }
+
+    /// Validates the constraints according to the versioning scheme: all versions need to be
+    /// valid, unique and sorted, and the comparators need to describe disjoint ranges.
+    pub fn validate(&self) -> Result<(), VersError> {
+        let scheme = self.version_scheme()?;
+        for constraint in &self.constraints {
+            scheme.validate(&constraint.version)?;
+        }
+        for pair in self.constraints.windows(2) {
+            match scheme.compare(&pair[0].version, &pair[1].version)? {
+                Ordering::Less => {}
+                Ordering::Equal => return Err(VersError::DuplicateVersion(pair[1].version.clone())),
+                Ordering::Greater => return Err(VersError::UnsortedConstraints(pair[1].version.clone())),
+            }
+        }
+
+        // Ignoring '!=', an equality may only be followed by an equality or a lower bound
+        let without_not_equal: Vec<Comparator> = self
+            .constraints
+            .iter()
+            .map(|c| c.comparator)
+            .filter(|c| *c != Comparator::NotEqual)
+            .collect();
+        for pair in without_not_equal.windows(2) {
+            if pair[0] == Comparator::Equal && pair[1].is_upper_bound() {
+                return Err(VersError::InvalidComparators);
+            }
+        }
+        // Ignoring '=' and '!=', lower and upper bounds need to alternate
+        let bounds: Vec<&Comparator> = without_not_equal.iter().filter(|c| **c != Comparator::Equal).collect();
+        for pair in bounds.windows(2) {
+            if pair[0].is_lower_bound() == pair[1].is_lower_bound() {
+                return Err(VersError::InvalidComparators);
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks whether the version is contained in the range, using the algorithm of the
+    /// vers specification. The constraints are expected to be valid, see [Vers::validate].
+    pub fn contains(&self, version: &str) -> Result<bool, VersError> {
+        let scheme = self.version_scheme()?;
+        scheme.validate(version)?;
+        if self.matches_all() {
+            return Ok(true);
+        }
+
+        let mut orderings = Vec::with_capacity(self.constraints.len());
+        for constraint in &self.constraints {
+            let ordering = scheme.compare(version, &constraint.version)?;
+            match constraint.comparator {
+                Comparator::Equal if ordering == Ordering::Equal => return Ok(true),
+                Comparator::NotEqual if ordering == Ordering::Equal => return Ok(false),
+                _ => {}
+            }
+            orderings.push((constraint.comparator, ordering));
+        }
+
+        let bounds: Vec<&(Comparator, Ordering)> = orderings
+            .iter()
+            .filter(|(c, _)| c.is_lower_bound() || c.is_upper_bound())
+            .collect();
+        let Some((first, last)) = bounds.first().zip(bounds.last()) else {
+            // Only equalities and exclusions: versions not excluded match, unless equalities are given
+            return Ok(!orderings.iter().any(|(c, _)| *c == Comparator::Equal));
+        };
+        if first.0.is_upper_bound() && first.0.matches(first.1) {
+            return Ok(true);
+        }
+        if last.0.is_lower_bound() && last.0.matches(last.1) {
+            return Ok(true);
+        }
+        Ok(bounds.windows(2).any(|pair| {
+            let ((lower, lower_ordering), (upper, upper_ordering)) = (pair[0], pair[1]);
+            lower.is_lower_bound() && lower.matches(*lower_ordering) && upper.matches(*upper_ordering)
+        }))
+    }
+}
+
+/// Removes the percent-encoding of a version.
+fn percent_decode(value: &str) -> Result<String, VersError> {
+    let error = || VersError::InvalidEncoding(value.to_string());
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let hex = value.get(i + 1..i + 3).ok_or_else(error)?;
+            decoded.push(u8::from_str_radix(hex, 16).map_err(|_| error())?);
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8(decoded).map_err(|_| error())
+}
+
+/// Percent-encodes the characters of a version which have a meaning in a specifier.
+fn percent_encode(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| match c {
+            '%' | '|' | '/' | ' ' => format!("%{:02X}", c as u32),
+            _ => c.to_string(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::vers::{Comparator, Vers, VersError, VersionConstraint};
+
+    #[test]
+    fn test_parse() {
+        let vers: Vers = "vers:npm/1.2.3|>=2.0.0|<5.0.0".parse().unwrap();
+        assert_eq!("npm", vers.scheme);
+        assert_eq!(
+            vec![
+                VersionConstraint { comparator: Comparator::Equal, version: "1.2.3".to_string() },
+                VersionConstraint { comparator: Comparator::GreaterThanOrEqual, version: "2.0.0".to_string() },
+                VersionConstraint { comparator: Comparator::LessThan, version: "5.0.0".to_string() },
+            ],
+            vers.constraints
+        );
+        assert_eq!("vers:npm/1.2.3|>=2.0.0|<5.0.0", vers.to_string());
+        assert_eq!("vers:pypi/>=1.0|<2.0", " vers:pypi/ >= 1.0 | < 2.0 ".parse::<Vers>().unwrap().to_string());
+        assert!("vers:deb/*".parse::<Vers>().unwrap().matches_all());
+        assert_eq!("1.0 beta", "vers:generic/1.0%20beta".parse::<Vers>().unwrap().constraints[0].version);
+
+        assert_eq!(Err(VersError::MissingPrefix), "npm/1.2.3".parse::<Vers>());
+        assert_eq!(Err(VersError::InvalidScheme("NPM".to_string())), "vers:NPM/1.2.3".parse::<Vers>());
+        assert_eq!(Err(VersError::InvalidScheme("npm".to_string())), "vers:npm".parse::<Vers>());
+        assert_eq!(Err(VersError::EmptyConstraint), "vers:npm/".parse::<Vers>());
+        assert_eq!(Err(VersError::EmptyConstraint), "vers:npm/1.0.0||>=2.0.0".parse::<Vers>());
+        assert_eq!(Err(VersError::EmptyConstraint), "vers:npm/>=".parse::<Vers>());
+        assert_eq!(Err(VersError::InvalidEncoding("1.0%2".to_string())), "vers:npm/1.0%2".parse::<Vers>());
+    }
+
+    #[test]
+    fn test_validate() {
+        let validate = |vers: &str| vers.parse::<Vers>().unwrap().validate();
+        assert_eq!(Ok(()), validate("vers:semver/1.0.0|>=2.0.0|!=2.5.0|<3.0.0|>4.0.0"));
+        assert_eq!(Ok(()), validate("vers:npm/*"));
+        assert_eq!(Err(VersError::UnsupportedScheme("gem".to_string())), validate("vers:gem/1.0.0"));
+        assert_eq!(Err(VersError::InvalidVersion("1.0".to_string())), validate("vers:semver/1.0"));
+        assert_eq!(Err(VersError::DuplicateVersion("1.0.0".to_string())), validate("vers:pypi/>=1.0|<1.0.0"));
+        assert_eq!(Err(VersError::UnsortedConstraints("1.0.0".to_string())), validate("vers:npm/<2.0.0|>=1.0.0"));
+        assert_eq!(Err(VersError::InvalidComparators), validate("vers:npm/>=1.0.0|>=2.0.0"));
+        assert_eq!(Err(VersError::InvalidComparators), validate("vers:npm/1.0.0|<2.0.0"));
+    }
+
+    #[test]
+    fn test_contains() {
+        let vers: Vers = "vers:npm/1.0.0|>=2.0.0|!=2.5.0|<3.0.0|>4.0.0".parse().unwrap();
+        for (version, expected) in [
+            ("0.9.0", false),
+            ("1.0.0", true),
+            ("2.0.0", true),
+            ("2.5.0", false),
+            ("2.9.9", true),
+            ("3.0.0", false),
+            ("4.0.0", false),
+            ("4.0.1", true),
+        ] {
+            assert_eq!(Ok(expected), vers.contains(version), "{}", version);
+        }
+
+        let vers: Vers = "vers:pypi/<1.0|>=2.0".parse().unwrap();
+        assert_eq!(Ok(true), vers.contains("1.0rc1"));
+        assert_eq!(Ok(false), vers.contains("1.0"));
+        assert_eq!(Ok(true), vers.contains("2.0.post1"));
+
+        assert_eq!(Ok(true), "vers:deb/!=1.0".parse::<Vers>().unwrap().contains("2.0"));
+        assert_eq!(Ok(false), "vers:deb/1.0|3.0".parse::<Vers>().unwrap().contains("2.0"));
+        assert_eq!(Ok(true), "vers:rpm/*".parse::<Vers>().unwrap().contains("1.0-1.el9"));
+        assert_eq!(
+            Err(VersError::InvalidVersion("latest".to_string())),
+            "vers:semver/*".parse::<Vers>().unwrap().contains("latest")
+        );
+    }
+}
@@ -0,0 +1,635 @@
+//! Version comparison according to the rules of the supported versioning schemes.
+
+use crate::csaf::document_version::SemanticVersion;
+use crate::csaf::vers::VersError;
+use regex::Regex;
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::sync::LazyLock;
+
+/// A versioning scheme of version range specifiers, which defines how versions are compared.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum VersionScheme {
+    /// Semantic Versioning 2.0.0
+    Semver,
+    /// The npm flavor of semantic versioning, which allows a leading `v` or `=`
+    Npm,
+    /// Python package versions according to PEP 440
+    Pypi,
+    /// Maven versions, compared like Maven's `ComparableVersion`
+    Maven,
+    /// Debian package versions (`[epoch:]upstream[-revision]`), compared like dpkg
+    Deb,
+    /// RPM package versions (`[epoch:]version[-release]`), compared like rpmvercmp
+    Rpm,
+}
+
+impl VersionScheme {
+    const ALL: [VersionScheme; 6] = [Self::Semver, Self::Npm, Self::Pypi, Self::Maven, Self::Deb, Self::Rpm];
+
+    /// Returns the scheme with the given name as used in version range specifiers.
+    pub fn from_name(name: &str) -> Option<VersionScheme> {
+        Self::ALL.into_iter().find(|scheme| scheme.name() == name)
+    }
+
+    /// Returns the name of the scheme as used in version range specifiers, e.g. `npm`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Semver => "semver",
+            Self::Npm => "npm",
+            Self::Pypi => "pypi",
+            Self::Maven => "maven",
+            Self::Deb => "deb",
+            Self::Rpm => "rpm",
+        }
+    }
+
+    /// Checks whether the version is valid according to this scheme.
+    pub fn validate(&self, version: &str) -> Result<(), VersError> {
+        self.compare(version, version).map(|_| ())
+    }
+
+    /// Compares two versions according to this scheme.
+    pub fn compare(&self, a: &str, b: &str) -> Result<Ordering, VersError> {
+        match self {
+            Self::Semver => Ok(parse_semver(a)?.precedence(&parse_semver(b)?)),
+            Self::Npm => Ok(parse_npm(a)?.precedence(&parse_npm(b)?)),
+            Self::Pypi => Ok(PypiVersion::parse(a)?.cmp(&PypiVersion::parse(b)?)),
+            Self::Maven => Ok(MavenItem::parse(a)?.compare(Some(&MavenItem::parse(b)?))),
+            Self::Deb => Ok(DebVersion::parse(a)?.cmp(&DebVersion::parse(b)?)),
+            Self::Rpm => Ok(RpmVersion::parse(a)?.cmp(&RpmVersion::parse(b)?)),
+        }
+    }
+}
+
+impl Display for VersionScheme {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+fn invalid(version: &str) -> VersError {
+    VersError::InvalidVersion(version.to_string())
+}
+
+fn parse_semver(version: &str) -> Result<SemanticVersion, VersError> {
+    SemanticVersion::from_str(version).map_err(|_| invalid(version))
+}
+
+fn parse_npm(version: &str) -> Result<SemanticVersion, VersError> {
+    let stripped = version.strip_prefix(['v', '=']).unwrap_or(version);
+    SemanticVersion::from_str(stripped).map_err(|_| invalid(version))
+}
+
+/// Compares two digit strings by their numeric value, without limiting their length.
+fn compare_numeric(a: &str, b: &str) -> Ordering {
+    let a = a.trim_start_matches('0');
+    let b = b.trim_start_matches('0');
+    a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
+
+/// A value of a PEP 440 sort key, where missing parts sort before or after all values.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Bound<T> {
+    NegativeInfinity,
+    Value(T),
+    Infinity,
+}
+
+/// A segment of the local version label, numeric segments sort after alphanumeric ones.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum LocalSegment {
+    AlphaNumeric(String),
+    Numeric(u64),
+}
+
+/// The sort key of a PEP 440 version.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct PypiVersion {
+    epoch: u64,
+    /// Without trailing zeros, as `1.0` equals `1`
+    release: Vec<u64>,
+    pre: Bound<(u8, u64)>,
+    post: Bound<u64>,
+    dev: Bound<u64>,
+    local: Bound<Vec<LocalSegment>>,
+}
+
+static PYPI_VERSION_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?ix)^v?
+        (?:(?P<epoch>[0-9]+)!)?
+        (?P<release>[0-9]+(?:\.[0-9]+)*)
+        (?:[-_.]?(?P<pre_l>alpha|a|beta|b|preview|pre|c|rc)[-_.]?(?P<pre_n>[0-9]+)?)?
+        (?:-(?P<post_n1>[0-9]+)|[-_.]?(?P<post_l>post|rev|r)[-_.]?(?P<post_n2>[0-9]+)?)?
+        (?:[-_.]?(?P<dev_l>dev)[-_.]?(?P<dev_n>[0-9]+)?)?
+        (?:\+(?P<local>[a-z0-9]+(?:[-_.][a-z0-9]+)*))?$",
+    )
+    .unwrap()
+});
+
+impl PypiVersion {
+    fn parse(version: &str) -> Result<Self, VersError> {
+        let captures = PYPI_VERSION_REGEX.captures(version.trim()).ok_or_else(|| invalid(version))?;
+        let number = |name: &str| -> Result<Option<u64>, VersError> {
+            captures
+                .name(name)
+                .map(|m| m.as_str().parse().map_err(|_| invalid(version)))
+                .transpose()
+        };
+
+        let mut release = captures["release"]
+            .split('.')
+            .map(|n| n.parse().map_err(|_| invalid(version)))
+            .collect::<Result<Vec<u64>, _>>()?;
+        while release.len() > 1 && release.last() == Some(&0) {
+            release.pop();
+        }
+
+        let pre = captures.name("pre_l").map(|label| match label.as_str().to_lowercase().as_str() {
+            "a" | "alpha" => 0,
+            "b" | "beta" => 1,
+            _ => 2,
+        });
+        let post = if captures.name("post_n1").is_some() {
+            number("post_n1")?
+        } else if captures.name("post_l").is_some() {
+            Some(number("post_n2")?.unwrap_or(0))
+        } else {
+            None
+        };
+        let dev = match captures.name("dev_l") {
+            Some(_) => Some(number("dev_n")?.unwrap_or(0)),
+            None => None,
+        };
+        let local = captures.name("local").map(|local| {
+            local
+                .as_str()
+                .to_lowercase()
+                .split(['-', '_', '.'])
+                .map(|segment| match segment.parse() {
+                    Ok(n) => LocalSegment::Numeric(n),
+                    Err(_) => LocalSegment::AlphaNumeric(segment.to_string()),
+                })
+                .collect()
+        });
+
+        Ok(PypiVersion {
+            epoch: number("epoch")?.unwrap_or(0),
+            release,
+            pre: match (pre, post, dev) {
+                // A development release of a final release sorts before its pre-releases
+                (None, None, Some(_)) => Bound::NegativeInfinity,
+                (None, _, _) => Bound::Infinity,
+                (Some(label), _, _) => Bound::Value((label, number("pre_n")?.unwrap_or(0))),
+            },
+            post: post.map_or(Bound::NegativeInfinity, Bound::Value),
+            dev: dev.map_or(Bound::Infinity, Bound::Value),
+            local: local.map_or(Bound::NegativeInfinity, Bound::Value),
+        })
+    }
+}
+
+/// An item of a Maven version, following the structure of Maven's `ComparableVersion`.
+#[derive(Debug, PartialEq, Eq)]
+enum MavenItem {
+    /// Digits without leading zeros
+    Numeric(String),
+    /// A qualifier in lower case with aliases resolved
+    Qualifier(String),
+    /// The items following a `-` or a transition between digits and letters
+    List(Vec<MavenItem>),
+}
+
+/// The maximum number of nested lists of a Maven version. Items are compared recursively, so
+/// deeper versions are rejected instead of overflowing the stack.
+const MAVEN_MAX_DEPTH: usize = 100;
+
+/// The well-known qualifiers in ascending order, the empty qualifier marks a release.
+const MAVEN_QUALIFIERS: [&str; 7] = ["alpha", "beta", "milestone", "rc", "snapshot", "", "sp"];
+
+impl MavenItem {
+    fn parse(version: &str) -> Result<Self, VersError> {
+        if version.is_empty() || !version.bytes().all(|b| b.is_ascii_graphic()) {
+            return Err(invalid(version));
+        }
+        let lowercase = version.to_lowercase();
+
+        // Each list is stored with the item it is nested in, the innermost list is last
+        let mut lists: Vec<Vec<MavenItem>> = vec![Vec::new()];
+        let mut start = 0;
+        let mut is_digit = false;
+        for (i, c) in lowercase.char_indices() {
+            let token = &lowercase[start..i];
+            match c {
+                '.' | '-' => {
+                    let item = if token.is_empty() {
+                        Self::Numeric(String::new())
+                    } else {
+                        Self::item(token, is_digit, false)
+                    };
+                    lists.last_mut().unwrap().push(item);
+                    start = i + 1;
+                    if c == '-' {
+                        lists.push(Vec::new());
+                    }
+                }
+                '0'..='9' => {
+                    if !is_digit && !token.is_empty() {
+                        lists.last_mut().unwrap().push(Self::item(token, false, true));
+                        start = i;
+                        lists.push(Vec::new());
+                    }
+                    is_digit = true;
+                }
+                _ => {
+                    if is_digit && !token.is_empty() {
+                        lists.last_mut().unwrap().push(Self::item(token, true, false));
+                        start = i;
+                        lists.push(Vec::new());
+                    }
+                    is_digit = false;
+                }
+            }
+        }
+        if start < lowercase.len() {
+            lists.last_mut().unwrap().push(Self::item(&lowercase[start..], is_digit, false));
+        }
+        if lists.len() > MAVEN_MAX_DEPTH {
+            return Err(invalid(version));
+        }
+
+        // Normalize and nest the lists from the innermost to the outermost
+        let mut item = Self::normalize(lists.pop().unwrap());
+        while let Some(mut list) = lists.pop() {
+            list.push(item);
+            item = Self::normalize(list);
+        }
+        Ok(item)
+    }
+
+    fn item(token: &str, is_digit: bool, followed_by_digit: bool) -> Self {
+        if is_digit {
+            return Self::Numeric(token.trim_start_matches('0').to_string());
+        }
+        let qualifier = match token {
+            "a" if followed_by_digit => "alpha",
+            "b" if followed_by_digit => "beta",
+            "m" if followed_by_digit => "milestone",
+            "ga" | "final" | "release" => "",
+            "cr" => "rc",
+            _ => token,
+        };
+        Self::Qualifier(qualifier.to_string())
+    }
+
+    /// Removes trailing null items (zero, release qualifier or empty list) before the last
+    /// item which is not a list.
+    fn normalize(mut list: Vec<MavenItem>) -> Self {
+        let mut i = list.len();
+        while i > 0 {
+            i -= 1;
+            if list[i].is_null() {
+                list.remove(i);
+            } else if !matches!(list[i], Self::List(_)) {
+                break;
+            }
+        }
+        Self::List(list)
+    }
+
+    fn is_null(&self) -> bool {
+        match self {
+            Self::Numeric(n) => n.is_empty(),
+            Self::Qualifier(q) => q.is_empty(),
+            Self::List(items) => items.is_empty(),
+        }
+    }
+
+    fn comparable_qualifier(qualifier: &str) -> String {
+        match MAVEN_QUALIFIERS.iter().position(|q| *q == qualifier) {
+            Some(i) => i.to_string(),
+            None => format!("{}-{}", MAVEN_QUALIFIERS.len(), qualifier),
+        }
+    }
+
+    /// Compares this item to another one, a missing item is treated like padding.
+    fn compare(&self, other: Option<&MavenItem>) -> Ordering {
+        match (self, other) {
+            (Self::Numeric(n), None) => if n.is_empty() { Ordering::Equal } else { Ordering::Greater },
+            (Self::Numeric(a), Some(Self::Numeric(b))) => compare_numeric(a, b),
+            (Self::Numeric(_), Some(_)) => Ordering::Greater,
+            (Self::Qualifier(q), None) => Self::comparable_qualifier(q).cmp(&Self::comparable_qualifier("")),
+            (Self::Qualifier(a), Some(Self::Qualifier(b))) => {
+                Self::comparable_qualifier(a).cmp(&Self::comparable_qualifier(b))
+            }
+            (Self::Qualifier(_), Some(Self::Numeric(_))) => Ordering::Less,
+            (Self::Qualifier(_), Some(Self::List(_))) => Ordering::Less,
+            (Self::List(items), None) => items
+                .iter()
+                .map(|item| item.compare(None))
+                .find(|o| *o != Ordering::Equal)
+                .unwrap_or(Ordering::Equal),
+            (Self::List(_), Some(Self::Numeric(_))) => Ordering::Less,
+            (Self::List(_), Some(Self::Qualifier(_))) => Ordering::Greater,
+            (Self::List(a), Some(Self::List(b))) => {
+                for i in 0..a.len().max(b.len()) {
+                    let ordering = match (a.get(i), b.get(i)) {
+                        (Some(a), b) => a.compare(b),
+                        (None, Some(b)) => b.compare(None).reverse(),
+                        (None, None) => Ordering::Equal,
+                    };
+                    if ordering != Ordering::Equal {
+                        return ordering;
+                    }
+                }
+                Ordering::Equal
+            }
+        }
+    }
+}
+
+/// Splits an optional numeric epoch (`<epoch>:`) from a Debian or RPM version.
+fn split_epoch(version: &str) -> Result<(u64, &str), VersError> {
+    match version.split_once(':') {
+        Some((epoch, rest)) if !epoch.is_empty() && epoch.bytes().all(|b| b.is_ascii_digit()) => {
+            Ok((epoch.parse().map_err(|_| invalid(version))?, rest))
+        }
+        Some(_) => Err(invalid(version)),
+        None => Ok((0, version)),
+    }
+}
+
+/// A Debian package version.
+#[derive(Debug, PartialEq, Eq)]
+struct DebVersion<'a> {
+    epoch: u64,
+    upstream: &'a str,
+    revision: &'a str,
+}
+
+impl<'a> DebVersion<'a> {
+    fn parse(version: &'a str) -> Result<Self, VersError> {
+        let (epoch, rest) = split_epoch(version)?;
+        let (upstream, revision) = rest.rsplit_once('-').unwrap_or((rest, ""));
+        let valid_upstream = upstream.starts_with(|c: char| c.is_ascii_digit())
+            && upstream.bytes().all(|b| b.is_ascii_alphanumeric() || b".+~-:".contains(&b));
+        let valid_revision = revision.bytes().all(|b| b.is_ascii_alphanumeric() || b".+~".contains(&b));
+        if !valid_upstream || !valid_revision || (rest.contains('-') && revision.is_empty()) {
+            return Err(invalid(version));
+        }
+        Ok(DebVersion { epoch, upstream, revision })
+    }
+
+    /// The sort weight of a character in the non-digit part, as defined by dpkg.
+    fn order(c: Option<&u8>) -> i32 {
+        match c {
+            None => 0,
+            Some(b'~') => -1,
+            Some(c) if c.is_ascii_digit() => 0,
+            Some(c) if c.is_ascii_alphabetic() => *c as i32,
+            Some(c) => *c as i32 + 256,
+        }
+    }
+
+    /// Compares an upstream version or revision like dpkg's `verrevcmp`.
+    fn compare_part(a: &str, b: &str) -> Ordering {
+        let (a, b) = (a.as_bytes(), b.as_bytes());
+        let (mut i, mut j) = (0, 0);
+        while i < a.len() || j < b.len() {
+            while (i < a.len() && !a[i].is_ascii_digit()) || (j < b.len() && !b[j].is_ascii_digit()) {
+                let (order_a, order_b) = (Self::order(a.get(i)), Self::order(b.get(j)));
+                if order_a != order_b {
+                    return order_a.cmp(&order_b);
+                }
+                i += 1;
+                j += 1;
+            }
+            let digits_a = a[i.min(a.len())..].iter().take_while(|c| c.is_ascii_digit()).count();
+            let digits_b = b[j.min(b.len())..].iter().take_while(|c| c.is_ascii_digit()).count();
+            let ordering = compare_numeric(
+                std::str::from_utf8(&a[i.min(a.len())..i.min(a.len()) + digits_a]).unwrap_or_default(),
+                std::str::from_utf8(&b[j.min(b.len())..j.min(b.len()) + digits_b]).unwrap_or_default(),
+            );
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+            i += digits_a;
+            j += digits_b;
+        }
+        Ordering::Equal
+    }
+}
+
+impl Ord for DebVersion<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.epoch
+            .cmp(&other.epoch)
+            .then_with(|| Self::compare_part(self.upstream, other.upstream))
+            .then_with(|| Self::compare_part(self.revision, other.revision))
+    }
+}
+
+impl PartialOrd for DebVersion<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// An RPM package version.
+#[derive(Debug, PartialEq, Eq)]
+struct RpmVersion<'a> {
+    epoch: u64,
+    version: &'a str,
+    release: Option<&'a str>,
+}
+
+impl<'a> RpmVersion<'a> {
+    fn parse(version: &'a str) -> Result<Self, VersError> {
+        let (epoch, rest) = split_epoch(version)?;
+        let (rpm_version, release) = match rest.rsplit_once('-') {
+            Some((rpm_version, release)) => (rpm_version, Some(release)),
+            None => (rest, None),
+        };
+        let valid = |part: &str| {
+            !part.is_empty() && part.bytes().all(|b| b.is_ascii_alphanumeric() || b"._+~^".contains(&b))
+        };
+        if !valid(rpm_version) || !release.is_none_or(valid) {
+            return Err(invalid(version));
+        }
+        Ok(RpmVersion { epoch, version: rpm_version, release })
+    }
+
+    /// Compares a version or release like `rpmvercmp`.
+    fn compare_part(a: &str, b: &str) -> Ordering {
+        let (a, b) = (a.as_bytes(), b.as_bytes());
+        let is_separator = |c: &u8| !c.is_ascii_alphanumeric() && *c != b'~' && *c != b'^';
+        let (mut i, mut j) = (0, 0);
+        loop {
+            while a.get(i).is_some_and(is_separator) {
+                i += 1;
+            }
+            while b.get(j).is_some_and(is_separator) {
+                j += 1;
+            }
+
+            // A tilde sorts before everything, even the end of the version
+            if a.get(i) == Some(&b'~') || b.get(j) == Some(&b'~') {
+                if a.get(i) != Some(&b'~') {
+                    return Ordering::Greater;
+                }
+                if b.get(j) != Some(&b'~') {
+                    return Ordering::Less;
+                }
+                i += 1;
+                j += 1;
+                continue;
+            }
+            // A caret sorts after the end of the version, but before everything else
+            if a.get(i) == Some(&b'^') || b.get(j) == Some(&b'^') {
+                if i >= a.len() {
+                    return Ordering::Less;
+                }
+                if j >= b.len() {
+                    return Ordering::Greater;
+                }
+                if a[i] != b'^' {
+                    return Ordering::Greater;
+                }
+                if b[j] != b'^' {
+                    return Ordering::Less;
+                }
+                i += 1;
+                j += 1;
+                continue;
+            }
+            if i >= a.len() || j >= b.len() {
+                break;
+            }
+
+            let numeric = a[i].is_ascii_digit();
+            let segment = |s: &[u8], start: usize| {
+                let len = s[start..]
+                    .iter()
+                    .take_while(|c| if numeric { c.is_ascii_digit() } else { c.is_ascii_alphabetic() })
+                    .count();
+                std::str::from_utf8(&s[start..start + len]).unwrap_or_default().to_string()
+            };
+            let (segment_a, segment_b) = (segment(a, i), segment(b, j));
+            if segment_b.is_empty() {
+                // Numeric segments are newer than alphabetic ones
+                return if numeric { Ordering::Greater } else { Ordering::Less };
+            }
+            let ordering = if numeric {
+                compare_numeric(&segment_a, &segment_b)
+            } else {
+                segment_a.cmp(&segment_b)
+            };
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+            i += segment_a.len();
+            j += segment_b.len();
+        }
+        // The version with remaining characters is newer
+        (i < a.len()).cmp(&(j < b.len()))
+    }
+}
+
+impl Ord for RpmVersion<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.epoch
+            .cmp(&other.epoch)
+            .then_with(|| Self::compare_part(self.version, other.version))
+            .then_with(|| match (self.release, other.release) {
+                (Some(a), Some(b)) => Self::compare_part(a, b),
+                _ => Ordering::Equal,
+            })
+    }
+}
+
+impl PartialOrd for RpmVersion<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf::vers::{VersError, VersionScheme};
+    use std::cmp::Ordering;
+
+    /// Asserts that the versions are in strictly ascending order.
+    fn assert_ascending(scheme: VersionScheme, versions: &[&str]) {
+        for pair in versions.windows(2) {
+            assert_eq!(Ok(Ordering::Less), scheme.compare(pair[0], pair[1]), "{} {} < {}", scheme, pair[0], pair[1]);
+            assert_eq!(Ok(Ordering::Greater), scheme.compare(pair[1], pair[0]), "{} {} > {}", scheme, pair[1], pair[0]);
+        }
+    }
+
+    fn assert_equal(scheme: VersionScheme, a: &str, b: &str) {
+        assert_eq!(Ok(Ordering::Equal), scheme.compare(a, b), "{} {} = {}", scheme, a, b);
+    }
+
+    #[test]
+    fn test_semver() {
+        assert_ascending(VersionScheme::Semver, &["1.0.0-alpha", "1.0.0-alpha.1", "1.0.0-beta", "1.0.0", "1.0.1", "1.10.0"]);
+        assert_equal(VersionScheme::Semver, "1.0.0+build.1", "1.0.0");
+        assert_eq!(Err(VersError::InvalidVersion("v1.0.0".to_string())), VersionScheme::Semver.validate("v1.0.0"));
+        assert_equal(VersionScheme::Npm, "v1.0.0", "1.0.0");
+    }
+
+    #[test]
+    fn test_pypi() {
+        assert_ascending(
+            VersionScheme::Pypi,
+            &["1.0.dev1", "1.0a1", "1.0a2.dev1", "1.0b1", "1.0rc1", "1.0", "1.0+local", "1.0.post1", "1.1", "1!0.1"],
+        );
+        assert_equal(VersionScheme::Pypi, "1.0", "1.0.0");
+        assert_equal(VersionScheme::Pypi, "1.0-alpha-1", "1.0a1");
+        assert_equal(VersionScheme::Pypi, "1.0-1", "1.0.post1");
+        assert!(VersionScheme::Pypi.validate("1.0.foo").is_err());
+    }
+
+    #[test]
+    fn test_maven() {
+        assert_ascending(
+            VersionScheme::Maven,
+            &["1-alpha-1", "1-beta-1", "1-milestone-1", "1-rc-1", "1-snapshot", "1", "1-sp", "1-abc", "1.1", "1.10"],
+        );
+        assert_equal(VersionScheme::Maven, "1.0.0", "1");
+        assert_equal(VersionScheme::Maven, "1-ga", "1");
+        assert_equal(VersionScheme::Maven, "1.0-final", "1");
+        assert_equal(VersionScheme::Maven, "1a1", "1-alpha-1");
+        assert_equal(VersionScheme::Maven, "1-cr-1", "1-rc-1");
+        // Each '-' nests a list
+        assert!(VersionScheme::Maven.validate(&"1-".repeat(99)).is_ok());
+        assert!(VersionScheme::Maven.validate(&"1-".repeat(100)).is_err());
+        assert!(VersionScheme::Maven.validate(&"1-".repeat(100_000)).is_err());
+    }
+
+    #[test]
+    fn test_deb() {
+        assert_ascending(
+            VersionScheme::Deb,
+            &["1.0~rc1", "1.0", "1.0-1", "1.0-1ubuntu1", "1.0a", "1.0+b1", "1.2", "1.10", "1:0.9"],
+        );
+        assert_equal(VersionScheme::Deb, "0:1.0-1", "1.0-1");
+        assert_equal(VersionScheme::Deb, "1.01", "1.1");
+        assert!(VersionScheme::Deb.validate("a1.0").is_err());
+        assert!(VersionScheme::Deb.validate("x:1.0").is_err());
+    }
+
+    #[test]
+    fn test_rpm() {
+        assert_ascending(
+            VersionScheme::Rpm,
+            &["1.0~rc1", "1.0", "1.0^git1", "1.0a", "1.0.1", "1.10", "1:0.9"],
+        );
+        assert_ascending(VersionScheme::Rpm, &["1.0-1.el8", "1.0-1.el9", "1.0-2.el8"]);
+        assert_equal(VersionScheme::Rpm, "1.0", "1.0-5");
+        assert_equal(VersionScheme::Rpm, "1.01", "1.1");
+        assert!(VersionScheme::Rpm.validate("1.0-").is_err());
+    }
+}
@@ -1,20 +1,27 @@
+mod output;
+
+use std::fmt::{Display, Formatter};
+use std::process::ExitCode;
 use std::str::FromStr;
 use anyhow::{bail, Result};
-use csaf_rs::csaf::csaf2_0::loader::load_document as load_document_2_0;
-use csaf_rs::csaf::csaf2_1::loader::load_document as load_document_2_1;
-use csaf_rs::csaf::validation::{validate_by_preset, validate_by_test, Validatable, ValidationPreset};
+use csaf_rs::csaf::document::{CsafVersion, VersionedCsafDocument};
+use csaf_rs::csaf::loader::{load_json, resolve_version};
+use csaf_rs::csaf::registry::test_infos;
+use csaf_rs::csaf::validation::{TestResult, TestResultStatus, ValidationPreset, ValidationReport};
+use csaf_rs::csaf::validations::test_schema::validate_csaf_schema;
 use clap::Parser;
+use output::{to_json, to_junit, to_sarif, OutputFormat};
 
 /// A validator for CSAF documents
 #[derive(Parser, Debug)]
 #[command(version, about, long_about = None)]
 struct Args {
-    #[arg()]
-    path: String,
+    #[arg(required_unless_present = "list_tests")]
+    path: Option<String>,
 
-    /// Version of CSAF to use
-    #[arg(short, long, default_value = "2.0")]
-    csaf_version: String,
+    /// Version of CSAF to use, detected from the document if omitted
+    #[arg(short, long)]
+    csaf_version: Option<String>,
 
     /// The validation preset to use
     #[arg(short, long, default_value = "basic")]
@@ -23,38 +30,276 @@ struct Args {
     /// Run only the selected tests, may be specified multiple times
     #[arg(short, long, action = clap::ArgAction::Append)]
     test_id: Vec<String>,
+
+    /// The output format of the validation report: text, json, sarif or junit
+    #[arg(short, long, default_value = "text")]
+    format: String,
+
+    /// List all available tests for the selected CSAF version and exit
+    #[arg(long)]
+    list_tests: bool,
+}
+
+/// Exit status of the validator, allowing scripts to react on the outcome without parsing the output.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum ExitStatus {
+    /// The document is valid and there are no findings at all.
+    Valid = 0,
+    /// At least one test reported an error, e.g. a mandatory test failed.
+    Invalid = 1,
+    /// The arguments are invalid (this is also used by clap).
+    UsageError = 2,
+    /// The document is valid, but there are warnings or infos.
+    ValidWithFindings = 3,
+    /// The document could not be loaded or parsed.
+    LoadError = 4,
+    /// At least one of the selected tests is unknown, but none of the known tests found an error.
+    UnknownTest = 5,
+}
+
+impl ExitStatus {
+    /// Derives the exit status from the validation report.
+    fn of(report: &ValidationReport) -> ExitStatus {
+        if !report.is_valid() {
+            ExitStatus::Invalid
+        } else if report.tests.iter().any(|t| t.status == TestResultStatus::NotImplemented) {
+            ExitStatus::UnknownTest
+        } else if report.errors().next().is_some() {
+            ExitStatus::ValidWithFindings
+        } else {
+            ExitStatus::Valid
+        }
+    }
+}
+
+/// Error for invalid arguments, which exits with [ExitStatus::UsageError].
+#[derive(Debug)]
+struct UsageError(String);
+
+impl Display for UsageError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for UsageError {}
+
+fn main() -> ExitCode {
+    let status = match run(Args::parse()) {
+        Ok(status) => status,
+        Err(err) => {
+            eprintln!("Error: {:?}", err);
+            if err.is::<UsageError>() {
+                ExitStatus::UsageError
+            } else {
+                ExitStatus::LoadError
+            }
+        }
+    };
+    ExitCode::from(status as u8)
 }
 
-fn main() -> Result<()> {
-    let args = Args::parse();
+fn run(args: Args) -> Result<ExitStatus> {
+    let version = match args.csaf_version.as_deref().map(CsafVersion::from_str) {
+        None => None,
+        Some(Ok(version)) => Some(version),
+        Some(Err(_)) => bail!(UsageError(format!("Invalid CSAF version: {}", args.csaf_version.unwrap_or_default()))),
+    };
+
+    let format = match OutputFormat::from_str(args.format.as_str()) {
+        Ok(format) => format,
+        Err(_) => bail!(UsageError(format!("Invalid output format: {}", args.format))),
+    };
+
+    let preset = match ValidationPreset::from_str(args.preset.as_str()) {
+        Ok(preset) => preset,
+        Err(_) => bail!(UsageError(format!("Invalid validation preset: {}", args.preset))),
+    };
 
-    match args.csaf_version.as_str() {
-        "2.0" => {
-            process_document(load_document_2_0(args.path.as_str())?, &args)
+    if args.list_tests {
+        match version {
+            Some(version) => list_tests(version),
+            None => {
+                list_tests(CsafVersion::V2_0);
+                println!();
+                list_tests(CsafVersion::V2_1);
+            }
         }
-        "2.1" => {
-            process_document(load_document_2_1(args.path.as_str())?, &args)
+        return Ok(ExitStatus::Valid);
+    }
+
+    // Presence is ensured by clap, unless tests are listed
+    let path = args.path.as_deref().unwrap_or_default();
+    log(format, &format!("Trying to load document {}", path));
+    let json = load_json(path)?;
+    let version = resolve_version(&json, version)?;
+    let output = Output { format, path, version };
+    match VersionedCsafDocument::from_json(json.clone(), version) {
+        Ok(document) => {
+            log(format, &format!("Successfully parsed CSAF {} document", version));
+            Ok(process_document(document, &args, preset, &output))
         }
-        _ => bail!(format!("Invalid CSAF version: {}", args.csaf_version)),
+        Err(err) => report_unparsable_document(&json, err, &output),
+    }
+}
+
+/// Where and how the validation report of a document is printed.
+struct Output<'a> {
+    format: OutputFormat,
+    path: &'a str,
+    version: CsafVersion,
+}
+
+/// Prints progress information. It goes to stderr for machine-readable formats,
+/// so that stdout only contains the report.
+fn log(format: OutputFormat, message: &str) {
+    if format == OutputFormat::Text {
+        println!("{}", message);
+    } else {
+        eprintln!("{}", message);
+    }
+}
+
+/// Prints the ID, category and title of all tests available for the given CSAF version.
+fn list_tests(version: CsafVersion) {
+    println!("Available tests for CSAF {}:", version);
+    let infos = test_infos(version);
+    let id_width = infos.iter().map(|info| info.id.len()).max().unwrap_or_default();
+    for info in infos {
+        println!("{:<id_width$} {:<12} {}", info.id, info.category.to_string(), info.title);
     }
 }
 
-fn process_document<T>(document: T, args: &Args) -> Result<()>
-where
-    T: Validatable<T>,
-{
-    if !args.test_id.is_empty() {
-        for test_id in &args.test_id {
-            println!("\nExecuting Test {}... ", test_id);
-            validate_by_test(&document, test_id.as_str());
+/// Handles a document which could not be parsed. As it is valid JSON, it is most likely
+/// not valid according to the JSON schema, in which case the schema errors are reported and
+/// the document is invalid. Otherwise, loading the document failed.
+fn report_unparsable_document(json: &serde_json::Value, err: serde_json::Error, output: &Output) -> Result<ExitStatus> {
+    let Err(errors) = validate_csaf_schema(output.version, json) else {
+        bail!("Could not parse document as CSAF {}: {}", output.version, err)
+    };
+
+    let report = ValidationReport {
+        tests: vec![TestResult {
+            test_id: "schema".to_string(),
+            status: TestResultStatus::Failure { errors },
+        }],
+    };
+    print_report(&report, output);
+    Ok(ExitStatus::of(&report))
+}
+
+/// Validates the document, prints the report and returns the resulting exit status.
+fn process_document(
+    document: VersionedCsafDocument,
+    args: &Args,
+    preset: ValidationPreset,
+    output: &Output,
+) -> ExitStatus {
+    let report = if !args.test_id.is_empty() {
+        ValidationReport {
+            tests: args
+                .test_id
+                .iter()
+                .map(|test_id| document.validate_by_test(test_id.as_str()))
+                .collect(),
         }
-        Ok(())
     } else {
-        let preset = match ValidationPreset::from_str(args.preset.as_str()) {
-            Ok(preset) => preset,
-            Err(_) => bail!(format!("Invalid validation preset: {}", args.preset)),
-        };
-        validate_by_preset(&document, preset);
-        Ok(())
+        log(output.format, &format!("Validating document with {:?} preset... \n", preset));
+        document.validate_by_preset(preset)
+    };
+
+    print_report(&report, output);
+    ExitStatus::of(&report)
+}
+
+/// Prints the validation report to stdout in the selected output format.
+fn print_report(report: &ValidationReport, output: &Output) {
+    match output.format {
+        OutputFormat::Text => print_text_report(report),
+        OutputFormat::Json => println!("{:#}", to_json(report)),
+        OutputFormat::Sarif => println!("{:#}", to_sarif(report, output.path, output.version)),
+        OutputFormat::Junit => print!("{}", to_junit(report, output.path, output.version)),
+    }
+}
+
+/// Prints a human-readable summary of the validation report to stdout.
+fn print_text_report(report: &ValidationReport) {
+    if report.tests.is_empty() {
+        println!("No tests found for preset");
+        return;
+    }
+
+    for result in &report.tests {
+        print_test_result(result);
+    }
+
+    if report.is_valid() {
+        println!("Document is valid");
+    } else {
+        println!("Document is invalid");
+    }
+}
+
+fn print_test_result(result: &TestResult) {
+    println!("Executing Test {}... ", result.test_id);
+    match &result.status {
+        TestResultStatus::Success => println!("> Test Success"),
+        TestResultStatus::Failure { errors } => {
+            for error in errors {
+                println!("> {}: {}", error.severity, error);
+            }
+        }
+        TestResultStatus::NotImplemented => {
+            println!("Test with ID {} is missing implementation", result.test_id)
+        }
+        TestResultStatus::NotApplicable => println!("> Test not applicable"),
+    }
+    println!();
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ExitStatus;
+    use csaf_rs::csaf::validation::{TestResult, TestResultStatus, ValidationError, ValidationReport, ValidationSeverity};
+
+    fn report(statuses: Vec<TestResultStatus>) -> ValidationReport {
+        ValidationReport {
+            tests: statuses
+                .into_iter()
+                .map(|status| TestResult { test_id: "6.1.1".to_string(), status })
+                .collect(),
+        }
+    }
+
+    fn failure(severity: ValidationSeverity) -> TestResultStatus {
+        TestResultStatus::Failure {
+            errors: vec![ValidationError {
+                message: "finding".to_string(),
+                instance_path: "/document".to_string(),
+                severity,
+            }],
+        }
+    }
+
+    #[test]
+    fn test_exit_status_of_report() {
+        assert_eq!(ExitStatus::Valid, ExitStatus::of(&report(vec![TestResultStatus::Success])));
+        assert_eq!(
+            ExitStatus::ValidWithFindings,
+            ExitStatus::of(&report(vec![failure(ValidationSeverity::Warning), failure(ValidationSeverity::Info)]))
+        );
+        assert_eq!(
+            ExitStatus::Invalid,
+            ExitStatus::of(&report(vec![failure(ValidationSeverity::Warning), failure(ValidationSeverity::Error)]))
+        );
+        // Errors of the known tests take precedence over unknown tests
+        assert_eq!(
+            ExitStatus::Invalid,
+            ExitStatus::of(&report(vec![failure(ValidationSeverity::Error), TestResultStatus::NotImplemented]))
+        );
+        assert_eq!(
+            ExitStatus::UnknownTest,
+            ExitStatus::of(&report(vec![failure(ValidationSeverity::Warning), TestResultStatus::NotImplemented]))
+        );
     }
 }
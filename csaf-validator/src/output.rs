@@ -0,0 +1,282 @@
+use csaf_rs::csaf::document::CsafVersion;
+use csaf_rs::csaf::registry::test_infos;
+use csaf_rs::csaf::validation::{TestResult, TestResultStatus, ValidationError, ValidationReport, ValidationSeverity};
+use serde_json::{json, Value};
+use std::fmt::Write;
+use std::str::FromStr;
+
+/// Format in which the validation report is printed.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum OutputFormat {
+    /// Human-readable text
+    Text,
+    /// JSON in the result shape of the BSI csaf_validator_lib
+    Json,
+    /// SARIF 2.1.0, e.g. for code scanning dashboards
+    Sarif,
+    /// JUnit XML, e.g. for test reporting in CI systems
+    Junit,
+}
+
+impl FromStr for OutputFormat {
+    type Err = ();
+
+    fn from_str(input: &str) -> Result<OutputFormat, Self::Err> {
+        match input {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            "sarif" => Ok(OutputFormat::Sarif),
+            "junit" => Ok(OutputFormat::Junit),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Returns whether the test was executed, i.e. it is implemented and applies to the document.
+fn was_executed(result: &TestResult) -> bool {
+    !matches!(result.status, TestResultStatus::NotImplemented | TestResultStatus::NotApplicable)
+}
+
+/// Returns the findings of a test with the given severity.
+fn findings(result: &TestResult, severity: ValidationSeverity) -> Vec<&ValidationError> {
+    result.errors().iter().filter(|e| e.severity == severity).collect()
+}
+
+/// Converts the report into the result shape of the BSI csaf_validator_lib, i.e.
+/// `{ isValid, tests: [{ name, isValid, errors, warnings, infos }] }`.
+/// Tests without implementation or not applying to the document were not executed and are omitted.
+pub fn to_json(report: &ValidationReport) -> Value {
+    let tests: Vec<Value> = report
+        .tests
+        .iter()
+        .filter(|result| was_executed(result))
+        .map(|result| {
+            let errors = findings(result, ValidationSeverity::Error);
+            json!({
+                "name": result.test_id,
+                "isValid": errors.is_empty(),
+                "errors": errors,
+                "warnings": findings(result, ValidationSeverity::Warning),
+                "infos": findings(result, ValidationSeverity::Info),
+            })
+        })
+        .collect();
+
+    json!({
+        "isValid": report.is_valid(),
+        "tests": tests,
+    })
+}
+
+/// Converts the report into a SARIF 2.1.0 log with one result per finding. Each executed test
+/// is a rule, the location of a finding is the document and its JSON pointer.
+pub fn to_sarif(report: &ValidationReport, path: &str, version: CsafVersion) -> Value {
+    let infos = test_infos(version);
+    let rules: Vec<Value> = report
+        .tests
+        .iter()
+        .filter(|result| was_executed(result))
+        .map(|result| {
+            let title = infos
+                .iter()
+                .find(|info| info.id == result.test_id)
+                .map_or(result.test_id.as_str(), |info| info.title);
+            json!({
+                "id": result.test_id,
+                "shortDescription": { "text": title },
+            })
+        })
+        .collect();
+    let results: Vec<Value> = report
+        .tests
+        .iter()
+        .flat_map(|result| result.errors().iter().map(move |error| (result, error)))
+        .map(|(result, error)| {
+            json!({
+                "ruleId": result.test_id,
+                "level": match error.severity {
+                    ValidationSeverity::Error => "error",
+                    ValidationSeverity::Warning => "warning",
+                    ValidationSeverity::Info => "note",
+                },
+                "message": { "text": error.message },
+                "locations": [{
+                    "physicalLocation": { "artifactLocation": { "uri": path } },
+                    "logicalLocations": [{ "fullyQualifiedName": error.instance_path }],
+                }],
+            })
+        })
+        .collect();
+
+    json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "csaf-validator",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "informationUri": "https://github.com/csaf-rs/csaf",
+                    "rules": rules,
+                },
+            },
+            "results": results,
+        }],
+    })
+}
+
+/// Escapes the characters which are not allowed in XML attributes and text.
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// Converts the report into a JUnit XML test suite with one test case per test. Tests with
+/// errors fail, tests without implementation or not applying to the document are skipped and
+/// warnings and infos are reported as output of the test case.
+pub fn to_junit(report: &ValidationReport, path: &str, version: CsafVersion) -> String {
+    let count = |f: fn(&TestResult) -> bool| report.tests.iter().filter(|result| f(result)).count();
+    let tests = report.tests.len();
+    let failures = count(|result| !findings(result, ValidationSeverity::Error).is_empty());
+    let skipped = count(|result| !was_executed(result));
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(
+        xml,
+        "<testsuites name=\"csaf-validator\" tests=\"{}\" failures=\"{}\" skipped=\"{}\">",
+        tests, failures, skipped
+    );
+    let _ = writeln!(
+        xml,
+        "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" skipped=\"{}\">",
+        escape_xml(path),
+        tests,
+        failures,
+        skipped
+    );
+    for result in &report.tests {
+        let _ = write!(
+            xml,
+            "    <testcase name=\"{}\" classname=\"csaf-{}\"",
+            escape_xml(&result.test_id),
+            version
+        );
+        let errors = findings(result, ValidationSeverity::Error);
+        let notes: Vec<&ValidationError> = result
+            .errors()
+            .iter()
+            .filter(|e| e.severity != ValidationSeverity::Error)
+            .collect();
+        if was_executed(result) && errors.is_empty() && notes.is_empty() {
+            xml.push_str("/>\n");
+            continue;
+        }
+
+        xml.push_str(">\n");
+        match result.status {
+            TestResultStatus::NotImplemented => xml.push_str("      <skipped message=\"Test is not implemented\"/>\n"),
+            TestResultStatus::NotApplicable => {
+                xml.push_str("      <skipped message=\"Test does not apply to this document\"/>\n")
+            }
+            _ => {}
+        }
+        if !errors.is_empty() {
+            let _ = writeln!(
+                xml,
+                "      <failure message=\"{} error(s)\">{}</failure>",
+                errors.len(),
+                escape_xml(&format_findings(&errors))
+            );
+        }
+        if !notes.is_empty() {
+            let _ = writeln!(xml, "      <system-out>{}</system-out>", escape_xml(&format_findings(&notes)));
+        }
+        xml.push_str("    </testcase>\n");
+    }
+    xml.push_str("  </testsuite>\n</testsuites>\n");
+    xml
+}
+
+/// Formats findings as one line each, like the text output.
+fn format_findings(findings: &[&ValidationError]) -> String {
+    findings
+        .iter()
+        .map(|e| format!("{}: {} at {}", e.severity, e.message, e.instance_path))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::output::{to_json, to_junit, to_sarif};
+    use csaf_rs::csaf::document::CsafVersion;
+    use csaf_rs::csaf::validation::{TestResult, TestResultStatus, ValidationError, ValidationReport, ValidationSeverity};
+    use serde_json::json;
+
+    fn report() -> ValidationReport {
+        let finding = |message: &str, severity| ValidationError {
+            message: message.to_string(),
+            instance_path: "/document/title".to_string(),
+            severity,
+        };
+        ValidationReport {
+            tests: vec![
+                TestResult { test_id: "schema".to_string(), status: TestResultStatus::Success },
+                TestResult {
+                    test_id: "6.1.1".to_string(),
+                    status: TestResultStatus::Failure {
+                        errors: vec![finding("<broken> & \"quoted\"", ValidationSeverity::Error)],
+                    },
+                },
+                TestResult {
+                    test_id: "6.2.1".to_string(),
+                    status: TestResultStatus::Failure {
+                        errors: vec![finding("Unused", ValidationSeverity::Warning)],
+                    },
+                },
+                TestResult { test_id: "6.9.9".to_string(), status: TestResultStatus::NotImplemented },
+                TestResult { test_id: "6.1.34".to_string(), status: TestResultStatus::NotApplicable },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_json() {
+        let json = to_json(&report());
+        assert_eq!(json!(false), json["isValid"]);
+        assert_eq!(3, json["tests"].as_array().unwrap().len());
+        assert_eq!(json!("6.1.1"), json["tests"][1]["name"]);
+        assert_eq!(json!(false), json["tests"][1]["isValid"]);
+        assert_eq!(json!("/document/title"), json["tests"][1]["errors"][0]["instancePath"]);
+        assert_eq!(json!(true), json["tests"][2]["isValid"]);
+        assert_eq!(json!("Unused"), json["tests"][2]["warnings"][0]["message"]);
+    }
+
+    #[test]
+    fn test_sarif() {
+        let sarif = to_sarif(&report(), "doc.json", CsafVersion::V2_0);
+        let run = &sarif["runs"][0];
+        assert_eq!(json!("Missing Definition of Product ID"), run["tool"]["driver"]["rules"][1]["shortDescription"]["text"]);
+        assert_eq!(2, run["results"].as_array().unwrap().len());
+        assert_eq!(json!("error"), run["results"][0]["level"]);
+        assert_eq!(json!("warning"), run["results"][1]["level"]);
+        assert_eq!(
+            json!("/document/title"),
+            run["results"][0]["locations"][0]["logicalLocations"][0]["fullyQualifiedName"]
+        );
+    }
+
+    #[test]
+    fn test_junit() {
+        let junit = to_junit(&report(), "doc.json", CsafVersion::V2_0);
+        assert!(junit.contains("tests=\"5\" failures=\"1\" skipped=\"2\""));
+        assert!(junit.contains("<testcase name=\"schema\" classname=\"csaf-2.0\"/>"));
+        assert!(junit.contains("&lt;broken&gt; &amp; &quot;quoted&quot;"));
+        assert!(junit.contains("<system-out>Warning: Unused at /document/title</system-out>"));
+        assert!(junit.contains("<skipped message=\"Test is not implemented\"/>"));
+        assert!(junit.contains("<skipped message=\"Test does not apply to this document\"/>"));
+    }
+}